@@ -1,10 +1,13 @@
 mod list_files;
+mod search_index;
 
 use self::list_files::ListFiles;
+use self::search_index::{BModified, SearchIndex};
 use anyhow::{bail, Context};
 use entity::{BId, BMeta};
 use limited_date_time::{Date, DateTime, Instant, OffsetDateTime, Time, TimeZoneOffset};
 use std::{
+    collections::BTreeSet,
     ffi::OsStr,
     fs::{self, File},
     io::{BufReader, Read},
@@ -15,7 +18,10 @@ use use_case::BRepository;
 
 type DateTimeRange = (Instant, Instant);
 
-#[derive(Debug, serde::Deserialize, serde::Serialize)]
+const SEARCH_INDEX_FILE_NAME: &str = ".search-index.json";
+const TRASH_DIR_NAME: &str = "trash";
+
+#[derive(Debug, serde::Deserialize)]
 struct BMetaJson {
     tags: Option<Vec<String>>,
     title: Option<String>,
@@ -24,6 +30,7 @@ struct BMetaJson {
 pub struct FsBRepository {
     data_dir: PathBuf,
     time_zone_offset: TimeZoneOffset,
+    use_search_index: bool,
 }
 
 fn to_dir_components(id: &BId) -> Vec<String> {
@@ -37,6 +44,36 @@ fn to_dir_components(id: &BId) -> Vec<String> {
 }
 
 impl BRepository for FsBRepository {
+    fn delete(&self, id: BId) -> anyhow::Result<()> {
+        let meta_path_buf = self.to_meta_path_buf(&id);
+        if !meta_path_buf.exists() {
+            bail!("b meta not found: {}", id);
+        }
+        // flow/YYYY/MM/DD -> trash/YYYY/MM/DD
+        let trash_dir = to_dir_components(&id)
+            .into_iter()
+            .skip(1)
+            .fold(self.data_dir.join(TRASH_DIR_NAME), |acc, x| acc.join(x));
+        let mut renames = vec![];
+        for path_buf in [self.to_content_path_buf(&id), meta_path_buf] {
+            if !path_buf.exists() {
+                continue;
+            }
+            let file_name = path_buf.file_name().with_context(|| "invalid file_name")?;
+            let trash_path_buf = trash_dir.join(file_name);
+            // fs::rename overwrites the trashed file
+            if trash_path_buf.exists() {
+                bail!("already exists in trash: {}", trash_path_buf.display());
+            }
+            renames.push((path_buf, trash_path_buf));
+        }
+        fs::create_dir_all(trash_dir.as_path())?;
+        for (from, to) in renames {
+            fs::rename(from.as_path(), to.as_path())?;
+        }
+        Ok(())
+    }
+
     // TODO: hide path ?
     fn find_by_content_path(&self, path: &Path) -> anyhow::Result<BId> {
         // TODO: using fs
@@ -106,6 +143,20 @@ impl BRepository for FsBRepository {
         Ok(bids)
     }
 
+    fn find_candidate_ids(&self, query: &query::Query) -> anyhow::Result<Vec<BId>> {
+        let bids = self
+            .find_ids_by_query(query.clone())?
+            .collect::<anyhow::Result<Vec<BId>>>()?;
+        if !self.use_search_index {
+            return Ok(bids);
+        }
+        let index = self.load_search_index(query, &bids)?;
+        Ok(bids
+            .into_iter()
+            .filter(|bid| index.may_match(bid, query))
+            .collect())
+    }
+
     fn find_meta(&self, id: BId) -> anyhow::Result<Option<BMeta>> {
         let meta_path_buf = self.to_meta_path_buf(&id);
         let content_path_buf = self.to_content_path_buf(&id);
@@ -138,6 +189,31 @@ impl BRepository for FsBRepository {
         }))
     }
 
+    fn save_content(&self, id: BId, content: &str) -> anyhow::Result<()> {
+        if !self.to_meta_path_buf(&id).exists() {
+            bail!("b meta not found: {}", id);
+        }
+        fs::write(self.to_content_path_buf(&id), content)?;
+        Ok(())
+    }
+
+    fn save_tags(&self, id: BId, tags: &[String]) -> anyhow::Result<()> {
+        self.update_meta_json(id, |json| {
+            json.insert("tags".to_string(), serde_json::json!(tags));
+        })
+    }
+
+    fn save_title(&self, id: BId, title: Option<&str>) -> anyhow::Result<()> {
+        self.update_meta_json(id, |json| match title {
+            Some(title) => {
+                json.insert("title".to_string(), serde_json::json!(title));
+            }
+            None => {
+                json.shift_remove("title");
+            }
+        })
+    }
+
     fn to_content_path_buf(&self, id: &BId) -> PathBuf {
         self.to_meta_path_buf(id).with_extension("md")
     }
@@ -157,6 +233,15 @@ impl FsBRepository {
         Self {
             data_dir,
             time_zone_offset,
+            use_search_index: false,
+        }
+    }
+
+    /// Uses the search index (`DATA_DIR/.search-index.json`) to narrow the candidates.
+    pub fn with_search_index(self) -> Self {
+        Self {
+            use_search_index: true,
+            ..self
         }
     }
 
@@ -199,6 +284,83 @@ impl FsBRepository {
             }))
     }
 
+    /// Updates the meta file, keeping the other fields, the key order and the pretty-printing.
+    fn update_meta_json<F: FnOnce(&mut serde_json::Map<String, serde_json::Value>)>(
+        &self,
+        id: BId,
+        f: F,
+    ) -> anyhow::Result<()> {
+        let meta_path_buf = self.to_meta_path_buf(&id);
+        if !meta_path_buf.exists() {
+            bail!("b meta not found: {}", id);
+        }
+        let json_string = fs::read_to_string(meta_path_buf.as_path())?;
+        let mut json = match serde_json::from_str::<serde_json::Value>(json_string.as_str())? {
+            serde_json::Value::Object(map) => map,
+            _ => bail!("b meta is not an object: {}", id),
+        };
+        f(&mut json);
+        let pretty = json_string.trim_end().contains('\n');
+        let mut updated = if pretty {
+            serde_json::to_string_pretty(&json)?
+        } else {
+            serde_json::to_string(&json)?
+        };
+        if pretty || json_string.ends_with('\n') {
+            updated.push('\n');
+        }
+        fs::write(meta_path_buf.as_path(), updated)?;
+        Ok(())
+    }
+
+    fn find_modified(&self, id: &BId) -> anyhow::Result<BModified> {
+        let meta = fs::metadata(self.to_meta_path_buf(id))?.modified()?;
+        let content_path_buf = self.to_content_path_buf(id);
+        let content = if content_path_buf.is_file() {
+            Some(fs::metadata(content_path_buf)?.modified()?)
+        } else {
+            None
+        };
+        Ok(BModified { meta, content })
+    }
+
+    /// Loads the search index and updates the entries in `bids` modified since the last call.
+    fn load_search_index(&self, query: &query::Query, bids: &[BId]) -> anyhow::Result<SearchIndex> {
+        let path = self.data_dir.join(SEARCH_INDEX_FILE_NAME);
+        let loaded = if path.is_file() {
+            // rebuilds the broken or old index
+            SearchIndex::from_str(fs::read_to_string(path.as_path())?.as_str()).ok()
+        } else {
+            None
+        };
+        let mut updated = loaded.is_none();
+        let mut index = loaded.unwrap_or_default();
+
+        // removes the deleted entries in the date range of the query
+        let (since, until) = query.naive_date_time_range();
+        let since = i64::from(OffsetDateTime::new(since, self.time_zone_offset).instant());
+        let until = i64::from(OffsetDateTime::new(until, self.time_zone_offset).instant());
+        let found = bids.iter().copied().collect::<BTreeSet<BId>>();
+        updated |= index
+            .retain(|bid| found.contains(bid) || !(since..=until).contains(&bid.to_timestamp()));
+        for bid in bids {
+            let modified = self.find_modified(bid)?;
+            if index.is_fresh(bid, &modified) {
+                continue;
+            }
+            if let Some(bmeta) = self.find_meta(*bid)? {
+                let content = self.find_content(*bid)?.unwrap_or_default();
+                index.insert(&bmeta, content.as_str(), modified);
+                updated = true;
+            }
+        }
+
+        if updated {
+            fs::write(path.as_path(), index.to_json_string()?)?;
+        }
+        Ok(index)
+    }
+
     fn dirs(&self, date_time_range: &DateTimeRange) -> Vec<PathBuf> {
         let (start, end) = date_time_range;
         let dates = if start == end {
@@ -262,4 +424,133 @@ mod tests {
             .unwrap();
         assert_eq!(repository.to_meta_path_buf(&bid), meta_path_buf);
     }
+
+    #[test]
+    fn write_test() -> anyhow::Result<()> {
+        let time_zone_offset = TimeZoneOffset::from_str("+09:00")?;
+        let temp_dir = tempfile::tempdir()?;
+        let repository = FsBRepository::new(temp_dir.path().to_path_buf(), time_zone_offset);
+        let bid = BId::from_str("20210203T000000Z")?;
+        let meta_path_buf = repository.to_meta_path_buf(&bid);
+        let content_path_buf = repository.to_content_path_buf(&bid);
+        assert!(repository.save_content(bid, "content").is_err());
+        fs::create_dir_all(meta_path_buf.parent().context("no parent")?)?;
+        fs::write(
+            meta_path_buf.as_path(),
+            r#"{"created_at":"2021-02-03T09:00:00+09:00","tags":["a"]}"#,
+        )?;
+
+        repository.save_content(bid, "Hello, world!")?;
+        assert_eq!(
+            repository.find_content(bid)?,
+            Some("Hello, world!".to_string())
+        );
+        assert_eq!(
+            repository.find_meta(bid)?.context("no meta")?.title,
+            "Hello, world!"
+        );
+
+        repository.save_tags(bid, &["b".to_string(), "c".to_string()])?;
+        repository.save_title(bid, Some("TITLE"))?;
+        assert_eq!(
+            repository.find_meta(bid)?,
+            Some(BMeta {
+                id: bid,
+                tags: vec!["b".to_string(), "c".to_string()],
+                title: "TITLE".to_string(),
+            })
+        );
+        assert_eq!(
+            fs::read_to_string(meta_path_buf.as_path())?,
+            r#"{"created_at":"2021-02-03T09:00:00+09:00","tags":["b","c"],"title":"TITLE"}"#
+        );
+        repository.save_title(bid, None)?;
+        assert_eq!(
+            repository.find_meta(bid)?.context("no meta")?.title,
+            "Hello, world!"
+        );
+
+        repository.delete(bid)?;
+        assert_eq!(repository.find_meta(bid)?, None);
+        assert_eq!(repository.find_content(bid)?, None);
+        assert!(!content_path_buf.exists());
+        let trash_dir = temp_dir
+            .path()
+            .join("trash")
+            .join("2021")
+            .join("02")
+            .join("03");
+        assert!(trash_dir.join("20210203T000000Z.json").is_file());
+        assert!(trash_dir.join("20210203T000000Z.md").is_file());
+        assert!(repository.delete(bid).is_err());
+
+        fs::write(meta_path_buf.as_path(), "{}")?;
+        assert!(repository.delete(bid).is_err());
+        assert!(meta_path_buf.exists());
+        assert_eq!(
+            fs::read_to_string(trash_dir.join("20210203T000000Z.json"))?,
+            r#"{"created_at":"2021-02-03T09:00:00+09:00","tags":["b","c"]}"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn save_pretty_meta_test() -> anyhow::Result<()> {
+        let time_zone_offset = TimeZoneOffset::from_str("+09:00")?;
+        let temp_dir = tempfile::tempdir()?;
+        let repository = FsBRepository::new(temp_dir.path().to_path_buf(), time_zone_offset);
+        let bid = BId::from_str("20210203T000000Z")?;
+        let meta_path_buf = repository.to_meta_path_buf(&bid);
+        fs::create_dir_all(meta_path_buf.parent().context("no parent")?)?;
+        fs::write(
+            meta_path_buf.as_path(),
+            [
+                "{",
+                r#"  "title": "TITLE","#,
+                r#"  "created_at": "2021-02-03T09:00:00+09:00","#,
+                r#"  "tags": ["#,
+                r#"    "a""#,
+                "  ],",
+                r#"  "pinned": true"#,
+                "}",
+                "",
+            ]
+            .join("\n"),
+        )?;
+
+        repository.save_tags(bid, &["b".to_string()])?;
+        repository.save_title(bid, Some("TITLE2"))?;
+        assert_eq!(
+            fs::read_to_string(meta_path_buf.as_path())?,
+            [
+                "{",
+                r#"  "title": "TITLE2","#,
+                r#"  "created_at": "2021-02-03T09:00:00+09:00","#,
+                r#"  "tags": ["#,
+                r#"    "b""#,
+                "  ],",
+                r#"  "pinned": true"#,
+                "}",
+                "",
+            ]
+            .join("\n")
+        );
+
+        repository.save_title(bid, None)?;
+        assert_eq!(
+            fs::read_to_string(meta_path_buf.as_path())?,
+            [
+                "{",
+                r#"  "created_at": "2021-02-03T09:00:00+09:00","#,
+                r#"  "tags": ["#,
+                r#"    "b""#,
+                "  ],",
+                r#"  "pinned": true"#,
+                "}",
+                "",
+            ]
+            .join("\n")
+        );
+        Ok(())
+    }
 }
@@ -96,9 +96,11 @@ impl ListFiles {
             (Some(yyyy), Some(mm), Some(dd)) => OptionalDate::from_yyyymmdd(yyyy, mm, dd),
         };
 
+        // the directories are in UTC (see `to_dir_components`)
+        let utc = TimeZoneOffset::from_str("+00:00")?;
         let (since, until) = optional_date.date_time_range()?;
-        let since = OffsetDateTime::new(since, self.query_time_zone_offset).instant();
-        let until = OffsetDateTime::new(until, self.query_time_zone_offset).instant();
+        let since = OffsetDateTime::new(since, utc).instant();
+        let until = OffsetDateTime::new(until, utc).instant();
         Ok((since, until))
     }
 
@@ -230,6 +232,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn list_files_query_time_zone_offset_test() -> anyhow::Result<()> {
+        let time_zone_offset = TimeZoneOffset::from_str("+09:00")?;
+        let temp_dir = tempdir()?;
+        let root_dir = temp_dir.path().join("data_dir").join("flow");
+        // 2021-02-03T00:30:00+09:00 is in the 2021-02-02 (UTC) directory
+        let d20210202 = root_dir.join("2021").join("02").join("02");
+        fs::create_dir_all(d20210202.as_path())?;
+        let f20210202 = d20210202.as_path().join("20210202T153000Z.json");
+        fs::write(f20210202.as_path(), "{}")?;
+        let path_bufs = ListFiles::new(
+            root_dir.as_path(),
+            "date:2021-02-03".parse()?,
+            time_zone_offset,
+        )?
+        .collect::<io::Result<Vec<PathBuf>>>()?;
+        assert_eq!(path_bufs, vec![f20210202]);
+        let path_bufs = ListFiles::new(
+            root_dir.as_path(),
+            "date:2021-02-02".parse()?,
+            time_zone_offset,
+        )?
+        .collect::<io::Result<Vec<PathBuf>>>()?;
+        assert!(path_bufs.is_empty());
+        Ok(())
+    }
+
     #[test]
     fn list_files_query_test() -> anyhow::Result<()> {
         // TODO: TimeZoneOffset::system_default()
@@ -0,0 +1,234 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr,
+    time::SystemTime,
+};
+
+use entity::{BId, BMeta};
+use query::{tokenize, Query, QueryParam};
+use serde::{Deserialize, Serialize};
+
+const VERSION: u32 = 2;
+
+/// The modified times of the meta file and the content file of a b.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct BModified {
+    pub meta: SystemTime,
+    pub content: Option<SystemTime>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct IndexedB {
+    modified: BModified,
+    tags: BTreeSet<String>,
+    title_terms: BTreeSet<String>,
+    content_terms: BTreeSet<String>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SearchIndex {
+    bs: BTreeMap<BId, IndexedB>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct SearchIndexJson {
+    version: u32,
+    bs: Vec<IndexedBJson>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct IndexedBJson {
+    id: String,
+    modified: BModified,
+    tags: BTreeSet<String>,
+    title_terms: BTreeSet<String>,
+    content_terms: BTreeSet<String>,
+}
+
+impl FromStr for SearchIndex {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = serde_json::from_str::<SearchIndexJson>(s)?;
+        if json.version != VERSION {
+            anyhow::bail!("unsupported search index version: {}", json.version);
+        }
+        let mut bs = BTreeMap::new();
+        for b in json.bs {
+            bs.insert(
+                BId::from_str(b.id.as_str())?,
+                IndexedB {
+                    modified: b.modified,
+                    tags: b.tags,
+                    title_terms: b.title_terms,
+                    content_terms: b.content_terms,
+                },
+            );
+        }
+        Ok(Self { bs })
+    }
+}
+
+impl SearchIndex {
+    pub fn to_json_string(&self) -> anyhow::Result<String> {
+        let json = SearchIndexJson {
+            version: VERSION,
+            bs: self
+                .bs
+                .iter()
+                .map(|(bid, indexed)| IndexedBJson {
+                    id: bid.to_string(),
+                    modified: indexed.modified,
+                    tags: indexed.tags.clone(),
+                    title_terms: indexed.title_terms.clone(),
+                    content_terms: indexed.content_terms.clone(),
+                })
+                .collect(),
+        };
+        Ok(serde_json::to_string(&json)?)
+    }
+
+    pub fn is_fresh(&self, bid: &BId, modified: &BModified) -> bool {
+        self.bs
+            .get(bid)
+            .map(|indexed| &indexed.modified == modified)
+            .unwrap_or(false)
+    }
+
+    pub fn insert(&mut self, bmeta: &BMeta, content: &str, modified: BModified) {
+        self.bs.insert(
+            bmeta.id,
+            IndexedB {
+                modified,
+                tags: bmeta.tags.iter().cloned().collect(),
+                title_terms: tokenize(bmeta.title.as_str()),
+                content_terms: tokenize(content),
+            },
+        );
+    }
+
+    /// Removes the bs not satisfying `f` and returns `true` if removed.
+    pub fn retain<F: FnMut(&BId) -> bool>(&mut self, mut f: F) -> bool {
+        let len = self.bs.len();
+        self.bs.retain(|bid, _| f(bid));
+        self.bs.len() != len
+    }
+
+    /// Returns `false` if the b never matches the query.
+    ///
+    /// Only the tags and the words required by the top-level params are checked.
+    /// The negated params and the `OR` groups are left to the caller.
+    pub fn may_match(&self, bid: &BId, query: &Query) -> bool {
+        let indexed = match self.bs.get(bid) {
+            Some(indexed) => indexed,
+            None => return true,
+        };
+        query.iter().all(|param| match param {
+            QueryParam::Tag(tag) => indexed.tags.contains(tag.as_str()),
+            QueryParam::Text(text) if text.is_phrase() => {
+                // the words at the both ends of the phrase may be a part of the longer words
+                let inner = text
+                    .as_str()
+                    .trim()
+                    .trim_matches(|c: char| c.is_ascii_alphanumeric());
+                let tokens = tokenize(inner);
+                !text.as_str().trim().is_empty()
+                    && (tokens.is_subset(&indexed.title_terms)
+                        || tokens.is_subset(&indexed.content_terms))
+            }
+            QueryParam::Text(text) => {
+                let tokens = tokenize(text.as_str());
+                // a term without words (e.g. `"!!"`) matches nothing
+                !tokens.is_empty()
+                    && tokens.iter().all(|t| {
+                        indexed.title_terms.contains(t) || indexed.content_terms.contains(t)
+                    })
+            }
+            QueryParam::Title(title) => {
+                let tokens = tokenize(title.as_str());
+                !tokens.is_empty() && tokens.iter().all(|t| indexed.title_terms.contains(t))
+            }
+            QueryParam::Date(_) | QueryParam::Not(_) | QueryParam::Or(_) => true,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::*;
+
+    fn bmeta(id: &str, tags: &[&str], title: &str) -> anyhow::Result<BMeta> {
+        Ok(BMeta {
+            id: BId::from_str(id)?,
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            title: title.to_string(),
+        })
+    }
+
+    fn modified(meta: u64, content: Option<u64>) -> BModified {
+        BModified {
+            meta: UNIX_EPOCH + Duration::from_secs(meta),
+            content: content.map(|secs| UNIX_EPOCH + Duration::from_secs(secs)),
+        }
+    }
+
+    #[test]
+    fn may_match_test() -> anyhow::Result<()> {
+        let mut index = SearchIndex::default();
+        let b1 = bmeta("20210203T000000Z", &["diary"], "Rust入門")?;
+        let b2 = bmeta("20210204T000000Z", &["memo"], "TITLE")?;
+        index.insert(&b1, "Hello, World!", modified(1, Some(1)));
+        index.insert(&b2, "日本語の文章", modified(1, Some(1)));
+        let f = |query: &str| -> anyhow::Result<Vec<BId>> {
+            let query = Query::from_str(query)?;
+            Ok([b1.id, b2.id]
+                .into_iter()
+                .filter(|bid| index.may_match(bid, &query))
+                .collect())
+        };
+        assert_eq!(f("")?, vec![b1.id, b2.id]);
+        assert_eq!(f("tag:diary")?, vec![b1.id]);
+        assert_eq!(f("rust hello")?, vec![b1.id]);
+        assert_eq!(f("日本語")?, vec![b2.id]);
+        assert_eq!(f("title:hello")?, Vec::<BId>::new());
+        assert_eq!(f("title:入門")?, vec![b1.id]);
+        assert_eq!(f("!!")?, Vec::<BId>::new());
+        assert_eq!(f("title:!!")?, Vec::<BId>::new());
+        assert_eq!(f("-tag:diary hello OR 日本")?, vec![b1.id, b2.id]);
+        assert_eq!(f("\"ello, wor\"")?, vec![b1.id, b2.id]);
+        assert_eq!(f("\"st入門\"")?, vec![b1.id]);
+        assert_eq!(f("\"本語の文\"")?, vec![b2.id]);
+        assert_eq!(f("\"the quick brown fox\"")?, Vec::<BId>::new());
+        assert_eq!(f("\"hello 日本\"")?, vec![b2.id]);
+        assert_eq!(f("\" \"")?, Vec::<BId>::new());
+        // not indexed
+        let b3 = BId::from_str("20210205T000000Z")?;
+        assert!(index.may_match(&b3, &Query::from_str("tag:diary")?));
+        Ok(())
+    }
+
+    #[test]
+    fn index_test() -> anyhow::Result<()> {
+        let mut index = SearchIndex::default();
+        let b1 = bmeta("20210203T000000Z", &[], "TITLE1")?;
+        assert!(!index.is_fresh(&b1.id, &modified(1, None)));
+        index.insert(&b1, "CONTENT1", modified(1, None));
+        assert!(index.is_fresh(&b1.id, &modified(1, None)));
+        assert!(!index.is_fresh(&b1.id, &modified(1, Some(1))));
+        assert!(!index.is_fresh(&b1.id, &modified(2, None)));
+
+        let deserialized = SearchIndex::from_str(index.to_json_string()?.as_str())?;
+        assert_eq!(deserialized, index);
+        assert!(SearchIndex::from_str(r#"{"version":1,"entries":{}}"#).is_err());
+        assert!(SearchIndex::from_str(r#"{"version":2,"bs":[]}"#)?
+            .bs
+            .is_empty());
+
+        assert!(!index.retain(|bid| bid == &b1.id));
+        assert!(index.retain(|_| false));
+        assert!(!index.is_fresh(&b1.id, &modified(1, None)));
+        Ok(())
+    }
+}
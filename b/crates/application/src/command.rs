@@ -1,7 +1,15 @@
+mod edit;
 mod list;
 mod new;
+mod retitle;
+mod rm;
+mod tag;
 mod view;
 
+pub use self::edit::edit;
 pub use self::list::list;
 pub use self::new::new;
+pub use self::retitle::retitle;
+pub use self::rm::rm;
+pub use self::tag::{tag_add, tag_remove};
 pub use self::view::view;
@@ -0,0 +1,35 @@
+use anyhow::{bail, Context};
+use entity::BId;
+use std::{env, fs, io::Write, process::Command};
+use use_case::{EditUseCase, HasEditUseCase};
+
+pub fn edit<T: HasEditUseCase>(app: &T, id: BId) -> anyhow::Result<()> {
+    app.edit_use_case().handle(id, |content| {
+        // e.g. `vim` or `code --wait`
+        let editor = env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+        let mut args = editor.split_whitespace();
+        let program = args.next().context("EDITOR is empty")?;
+        let mut file = tempfile::Builder::new()
+            .prefix(format!("b-{}-", id).as_str())
+            .suffix(".md")
+            .tempfile()?;
+        file.write_all(content.as_bytes())?;
+        // closes the file. it is removed on drop
+        let path = file.into_temp_path();
+        let status = Command::new(program)
+            .args(args)
+            .arg(&path)
+            .status()
+            .with_context(|| format!("{} cannot be executed", program))?;
+        if !status.success() {
+            // keeps the edits
+            let path = path.keep()?;
+            bail!(
+                "{} exited with an error. the file is kept: {}",
+                program,
+                path.display()
+            );
+        }
+        Ok(fs::read_to_string(&path)?)
+    })
+}
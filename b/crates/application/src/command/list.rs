@@ -1,8 +1,9 @@
 use anyhow::anyhow;
 use entity::BMeta;
 use limited_date_time::{OffsetDateTime, TimeZoneOffset};
+use query::Query;
 use std::{io, path::PathBuf, str::FromStr};
-use use_case::{BRepository, HasBRepository, HasListUseCase, ListUseCase, Query};
+use use_case::{BRepository, HasBRepository, HasListUseCase, ListUseCase};
 
 #[derive(Debug, Eq, PartialEq, serde::Serialize)]
 struct BOutput {
@@ -0,0 +1,6 @@
+use entity::BId;
+use use_case::{HasRetitleUseCase, RetitleUseCase};
+
+pub fn retitle<T: HasRetitleUseCase>(app: &T, id: BId, title: String) -> anyhow::Result<()> {
+    app.retitle_use_case().handle(id, title.as_str())
+}
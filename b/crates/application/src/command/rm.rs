@@ -0,0 +1,6 @@
+use entity::BId;
+use use_case::{DeleteUseCase, HasDeleteUseCase};
+
+pub fn rm<T: HasDeleteUseCase>(app: &T, id: BId) -> anyhow::Result<()> {
+    app.delete_use_case().handle(id)
+}
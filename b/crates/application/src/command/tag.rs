@@ -0,0 +1,10 @@
+use entity::BId;
+use use_case::{HasTagUseCase, TagUseCase};
+
+pub fn tag_add<T: HasTagUseCase>(app: &T, id: BId, tags: Vec<String>) -> anyhow::Result<()> {
+    app.tag_use_case().add(id, &tags)
+}
+
+pub fn tag_remove<T: HasTagUseCase>(app: &T, id: BId, tags: Vec<String>) -> anyhow::Result<()> {
+    app.tag_use_case().remove(id, &tags)
+}
@@ -7,7 +7,10 @@ use config::{Config, ConfigRepository};
 use entity::BId;
 use limited_date_time::TimeZoneOffset;
 use std::{io, path::PathBuf, str::FromStr};
-use use_case::{HasBRepository, HasListUseCase, HasViewUseCase};
+use use_case::{
+    HasBRepository, HasDeleteUseCase, HasEditUseCase, HasListUseCase, HasRetitleUseCase,
+    HasTagUseCase, HasViewUseCase,
+};
 
 #[derive(Debug, clap::Parser)]
 struct Opt {
@@ -22,10 +25,20 @@ enum Subcommand {
         #[arg(name = "SHELL", help = "the shell", value_enum)]
         shell: Shell,
     },
+    /// Edits the content of the b file with $EDITOR
+    Edit {
+        #[arg(name = "BID")]
+        id: BId,
+    },
     /// Lists b files
     List {
+        /// Uses and updates the search index in the data directory
+        #[arg(long)]
+        index: bool,
         #[arg(long)]
         json: bool,
+        /// e.g. `2021-02-03 tag:abc -tag:def title:abc rust OR go "hello world"` (a quoted text is a phrase)
+        #[arg(allow_hyphen_values = true)]
         query: String,
     },
     /// Creates a new file
@@ -37,6 +50,24 @@ enum Subcommand {
         #[arg(short, long)]
         template: PathBuf,
     },
+    /// Changes the title of the b file
+    Retitle {
+        #[arg(name = "BID")]
+        id: BId,
+        /// The new title (an empty title uses the beginning of the content)
+        #[arg(name = "TITLE")]
+        title: String,
+    },
+    /// Moves the b file to the trash directory
+    Rm {
+        #[arg(name = "BID")]
+        id: BId,
+    },
+    /// Adds or removes the tags of the b file
+    Tag {
+        #[command(subcommand)]
+        subcommand: TagSubcommand,
+    },
     /// Views the b file
     View {
         #[arg(name = "BID")]
@@ -50,6 +81,24 @@ enum Subcommand {
     },
 }
 
+#[derive(Debug, clap::Subcommand)]
+enum TagSubcommand {
+    /// Adds the tags
+    Add {
+        #[arg(name = "BID")]
+        id: BId,
+        #[arg(name = "TAG", required = true)]
+        tags: Vec<String>,
+    },
+    /// Removes the tags
+    Remove {
+        #[arg(name = "BID")]
+        id: BId,
+        #[arg(name = "TAG", required = true)]
+        tags: Vec<String>,
+    },
+}
+
 // FIXME:
 struct App {
     brepository: FsBRepository,
@@ -63,6 +112,22 @@ impl HasBRepository for App {
     }
 }
 
+impl HasDeleteUseCase for App {
+    type DeleteUseCase = App;
+
+    fn delete_use_case(&self) -> &Self::DeleteUseCase {
+        self
+    }
+}
+
+impl HasEditUseCase for App {
+    type EditUseCase = App;
+
+    fn edit_use_case(&self) -> &Self::EditUseCase {
+        self
+    }
+}
+
 impl HasListUseCase for App {
     type ListUseCase = App;
 
@@ -71,6 +136,22 @@ impl HasListUseCase for App {
     }
 }
 
+impl HasRetitleUseCase for App {
+    type RetitleUseCase = App;
+
+    fn retitle_use_case(&self) -> &Self::RetitleUseCase {
+        self
+    }
+}
+
+impl HasTagUseCase for App {
+    type TagUseCase = App;
+
+    fn tag_use_case(&self) -> &Self::TagUseCase {
+        self
+    }
+}
+
 impl HasViewUseCase for App {
     type ViewUseCase = App;
 
@@ -79,10 +160,15 @@ impl HasViewUseCase for App {
     }
 }
 
-fn build_app(config: Config) -> anyhow::Result<App> {
+fn build_app(config: Config, index: bool) -> anyhow::Result<App> {
     let data_dir = config.data_dir();
     let time_zone_offset = TimeZoneOffset::from_str(config.time_zone_offset())?;
     let brepository = FsBRepository::new(data_dir.to_path_buf(), time_zone_offset);
+    let brepository = if index {
+        brepository.with_search_index()
+    } else {
+        brepository
+    };
     let app = App { brepository };
     Ok(app)
 }
@@ -95,9 +181,14 @@ fn main() -> anyhow::Result<()> {
             generate(shell, &mut command, "b", &mut io::stdout());
             Ok(())
         }
-        Subcommand::List { json, query } => {
+        Subcommand::Edit { id } => {
+            let config = ConfigRepository::new().load()?;
+            let app = build_app(config, false)?;
+            command::edit(&app, id)
+        }
+        Subcommand::List { index, json, query } => {
             let config = ConfigRepository::new().load()?;
-            let app = build_app(config)?;
+            let app = build_app(config, index)?;
             command::list(&app, json, query, &mut io::stdout())
         }
         Subcommand::New {
@@ -107,9 +198,27 @@ fn main() -> anyhow::Result<()> {
             // TODO: use App
             command::new(data_file, template)
         }
+        Subcommand::Retitle { id, title } => {
+            let config = ConfigRepository::new().load()?;
+            let app = build_app(config, false)?;
+            command::retitle(&app, id, title)
+        }
+        Subcommand::Rm { id } => {
+            let config = ConfigRepository::new().load()?;
+            let app = build_app(config, false)?;
+            command::rm(&app, id)
+        }
+        Subcommand::Tag { subcommand } => {
+            let config = ConfigRepository::new().load()?;
+            let app = build_app(config, false)?;
+            match subcommand {
+                TagSubcommand::Add { id, tags } => command::tag_add(&app, id, tags),
+                TagSubcommand::Remove { id, tags } => command::tag_remove(&app, id, tags),
+            }
+        }
         Subcommand::View { content, id, meta } => {
             let config = ConfigRepository::new().load()?;
-            let app = build_app(config)?;
+            let app = build_app(config, false)?;
             command::view(&app, content, id, meta, &mut io::stdout())
         }
     }
@@ -118,7 +227,8 @@ fn main() -> anyhow::Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ::use_case::{BRepository, ListUseCase, Query};
+    use ::query::Query;
+    use ::use_case::{BRepository, ListUseCase};
     use adapter_fs::FsBRepository;
     use std::{fs, str::FromStr};
     use tempfile::tempdir;
@@ -171,6 +281,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn list_search_test() -> anyhow::Result<()> {
+        let dir = tempdir()?;
+        let dir20210203 = dir.path().join("flow").join("2021").join("02").join("03");
+        fs::create_dir_all(dir20210203.as_path())?;
+        for (id, meta, content) in [
+            (
+                "20210203T000000Z",
+                r#"{"tags":["rust"],"title":"Rust入門"}"#,
+                "Hello, World!",
+            ),
+            (
+                "20210203T000001Z",
+                r#"{"tags":["diary","rust"],"title":"日記"}"#,
+                "I wrote Rust.",
+            ),
+            ("20210203T000002Z", r#"{"tags":["diary"]}"#, "go"),
+        ] {
+            fs::write(dir20210203.join(format!("{}.json", id)), meta)?;
+            fs::write(dir20210203.join(format!("{}.md", id)), content)?;
+        }
+        for index in [false, true, true] {
+            let repository = FsBRepository::new(
+                dir.path().to_path_buf(),
+                TimeZoneOffset::from_str("+09:00")?,
+            );
+            let app = App {
+                brepository: if index {
+                    repository.with_search_index()
+                } else {
+                    repository
+                },
+            };
+            let f = |query: &str| -> anyhow::Result<Vec<String>> {
+                Ok(app
+                    .list_use_case()
+                    .handle(&Query::from_str(query)?)?
+                    .into_iter()
+                    .map(|bmeta| bmeta.title)
+                    .collect())
+            };
+            assert_eq!(f("2021-02-03")?, vec!["Rust入門", "日記", "go"]);
+            assert_eq!(f("2021-02-04")?, Vec::<String>::new());
+            assert_eq!(f("tag:rust")?, vec!["Rust入門", "日記"]);
+            assert_eq!(f("tag:rust -tag:diary")?, vec!["Rust入門"]);
+            assert_eq!(f("RUST")?, vec!["Rust入門", "日記"]);
+            assert_eq!(f("title:rust")?, vec!["Rust入門"]);
+            assert_eq!(f("\"hello, world\"")?, vec!["Rust入門"]);
+            assert_eq!(f("\"world hello\"")?, Vec::<String>::new());
+            assert_eq!(f("\"WROTE  rust\"")?, vec!["日記"]);
+            assert_eq!(f("wrote i")?, vec!["日記"]);
+            assert_eq!(f("\"wrote i\"")?, Vec::<String>::new());
+            assert_eq!(f("\"i rust\"")?, Vec::<String>::new());
+            assert_eq!(f("\"st入\"")?, vec!["Rust入門"]);
+            assert_eq!(f("hello OR go")?, vec!["Rust入門", "go"]);
+            assert_eq!(f("\"!!\"")?, Vec::<String>::new());
+            assert_eq!(f("title:!!")?, Vec::<String>::new());
+            assert_eq!(f("-rust")?, vec!["go"]);
+            assert_eq!(
+                f("date:2021 tag:diary OR title:入門 -go")?,
+                vec!["Rust入門", "日記"]
+            );
+        }
+        assert!(dir.path().join(".search-index.json").is_file());
+        Ok(())
+    }
+
     #[test]
     fn view_test() -> anyhow::Result<()> {
         let dir = tempdir().unwrap();
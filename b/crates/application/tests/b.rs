@@ -1,6 +1,6 @@
 use anyhow::Context;
 use assert_cmd::Command;
-use predicates::str::contains;
+use predicates::{prelude::PredicateBooleanExt, str::contains};
 use std::{env, fs};
 
 #[test]
@@ -76,3 +76,111 @@ fn list_test() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn list_search_test() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let b_dir = dir.path().join("flow").join("2021").join("02").join("03");
+    fs::create_dir_all(b_dir.as_path())?;
+    let md1 = b_dir.join("20210203T000000Z.md");
+    fs::write(md1.as_path(), "Hello, World!")?;
+    fs::write(md1.with_extension("json"), r#"{"tags":["rust"]}"#)?;
+    let md2 = b_dir.join("20210203T000001Z.md");
+    fs::write(md2.as_path(), "go")?;
+    fs::write(md2.with_extension("json"), r#"{"tags":["diary"]}"#)?;
+    let config_dir = dir.path().join("config");
+    fs::create_dir_all(config_dir.as_path())?;
+    fs::write(
+        config_dir.join("config.json"),
+        format!(
+            r#"{{"data_dir":"{}","time_zone_offset":"{}"}}"#,
+            dir.path().to_str().context("data_dir.to_str()")?,
+            "+09:00"
+        ),
+    )?;
+
+    for args in [vec!["hello OR tag:none"], vec!["--index", "-tag:diary"]] {
+        Command::cargo_bin("b")?
+            .arg("list")
+            .args(args)
+            .env("B_TEST_CONFIG_DIR", config_dir.as_path())
+            .assert()
+            .stdout(contains(md1.to_str().context("md1.to_str()")?))
+            .stdout(contains(md2.to_str().context("md2.to_str()")?).not())
+            .success();
+    }
+    assert!(dir.path().join(".search-index.json").is_file());
+
+    Command::cargo_bin("b")?
+        .arg("list")
+        .arg("tag:")
+        .env("B_TEST_CONFIG_DIR", config_dir.as_path())
+        .assert()
+        .failure();
+
+    Ok(())
+}
+
+#[test]
+fn write_test() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let b_dir = dir.path().join("flow").join("2021").join("02").join("03");
+    fs::create_dir_all(b_dir.as_path())?;
+    let md = b_dir.join("20210203T000000Z.md");
+    fs::write(md.as_path(), "markdown")?;
+    let json = b_dir.join("20210203T000000Z.json");
+    fs::write(json.as_path(), r#"{"tags":["a"]}"#)?;
+    let config_dir = dir.path().join("config");
+    fs::create_dir_all(config_dir.as_path())?;
+    fs::write(
+        config_dir.join("config.json"),
+        format!(
+            r#"{{"data_dir":"{}","time_zone_offset":"{}"}}"#,
+            dir.path().to_str().context("data_dir.to_str()")?,
+            "+09:00"
+        ),
+    )?;
+    let b = |args: &[&str]| -> anyhow::Result<assert_cmd::assert::Assert> {
+        Ok(Command::cargo_bin("b")?
+            .args(args)
+            .env("B_TEST_CONFIG_DIR", config_dir.as_path())
+            .assert())
+    };
+
+    b(&["tag", "add", "20210203T000000Z", "b", "c", "a"])?.success();
+    b(&["tag", "remove", "20210203T000000Z", "a"])?.success();
+    b(&["retitle", "20210203T000000Z", "TITLE"])?.success();
+    assert_eq!(
+        fs::read_to_string(json.as_path())?,
+        r#"{"tags":["b","c"],"title":"TITLE"}"#
+    );
+    b(&["tag", "add", "20210204T000000Z", "a"])?.failure();
+
+    // `cp EDITED PATH` as the editor
+    let edited = dir.path().join("edited.md");
+    fs::write(edited.as_path(), "edited")?;
+    Command::cargo_bin("b")?
+        .arg("edit")
+        .arg("20210203T000000Z")
+        .env("B_TEST_CONFIG_DIR", config_dir.as_path())
+        .env(
+            "EDITOR",
+            format!("cp {}", edited.to_str().context("edited.to_str()")?),
+        )
+        .assert()
+        .success();
+    assert_eq!(fs::read_to_string(md.as_path())?, "edited");
+
+    b(&["rm", "20210203T000000Z"])?.success();
+    assert!(!md.exists());
+    assert!(!json.exists());
+    let trash_dir = dir.path().join("trash").join("2021").join("02").join("03");
+    assert_eq!(
+        fs::read_to_string(trash_dir.join("20210203T000000Z.md"))?,
+        "edited"
+    );
+    b(&["view", "20210203T000000Z"])?.failure();
+    b(&["rm", "20210203T000000Z"])?.failure();
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use limited_date_time::{DayOfMonth, Month, Year};
+use limited_date_time::{Date, DateTime, DayOfMonth, Month, Time, Year};
 use nom::{
     branch::alt,
     bytes::complete::{tag, take_while_m_n},
@@ -76,6 +76,18 @@ impl std::fmt::Display for DateRangeDate {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DateParamRange(DateRangeDate, DateRangeDate);
 
+impl DateParamRange {
+    /// Returns `None` if the date does not exist (e.g. `2021-02-30`).
+    pub fn date_time_range(&self) -> Option<(DateTime, DateTime)> {
+        let start = Date::from_ymd(self.0 .0, self.0 .1, self.0 .2).ok()?;
+        let end = Date::from_ymd(self.1 .0, self.1 .1, self.1 .2).ok()?;
+        Some((
+            DateTime::from_date_time(start, Time::min()),
+            DateTime::from_date_time(end, Time::max()),
+        ))
+    }
+}
+
 impl std::fmt::Display for DateParamRange {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}/{}", self.0, self.1)
@@ -135,11 +147,15 @@ fn year(s: &str) -> IResult<&str, Year> {
 
 pub fn parse(s: &str) -> IResult<&str, DateParam> {
     let (s, _) = tag("date:")(s)?;
-    let (s, date) = alt((
+    parse_bare(s)
+}
+
+// without the `date:` prefix
+pub(crate) fn parse_bare(s: &str) -> IResult<&str, DateParam> {
+    alt((
         map(date_range, DateParam::Range),
         map(alt((yyyymmdd, yyyymm, yyyy)), DateParam::Single),
-    ))(s)?;
-    Ok((s, date))
+    ))(s)
 }
 
 #[cfg(test)]
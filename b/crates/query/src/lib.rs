@@ -4,6 +4,9 @@ mod optional_date;
 mod query;
 mod query_param;
 mod tag_param;
+mod text_param;
+mod title_param;
+mod tokenize;
 
 pub use self::date_param::*;
 pub use self::date_range_inclusive::*;
@@ -11,3 +14,6 @@ pub use self::optional_date::*;
 pub use self::query::*;
 pub use self::query_param::*;
 pub use self::tag_param::*;
+pub use self::text_param::*;
+pub use self::title_param::*;
+pub use self::tokenize::*;
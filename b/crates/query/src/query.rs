@@ -4,13 +4,17 @@ use limited_date_time::DateTime;
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    combinator::{all_consuming, map},
-    multi::separated_list0,
+    character::complete::char,
+    combinator::{all_consuming, eof, map, peek},
+    multi::{separated_list0, separated_list1},
+    sequence::{preceded, terminated},
     IResult,
 };
 
-use super::date_param::parse as date_param;
+use super::date_param::{parse as date_param, parse_bare as bare_date};
 use super::tag_param::parse as tag_param;
+use super::text_param::parse as text_param;
+use super::title_param::parse as title_param;
 use crate::QueryParam;
 
 use thiserror::Error;
@@ -30,28 +34,35 @@ impl Query {
         let mut max = DateTime::from_str("9999-12-30T23:59:59").unwrap();
         let empty = (max, min);
         for query_param in self.clone().into_iter() {
-            match query_param {
+            let (mn, mx) = match query_param {
                 QueryParam::Date(datea_param) => match datea_param {
                     crate::DateParam::Single(optional_date) => {
                         // TODO: unwrap OptionalDate::date_time_range
-                        let (mn, mx) = optional_date.date_time_range().unwrap();
-                        if max < mn || mx < min {
-                            return empty;
-                        }
-                        min = min.max(mn);
-                        max = max.min(mx);
-                        if min > max {
-                            return empty;
-                        }
+                        optional_date.date_time_range().unwrap()
                     }
-                    crate::DateParam::Range(_) => todo!(),
+                    crate::DateParam::Range(range) => match range.date_time_range() {
+                        Some(date_time_range) => date_time_range,
+                        None => return empty,
+                    },
                 },
-                QueryParam::Tag(_) => continue,
+                _ => continue,
+            };
+            if max < mn || mx < min {
+                return empty;
+            }
+            min = min.max(mn);
+            max = max.min(mx);
+            if min > max {
+                return empty;
             }
         }
 
         (min, max)
     }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, QueryParam> {
+        self.0.iter()
+    }
 }
 
 impl std::fmt::Display for Query {
@@ -86,13 +97,45 @@ impl IntoIterator for Query {
     }
 }
 
+// the params that can be negated and grouped by `OR`
+fn operand(s: &str) -> IResult<&str, QueryParam> {
+    let negatable = |s| {
+        alt((
+            map(tag_param, QueryParam::Tag),
+            map(title_param, QueryParam::Title),
+            map(text_param, QueryParam::Text),
+        ))(s)
+    };
+    alt((
+        map(preceded(char('-'), negatable), |p| {
+            QueryParam::Not(Box::new(p))
+        }),
+        negatable,
+    ))(s)
+}
+
+fn or_group(s: &str) -> IResult<&str, QueryParam> {
+    map(separated_list1(tag(" OR "), operand), |mut ps| {
+        if ps.len() == 1 {
+            ps.remove(0)
+        } else {
+            QueryParam::Or(ps)
+        }
+    })(s)
+}
+
 fn parse(s: &str) -> IResult<&str, Query> {
     map(
         all_consuming(separated_list0(
             tag(" "),
             alt((
                 map(date_param, QueryParam::Date),
-                map(tag_param, QueryParam::Tag),
+                // `YYYY-MM-DD` is `date:YYYY-MM-DD`
+                map(
+                    terminated(bare_date, peek(alt((eof, tag(" "))))),
+                    QueryParam::Date,
+                ),
+                or_group,
             )),
         )),
         Query,
@@ -103,7 +146,7 @@ fn parse(s: &str) -> IResult<&str, Query> {
 mod tests {
     use std::str::FromStr;
 
-    use crate::{DateParam, TagParam};
+    use crate::{DateParam, TagParam, TextParam, TitleParam};
 
     use super::*;
 
@@ -120,6 +163,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn search_params_test() -> anyhow::Result<()> {
+        let s = "date:2021 tag:abc -tag:def title:\"a b\" rust OR go OR -tag:ghi \"hello world\"";
+        assert_eq!(Query::from_str(s)?.to_string(), s.to_string());
+        assert_eq!(
+            Query::from_str("2021-02-03 -\"a b\" OR title:c")?
+                .into_iter()
+                .collect::<Vec<QueryParam>>(),
+            vec![
+                QueryParam::Date(DateParam::from_str("date:2021-02-03")?),
+                QueryParam::Or(vec![
+                    QueryParam::Not(Box::new(QueryParam::Text(TextParam::from_str("\"a b\"")?))),
+                    QueryParam::Title(TitleParam::from_str("title:c")?),
+                ]),
+            ]
+        );
+        assert_eq!(
+            Query::from_str("2021 2021x")?.to_string(),
+            "date:2021 2021x".to_string()
+        );
+        assert!(Query::from_str("OR").is_err());
+        assert!(Query::from_str("a OR").is_err());
+        assert!(Query::from_str("-date:2021").is_err());
+        assert!(Query::from_str("tag:abc OR date:2021").is_err());
+        assert!(Query::from_str("title:\"abc").is_err());
+        Ok(())
+    }
+
     #[test]
     fn iterator_test() -> anyhow::Result<()> {
         let q = Query::from_str("date:2021-02-03 tag:abc")?;
@@ -171,7 +242,20 @@ mod tests {
                 DateTime::from_str("2021-02-03T23:59:59")?,
             )
         );
-        // TODO: date:2021-02-03/2021-03-04
+        assert_eq!(
+            Query::from_str("date:2021-02-03/2021-03-04")?.naive_date_time_range(),
+            (
+                DateTime::from_str("2021-02-03T00:00:00")?,
+                DateTime::from_str("2021-03-04T23:59:59")?,
+            )
+        );
+        assert_eq!(
+            Query::from_str("date:2021 tag:abc rust")?.naive_date_time_range(),
+            (
+                DateTime::from_str("2021-01-01T00:00:00")?,
+                DateTime::from_str("2021-12-31T23:59:59")?,
+            )
+        );
 
         Ok(())
     }
@@ -1,4 +1,4 @@
-use crate::{DateParam, TagParam};
+use crate::{DateParam, TagParam, TextParam, TitleParam};
 
 use thiserror::Error;
 
@@ -10,6 +10,12 @@ pub struct ParseQueryError;
 pub enum QueryParam {
     Date(DateParam),
     Tag(TagParam),
+    Text(TextParam),
+    Title(TitleParam),
+    // e.g. `-tag:abc`
+    Not(Box<QueryParam>),
+    // e.g. `tag:abc OR tag:def`
+    Or(Vec<QueryParam>),
 }
 
 impl std::fmt::Display for QueryParam {
@@ -20,6 +26,14 @@ impl std::fmt::Display for QueryParam {
             match self {
                 QueryParam::Date(d) => d.to_string(),
                 QueryParam::Tag(t) => t.to_string(),
+                QueryParam::Text(t) => t.to_string(),
+                QueryParam::Title(t) => t.to_string(),
+                QueryParam::Not(p) => format!("-{}", p),
+                QueryParam::Or(ps) => ps
+                    .iter()
+                    .map(|p| p.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" OR "),
             }
         )
     }
@@ -23,6 +23,12 @@ pub struct ParseTagParamError;
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TagParam(String);
 
+impl TagParam {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
 impl std::fmt::Display for TagParam {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if self.0.contains(' ') {
@@ -0,0 +1,122 @@
+use std::str::FromStr;
+
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while, take_while1},
+    combinator::{all_consuming, map, verify},
+    sequence::delimited,
+    IResult,
+};
+use thiserror::Error;
+
+use crate::date_param::parse_bare as bare_date;
+
+// ParseTextParamError
+
+#[derive(Debug, Eq, Error, PartialEq)]
+#[error("parse text param error")]
+pub struct ParseTextParamError;
+
+// TextParam
+
+/// A free-text term. e.g. `rust` or `"hello world"` (a phrase)
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TextParam {
+    text: String,
+    phrase: bool,
+}
+
+impl TextParam {
+    pub fn as_str(&self) -> &str {
+        self.text.as_str()
+    }
+
+    /// Returns `true` if the term is quoted.
+    pub fn is_phrase(&self) -> bool {
+        self.phrase
+    }
+}
+
+impl std::fmt::Display for TextParam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.phrase {
+            write!(f, "\"{}\"", self.text)
+        } else {
+            write!(f, "{}", self.text)
+        }
+    }
+}
+
+impl FromStr for TextParam {
+    type Err = ParseTextParamError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        all_consuming(parse)(s)
+            .map(|(_, p)| p)
+            .map_err(|_| ParseTextParamError)
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    !c.is_whitespace() && c != '"'
+}
+
+// a bare word that is not the `OR` operator, a negation, a date or another param
+fn word(s: &str) -> IResult<&str, &str> {
+    verify(take_while1(is_word_char), |w: &str| {
+        w != "OR"
+            && !w.starts_with('-')
+            && all_consuming(bare_date)(w).is_err()
+            && !["date:", "tag:", "title:"]
+                .iter()
+                .any(|prefix| w.starts_with(prefix))
+    })(s)
+}
+
+pub(crate) fn parse(s: &str) -> IResult<&str, TextParam> {
+    alt((
+        map(
+            delimited(tag("\""), take_while(|c| c != '"'), tag("\"")),
+            |s: &str| TextParam {
+                text: s.to_string(),
+                phrase: true,
+            },
+        ),
+        map(word, |s: &str| TextParam {
+            text: s.to_string(),
+            phrase: false,
+        }),
+    ))(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn str_conversion_test() -> anyhow::Result<()> {
+        let f = |s: &str| -> anyhow::Result<()> {
+            assert_eq!(TextParam::from_str(s)?.to_string(), s.to_string());
+            Ok(())
+        };
+        f("rust")?;
+        f("日本語")?;
+        f("\"hello world\"")?;
+        f("\"OR\"")?;
+        f("\"2021\"")?;
+        f("\"tag:abc\"")?;
+        f("\"rust\"")?;
+        f("\"\"")?;
+        assert_eq!(TextParam::from_str("\"a b\"")?.as_str(), "a b");
+        assert!(TextParam::from_str("\"a b\"")?.is_phrase());
+        assert!(TextParam::from_str("\"rust\"")?.is_phrase());
+        assert!(!TextParam::from_str("rust")?.is_phrase());
+        assert!(TextParam::from_str("OR").is_err());
+        assert!(TextParam::from_str("-rust").is_err());
+        assert!(TextParam::from_str("2021-02-03").is_err());
+        assert_eq!(TextParam::from_str("123")?.to_string(), "123");
+        assert!(TextParam::from_str("tag:abc").is_err());
+        assert!(TextParam::from_str("a b").is_err());
+        Ok(())
+    }
+}
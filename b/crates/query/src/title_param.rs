@@ -0,0 +1,76 @@
+use std::str::FromStr;
+
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while, take_while1},
+    combinator::{all_consuming, map},
+    sequence::{delimited, preceded},
+    IResult,
+};
+use thiserror::Error;
+
+// ParseTitleParamError
+
+#[derive(Debug, Eq, Error, PartialEq)]
+#[error("parse title param error")]
+pub struct ParseTitleParamError;
+
+// TitleParam
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TitleParam(String);
+
+impl TitleParam {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl std::fmt::Display for TitleParam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() || self.0.contains(char::is_whitespace) {
+            write!(f, "title:\"{}\"", self.0)
+        } else {
+            write!(f, "title:{}", self.0)
+        }
+    }
+}
+
+impl FromStr for TitleParam {
+    type Err = ParseTitleParamError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        all_consuming(parse)(s)
+            .map(|(_, p)| p)
+            .map_err(|_| ParseTitleParamError)
+    }
+}
+
+pub(crate) fn parse(s: &str) -> IResult<&str, TitleParam> {
+    preceded(
+        tag("title:"),
+        map(
+            alt((
+                delimited(tag("\""), take_while(|c| c != '"'), tag("\"")),
+                take_while1(|c: char| !c.is_whitespace() && c != '"'),
+            )),
+            |s: &str| TitleParam(s.to_string()),
+        ),
+    )(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn str_conversion_test() -> anyhow::Result<()> {
+        let s = "title:\"abc 123\"";
+        assert_eq!(TitleParam::from_str(s)?.to_string(), s.to_string());
+        let s = "title:abc";
+        assert_eq!(TitleParam::from_str(s)?.to_string(), s.to_string());
+        assert_eq!(TitleParam::from_str("title:\"abc\"")?.as_str(), "abc");
+        assert!(TitleParam::from_str("title:").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use std::collections::BTreeSet;
+
+/// Returns the search terms of `s`.
+///
+/// A run of ASCII letters and digits is a term (lowercase).
+/// A run of the other letters (e.g. Japanese) is split into the characters and the adjacent pairs.
+/// e.g. `b の使い方` -> `b`, `の`, `使`, `い`, `方`, `の使`, `使い`, `い方`
+pub fn tokenize(s: &str) -> BTreeSet<String> {
+    let mut terms = BTreeSet::new();
+    for run in s.split(|c: char| !c.is_alphanumeric()) {
+        let chars = run
+            .chars()
+            .flat_map(char::to_lowercase)
+            .collect::<Vec<char>>();
+        for group in chars.split_inclusive(|c| !c.is_ascii()) {
+            let (ascii, other) = match group.split_last() {
+                Some((last, init)) if !last.is_ascii() => (init, Some(*last)),
+                _ => (group, None),
+            };
+            if !ascii.is_empty() {
+                terms.insert(ascii.iter().collect::<String>());
+            }
+            if let Some(c) = other {
+                terms.insert(c.to_string());
+            }
+        }
+        for pair in chars.windows(2) {
+            if !pair[0].is_ascii() && !pair[1].is_ascii() {
+                terms.insert(pair.iter().collect::<String>());
+            }
+        }
+    }
+    terms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terms(ss: &[&str]) -> BTreeSet<String> {
+        ss.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn tokenize_test() {
+        assert_eq!(tokenize(""), terms(&[]));
+        assert_eq!(tokenize("!! -- ??"), terms(&[]));
+        assert_eq!(tokenize("Hello, World!"), terms(&["hello", "world"]));
+        assert_eq!(tokenize("RUST rust Rust"), terms(&["rust"]));
+        assert_eq!(
+            tokenize("20210203T000000Z.md"),
+            terms(&["20210203t000000z", "md"])
+        );
+        assert_eq!(tokenize("2021-02-03"), terms(&["2021", "02", "03"]));
+        assert_eq!(tokenize("Rust入門"), terms(&["rust", "入", "門", "入門"]));
+        assert_eq!(
+            tokenize("b の使い方"),
+            terms(&["b", "の", "使", "い", "方", "の使", "使い", "い方"])
+        );
+        assert_eq!(
+            tokenize("日本語とEnglish"),
+            terms(&["日", "本", "語", "と", "日本", "本語", "語と", "english"])
+        );
+        assert_eq!(
+            tokenize("日記。メモ"),
+            terms(&["日", "記", "日記", "メ", "モ", "メモ"])
+        );
+    }
+}
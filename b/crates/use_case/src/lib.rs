@@ -1,7 +1,5 @@
 mod port;
-mod query;
 mod use_case;
 
 pub use self::port::*;
-pub use self::query::*;
 pub use self::use_case::*;
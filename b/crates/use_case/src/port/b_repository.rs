@@ -1,8 +1,12 @@
 use std::path::{Path, PathBuf};
 
 use entity::{BId, BMeta};
+use query::Query;
 
 pub trait BRepository {
+    /// Deletes the b. The deleted b may be kept (e.g. in the trash directory).
+    fn delete(&self, id: BId) -> anyhow::Result<()>;
+
     // TODO: hide path ?
     fn find_by_content_path(&self, path: &Path) -> anyhow::Result<BId>;
 
@@ -13,8 +17,18 @@ pub trait BRepository {
 
     fn find_ids(&self, date: &str) -> anyhow::Result<Vec<BId>>;
 
+    /// Returns the ids that may match the query. The caller checks the params other than the date.
+    fn find_candidate_ids(&self, query: &Query) -> anyhow::Result<Vec<BId>>;
+
     fn find_meta(&self, id: BId) -> anyhow::Result<Option<BMeta>>;
 
+    fn save_content(&self, id: BId, content: &str) -> anyhow::Result<()>;
+
+    fn save_tags(&self, id: BId, tags: &[String]) -> anyhow::Result<()>;
+
+    /// `None` uses the beginning of the content as the title.
+    fn save_title(&self, id: BId, title: Option<&str>) -> anyhow::Result<()>;
+
     fn to_content_path_buf(&self, id: &BId) -> PathBuf;
 
     fn to_meta_path_buf(&self, id: &BId) -> PathBuf;
@@ -1,5 +1,13 @@
+mod delete;
+mod edit;
 mod list;
+mod retitle;
+mod tag;
 mod view;
 
+pub use self::delete::*;
+pub use self::edit::*;
 pub use self::list::*;
+pub use self::retitle::*;
+pub use self::tag::*;
 pub use self::view::*;
@@ -0,0 +1,20 @@
+use anyhow::Context;
+use entity::BId;
+
+use crate::{BRepository, HasBRepository};
+
+pub trait DeleteUseCase: HasBRepository {
+    fn handle(&self, id: BId) -> anyhow::Result<()> {
+        let repository = self.b_repository();
+        repository.find_meta(id)?.context("b meta not found")?;
+        repository.delete(id)
+    }
+}
+
+impl<T: HasBRepository> DeleteUseCase for T {}
+
+pub trait HasDeleteUseCase {
+    type DeleteUseCase: DeleteUseCase;
+
+    fn delete_use_case(&self) -> &Self::DeleteUseCase;
+}
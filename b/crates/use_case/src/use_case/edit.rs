@@ -0,0 +1,29 @@
+use anyhow::Context;
+use entity::BId;
+
+use crate::{BRepository, HasBRepository};
+
+pub trait EditUseCase: HasBRepository {
+    /// Replaces the content with the result of `f` (e.g. the content edited with $EDITOR).
+    fn handle<F>(&self, id: BId, f: F) -> anyhow::Result<()>
+    where
+        F: FnOnce(String) -> anyhow::Result<String>,
+    {
+        let repository = self.b_repository();
+        repository.find_meta(id)?.context("b meta not found")?;
+        let content = repository.find_content(id)?.unwrap_or_default();
+        let edited = f(content.clone())?;
+        if edited != content {
+            repository.save_content(id, edited.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: HasBRepository> EditUseCase for T {}
+
+pub trait HasEditUseCase {
+    type EditUseCase: EditUseCase;
+
+    fn edit_use_case(&self) -> &Self::EditUseCase;
+}
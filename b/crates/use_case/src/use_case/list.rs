@@ -1,25 +1,33 @@
+use std::collections::BTreeSet;
+
 use anyhow::Context;
 use entity::BMeta;
+use query::{tokenize, Query, QueryParam};
 
-use crate::{BRepository, HasBRepository, Query};
+use crate::{BRepository, HasBRepository};
 
 pub trait ListUseCase: HasBRepository {
     fn handle(&self, query: &Query) -> anyhow::Result<Vec<BMeta>> {
         let repository = self.b_repository();
+        let needs_content = query.iter().any(has_text);
         let mut bmetas = vec![];
-        let bids = repository.find_ids(query.date.as_str())?;
+        let bids = repository.find_candidate_ids(query)?;
         for bid in bids {
             let bmeta = repository.find_meta(bid)?.context("no meta error")?;
-            match &query.tags {
-                Some(ref tags) => {
-                    if tags
-                        .iter()
-                        .all(|tag| bmeta.tags.iter().any(|s| s.as_str() == tag))
-                    {
-                        bmetas.push(bmeta);
-                    }
-                }
-                None => bmetas.push(bmeta),
+            let content = if needs_content {
+                repository.find_content(bid)?.unwrap_or_default()
+            } else {
+                String::new()
+            };
+            let target = Target {
+                bmeta: &bmeta,
+                title: normalize(bmeta.title.as_str()),
+                content: normalize(content.as_str()),
+                title_terms: tokenize(bmeta.title.as_str()),
+                content_terms: tokenize(content.as_str()),
+            };
+            if query.iter().all(|param| target.is_match(param)) {
+                bmetas.push(bmeta);
             }
         }
         Ok(bmetas)
@@ -33,3 +41,58 @@ pub trait HasListUseCase {
 
     fn list_use_case(&self) -> &Self::ListUseCase;
 }
+
+struct Target<'a> {
+    bmeta: &'a BMeta,
+    title: String,
+    content: String,
+    title_terms: BTreeSet<String>,
+    content_terms: BTreeSet<String>,
+}
+
+impl<'a> Target<'a> {
+    fn is_match(&self, param: &QueryParam) -> bool {
+        match param {
+            // BRepository::find_candidate_ids filters by the date
+            QueryParam::Date(_) => true,
+            QueryParam::Tag(tag) => self.bmeta.tags.iter().any(|t| t.as_str() == tag.as_str()),
+            QueryParam::Text(text) if text.is_phrase() => {
+                let phrase = normalize(text.as_str());
+                !phrase.is_empty()
+                    && (self.title.contains(phrase.as_str())
+                        || self.content.contains(phrase.as_str()))
+            }
+            QueryParam::Text(text) => {
+                let tokens = tokenize(text.as_str());
+                // a term without words (e.g. `"!!"`) matches nothing
+                !tokens.is_empty()
+                    && tokens
+                        .iter()
+                        .all(|t| self.title_terms.contains(t) || self.content_terms.contains(t))
+            }
+            QueryParam::Title(title) => {
+                let tokens = tokenize(title.as_str());
+                !tokens.is_empty() && tokens.iter().all(|t| self.title_terms.contains(t))
+            }
+            QueryParam::Not(param) => !self.is_match(param),
+            QueryParam::Or(params) => params.iter().any(|param| self.is_match(param)),
+        }
+    }
+}
+
+// lowercase and the whitespaces squashed into a space
+fn normalize(s: &str) -> String {
+    s.split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+fn has_text(param: &QueryParam) -> bool {
+    match param {
+        QueryParam::Text(_) => true,
+        QueryParam::Not(param) => has_text(param),
+        QueryParam::Or(params) => params.iter().any(has_text),
+        QueryParam::Date(_) | QueryParam::Tag(_) | QueryParam::Title(_) => false,
+    }
+}
@@ -0,0 +1,22 @@
+use anyhow::Context;
+use entity::BId;
+
+use crate::{BRepository, HasBRepository};
+
+pub trait RetitleUseCase: HasBRepository {
+    /// An empty title uses the beginning of the content as the title.
+    fn handle(&self, id: BId, title: &str) -> anyhow::Result<()> {
+        let repository = self.b_repository();
+        repository.find_meta(id)?.context("b meta not found")?;
+        let title = title.trim();
+        repository.save_title(id, if title.is_empty() { None } else { Some(title) })
+    }
+}
+
+impl<T: HasBRepository> RetitleUseCase for T {}
+
+pub trait HasRetitleUseCase {
+    type RetitleUseCase: RetitleUseCase;
+
+    fn retitle_use_case(&self) -> &Self::RetitleUseCase;
+}
@@ -0,0 +1,41 @@
+use anyhow::{bail, Context};
+use entity::BId;
+
+use crate::{BRepository, HasBRepository};
+
+pub trait TagUseCase: HasBRepository {
+    /// Adds the tags not yet added.
+    fn add(&self, id: BId, tags: &[String]) -> anyhow::Result<()> {
+        if tags.iter().any(|tag| tag.trim().is_empty()) {
+            bail!("empty tag");
+        }
+        let repository = self.b_repository();
+        let meta = repository.find_meta(id)?.context("b meta not found")?;
+        let mut updated = meta.tags;
+        for tag in tags {
+            if !updated.contains(tag) {
+                updated.push(tag.clone());
+            }
+        }
+        repository.save_tags(id, &updated)
+    }
+
+    fn remove(&self, id: BId, tags: &[String]) -> anyhow::Result<()> {
+        let repository = self.b_repository();
+        let meta = repository.find_meta(id)?.context("b meta not found")?;
+        let updated = meta
+            .tags
+            .into_iter()
+            .filter(|tag| !tags.contains(tag))
+            .collect::<Vec<String>>();
+        repository.save_tags(id, &updated)
+    }
+}
+
+impl<T: HasBRepository> TagUseCase for T {}
+
+pub trait HasTagUseCase {
+    type TagUseCase: TagUseCase;
+
+    fn tag_use_case(&self) -> &Self::TagUseCase;
+}
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS sync_states (
+  bbn_entry_id TEXT PRIMARY KEY,
+  hatena_blog_entry_id TEXT NOT NULL,
+  title TEXT NOT NULL,
+  content TEXT NOT NULL,
+  edited INTEGER NOT NULL,
+  synced_at INTEGER NOT NULL
+)
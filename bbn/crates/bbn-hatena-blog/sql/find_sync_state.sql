@@ -0,0 +1,8 @@
+SELECT bbn_entry_id,
+  hatena_blog_entry_id,
+  title,
+  content,
+  edited,
+  synced_at
+FROM sync_states
+WHERE bbn_entry_id = ?
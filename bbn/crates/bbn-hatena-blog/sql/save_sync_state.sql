@@ -0,0 +1,9 @@
+INSERT OR REPLACE INTO sync_states(
+    bbn_entry_id,
+    hatena_blog_entry_id,
+    title,
+    content,
+    edited,
+    synced_at
+  )
+VALUES (?, ?, ?, ?, ?, ?)
@@ -9,6 +9,8 @@ mod indexing_id;
 mod member_request;
 mod member_request_id;
 mod member_response_id;
+mod sync_entry;
+mod sync_state;
 mod upload_entry;
 
 pub use self::download_entry::*;
@@ -22,4 +24,6 @@ pub use self::indexing_id::*;
 pub use self::member_request::*;
 pub use self::member_request_id::*;
 pub use self::member_response_id::*;
+pub use self::sync_entry::*;
+pub use self::sync_state::*;
 pub use self::upload_entry::*;
@@ -1,6 +1,6 @@
 use crate::hatena_blog::{
     HatenaBlogEntry, HatenaBlogEntryId, HatenaBlogListEntriesResponse, Indexing, IndexingId,
-    MemberRequest, MemberRequestId, MemberResponseId,
+    MemberRequest, MemberRequestId, MemberResponseId, SyncState,
 };
 use anyhow::Context as _;
 use bbn_data::{DateTime, EntryMeta, Timestamp};
@@ -37,6 +37,8 @@ impl HatenaBlogRepository {
             // member_requests
             include_str!("../../sql/create_table_member_requests.sql"),
             include_str!("../../sql/create_table_member_request_results.sql"),
+            // sync
+            include_str!("../../sql/create_table_sync_states.sql"),
         ];
         for migration in migrations.iter() {
             sqlx::query(migration).execute(&pool).await?;
@@ -319,6 +321,32 @@ impl HatenaBlogRepository {
         row.map(|(at,)| Timestamp::try_from(at)).transpose()
     }
 
+    pub async fn find_sync_state(
+        &self,
+        bbn_entry_id: &bbn_data::EntryId,
+    ) -> anyhow::Result<Option<SyncState>> {
+        let row: Option<(String, String, String, String, i64, i64)> =
+            sqlx::query_as(include_str!("../../sql/find_sync_state.sql"))
+                .bind(bbn_entry_id.to_string())
+                .fetch_optional(&self.pool)
+                .await?;
+        row.map(
+            |(bbn_entry_id, hatena_blog_entry_id, title, content, edited, synced_at)| {
+                Ok(SyncState {
+                    bbn_entry_id: bbn_data::EntryId::from_str(bbn_entry_id.as_str())?,
+                    hatena_blog_entry_id: HatenaBlogEntryId::from_str(
+                        hatena_blog_entry_id.as_str(),
+                    )?,
+                    title,
+                    content,
+                    edited: Timestamp::try_from(edited)?,
+                    synced_at: Timestamp::try_from(synced_at)?,
+                })
+            },
+        )
+        .transpose()
+    }
+
     #[allow(dead_code)]
     async fn find_indexing(&self, id: IndexingId) -> anyhow::Result<Option<Indexing>> {
         let row: Option<(i64, i64)> = sqlx::query_as(include_str!("../../sql/find_indexing.sql"))
@@ -339,6 +367,19 @@ impl HatenaBlogRepository {
             .await?;
         Ok(row.map(|(at,)| Timestamp::try_from(at)).transpose()?)
     }
+
+    pub async fn save_sync_state(&self, sync_state: &SyncState) -> anyhow::Result<()> {
+        sqlx::query(include_str!("../../sql/save_sync_state.sql"))
+            .bind(sync_state.bbn_entry_id.to_string())
+            .bind(sync_state.hatena_blog_entry_id.to_string())
+            .bind(sync_state.title.as_str())
+            .bind(sync_state.content.as_str())
+            .bind(i64::from(sync_state.edited))
+            .bind(i64::from(sync_state.synced_at))
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -357,4 +398,35 @@ mod tests {
         assert_eq!(found, Some(created));
         Ok(())
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn sync_state_test() -> anyhow::Result<()> {
+        let temp_dir = tempdir()?;
+        let data_file = temp_dir.path().join("db");
+        let repository = HatenaBlogRepository::new(data_file).await?;
+        let bbn_entry_id = bbn_data::EntryId::from_str("2021-02-03")?;
+        assert_eq!(repository.find_sync_state(&bbn_entry_id).await?, None);
+
+        let mut sync_state = SyncState {
+            bbn_entry_id: bbn_entry_id.clone(),
+            hatena_blog_entry_id: HatenaBlogEntryId::from_str("1")?,
+            title: "TITLE".to_string(),
+            content: "CONTENT".to_string(),
+            edited: Timestamp::try_from(1_i64)?,
+            synced_at: Timestamp::try_from(2_i64)?,
+        };
+        repository.save_sync_state(&sync_state).await?;
+        assert_eq!(
+            repository.find_sync_state(&bbn_entry_id).await?,
+            Some(sync_state.clone())
+        );
+
+        sync_state.content = "CONTENT2".to_string();
+        repository.save_sync_state(&sync_state).await?;
+        assert_eq!(
+            repository.find_sync_state(&bbn_entry_id).await?,
+            Some(sync_state)
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,409 @@
+use crate::hatena_blog::{HatenaBlogEntry, SyncState};
+use bbn_data::{Entry, Timestamp};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SyncAction {
+    /// The local entry and the remote entry are the same (or the differences are not changes).
+    Unchanged,
+    /// Creates the remote entry.
+    Create,
+    /// Updates the remote entry with the local entry.
+    Push,
+    /// Updates (or creates) the local entry with the remote entry.
+    Pull,
+    /// Updates both entries with the merged title and content.
+    Merge { title: String, content: String },
+    /// Both entries have been changed since the last sync. `diff` has the conflict markers.
+    Conflict { diff: String },
+    /// The remote entry has been deleted since the last sync.
+    RemoteDeleted,
+    /// The local entry has been deleted since the last sync.
+    LocalDeleted,
+}
+
+/// Decides the action from the last synced state (`base`), the local entry and the remote entry.
+pub fn plan_sync(
+    base: Option<&SyncState>,
+    local: Option<&Entry>,
+    remote: Option<&HatenaBlogEntry>,
+) -> SyncAction {
+    let (local, remote) = match (local, remote) {
+        (None, None) => return SyncAction::Unchanged,
+        (None, Some(remote)) => {
+            return match base {
+                None => SyncAction::Pull,
+                Some(base) if is_remote_changed(base, remote) => SyncAction::Conflict {
+                    diff: conflict_diff(base.content.as_str(), "", remote.content.as_str()),
+                },
+                Some(_) => SyncAction::LocalDeleted,
+            }
+        }
+        (Some(_), None) => {
+            return if base.is_some() {
+                SyncAction::RemoteDeleted
+            } else {
+                SyncAction::Create
+            }
+        }
+        (Some(local), Some(remote)) => (local, remote),
+    };
+    let local_title = local.meta().title.as_str();
+    let local_content = local.content();
+    if is_same(local_title, remote.title.as_str())
+        && is_same(local_content, remote.content.as_str())
+    {
+        return SyncAction::Unchanged;
+    }
+
+    let base = match base {
+        None => {
+            // the first sync. both sides are treated as changed
+            return SyncAction::Conflict {
+                diff: conflict_diff("", local_content, remote.content.as_str()),
+            };
+        }
+        Some(base) => base,
+    };
+    let local_changed = base.title != local_title || base.content != local_content;
+    match (local_changed, is_remote_changed(base, remote)) {
+        (false, false) => SyncAction::Unchanged,
+        (true, false) => SyncAction::Push,
+        (false, true) => SyncAction::Pull,
+        (true, true) => {
+            let title = merge_title(base.title.as_str(), local_title, remote.title.as_str());
+            let merged = merge3(
+                base.content.as_str(),
+                local_content,
+                remote.content.as_str(),
+            );
+            match (title, merged) {
+                (Some(title), Merged { text, conflicts: 0 }) => SyncAction::Merge {
+                    title: title.to_string(),
+                    content: text,
+                },
+                (title, Merged { text, .. }) => SyncAction::Conflict {
+                    diff: match title {
+                        Some(_) => text,
+                        None => format!(
+                            "{}{}",
+                            conflict_diff(base.title.as_str(), local_title, remote.title.as_str()),
+                            text
+                        ),
+                    },
+                },
+            }
+        }
+    }
+}
+
+// the remote entry is normalized by the server (e.g. line endings) without `edited` changes
+fn is_remote_changed(base: &SyncState, remote: &HatenaBlogEntry) -> bool {
+    base.edited != Timestamp::from(remote.edited)
+        && (base.title != remote.title || base.content != remote.content)
+}
+
+// ignores the differences of the line endings and the trailing whitespaces
+fn is_same(a: &str, b: &str) -> bool {
+    a.replace("\r\n", "\n").trim_end() == b.replace("\r\n", "\n").trim_end()
+}
+
+fn merge_title<'a>(base: &'a str, local: &'a str, remote: &'a str) -> Option<&'a str> {
+    if local == remote || remote == base {
+        Some(local)
+    } else if local == base {
+        Some(remote)
+    } else {
+        None
+    }
+}
+
+fn conflict_diff(base: &str, local: &str, remote: &str) -> String {
+    let mut lines = vec!["<<<<<<< local"];
+    lines.extend(local.lines());
+    lines.push("||||||| base");
+    lines.extend(base.lines());
+    lines.push("=======");
+    lines.extend(remote.lines());
+    lines.push(">>>>>>> remote");
+    let mut s = lines.join("\n");
+    s.push('\n');
+    s
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Merged {
+    pub text: String,
+    pub conflicts: usize,
+}
+
+// the changes from the base lines
+struct Changes<'a> {
+    // `kept[i]` is `true` if the base line `i` is kept
+    kept: Vec<bool>,
+    // `inserted[i]` is the lines inserted before the base line `i` (`i == base_lines.len()` is the end)
+    inserted: Vec<Vec<&'a str>>,
+}
+
+impl<'a> Changes<'a> {
+    fn new(base: &'a str, other: &'a str) -> Self {
+        let base_lines = base.lines().collect::<Vec<&str>>();
+        let other_lines = other.lines().collect::<Vec<&str>>();
+        let mut kept = vec![false; base_lines.len()];
+        let mut inserted = vec![vec![]; base_lines.len() + 1];
+        let mut i = 0;
+        // the first line of the deleted lines. the lines replacing them are inserted there
+        let mut deleted = None;
+        for result in diff::slice(&base_lines, &other_lines) {
+            match result {
+                diff::Result::Left(_) => {
+                    deleted.get_or_insert(i);
+                    i += 1;
+                }
+                diff::Result::Both(..) => {
+                    kept[i] = true;
+                    deleted = None;
+                    i += 1;
+                }
+                diff::Result::Right(line) => inserted[deleted.unwrap_or(i)].push(*line),
+            }
+        }
+        Self { kept, inserted }
+    }
+
+    fn is_changed(&self, i: usize) -> bool {
+        !self.inserted[i].is_empty() || self.kept.get(i).map(|kept| !kept).unwrap_or(false)
+    }
+
+    fn lines(&self, base_lines: &[&'a str], from: usize, to: usize) -> Vec<&'a str> {
+        let mut lines = vec![];
+        for (i, inserted) in self.inserted.iter().enumerate().take(to).skip(from) {
+            lines.extend(inserted.iter());
+            if self.kept.get(i).copied().unwrap_or(false) {
+                lines.extend(base_lines.get(i));
+            }
+        }
+        lines
+    }
+}
+
+/// Merges the local changes and the remote changes from the base line by line (like `diff3 -m`).
+pub fn merge3(base: &str, local: &str, remote: &str) -> Merged {
+    let base_lines = base.lines().collect::<Vec<&str>>();
+    let local_changes = Changes::new(base, local);
+    let remote_changes = Changes::new(base, remote);
+    let mut lines = vec![];
+    let mut conflicts = 0;
+    let mut i = 0;
+    while i <= base_lines.len() {
+        if !local_changes.is_changed(i) && !remote_changes.is_changed(i) {
+            lines.extend(base_lines.get(i));
+            i += 1;
+            continue;
+        }
+
+        let from = i;
+        while i <= base_lines.len() && (local_changes.is_changed(i) || remote_changes.is_changed(i))
+        {
+            i += 1;
+        }
+        let base_region = base_lines[from..i.min(base_lines.len())].to_vec();
+        let local_region = local_changes.lines(&base_lines, from, i);
+        let remote_region = remote_changes.lines(&base_lines, from, i);
+        if local_region == remote_region || remote_region == base_region {
+            lines.extend(local_region);
+        } else if local_region == base_region {
+            lines.extend(remote_region);
+        } else {
+            conflicts += 1;
+            lines.push("<<<<<<< local");
+            lines.extend(local_region);
+            lines.push("||||||| base");
+            lines.extend(base_region);
+            lines.push("=======");
+            lines.extend(remote_region);
+            lines.push(">>>>>>> remote");
+        }
+    }
+    let mut text = lines.join("\n");
+    if !lines.is_empty() && (local.ends_with('\n') || remote.ends_with('\n')) {
+        text.push('\n');
+    }
+    Merged { text, conflicts }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::hatena_blog::HatenaBlogEntryId;
+    use bbn_data::{DateTime, EntryId, EntryMeta};
+
+    use super::*;
+
+    fn local(title: &str, content: &str) -> anyhow::Result<Entry> {
+        Ok(Entry::new(
+            EntryId::from_str("2021-02-03")?,
+            EntryMeta::new(
+                5,
+                DateTime::from_str("2021-02-03T00:00:00+09:00")?,
+                vec![],
+                title.to_string(),
+            ),
+            content.to_string(),
+        ))
+    }
+
+    fn remote(title: &str, content: &str, edited: &str) -> anyhow::Result<HatenaBlogEntry> {
+        let at = DateTime::from_str("2021-02-03T00:00:00+09:00")?;
+        Ok(HatenaBlogEntry {
+            author_name: "bouzuya".to_string(),
+            categories: vec![],
+            content: content.to_string(),
+            draft: false,
+            edit_url: "https://example.com/edit".to_string(),
+            edited: DateTime::from_str(edited)?,
+            id: HatenaBlogEntryId::from_str("1")?,
+            published: at,
+            title: title.to_string(),
+            updated: at,
+            url: "https://example.com/".to_string(),
+        })
+    }
+
+    fn base(title: &str, content: &str, edited: &str) -> anyhow::Result<SyncState> {
+        Ok(SyncState {
+            bbn_entry_id: EntryId::from_str("2021-02-03")?,
+            hatena_blog_entry_id: HatenaBlogEntryId::from_str("1")?,
+            title: title.to_string(),
+            content: content.to_string(),
+            edited: Timestamp::from(DateTime::from_str(edited)?),
+            synced_at: Timestamp::from(DateTime::from_str(edited)?),
+        })
+    }
+
+    #[test]
+    fn plan_sync_test() -> anyhow::Result<()> {
+        let t1 = "2021-02-03T00:00:00+09:00";
+        let t2 = "2021-02-04T00:00:00+09:00";
+        let base = base("T", "a\nb\nc\n", t1)?;
+        let same = local("T", "a\nb\nc\n")?;
+        let unchanged_remote = remote("T", "a\nb\nc\n", t1)?;
+
+        assert_eq!(plan_sync(None, None, None), SyncAction::Unchanged);
+        assert_eq!(
+            plan_sync(None, None, Some(&unchanged_remote)),
+            SyncAction::Pull
+        );
+        assert_eq!(
+            plan_sync(Some(&base), None, Some(&unchanged_remote)),
+            SyncAction::LocalDeleted
+        );
+        assert_eq!(
+            plan_sync(Some(&base), None, Some(&remote("T", "a\nb\nC\n", t2)?)),
+            SyncAction::Conflict {
+                diff: "<<<<<<< local\n||||||| base\na\nb\nc\n=======\na\nb\nC\n>>>>>>> remote\n"
+                    .to_string()
+            }
+        );
+        assert_eq!(plan_sync(None, Some(&same), None), SyncAction::Create);
+        assert_eq!(
+            plan_sync(Some(&base), Some(&same), None),
+            SyncAction::RemoteDeleted
+        );
+        assert_eq!(
+            plan_sync(None, Some(&same), Some(&unchanged_remote)),
+            SyncAction::Unchanged
+        );
+        assert_eq!(
+            plan_sync(
+                Some(&base),
+                Some(&local("T", "A\nb\nc\n")?),
+                Some(&unchanged_remote)
+            ),
+            SyncAction::Push
+        );
+        assert_eq!(
+            plan_sync(
+                Some(&base),
+                Some(&same),
+                Some(&remote("T", "a\nb\nC\n", t2)?)
+            ),
+            SyncAction::Pull
+        );
+        // normalized by the server
+        assert_eq!(
+            plan_sync(None, Some(&same), Some(&remote("T", "a\r\nb\r\nc", t1)?)),
+            SyncAction::Unchanged
+        );
+        assert_eq!(
+            plan_sync(
+                Some(&base),
+                Some(&same),
+                Some(&remote("T", "a\r\nb\r\nc\r\n", t1)?)
+            ),
+            SyncAction::Unchanged
+        );
+        assert_eq!(
+            plan_sync(
+                Some(&base),
+                Some(&local("T2", "A\nb\nc\n")?),
+                Some(&remote("T", "a\nb\nC\n", t2)?)
+            ),
+            SyncAction::Merge {
+                title: "T2".to_string(),
+                content: "A\nb\nC\n".to_string()
+            }
+        );
+        assert_eq!(
+            plan_sync(
+                Some(&base),
+                Some(&local("T2", "a\nb\nc\n")?),
+                Some(&remote("T3", "a\nb\nc\n", t2)?)
+            ),
+            SyncAction::Conflict {
+                diff: "<<<<<<< local\nT2\n||||||| base\nT\n=======\nT3\n>>>>>>> remote\na\nb\nc\n"
+                    .to_string()
+            }
+        );
+        assert_eq!(
+            plan_sync(
+                None,
+                Some(&local("T", "a\n")?),
+                Some(&remote("T", "b\n", t2)?)
+            ),
+            SyncAction::Conflict {
+                diff: "<<<<<<< local\na\n||||||| base\n=======\nb\n>>>>>>> remote\n".to_string()
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn merge3_test() {
+        let merged = |text: &str, conflicts: usize| Merged {
+            text: text.to_string(),
+            conflicts,
+        };
+        assert_eq!(
+            merge3("a\nb\nc\n", "a\nb\nc\n", "a\nb\nc\n"),
+            merged("a\nb\nc\n", 0)
+        );
+        assert_eq!(
+            merge3("a\nb\nc\n", "A\nb\nc\n", "a\nb\nC\n"),
+            merged("A\nb\nC\n", 0)
+        );
+        assert_eq!(
+            merge3("a\nb\nc\n", "x\na\nb\nc\n", "a\nb\nc\ny\n"),
+            merged("x\na\nb\nc\ny\n", 0)
+        );
+        assert_eq!(merge3("a\nb\nc\n", "a\nc\n", "a\nc\n"), merged("a\nc\n", 0));
+        assert_eq!(
+            merge3("a\nb\nc\n", "a\nx\nc\n", "a\ny\nc\n"),
+            merged(
+                "a\n<<<<<<< local\nx\n||||||| base\nb\n=======\ny\n>>>>>>> remote\nc\n",
+                1
+            )
+        );
+        assert_eq!(merge3("", "", ""), merged("", 0));
+    }
+}
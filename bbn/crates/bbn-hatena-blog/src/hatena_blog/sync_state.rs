@@ -0,0 +1,14 @@
+use crate::hatena_blog::HatenaBlogEntryId;
+use bbn_data::{EntryId, Timestamp};
+
+/// The entry at the last sync. It is the base of the three-way merge.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SyncState {
+    pub bbn_entry_id: EntryId,
+    pub hatena_blog_entry_id: HatenaBlogEntryId,
+    pub title: String,
+    pub content: String,
+    // the `edited` of the hatena-blog entry
+    pub edited: Timestamp,
+    pub synced_at: Timestamp,
+}
@@ -1,9 +1,11 @@
 use std::{
+    collections::BTreeSet,
     convert::TryFrom,
     ffi::OsStr,
     fs, io,
     path::{Path, PathBuf},
     str::FromStr,
+    time::UNIX_EPOCH,
 };
 
 use anyhow::Context;
@@ -11,7 +13,7 @@ use date_range::date::{Date, YearMonth};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::query::Query;
+use crate::{query::Query, search_index::SearchIndex};
 use bbn_data::{DateTime, Entry, EntryId, EntryMeta};
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -58,6 +60,9 @@ impl From<EntryMeta> for MetaJson {
     }
 }
 
+// data/.search-index.json
+const SEARCH_INDEX_FILE_NAME: &str = ".search-index.json";
+
 #[derive(Debug)]
 pub struct BbnRepository {
     data_dir: PathBuf,
@@ -95,10 +100,18 @@ impl BbnRepository {
 
     pub fn find_ids_by_query(&self, query: Query) -> anyhow::Result<Vec<EntryId>> {
         let posts = list_posts(self.data_dir.as_path(), &query)?;
-        posts
-            .into_iter()
-            .map(|post| self.post_to_entry_id(post))
-            .collect::<anyhow::Result<Vec<EntryId>>>()
+        let mut entry_ids = vec![];
+        for post in posts {
+            let entry_id = self.post_to_entry_id(post)?;
+            if query.needs_meta() {
+                let meta = self.find_meta_by_id(&entry_id)?.context("meta not found")?;
+                if !query.match_entry(entry_id.date().to_string().as_str(), &meta) {
+                    continue;
+                }
+            }
+            entry_ids.push(entry_id);
+        }
+        Ok(entry_ids)
     }
 
     pub fn find_meta_by_id(&self, entry_id: &EntryId) -> anyhow::Result<Option<EntryMeta>> {
@@ -136,6 +149,44 @@ impl BbnRepository {
         Ok(())
     }
 
+    /// Loads the search index and updates the entries modified since the last call.
+    pub fn search_index(&self) -> anyhow::Result<SearchIndex> {
+        let path = self.data_dir.join(SEARCH_INDEX_FILE_NAME);
+        let loaded = if path.is_file() {
+            // rebuilds the broken or old index
+            serde_json::from_str::<'_, SearchIndex>(fs::read_to_string(path.as_path())?.as_str())
+                .ok()
+                .filter(|index| index.is_compatible())
+        } else {
+            None
+        };
+        let mut updated = loaded.is_none();
+        let mut index = loaded.unwrap_or_default();
+
+        let entry_ids = self.find_ids_by_query(Query::All)?;
+        updated |= index.retain(
+            &entry_ids
+                .iter()
+                .map(|entry_id| entry_id.to_string())
+                .collect::<BTreeSet<String>>(),
+        );
+        for entry_id in entry_ids {
+            let modified = self.find_modified_by_id(&entry_id)?;
+            if index.is_fresh(&entry_id, modified) {
+                continue;
+            }
+            if let Some(entry) = self.find_entry_by_id(&entry_id)? {
+                index.insert(&entry, modified);
+                updated = true;
+            }
+        }
+
+        if updated {
+            fs::write(path.as_path(), serde_json::to_string(&index)?)?;
+        }
+        Ok(index)
+    }
+
     fn find_ids_by_year_month(&self, year_month: YearMonth) -> anyhow::Result<Vec<EntryId>> {
         let entry_dir = self
             .data_dir
@@ -160,6 +211,29 @@ impl BbnRepository {
         Ok(entry_ids)
     }
 
+    /// Returns the nanoseconds since UNIX epoch when the entry (`.json` or `.md`) was last modified.
+    fn find_modified_by_id(&self, entry_id: &EntryId) -> anyhow::Result<u64> {
+        let entry_dir = self
+            .data_dir
+            .join(entry_id.date().year().to_string())
+            .join(entry_id.date().month().to_string());
+        let mut modified = 0;
+        for path in [
+            entry_dir.join(format!("{entry_id}.json")),
+            entry_dir.join(format!("{entry_id}.md")),
+        ] {
+            if !path.is_file() {
+                continue;
+            }
+            let nanos = fs::metadata(path)?
+                .modified()?
+                .duration_since(UNIX_EPOCH)?
+                .as_nanos();
+            modified = modified.max(u64::try_from(nanos)?);
+        }
+        Ok(modified)
+    }
+
     fn post_to_entry_id(&self, post: Post) -> anyhow::Result<EntryId> {
         let date = Date::from_str(post.date.as_str())?;
         Ok(EntryId::new(date, post.id_title))
@@ -199,6 +273,9 @@ fn list_posts_year(path: &Path, query: &Query) -> Result<Vec<Post>, ListPostsErr
     let mut years = vec![];
     for dir_entry in path.read_dir()? {
         let path_buf = dir_entry?.path();
+        if !path_buf.is_dir() {
+            continue;
+        }
         if let Some(year) = path_buf.file_name() {
             if query.match_year(year) {
                 years.push(path_buf);
@@ -219,6 +296,9 @@ fn list_posts_month(path: &Path, query: &Query) -> Result<Vec<Post>, ListPostsEr
     let mut months = vec![];
     for dir_entry in path.read_dir()? {
         let path_buf = dir_entry?.path();
+        if !path_buf.is_dir() {
+            continue;
+        }
         if let Some(month) = path_buf.file_name() {
             if query.match_month(month) {
                 months.push(path_buf);
@@ -280,6 +360,8 @@ mod tests {
 
     use bbn_data::{DateTime, EntryId};
 
+    use crate::SearchQuery;
+
     use super::*;
 
     fn create_test_dir(temp_dir: &Path) -> anyhow::Result<PathBuf> {
@@ -391,8 +473,6 @@ mod tests {
 
     #[test]
     fn find_ids_by_query_test() -> anyhow::Result<()> {
-        // TODO: Query
-
         let temp_dir = tempdir()?;
         let data_dir = create_test_dir(temp_dir.path())?;
 
@@ -406,6 +486,24 @@ mod tests {
                 EntryId::from_str("2021-07-07-id1")?
             ]
         );
+
+        let f = |s: &str| -> anyhow::Result<Vec<String>> {
+            Ok(repository
+                .find_ids_by_query(Query::try_from(s)?)?
+                .into_iter()
+                .map(|entry_id| entry_id.to_string())
+                .collect())
+        };
+        assert_eq!(f("date:2021-07-07")?, vec!["2021-07-07-id1"]);
+        assert_eq!(f("-date:2021-07-07")?, vec!["2021-07-06"]);
+        assert_eq!(f("tag:tag1")?, vec!["2021-07-06"]);
+        assert_eq!(f("title:title2")?, vec!["2021-07-07-id1"]);
+        assert_eq!(f("minutes:>5")?, vec!["2021-07-07-id1"]);
+        assert_eq!(
+            f("tag:tag1 OR minutes:>5")?,
+            vec!["2021-07-06", "2021-07-07-id1"]
+        );
+        assert_eq!(f("date:2021-07-07 tag:tag1")?, Vec::<String>::new());
         Ok(())
     }
 
@@ -439,6 +537,63 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn search_index_test() -> anyhow::Result<()> {
+        let temp_dir = tempdir()?;
+        let data_dir = create_test_dir(temp_dir.path())?;
+        let repository = BbnRepository::new(data_dir.clone());
+        let search = |words: &[&str]| -> anyhow::Result<Vec<EntryId>> {
+            Ok(repository
+                .search_index()?
+                .search(&words.iter().collect::<SearchQuery>(), false)?
+                .into_iter()
+                .map(|hit| hit.entry_id)
+                .collect())
+        };
+        assert_eq!(
+            search(&["content2"])?,
+            vec![EntryId::from_str("2021-07-07-id1")?]
+        );
+        assert!(data_dir.join(".search-index.json").is_file());
+        // the index file is not an entry
+        assert_eq!(repository.find_ids_by_query(Query::try_from("")?)?.len(), 2);
+
+        // updated
+        repository.save(Entry::new(
+            EntryId::from_str("2021-07-07-id1")?,
+            EntryMeta::new(
+                6,
+                DateTime::from_str("2021-07-07T23:59:59+09:00")?,
+                vec![],
+                "TITLE2".to_string(),
+            ),
+            "CONTENT3".to_string(),
+        ))?;
+        let path = data_dir.join("2021").join("07").join("2021-07-07-id1.md");
+        let modified =
+            fs::metadata(path.as_path())?.modified()? + std::time::Duration::from_secs(1);
+        fs::File::options()
+            .write(true)
+            .open(path.as_path())?
+            .set_modified(modified)?;
+        assert!(search(&["content2"])?.is_empty());
+        assert_eq!(
+            search(&["content3"])?,
+            vec![EntryId::from_str("2021-07-07-id1")?]
+        );
+
+        // removed
+        fs::remove_file(path)?;
+        fs::remove_file(data_dir.join("2021").join("07").join("2021-07-07-id1.json"))?;
+        assert!(search(&["content3"])?.is_empty());
+        assert_eq!(search(&["title1"])?, vec![EntryId::from_str("2021-07-06")?]);
+
+        // broken
+        fs::write(data_dir.join(".search-index.json"), "{")?;
+        assert_eq!(search(&["title1"])?, vec![EntryId::from_str("2021-07-06")?]);
+        Ok(())
+    }
+
     #[test]
     fn save_test() -> anyhow::Result<()> {
         let temp_dir = tempdir()?;
@@ -1,5 +1,7 @@
 mod bbn_repository;
 mod query;
+mod search_index;
 
 pub use self::bbn_repository::*;
 pub use self::query::*;
+pub use self::search_index::*;
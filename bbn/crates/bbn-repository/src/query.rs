@@ -1,20 +1,64 @@
-use std::ffi::OsStr;
+use std::{ffi::OsStr, str::FromStr};
 
+use bbn_data::EntryMeta;
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_while_m_n},
-    character::complete::char,
-    combinator::{all_consuming, map},
-    sequence::tuple,
+    bytes::complete::{tag, take_while, take_while1, take_while_m_n},
+    character::complete::{char, digit1, multispace0, multispace1},
+    combinator::{all_consuming, map, map_res, opt, value},
+    multi::many0,
+    sequence::{delimited, pair, preceded, tuple},
     IResult,
 };
 use thiserror::Error;
 
+/// e.g. `date:2021 tag:rust -title:"foo bar" OR (minutes:>10 AND NOT tag:diary)`
+///
+/// `NOT` (`-`) binds tighter than `AND` (or a space), and `AND` binds tighter than `OR`.
 #[derive(Debug, Eq, PartialEq)]
 pub enum Query<'a> {
     All,
     Date(Date<'a>),
     DateRange(DateRange<'a>),
+    And(Box<Query<'a>>, Box<Query<'a>>),
+    Minutes(Comparison, u64),
+    Not(Box<Query<'a>>),
+    Or(Box<Query<'a>>, Box<Query<'a>>),
+    Tag(&'a str),
+    Title(&'a str),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Comparison {
+    Eq,
+    Ge,
+    Gt,
+    Le,
+    Lt,
+}
+
+impl Comparison {
+    fn compare(&self, lhs: u64, rhs: u64) -> bool {
+        match self {
+            Comparison::Eq => lhs == rhs,
+            Comparison::Ge => lhs >= rhs,
+            Comparison::Gt => lhs > rhs,
+            Comparison::Le => lhs <= rhs,
+            Comparison::Lt => lhs < rhs,
+        }
+    }
+}
+
+impl std::fmt::Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Comparison::Eq => write!(f, ""),
+            Comparison::Ge => write!(f, ">="),
+            Comparison::Gt => write!(f, ">"),
+            Comparison::Le => write!(f, "<="),
+            Comparison::Lt => write!(f, "<"),
+        }
+    }
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -121,11 +165,16 @@ pub enum ParseQueryError {
 }
 
 impl<'a> Query<'a> {
+    // `match_year`, `match_month` and `match_day` return `true` if the entries in the dir may match.
+
     pub fn match_year(&self, year: &OsStr) -> bool {
         match self {
             Query::All => true,
             Query::Date(date) => date.match_year(year),
             Query::DateRange(date_range) => date_range.match_year(year),
+            Query::And(q1, q2) => q1.match_year(year) && q2.match_year(year),
+            Query::Or(q1, q2) => q1.match_year(year) || q2.match_year(year),
+            Query::Minutes(..) | Query::Not(_) | Query::Tag(_) | Query::Title(_) => true,
         }
     }
 
@@ -134,6 +183,9 @@ impl<'a> Query<'a> {
             Query::All => true,
             Query::Date(date) => date.match_month(month),
             Query::DateRange(date_range) => date_range.match_month(month),
+            Query::And(q1, q2) => q1.match_month(month) && q2.match_month(month),
+            Query::Or(q1, q2) => q1.match_month(month) || q2.match_month(month),
+            Query::Minutes(..) | Query::Not(_) | Query::Tag(_) | Query::Title(_) => true,
         }
     }
 
@@ -142,24 +194,92 @@ impl<'a> Query<'a> {
             Query::All => true,
             Query::Date(date) => date.match_day(day),
             Query::DateRange(date_range) => date_range.match_day(day),
+            Query::And(q1, q2) => q1.match_day(day) && q2.match_day(day),
+            Query::Or(q1, q2) => q1.match_day(day) || q2.match_day(day),
+            Query::Minutes(..) | Query::Not(_) | Query::Tag(_) | Query::Title(_) => true,
         }
     }
 
+    /// Returns `true` if the entry of the date may match (exactly if `!self.needs_meta()`).
     pub fn match_date(&self, date: &str) -> bool {
         match self {
             Query::All => true,
             Query::Date(d) => d.match_date(date),
             Query::DateRange(dr) => dr.match_date(date),
+            Query::And(q1, q2) => q1.match_date(date) && q2.match_date(date),
+            Query::Or(q1, q2) => q1.match_date(date) || q2.match_date(date),
+            Query::Not(q) => q.needs_meta() || !q.match_date(date),
+            Query::Minutes(..) | Query::Tag(_) | Query::Title(_) => true,
+        }
+    }
+
+    pub fn match_entry(&self, date: &str, meta: &EntryMeta) -> bool {
+        match self {
+            Query::All => true,
+            Query::Date(d) => d.match_date(date),
+            Query::DateRange(dr) => dr.match_date(date),
+            Query::And(q1, q2) => q1.match_entry(date, meta) && q2.match_entry(date, meta),
+            Query::Or(q1, q2) => q1.match_entry(date, meta) || q2.match_entry(date, meta),
+            Query::Not(q) => !q.match_entry(date, meta),
+            Query::Minutes(comparison, minutes) => comparison.compare(meta.minutes, *minutes),
+            Query::Tag(tag) => meta
+                .tags
+                .iter()
+                .any(|t| t.to_lowercase() == tag.to_lowercase()),
+            Query::Title(title) => meta.title.to_lowercase().contains(&title.to_lowercase()),
+        }
+    }
+
+    /// Returns `true` if the query uses the fields of `EntryMeta`.
+    pub fn needs_meta(&self) -> bool {
+        match self {
+            Query::All | Query::Date(_) | Query::DateRange(_) => false,
+            Query::And(q1, q2) | Query::Or(q1, q2) => q1.needs_meta() || q2.needs_meta(),
+            Query::Not(q) => q.needs_meta(),
+            Query::Minutes(..) | Query::Tag(_) | Query::Title(_) => true,
         }
     }
 }
 
 impl<'a> std::fmt::Display for Query<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let group = |q: &Query, parenthesized: bool| {
+            if parenthesized {
+                format!("({q})")
+            } else {
+                q.to_string()
+            }
+        };
         match self {
             Query::All => write!(f, ""),
             Query::Date(date) => write!(f, "date:{date}"),
             Query::DateRange(date_range) => write!(f, "date:{date_range}"),
+            Query::And(q1, q2) => write!(
+                f,
+                "{} {}",
+                group(q1, matches!(**q1, Query::Or(..))),
+                group(q2, matches!(**q2, Query::And(..) | Query::Or(..)))
+            ),
+            Query::Or(q1, q2) => write!(
+                f,
+                "{} OR {}",
+                group(q1, false),
+                group(q2, matches!(**q2, Query::Or(..)))
+            ),
+            Query::Not(q) => write!(
+                f,
+                "-{}",
+                group(q, matches!(**q, Query::And(..) | Query::Or(..)))
+            ),
+            Query::Minutes(comparison, minutes) => write!(f, "minutes:{comparison}{minutes}"),
+            Query::Tag(tag) => write!(f, "tag:{tag}"),
+            Query::Title(title) => {
+                if !title.is_empty() && title.chars().all(is_word_char) {
+                    write!(f, "title:{title}")
+                } else {
+                    write!(f, "title:\"{title}\"")
+                }
+            }
         }
     }
 }
@@ -168,6 +288,10 @@ fn is_digit(c: char) -> bool {
     c.is_ascii_digit()
 }
 
+fn is_word_char(c: char) -> bool {
+    !c.is_whitespace() && c != '(' && c != ')' && c != '"'
+}
+
 fn date_range_date(s: &str) -> IResult<&str, DateRangeDate> {
     map(
         tuple((
@@ -233,18 +357,108 @@ fn dd(s: &str) -> IResult<&str, Date> {
     Ok((s, Date(None, None, Some(d))))
 }
 
-fn parse(s: &str) -> IResult<&str, Query> {
-    if s.is_empty() {
+fn word(s: &str) -> IResult<&str, &str> {
+    alt((
+        delimited(char('"'), take_while(|c| c != '"'), char('"')),
+        take_while1(is_word_char),
+    ))(s)
+}
+
+fn date_query(s: &str) -> IResult<&str, Query<'_>> {
+    preceded(
+        tag("date:"),
+        alt((
+            map(date_range, Query::DateRange),
+            map(alt((yyyymmdd, yyyymm, yyyy, mmdd, mm, dd)), Query::Date),
+        )),
+    )(s)
+}
+
+fn minutes_query(s: &str) -> IResult<&str, Query<'_>> {
+    let (s, _) = tag("minutes:")(s)?;
+    let (s, comparison) = opt(alt((
+        value(Comparison::Ge, tag(">=")),
+        value(Comparison::Gt, tag(">")),
+        value(Comparison::Le, tag("<=")),
+        value(Comparison::Lt, tag("<")),
+        value(Comparison::Eq, tag("=")),
+    )))(s)?;
+    let (s, minutes) = map_res(digit1, u64::from_str)(s)?;
+    Ok((
+        s,
+        Query::Minutes(comparison.unwrap_or(Comparison::Eq), minutes),
+    ))
+}
+
+fn tag_query(s: &str) -> IResult<&str, Query<'_>> {
+    map(preceded(tag("tag:"), take_while1(is_word_char)), Query::Tag)(s)
+}
+
+fn title_query(s: &str) -> IResult<&str, Query<'_>> {
+    map(preceded(tag("title:"), word), Query::Title)(s)
+}
+
+fn primary_query(s: &str) -> IResult<&str, Query<'_>> {
+    alt((
+        delimited(
+            pair(char('('), multispace0),
+            or_query,
+            pair(multispace0, char(')')),
+        ),
+        date_query,
+        minutes_query,
+        tag_query,
+        title_query,
+    ))(s)
+}
+
+fn not_query(s: &str) -> IResult<&str, Query<'_>> {
+    alt((
+        map(
+            preceded(
+                alt((
+                    value((), tag("-")),
+                    value((), pair(tag("NOT"), multispace1)),
+                )),
+                not_query,
+            ),
+            |q| Query::Not(Box::new(q)),
+        ),
+        primary_query,
+    ))(s)
+}
+
+fn and_query(s: &str) -> IResult<&str, Query<'_>> {
+    let (s, first) = not_query(s)?;
+    let (s, rest) = many0(preceded(
+        pair(multispace1, opt(pair(tag("AND"), multispace1))),
+        not_query,
+    ))(s)?;
+    Ok((
+        s,
+        rest.into_iter()
+            .fold(first, |q1, q2| Query::And(Box::new(q1), Box::new(q2))),
+    ))
+}
+
+fn or_query(s: &str) -> IResult<&str, Query<'_>> {
+    let (s, first) = and_query(s)?;
+    let (s, rest) = many0(preceded(
+        tuple((multispace1, tag("OR"), multispace1)),
+        and_query,
+    ))(s)?;
+    Ok((
+        s,
+        rest.into_iter()
+            .fold(first, |q1, q2| Query::Or(Box::new(q1), Box::new(q2))),
+    ))
+}
+
+fn parse(s: &str) -> IResult<&str, Query<'_>> {
+    if s.trim().is_empty() {
         return Ok((s, Query::All));
     }
-    let (s, _) = tag("date:")(s)?;
-    let (s, date) = all_consuming(alt((
-        map(date_range, Query::DateRange),
-        map(alt((yyyymmdd, yyyymm, yyyy, mmdd, mm, dd)), |d| {
-            Query::Date(d)
-        }),
-    )))(s)?;
-    Ok((s, date))
+    all_consuming(delimited(multispace0, or_query, multispace0))(s)
 }
 
 impl<'a> std::convert::TryFrom<&'a str> for Query<'a> {
@@ -273,6 +487,105 @@ mod tests {
         f("date:--02");
         f("date:---03");
         f("date:2021-02-03/2022-03-04");
+        f("tag:rust");
+        f("title:foo");
+        f("title:\"foo bar\"");
+        f("minutes:10");
+        f("minutes:>10");
+        f("minutes:>=10");
+        f("minutes:<10");
+        f("minutes:<=10");
+        f("-tag:rust");
+        f("date:2021 tag:rust");
+        f("date:2021 OR tag:rust");
+        f("date:2021 -tag:rust OR title:foo minutes:>10");
+        f("(date:2021 OR date:2022) tag:rust");
+        f("date:2021 (tag:rust tag:go)");
+        f("date:2021 OR (tag:rust OR tag:go)");
+        f("-(tag:rust OR tag:go)");
+    }
+
+    #[test]
+    fn parse_test() {
+        let q = |s: &'static str| Query::try_from(s).unwrap();
+        let and = |q1, q2| Query::And(Box::new(q1), Box::new(q2));
+        let or = |q1, q2| Query::Or(Box::new(q1), Box::new(q2));
+        let not = |q| Query::Not(Box::new(q));
+        assert_eq!(q(" "), Query::All);
+        assert_eq!(q("minutes:=10"), Query::Minutes(Comparison::Eq, 10));
+        assert_eq!(
+            q(" tag:a  AND  tag:b "),
+            and(Query::Tag("a"), Query::Tag("b"))
+        );
+        assert_eq!(
+            q("tag:a tag:b OR NOT tag:c tag:d"),
+            or(
+                and(Query::Tag("a"), Query::Tag("b")),
+                and(not(Query::Tag("c")), Query::Tag("d"))
+            )
+        );
+        assert_eq!(
+            q("( tag:a OR tag:b ) tag:c"),
+            and(or(Query::Tag("a"), Query::Tag("b")), Query::Tag("c"))
+        );
+        assert_eq!(q("title:\"\""), Query::Title(""));
+        for s in [
+            "foo",
+            "tag:",
+            "title:\"foo",
+            "minutes:>",
+            "date:2021-0",
+            "tag:a OR",
+            "tag:a AND",
+            "(tag:a",
+            "tag:a)",
+            "-",
+        ] {
+            assert!(Query::try_from(s).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn match_entry_test() -> anyhow::Result<()> {
+        let meta = EntryMeta::new(
+            15,
+            bbn_data::DateTime::from_str("2021-02-03T16:17:18+09:00")?,
+            vec!["Rust".to_string(), "diary".to_string()],
+            "Hello, World".to_string(),
+        );
+        let f = |s: &str| -> bool {
+            let q = Query::try_from(s).unwrap();
+            q.match_entry("2021-02-03", &meta)
+        };
+        assert!(f(""));
+        assert!(f("date:2021"));
+        assert!(!f("date:2022"));
+        assert!(f("tag:rust"));
+        assert!(!f("tag:go"));
+        assert!(f("title:world"));
+        assert!(f("title:\"hello, world\""));
+        assert!(!f("title:foo"));
+        assert!(f("minutes:15"));
+        assert!(f("minutes:>10"));
+        assert!(!f("minutes:>15"));
+        assert!(f("minutes:>=15"));
+        assert!(!f("minutes:<15"));
+        assert!(f("minutes:<=15"));
+        assert!(f("-tag:go"));
+        assert!(f("tag:rust tag:diary"));
+        assert!(!f("tag:rust tag:go"));
+        assert!(f("tag:go OR tag:rust"));
+        assert!(!f("date:2021 -(tag:go OR tag:rust)"));
+        Ok(())
+    }
+
+    #[test]
+    fn needs_meta_test() {
+        let f = |s: &str| Query::try_from(s).unwrap().needs_meta();
+        assert!(!f(""));
+        assert!(!f("date:2021 OR -date:2022-01"));
+        assert!(f("date:2021 tag:rust"));
+        assert!(f("-title:foo"));
     }
 
     #[test]
@@ -292,6 +605,10 @@ mod tests {
         assert!(f("date:2021-02-03/2022-03-04", "2021"));
         assert!(f("date:2021-02-03/2022-03-04", "2022"));
         assert!(!f("date:2021-02-03/2022-03-04", "2023"));
+        assert!(f("tag:rust", "2020"));
+        assert!(!f("date:2021 tag:rust", "2020"));
+        assert!(f("date:2021 OR date:2020", "2020"));
+        assert!(f("-date:2020", "2020"));
     }
 
     #[test]
@@ -359,5 +676,9 @@ mod tests {
         assert!(f("date:2021-02-03/2022-03-04", "2021-02-03"));
         assert!(f("date:2021-02-03/2022-03-04", "2022-03-04"));
         assert!(!f("date:2021-02-03/2022-03-04", "2022-03-05"));
+        assert!(!f("-date:2021", "2021-02-03"));
+        assert!(f("-date:2021", "2022-02-03"));
+        assert!(f("-tag:rust", "2021-02-03"));
+        assert!(!f("date:2021 -date:2021-02", "2021-02-03"));
     }
 }
@@ -0,0 +1,356 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+use bbn_data::{Entry, EntryId};
+
+const VERSION: u32 = 1;
+const TITLE_WEIGHT: u32 = 10;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct IndexedEntry {
+    modified: u64,
+    tags: Vec<String>,
+    title: String,
+    // token -> count in the content
+    terms: BTreeMap<String, u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SearchIndex {
+    version: u32,
+    // entry id -> indexed entry
+    entries: BTreeMap<String, IndexedEntry>,
+}
+
+impl Default for SearchIndex {
+    fn default() -> Self {
+        Self {
+            version: VERSION,
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+impl SearchIndex {
+    pub(crate) fn is_compatible(&self) -> bool {
+        self.version == VERSION
+    }
+
+    pub(crate) fn is_fresh(&self, entry_id: &EntryId, modified: u64) -> bool {
+        self.entries
+            .get(&entry_id.to_string())
+            .map(|indexed| indexed.modified == modified)
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn insert(&mut self, entry: &Entry, modified: u64) {
+        let mut terms = BTreeMap::new();
+        for token in tokenize(entry.content()) {
+            *terms.entry(token).or_default() += 1;
+        }
+        self.entries.insert(
+            entry.id().to_string(),
+            IndexedEntry {
+                modified,
+                tags: entry.meta().tags.clone(),
+                title: entry.meta().title.clone(),
+                terms,
+            },
+        );
+    }
+
+    /// Removes the entries not in `entry_ids` and returns `true` if removed.
+    pub(crate) fn retain(&mut self, entry_ids: &BTreeSet<String>) -> bool {
+        let len = self.entries.len();
+        self.entries
+            .retain(|entry_id, _| entry_ids.contains(entry_id));
+        self.entries.len() != len
+    }
+
+    /// Returns the entries that have all words and all tags, sorted by score (newest first in the same score).
+    pub fn search(&self, query: &SearchQuery, title_only: bool) -> anyhow::Result<Vec<SearchHit>> {
+        let words = query
+            .words
+            .iter()
+            .map(|word| tokenize(word))
+            .filter(|tokens| !tokens.is_empty())
+            .collect::<Vec<Vec<String>>>();
+        let mut hits = vec![];
+        for (entry_id, indexed) in self.entries.iter() {
+            if !query.tags.iter().all(|tag| {
+                indexed
+                    .tags
+                    .iter()
+                    .any(|t| t.to_lowercase() == tag.to_lowercase())
+            }) {
+                continue;
+            }
+
+            let mut title_terms = BTreeMap::new();
+            for token in tokenize(indexed.title.as_str()) {
+                *title_terms.entry(token).or_default() += 1;
+            }
+            let mut score = 0;
+            let mut matched = true;
+            for tokens in words.iter() {
+                let title_count = count(&title_terms, tokens);
+                let content_count = if title_only {
+                    0
+                } else {
+                    count(&indexed.terms, tokens)
+                };
+                if title_count == 0 && content_count == 0 {
+                    matched = false;
+                    break;
+                }
+                score += title_count * TITLE_WEIGHT + content_count;
+            }
+            if !matched {
+                continue;
+            }
+
+            hits.push(SearchHit {
+                entry_id: EntryId::from_str(entry_id)?,
+                score,
+                tags: indexed.tags.clone(),
+                title: indexed.title.clone(),
+            });
+        }
+        hits.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| b.entry_id.cmp(&a.entry_id))
+        });
+        Ok(hits)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SearchHit {
+    pub entry_id: EntryId,
+    pub score: u32,
+    pub tags: Vec<String>,
+    pub title: String,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SearchQuery {
+    pub words: Vec<String>,
+    pub tags: Vec<String>,
+}
+
+impl<S: AsRef<str>> FromIterator<S> for SearchQuery {
+    /// e.g. `["rust", "tag:blog"]`
+    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+        let mut query = Self::default();
+        for s in iter {
+            for term in s.as_ref().split_whitespace() {
+                match term.strip_prefix("tag:") {
+                    Some(tag) if !tag.is_empty() => query.tags.push(tag.to_string()),
+                    _ => query.words.push(term.to_string()),
+                }
+            }
+        }
+        query
+    }
+}
+
+// the number of occurrences of the word (the least count of its tokens)
+fn count(terms: &BTreeMap<String, u32>, tokens: &[String]) -> u32 {
+    tokens
+        .iter()
+        .map(|token| terms.get(token).copied().unwrap_or_default())
+        .min()
+        .unwrap_or_default()
+}
+
+// ASCII words, and unigrams and bigrams of the other letters (e.g. Japanese)
+fn tokenize(s: &str) -> Vec<String> {
+    let mut tokens = vec![];
+    let mut word = String::new();
+    let mut prev = None;
+    for c in s.chars().flat_map(char::to_lowercase) {
+        if c.is_ascii_alphanumeric() {
+            word.push(c);
+            prev = None;
+            continue;
+        }
+        if !word.is_empty() {
+            tokens.push(std::mem::take(&mut word));
+        }
+        if c.is_alphanumeric() {
+            tokens.push(c.to_string());
+            if let Some(p) = prev {
+                tokens.push(format!("{p}{c}"));
+            }
+            prev = Some(c);
+        } else {
+            prev = None;
+        }
+    }
+    if !word.is_empty() {
+        tokens.push(word);
+    }
+    tokens
+}
+
+/// Returns about `width` characters around the first occurrence of the words.
+pub fn snippet(content: &str, words: &[String], width: usize) -> String {
+    let chars = content
+        .chars()
+        .map(|c| if c.is_whitespace() { ' ' } else { c })
+        .collect::<Vec<char>>();
+    let lower = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect::<Vec<char>>();
+    let (start, len) = words
+        .iter()
+        .filter_map(|word| {
+            let word = word
+                .chars()
+                .flat_map(char::to_lowercase)
+                .collect::<Vec<char>>();
+            if word.is_empty() {
+                return None;
+            }
+            lower
+                .windows(word.len())
+                .position(|window| window == word.as_slice())
+                .map(|position| (position, word.len()))
+        })
+        .min()
+        .unwrap_or((0, 0));
+    let from = start.saturating_sub(width.saturating_sub(len) / 2);
+    let to = (from + width).max(start + len).min(chars.len());
+    let mut s = String::new();
+    if from > 0 {
+        s.push_str("...");
+    }
+    s.extend(&chars[from..to]);
+    if to < chars.len() {
+        s.push_str("...");
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use bbn_data::{DateTime, EntryMeta};
+
+    use super::*;
+
+    fn entry(id: &str, title: &str, tags: &[&str], content: &str) -> anyhow::Result<Entry> {
+        Ok(Entry::new(
+            EntryId::from_str(id)?,
+            EntryMeta::new(
+                5,
+                DateTime::from_str(format!("{id}T23:59:59+09:00").as_str())?,
+                tags.iter().map(|tag| tag.to_string()).collect(),
+                title.to_string(),
+            ),
+            content.to_string(),
+        ))
+    }
+
+    fn search(
+        index: &SearchIndex,
+        words: &[&str],
+        title_only: bool,
+    ) -> anyhow::Result<Vec<String>> {
+        Ok(index
+            .search(&words.iter().collect::<SearchQuery>(), title_only)?
+            .into_iter()
+            .map(|hit| hit.entry_id.to_string())
+            .collect())
+    }
+
+    #[test]
+    fn search_test() -> anyhow::Result<()> {
+        let mut index = SearchIndex::default();
+        index.insert(
+            &entry(
+                "2021-07-06",
+                "Rust",
+                &["rust"],
+                "I wrote Rust. 日本語の文章",
+            )?,
+            1,
+        );
+        index.insert(
+            &entry("2021-07-07", "日記", &["diary"], "rust rust rust")?,
+            1,
+        );
+        index.insert(&entry("2021-07-08", "日記", &["diary"], "go")?, 1);
+
+        assert_eq!(
+            search(&index, &["rust"], false)?,
+            vec!["2021-07-06", "2021-07-07"]
+        );
+        assert_eq!(search(&index, &["RUST"], true)?, vec!["2021-07-06"]);
+        assert_eq!(
+            search(&index, &["rust tag:diary"], false)?,
+            vec!["2021-07-07"]
+        );
+        assert_eq!(
+            search(&index, &["tag:Diary"], false)?,
+            vec!["2021-07-08", "2021-07-07"]
+        );
+        assert_eq!(
+            search(&index, &["rust", "go"], false)?,
+            Vec::<String>::new()
+        );
+        assert_eq!(search(&index, &["日本"], false)?, vec!["2021-07-06"]);
+        assert_eq!(
+            search(&index, &["日"], true)?,
+            vec!["2021-07-08", "2021-07-07"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn index_test() -> anyhow::Result<()> {
+        let mut index = SearchIndex::default();
+        let entry_id = EntryId::from_str("2021-07-06")?;
+        assert!(!index.is_fresh(&entry_id, 1));
+        index.insert(&entry("2021-07-06", "TITLE1", &[], "CONTENT1")?, 1);
+        assert!(index.is_fresh(&entry_id, 1));
+        assert!(!index.is_fresh(&entry_id, 2));
+
+        let serialized = serde_json::to_string(&index)?;
+        let deserialized = serde_json::from_str::<SearchIndex>(serialized.as_str())?;
+        assert!(deserialized.is_compatible());
+        assert_eq!(deserialized, index);
+
+        assert!(!index.retain(&BTreeSet::from(["2021-07-06".to_string()])));
+        assert!(index.retain(&BTreeSet::new()));
+        assert!(!index.is_fresh(&entry_id, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn snippet_test() {
+        let words = vec!["rust".to_string()];
+        assert_eq!(snippet("I wrote\nRust.", &words, 100), "I wrote Rust.");
+        assert_eq!(
+            snippet("abcdefghij Rust abcdefghij", &words, 8),
+            "...j Rust a..."
+        );
+        assert_eq!(snippet("abcdefghij", &words, 4), "abcd...");
+        assert_eq!(
+            snippet("日本語の文章", &["文章".to_string()], 4),
+            "...の文章"
+        );
+    }
+
+    #[test]
+    fn tokenize_test() {
+        assert_eq!(tokenize("Hello, World!"), vec!["hello", "world"]);
+        assert_eq!(tokenize("Rust入門"), vec!["rust", "入", "門", "入門"]);
+        assert_eq!(tokenize(""), Vec::<String>::new());
+    }
+}
@@ -1,15 +1,20 @@
+pub mod build;
 mod config;
 mod date_range;
 pub mod hatena_blog;
 mod json;
 pub mod link_completion;
+pub mod links;
 mod list;
+mod search;
 mod sitemap_xml;
+pub mod stats;
 mod view;
 
 pub use self::config::config;
 pub use self::date_range::date_range;
 pub use self::json::run as json;
 pub use self::list::list;
+pub use self::search::search;
 pub use self::sitemap_xml::run as sitemap_xml;
 pub use self::view::view;
@@ -0,0 +1,412 @@
+mod template;
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::Context;
+use bbn_data::{Entry, EntryId};
+use bbn_repository::{BbnRepository, Query};
+use chrono::{Local, SecondsFormat};
+
+use self::template::{escape_html, Template};
+use crate::{
+    command::{json::markdown_to_html, sitemap_xml::write_sitemap_xml},
+    config_repository::ConfigRepository,
+};
+
+const FEED_ENTRIES: usize = 20;
+
+pub struct Params {
+    pub author: String,
+    pub base_url: String,
+    pub out_dir: PathBuf,
+    pub per_page: usize,
+    pub query: Option<String>,
+    pub site_title: String,
+    pub template_dir: Option<PathBuf>,
+}
+
+struct Templates {
+    atom: Template,
+    atom_entry: Template,
+    entry: Template,
+    layout: Template,
+    list: Template,
+    list_item: Template,
+    tag: Template,
+    tag_item: Template,
+}
+
+impl Templates {
+    // `template_dir/{name}` or the default template
+    fn load(template_dir: Option<&Path>) -> anyhow::Result<Self> {
+        let load = |name: &str, default: &str| -> anyhow::Result<Template> {
+            let path = template_dir.map(|dir| dir.join(name));
+            match path.filter(|path| path.is_file()) {
+                Some(path) => Template::from_str(fs::read_to_string(path.as_path())?.as_str())
+                    .with_context(|| format!("invalid template: {}", path.display())),
+                None => Template::from_str(default),
+            }
+        };
+        Ok(Self {
+            atom: load("atom.xml", include_str!("build/templates/atom.xml"))?,
+            atom_entry: load(
+                "atom_entry.xml",
+                include_str!("build/templates/atom_entry.xml"),
+            )?,
+            entry: load("entry.html", include_str!("build/templates/entry.html"))?,
+            layout: load("layout.html", include_str!("build/templates/layout.html"))?,
+            list: load("list.html", include_str!("build/templates/list.html"))?,
+            list_item: load(
+                "list_item.html",
+                include_str!("build/templates/list_item.html"),
+            )?,
+            tag: load("tag.html", include_str!("build/templates/tag.html"))?,
+            tag_item: load(
+                "tag_item.html",
+                include_str!("build/templates/tag_item.html"),
+            )?,
+        })
+    }
+}
+
+struct Site<'a> {
+    author: &'a str,
+    base_path: &'a str,
+    base_url: &'a str,
+    out_dir: &'a Path,
+    per_page: usize,
+    site_title: &'a str,
+    templates: Templates,
+}
+
+impl<'a> Site<'a> {
+    // `{base_path}{path}`
+    fn href(&self, path: &str) -> String {
+        format!("{}{path}", self.base_path)
+    }
+
+    // `out_dir/{dir}/index.html`
+    fn write_page(&self, dir: &str, title: &str, content: String) -> anyhow::Result<()> {
+        let html = self.templates.layout.render(&BTreeMap::from([
+            ("base_path", self.base_path.to_string()),
+            ("base_url", self.base_url.to_string()),
+            ("content", content),
+            ("site_title", self.site_title.to_string()),
+            ("title", title.to_string()),
+        ]))?;
+        let dir = self.out_dir.join(dir);
+        fs::create_dir_all(dir.as_path())?;
+        fs::write(dir.join("index.html"), html)?;
+        Ok(())
+    }
+
+    fn write_entry_page(&self, entry: &Entry) -> anyhow::Result<()> {
+        let meta = entry.meta();
+        let mut tags = vec![];
+        for tag in meta.tags.iter() {
+            tags.push(self.templates.tag.render(&BTreeMap::from([
+                ("name", tag.clone()),
+                ("url", self.href(tag_path(tag).as_str())),
+            ]))?);
+        }
+        let content = self.templates.entry.render(&BTreeMap::from([
+            ("date", entry.id().date().to_string()),
+            ("html", markdown_to_html(entry.content())),
+            ("minutes", meta.minutes.to_string()),
+            ("pubdate", meta.pubdate.to_string()),
+            ("tags", tags.join("")),
+            ("title", meta.title.clone()),
+            ("url", self.href(entry_path(entry.id()).as_str())),
+        ]))?;
+        self.write_page(entry_dir(entry.id()).as_str(), meta.title.as_str(), content)
+    }
+
+    // newest first. `dir/index.html`, `dir/page/2/index.html`, ... if `paginated`
+    fn write_list_pages(
+        &self,
+        dir: &str,
+        title: &str,
+        entries: &[&Entry],
+        paginated: bool,
+    ) -> anyhow::Result<()> {
+        let per_page = if paginated {
+            self.per_page
+        } else {
+            entries.len().max(1)
+        };
+        let pages = entries.chunks(per_page).collect::<Vec<&[&Entry]>>();
+        let page_dir = |page: usize| -> String {
+            match (dir.is_empty(), page) {
+                (true, 1) => String::new(),
+                (true, _) => format!("page/{page}"),
+                (false, 1) => dir.to_string(),
+                (false, _) => format!("{dir}/page/{page}"),
+            }
+        };
+        for (index, page_entries) in pages.iter().enumerate() {
+            let page = index + 1;
+            let mut items = vec![];
+            for entry in page_entries.iter() {
+                items.push(self.templates.list_item.render(&BTreeMap::from([
+                    ("date", entry.id().date().to_string()),
+                    ("title", entry.meta().title.clone()),
+                    ("url", self.href(entry_path(entry.id()).as_str())),
+                ]))?);
+            }
+            let mut pagination = vec![];
+            if page > 1 {
+                pagination.push(format!(
+                    r#"<a rel="prev" href="{}">Newer</a>"#,
+                    escape_html(
+                        self.href(dir_path(page_dir(page - 1).as_str()).as_str())
+                            .as_str()
+                    )
+                ));
+            }
+            if page < pages.len() {
+                pagination.push(format!(
+                    r#"<a rel="next" href="{}">Older</a>"#,
+                    escape_html(
+                        self.href(dir_path(page_dir(page + 1).as_str()).as_str())
+                            .as_str()
+                    )
+                ));
+            }
+            let pagination = if pagination.is_empty() {
+                String::new()
+            } else {
+                format!(r#"<nav class="pagination">{}</nav>"#, pagination.join(" "))
+            };
+            let content = self.templates.list.render(&BTreeMap::from([
+                ("items", items.join("")),
+                ("pagination", pagination),
+                ("title", title.to_string()),
+            ]))?;
+            self.write_page(page_dir(page).as_str(), title, content)?;
+        }
+        Ok(())
+    }
+
+    fn write_tags_page(&self, tags: &BTreeMap<&str, Vec<&Entry>>) -> anyhow::Result<()> {
+        let mut items = vec![];
+        for (tag, entries) in tags.iter() {
+            items.push(self.templates.tag_item.render(&BTreeMap::from([
+                ("count", entries.len().to_string()),
+                ("name", tag.to_string()),
+                ("url", self.href(tag_path(tag).as_str())),
+            ]))?);
+        }
+        let content = self.templates.list.render(&BTreeMap::from([
+            ("items", items.join("")),
+            ("pagination", String::new()),
+            ("title", "tags".to_string()),
+        ]))?;
+        self.write_page("tags", "tags", content)
+    }
+
+    // the feed is updated at the build time if no entries
+    fn write_atom_feed(&self, entries: &[&Entry]) -> anyhow::Result<()> {
+        let mut items = vec![];
+        for entry in entries.iter().take(FEED_ENTRIES) {
+            items.push(self.templates.atom_entry.render(&BTreeMap::from([
+                ("content", markdown_to_html(entry.content())),
+                ("title", entry.meta().title.clone()),
+                ("updated", entry.meta().pubdate.to_string()),
+                (
+                    "url",
+                    format!("{}{}", self.base_url, entry_path(entry.id())),
+                ),
+            ]))?);
+        }
+        let updated = match entries.first() {
+            Some(latest) => latest.meta().pubdate.to_string(),
+            None => Local::now().to_rfc3339_opts(SecondsFormat::Secs, false),
+        };
+        let xml = self.templates.atom.render(&BTreeMap::from([
+            ("author", self.author.to_string()),
+            ("base_url", self.base_url.to_string()),
+            ("entries", items.join("")),
+            ("site_title", self.site_title.to_string()),
+            ("updated", updated),
+        ]))?;
+        fs::write(self.out_dir.join("atom.xml"), xml)?;
+        Ok(())
+    }
+}
+
+// `/` or `/{dir}/` (percent-encoded)
+fn dir_path(dir: &str) -> String {
+    if dir.is_empty() {
+        "/".to_string()
+    } else {
+        let segments = dir
+            .split('/')
+            .map(encode_path_segment)
+            .collect::<Vec<String>>();
+        format!("/{}/", segments.join("/"))
+    }
+}
+
+// keeps the unreserved characters (RFC 3986) and percent-encodes the others
+fn encode_path_segment(segment: &str) -> String {
+    let mut encoded = String::new();
+    for b in segment.bytes() {
+        if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(char::from(b));
+        } else {
+            encoded.push_str(format!("%{b:02X}").as_str());
+        }
+    }
+    encoded
+}
+
+// `https://example.com/blog` -> `/blog`
+fn base_path(base_url: &str) -> &str {
+    let rest = base_url
+        .split_once("://")
+        .map(|(_, rest)| rest)
+        .unwrap_or(base_url);
+    rest.find('/')
+        .map(|index| &rest[index..])
+        .unwrap_or_default()
+}
+
+// `/YYYY/MM/DD/` or `/YYYY/MM/DD/{id_title}/` (percent-encoded)
+pub(crate) fn entry_path(entry_id: &EntryId) -> String {
+    dir_path(entry_dir(entry_id).as_str())
+}
+
+// `YYYY/MM/DD` or `YYYY/MM/DD/{id_title}`
+fn entry_dir(entry_id: &EntryId) -> String {
+    let date = entry_id.date().to_string().replace('-', "/");
+    match entry_id.id_title() {
+        None => date,
+        Some(id_title) => format!("{date}/{id_title}"),
+    }
+}
+
+// `tags/{tag}`
+fn tag_dir(tag: &str) -> String {
+    format!("tags/{tag}")
+}
+
+// `/tags/{tag}/` (percent-encoded)
+fn tag_path(tag: &str) -> String {
+    dir_path(tag_dir(tag).as_str())
+}
+
+// a tag or an id_title is used as a directory name
+fn validate_dir_name(name: &str) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        !name.is_empty()
+            && name != "."
+            && name != ".."
+            && !name.contains(['/', '\\'])
+            && !name.contains(char::is_control),
+        "invalid dir name: {name:?}"
+    );
+    Ok(())
+}
+
+pub fn run(
+    Params {
+        author,
+        base_url,
+        out_dir,
+        per_page,
+        query,
+        site_title,
+        template_dir,
+    }: Params,
+) -> anyhow::Result<()> {
+    anyhow::ensure!(per_page > 0, "per_page must be greater than 0");
+    let config_repository = ConfigRepository::new()?;
+    let config = config_repository
+        .load()
+        .context("The configuration file does not found. Use `bbn config` command.")?;
+    let data_dir = config.data_dir().to_path_buf();
+
+    let bbn_repository = BbnRepository::new(data_dir);
+    let query = query.unwrap_or_default();
+    let query = Query::try_from(query.as_str())?;
+    let mut entry_ids = bbn_repository.find_ids_by_query(query)?;
+    entry_ids.sort();
+    entry_ids.reverse();
+    let mut entries = vec![];
+    for entry_id in entry_ids {
+        entries.push(
+            bbn_repository
+                .find_entry_by_id(&entry_id)?
+                .with_context(|| format!("entry not found: {entry_id}"))?,
+        );
+    }
+    let entries = entries.iter().collect::<Vec<&Entry>>();
+    let mut tags = BTreeMap::<&str, Vec<&Entry>>::new();
+    for entry in entries.iter() {
+        if let Some(id_title) = entry.id().id_title() {
+            validate_dir_name(id_title).with_context(|| format!("entry: {}", entry.id()))?;
+        }
+        for tag in entry.meta().tags.iter() {
+            validate_dir_name(tag).with_context(|| format!("entry: {}", entry.id()))?;
+            tags.entry(tag.as_str()).or_default().push(entry);
+        }
+    }
+
+    let base_url = base_url.trim_end_matches('/');
+    let site = Site {
+        author: author.as_str(),
+        base_path: base_path(base_url),
+        base_url,
+        out_dir: out_dir.as_path(),
+        per_page,
+        site_title: site_title.as_str(),
+        templates: Templates::load(template_dir.as_deref())?,
+    };
+    fs::create_dir_all(out_dir.as_path())?;
+
+    // index
+    site.write_list_pages("", site.site_title, &entries, true)?;
+
+    // per-day
+    for entry in entries.iter() {
+        site.write_entry_page(entry)?;
+    }
+
+    // archives
+    let mut years = BTreeMap::<String, Vec<&Entry>>::new();
+    let mut months = BTreeMap::<String, Vec<&Entry>>::new();
+    for entry in entries.iter() {
+        let date = entry.id().date();
+        years
+            .entry(date.year().to_string())
+            .or_default()
+            .push(entry);
+        months
+            .entry(format!("{}/{}", date.year(), date.month()))
+            .or_default()
+            .push(entry);
+    }
+    for (dir, entries) in years.iter().chain(months.iter()) {
+        site.write_list_pages(dir, dir.replace('/', "-").as_str(), entries, false)?;
+    }
+
+    // tags
+    site.write_tags_page(&tags)?;
+    for (tag, entries) in tags.iter() {
+        site.write_list_pages(tag_dir(tag).as_str(), tag, entries, true)?;
+    }
+
+    // feed and sitemap
+    site.write_atom_feed(&entries)?;
+    write_sitemap_xml(
+        out_dir.as_path(),
+        base_url,
+        entries.iter().map(|entry| (entry.id(), entry.meta())),
+    )?;
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use anyhow::Context;
+
+#[derive(Debug, Eq, PartialEq)]
+enum Segment {
+    Text(String),
+    // `{{name}}`
+    Escaped(String),
+    // `{{{name}}}`
+    Raw(String),
+}
+
+/// A template with `{{name}}` (HTML-escaped) and `{{{name}}}` (not escaped) variables.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Template(Vec<Segment>);
+
+impl Template {
+    pub fn render(&self, vars: &BTreeMap<&str, String>) -> anyhow::Result<String> {
+        let mut s = String::new();
+        for segment in self.0.iter() {
+            match segment {
+                Segment::Text(text) => s.push_str(text),
+                Segment::Escaped(name) => s.push_str(&escape_html(
+                    vars.get(name.as_str())
+                        .with_context(|| format!("unknown variable: {name}"))?,
+                )),
+                Segment::Raw(name) => s.push_str(
+                    vars.get(name.as_str())
+                        .with_context(|| format!("unknown variable: {name}"))?,
+                ),
+            }
+        }
+        Ok(s)
+    }
+}
+
+impl FromStr for Template {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut segments = vec![];
+        let mut rest = s;
+        while let Some(start) = rest.find("{{") {
+            if start > 0 {
+                segments.push(Segment::Text(rest[..start].to_string()));
+            }
+            let (raw, open, close) = if rest[start..].starts_with("{{{") {
+                (true, "{{{", "}}}")
+            } else {
+                (false, "{{", "}}")
+            };
+            let tail = &rest[start + open.len()..];
+            let end = tail
+                .find(close)
+                .with_context(|| format!("`{close}` not found"))?;
+            let name = tail[..end].trim().to_string();
+            anyhow::ensure!(!name.is_empty(), "empty variable name");
+            segments.push(if raw {
+                Segment::Raw(name)
+            } else {
+                Segment::Escaped(name)
+            });
+            rest = &tail[end + close.len()..];
+        }
+        if !rest.is_empty() {
+            segments.push(Segment::Text(rest.to_string()));
+        }
+        Ok(Self(segments))
+    }
+}
+
+pub fn escape_html(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() -> anyhow::Result<()> {
+        let template = Template::from_str("<h1>{{ title }}</h1>{{{content}}}!")?;
+        let vars = BTreeMap::from([
+            ("title", "<a & b>".to_string()),
+            ("content", "<p>c</p>".to_string()),
+        ]);
+        assert_eq!(
+            template.render(&vars)?,
+            "<h1>&lt;a &amp; b&gt;</h1><p>c</p>!"
+        );
+        assert_eq!(Template::from_str("text")?.render(&vars)?, "text");
+        Ok(())
+    }
+
+    #[test]
+    fn error_test() -> anyhow::Result<()> {
+        assert!(Template::from_str("{{title").is_err());
+        assert!(Template::from_str("{{{title}}").is_err());
+        assert!(Template::from_str("{{ }}").is_err());
+        assert!(Template::from_str("{{unknown}}")?
+            .render(&BTreeMap::new())
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn escape_html_test() {
+        assert_eq!(
+            escape_html(r#"<a href="x">'&'</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;"
+        );
+    }
+}
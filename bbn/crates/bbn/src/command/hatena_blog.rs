@@ -1,11 +1,13 @@
 mod diff;
 mod download;
 mod list;
+mod sync;
 mod upload;
 mod view;
 
 pub use self::diff::diff;
 pub use self::download::download;
 pub use self::list::list;
+pub use self::sync::sync;
 pub use self::upload::upload;
 pub use self::view::view;
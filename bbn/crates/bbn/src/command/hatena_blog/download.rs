@@ -78,7 +78,9 @@ async fn indexing(
     Ok(indexing.id())
 }
 
-async fn parse_entry(hatena_blog_repository: &HatenaBlogRepository) -> anyhow::Result<()> {
+pub(super) async fn parse_entry(
+    hatena_blog_repository: &HatenaBlogRepository,
+) -> anyhow::Result<()> {
     let last_parsed_at = hatena_blog_repository.find_last_parsed_at().await?;
     for body in hatena_blog_repository
         .find_entries_waiting_for_parsing(last_parsed_at)
@@ -95,7 +97,7 @@ async fn parse_entry(hatena_blog_repository: &HatenaBlogRepository) -> anyhow::R
     Ok(())
 }
 
-fn update_bbn_entry(
+pub(super) fn update_bbn_entry(
     entry_id: EntryId,
     hatena_blog_entry: HatenaBlogEntry,
     bbn_repository: &BbnRepository,
@@ -127,6 +129,21 @@ fn update_bbn_entry(
     bbn_repository.save(entry)
 }
 
+// the local entry id for the `updated` of the hatena-blog entry
+pub(super) fn entry_id_by_updated(
+    updated: Timestamp,
+    bbn_repository: &BbnRepository,
+) -> anyhow::Result<EntryId> {
+    let utc_naive_date_time = NaiveDateTime::from_timestamp(i64::from(updated), 0);
+    let fixed_datetime = Local.from_utc_datetime(&utc_naive_date_time);
+    let datetime = DateTime::from_str(&fixed_datetime.to_rfc3339())?;
+    let date = Date::from_str(datetime.to_string().get(0..10).unwrap())?;
+    Ok(match bbn_repository.find_id_by_date(date)? {
+        None => EntryId::new(date, None),
+        Some(entry_id) => entry_id,
+    })
+}
+
 async fn update_bbn_entries(
     target_entry_id: Option<EntryId>,
     bbn_repository: &BbnRepository,
@@ -136,14 +153,7 @@ async fn update_bbn_entries(
         .find_entries_updated_and_title()
         .await?
     {
-        let utc_naive_date_time = NaiveDateTime::from_timestamp(i64::from(updated), 0);
-        let fixed_datetime = Local.from_utc_datetime(&utc_naive_date_time);
-        let datetime = DateTime::from_str(&fixed_datetime.to_rfc3339())?;
-        let date = Date::from_str(datetime.to_string().get(0..10).unwrap())?;
-        let entry_id = match bbn_repository.find_id_by_date(date)? {
-            None => EntryId::new(date, None),
-            Some(entry_id) => entry_id,
-        };
+        let entry_id = entry_id_by_updated(updated, bbn_repository)?;
         if let Some(ref target) = target_entry_id {
             if target != &entry_id {
                 continue;
@@ -158,7 +168,7 @@ async fn update_bbn_entries(
     Ok(())
 }
 
-async fn download_impl(
+pub(super) async fn download_impl(
     data_file_only: bool,
     date: Option<Date>,
     bbn_repository: &BbnRepository,
@@ -0,0 +1,289 @@
+use std::{collections::BTreeSet, convert::TryFrom};
+
+use anyhow::{bail, Context};
+use bbn_data::{Entry, EntryId, EntryMeta, Timestamp};
+use bbn_hatena_blog::{
+    plan_sync, upload_entry, HatenaBlogClient, HatenaBlogEntry, HatenaBlogRepository, SyncAction,
+    SyncState,
+};
+use bbn_repository::{BbnRepository, Query};
+
+use super::download::{download_impl, entry_id_by_updated, parse_entry, update_bbn_entry};
+use crate::config_repository::ConfigRepository;
+
+#[derive(Debug, Default)]
+struct SyncStats {
+    unchanged: usize,
+    created: usize,
+    pushed: usize,
+    pulled: usize,
+    merged: usize,
+    conflicted: usize,
+    remote_deleted: usize,
+    local_deleted: usize,
+}
+
+struct Syncer<'a> {
+    dry_run: bool,
+    draft: bool,
+    hatena_id: &'a str,
+    bbn_repository: &'a BbnRepository,
+    hatena_blog_repository: &'a HatenaBlogRepository,
+    hatena_blog_client: &'a HatenaBlogClient,
+}
+
+impl<'a> Syncer<'a> {
+    async fn save_sync_state(
+        &self,
+        entry_id: &EntryId,
+        title: &str,
+        content: &str,
+        remote: &HatenaBlogEntry,
+    ) -> anyhow::Result<()> {
+        self.hatena_blog_repository
+            .save_sync_state(&SyncState {
+                bbn_entry_id: entry_id.clone(),
+                hatena_blog_entry_id: remote.id.clone(),
+                title: title.to_string(),
+                content: content.to_string(),
+                edited: Timestamp::from(remote.edited),
+                synced_at: Timestamp::now()?,
+            })
+            .await
+    }
+
+    async fn push(&self, entry: &Entry, draft: bool) -> anyhow::Result<()> {
+        upload_entry(
+            *entry.id().date(),
+            draft,
+            self.hatena_id,
+            self.bbn_repository,
+            self.hatena_blog_repository,
+            self.hatena_blog_client,
+        )
+        .await?;
+        parse_entry(self.hatena_blog_repository).await?;
+        let remote = self
+            .hatena_blog_repository
+            .find_entry_by_entry_meta(entry.meta())
+            .await?
+            .with_context(|| format!("the uploaded entry is not found: {}", entry.id()))?;
+        self.save_sync_state(
+            entry.id(),
+            entry.meta().title.as_str(),
+            entry.content(),
+            &remote,
+        )
+        .await
+    }
+
+    async fn pull(&self, entry_id: EntryId, remote: HatenaBlogEntry) -> anyhow::Result<()> {
+        self.save_sync_state(
+            &entry_id,
+            remote.title.as_str(),
+            remote.content.as_str(),
+            &remote,
+        )
+        .await?;
+        update_bbn_entry(entry_id, remote, self.bbn_repository)
+    }
+
+    async fn execute(
+        &self,
+        stats: &mut SyncStats,
+        base: Option<SyncState>,
+        local: Option<Entry>,
+        remote: Option<HatenaBlogEntry>,
+        entry_id: EntryId,
+    ) -> anyhow::Result<()> {
+        let action = plan_sync(base.as_ref(), local.as_ref(), remote.as_ref());
+        let status = match action {
+            SyncAction::Unchanged => {
+                stats.unchanged += 1;
+                if let (Some(local), Some(remote)) = (local.as_ref(), remote.as_ref()) {
+                    let stale = base
+                        .as_ref()
+                        .map(|base| base.edited != Timestamp::from(remote.edited))
+                        .unwrap_or(true);
+                    if stale && !self.dry_run {
+                        self.save_sync_state(
+                            &entry_id,
+                            local.meta().title.as_str(),
+                            local.content(),
+                            remote,
+                        )
+                        .await?;
+                    }
+                }
+                return Ok(());
+            }
+            SyncAction::Create => {
+                stats.created += 1;
+                if !self.dry_run {
+                    self.push(local.as_ref().context("no local entry")?, self.draft)
+                        .await?;
+                }
+                "create"
+            }
+            SyncAction::Push => {
+                stats.pushed += 1;
+                if !self.dry_run {
+                    let draft = remote.as_ref().map(|remote| remote.draft).unwrap_or(false);
+                    self.push(local.as_ref().context("no local entry")?, draft)
+                        .await?;
+                }
+                "push"
+            }
+            SyncAction::Pull => {
+                stats.pulled += 1;
+                if !self.dry_run {
+                    self.pull(entry_id.clone(), remote.context("no remote entry")?)
+                        .await?;
+                }
+                "pull"
+            }
+            SyncAction::Merge { title, content } => {
+                stats.merged += 1;
+                if !self.dry_run {
+                    let local = local.context("no local entry")?;
+                    let meta = local.meta().clone();
+                    let merged = local.update(content, EntryMeta { title, ..meta });
+                    self.bbn_repository.save(merged)?;
+                    let merged = self
+                        .bbn_repository
+                        .find_entry_by_id(&entry_id)?
+                        .context("no local entry")?;
+                    let draft = remote.as_ref().map(|remote| remote.draft).unwrap_or(false);
+                    self.push(&merged, draft).await?;
+                }
+                "merge"
+            }
+            SyncAction::Conflict { diff } => {
+                stats.conflicted += 1;
+                println!("conflict {entry_id}");
+                print!("{diff}");
+                return Ok(());
+            }
+            SyncAction::RemoteDeleted => {
+                stats.remote_deleted += 1;
+                "deleted"
+            }
+            SyncAction::LocalDeleted => {
+                stats.local_deleted += 1;
+                "local-deleted"
+            }
+        };
+        println!("{status} {entry_id}");
+        Ok(())
+    }
+}
+
+pub async fn sync(dry_run: bool, draft: bool, no_fetch: bool) -> anyhow::Result<()> {
+    let config_repository = ConfigRepository::new()?;
+    let config = config_repository
+        .load()
+        .context("The configuration file does not found. Use `bbn config` command.")?;
+    let data_dir = config.data_dir().to_path_buf();
+    let data_file = config.hatena_blog_data_file().to_path_buf();
+    let credentials = config_repository.load_credentials().with_context(|| {
+        format!(
+            "The credential file does not found. {:?}",
+            config_repository.credential_file_path()
+        )
+    })?;
+
+    let bbn_repository = BbnRepository::new(data_dir);
+    let hatena_blog_repository = HatenaBlogRepository::new(data_file).await?;
+    let hatena_blog_client = HatenaBlogClient::new(
+        credentials.hatena_id().to_string(),
+        credentials.hatena_blog_id().to_string(),
+        credentials.hatena_api_key().to_string(),
+    );
+    if !no_fetch {
+        download_impl(
+            true,
+            None,
+            &bbn_repository,
+            &hatena_blog_repository,
+            &hatena_blog_client,
+        )
+        .await?;
+    }
+
+    let syncer = Syncer {
+        dry_run,
+        draft,
+        hatena_id: credentials.hatena_id(),
+        bbn_repository: &bbn_repository,
+        hatena_blog_repository: &hatena_blog_repository,
+        hatena_blog_client: &hatena_blog_client,
+    };
+    let mut stats = SyncStats::default();
+    let mut synced = BTreeSet::new();
+    for entry_id in bbn_repository.find_ids_by_query(Query::try_from("")?)? {
+        let local = bbn_repository
+            .find_entry_by_id(&entry_id)?
+            .with_context(|| format!("the entry is not found: {entry_id}"))?;
+        if local.meta().hatena_blog_ignore == Some(true) {
+            continue;
+        }
+        let remote = hatena_blog_repository
+            .find_entry_by_entry_meta(local.meta())
+            .await?;
+        if let Some(remote) = remote.as_ref() {
+            synced.insert(remote.id.to_string());
+        }
+        let base = hatena_blog_repository.find_sync_state(&entry_id).await?;
+        syncer
+            .execute(&mut stats, base, Some(local), remote, entry_id)
+            .await?;
+    }
+
+    // the remote entries without the local entries
+    for (updated, _) in hatena_blog_repository
+        .find_entries_updated_and_title()
+        .await?
+    {
+        let remote = match hatena_blog_repository
+            .find_entry_by_updated(updated)
+            .await?
+        {
+            Some(remote) if !synced.contains(&remote.id.to_string()) => remote,
+            _ => continue,
+        };
+        let entry_id = entry_id_by_updated(updated, &bbn_repository)?;
+        if bbn_repository.find_entry_by_id(&entry_id)?.is_some() {
+            // the local entry is ignored or linked to another remote entry
+            continue;
+        }
+        synced.insert(remote.id.to_string());
+        // the local entry has been deleted if it has been synced with the remote entry
+        let base = hatena_blog_repository
+            .find_sync_state(&entry_id)
+            .await?
+            .filter(|base| base.hatena_blog_entry_id == remote.id);
+        syncer
+            .execute(&mut stats, base, None, Some(remote), entry_id)
+            .await?;
+    }
+
+    println!(
+        "{}unchanged = {} create = {} push = {} pull = {} merge = {} conflict = {} deleted = {} local-deleted = {}",
+        if dry_run { "(dry-run) " } else { "" },
+        stats.unchanged,
+        stats.created,
+        stats.pushed,
+        stats.pulled,
+        stats.merged,
+        stats.conflicted,
+        stats.remote_deleted,
+        stats.local_deleted,
+    );
+    if stats.conflicted > 0 {
+        bail!(
+            "{} conflicts. resolve them with `bbn hatena-blog upload DATE` or `bbn hatena-blog download DATE`",
+            stats.conflicted
+        );
+    }
+    Ok(())
+}
@@ -113,7 +113,7 @@ fn write_tags_json(out_dir: &Path, tags_json: &TagsJson) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn markdown_to_html(markdown: &str) -> String {
+pub(crate) fn markdown_to_html(markdown: &str) -> String {
     let mut html_output = String::new();
     html::push_html(&mut html_output, Parser::new(markdown));
     html_output
@@ -129,7 +129,7 @@ fn parse_links(markdown: &str) -> anyhow::Result<BTreeSet<EntryKey>> {
     Ok(links)
 }
 
-pub fn run(out_dir: PathBuf) -> anyhow::Result<()> {
+pub fn run(out_dir: PathBuf, query: Option<String>) -> anyhow::Result<()> {
     let config_repository = ConfigRepository::new()?;
     let config = config_repository
         .load()
@@ -137,7 +137,8 @@ pub fn run(out_dir: PathBuf) -> anyhow::Result<()> {
     let data_dir = config.data_dir().to_path_buf();
 
     let bbn_repository = BbnRepository::new(data_dir);
-    let query = Query::try_from("date:1970-01-01/9999-12-31")?;
+    let query = query.unwrap_or_else(|| "date:1970-01-01/9999-12-31".to_string());
+    let query = Query::try_from(query.as_str())?;
     let mut entry_ids = bbn_repository.find_ids_by_query(query)?;
     entry_ids.sort();
 
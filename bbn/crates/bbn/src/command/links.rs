@@ -0,0 +1,395 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    convert::TryFrom,
+    str::FromStr,
+};
+
+use anyhow::{bail, Context};
+use bbn_repository::{BbnRepository, Query};
+use date_range::date::Date;
+use pulldown_cmark::{Event, Parser, Tag};
+use regex::Regex;
+
+use crate::{config_repository::ConfigRepository, DateLike};
+
+pub struct Params {
+    pub base_url: String,
+    pub date_like: Option<DateLike>,
+    pub json: bool,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum LinkTarget {
+    Entry(Date),
+    External,
+    Broken(&'static str),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+struct BrokenLink {
+    url: String,
+    reason: &'static str,
+}
+
+#[derive(Debug, Default)]
+struct LinkGraph {
+    titles: BTreeMap<Date, String>,
+    // date -> the linked dates
+    links: BTreeMap<Date, BTreeSet<Date>>,
+    // date -> the linking dates
+    backlinks: BTreeMap<Date, BTreeSet<Date>>,
+    broken: BTreeMap<Date, Vec<BrokenLink>>,
+}
+
+impl LinkGraph {
+    // `entries` is `(date, title, content)`
+    fn new<I>(base_url: &str, entries: I) -> anyhow::Result<Self>
+    where
+        I: IntoIterator<Item = (Date, String, String)>,
+    {
+        let entries = entries.into_iter().collect::<Vec<(Date, String, String)>>();
+        let mut graph = Self {
+            titles: entries
+                .iter()
+                .map(|(date, title, _)| (*date, title.clone()))
+                .collect(),
+            ..Default::default()
+        };
+        let parser = LinkParser::new(base_url)?;
+        for (date, _, content) in entries.iter() {
+            for url in extract_urls(content) {
+                match parser.parse(url.as_str()) {
+                    LinkTarget::Entry(linked) if graph.titles.contains_key(&linked) => {
+                        graph.links.entry(*date).or_default().insert(linked);
+                        graph.backlinks.entry(linked).or_default().insert(*date);
+                    }
+                    LinkTarget::Entry(_) => {
+                        graph.broken.entry(*date).or_default().push(BrokenLink {
+                            url,
+                            reason: "no entry",
+                        })
+                    }
+                    LinkTarget::External => {}
+                    LinkTarget::Broken(reason) => graph
+                        .broken
+                        .entry(*date)
+                        .or_default()
+                        .push(BrokenLink { url, reason }),
+                }
+            }
+        }
+        Ok(graph)
+    }
+
+    fn entries(&self, dates: Option<&BTreeSet<Date>>) -> Vec<EntryJson> {
+        dates
+            .into_iter()
+            .flatten()
+            .map(|date| EntryJson {
+                date: date.to_string(),
+                title: self.titles.get(date).cloned().unwrap_or_default(),
+            })
+            .collect()
+    }
+}
+
+struct LinkParser {
+    // the base url without the scheme. e.g. `blog.bouzuya.net`
+    host: String,
+    date_path: Regex,
+    scheme: Regex,
+}
+
+impl LinkParser {
+    fn new(base_url: &str) -> anyhow::Result<Self> {
+        Ok(Self {
+            host: strip_http_scheme(base_url)
+                .unwrap_or(base_url)
+                .trim_end_matches('/')
+                .to_string(),
+            date_path: Regex::new(r"^/(\d{4})/(\d{2})/(\d{2})/?(?:[?#].*)?$")?,
+            scheme: Regex::new(r"^[A-Za-z][A-Za-z0-9+.-]*:")?,
+        })
+    }
+
+    fn parse(&self, url: &str) -> LinkTarget {
+        if url.is_empty() || url.chars().any(char::is_whitespace) {
+            return LinkTarget::Broken("malformed url");
+        }
+        let path = match strip_http_scheme(url) {
+            Some(rest) => match rest.strip_prefix(self.host.as_str()) {
+                Some(path) if path.is_empty() || path.starts_with('/') => path,
+                _ if rest.is_empty() || rest.starts_with('/') => {
+                    return LinkTarget::Broken("malformed url")
+                }
+                _ => return LinkTarget::External,
+            },
+            None if self.scheme.is_match(url) => {
+                return if url.starts_with("http:") || url.starts_with("https:") {
+                    LinkTarget::Broken("malformed url")
+                } else {
+                    LinkTarget::External
+                };
+            }
+            None if url.starts_with('/') && !url.starts_with("//") => url,
+            None => return LinkTarget::External,
+        };
+        match self.date_path.captures(path) {
+            None => LinkTarget::External,
+            Some(captures) => {
+                match Date::from_str(&format!(
+                    "{}-{}-{}",
+                    &captures[1], &captures[2], &captures[3]
+                )) {
+                    Ok(date) => LinkTarget::Entry(date),
+                    Err(_) => LinkTarget::Broken("invalid date"),
+                }
+            }
+        }
+    }
+}
+
+fn strip_http_scheme(url: &str) -> Option<&str> {
+    url.strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+}
+
+// the destinations of the inline links and the reference links
+fn extract_urls(content: &str) -> Vec<String> {
+    Parser::new(content)
+        .filter_map(|event| match event {
+            Event::Start(Tag::Link { dest_url, .. }) => Some(dest_url.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[derive(serde::Serialize)]
+struct EntryJson {
+    date: String,
+    title: String,
+}
+
+pub fn run(
+    Params {
+        base_url,
+        date_like,
+        json,
+    }: Params,
+) -> anyhow::Result<()> {
+    #[derive(serde::Serialize)]
+    struct EntryLinksJson {
+        date: String,
+        title: String,
+        links: Vec<EntryJson>,
+        backlinks: Vec<EntryJson>,
+        broken: Vec<BrokenLink>,
+    }
+
+    #[derive(serde::Serialize)]
+    struct BrokenLinkJson<'a> {
+        date: String,
+        #[serde(flatten)]
+        link: &'a BrokenLink,
+    }
+
+    #[derive(serde::Serialize)]
+    struct ReportJson<'a> {
+        entries: usize,
+        links: usize,
+        broken: Vec<BrokenLinkJson<'a>>,
+    }
+
+    let config_repository = ConfigRepository::new()?;
+    let config = config_repository
+        .load()
+        .context("The configuration file does not found. Use `bbn config` command.")?;
+    let data_dir = config.data_dir().to_path_buf();
+
+    let bbn_repository = BbnRepository::new(data_dir);
+    let mut entries = vec![];
+    for entry_id in bbn_repository.find_ids_by_query(Query::try_from("")?)? {
+        let entry = bbn_repository
+            .find_entry_by_id(&entry_id)?
+            .with_context(|| format!("the entry is not found: {entry_id}"))?;
+        entries.push((
+            *entry_id.date(),
+            entry.meta().title.clone(),
+            entry.content().to_string(),
+        ));
+    }
+    let graph = LinkGraph::new(base_url.as_str(), entries)?;
+
+    if let Some(date_like) = date_like {
+        let date = Date::from(date_like);
+        let title = graph
+            .titles
+            .get(&date)
+            .with_context(|| format!("the entry is not found: {date}"))?;
+        let output = EntryLinksJson {
+            date: date.to_string(),
+            title: title.clone(),
+            links: graph.entries(graph.links.get(&date)),
+            backlinks: graph.entries(graph.backlinks.get(&date)),
+            broken: graph.broken.get(&date).cloned().unwrap_or_default(),
+        };
+        if json {
+            println!("{}", serde_json::to_string(&output)?);
+        } else {
+            println!("{} {}", output.date, output.title);
+            for (label, entries) in [("links", &output.links), ("backlinks", &output.backlinks)] {
+                println!("{label}:");
+                for entry in entries {
+                    println!("  {} {}", entry.date, entry.title);
+                }
+            }
+            println!("broken:");
+            for link in output.broken.iter() {
+                println!("  {} ({})", link.url, link.reason);
+            }
+        }
+        return Ok(());
+    }
+
+    let report = ReportJson {
+        entries: graph.titles.len(),
+        links: graph.links.values().map(BTreeSet::len).sum(),
+        broken: graph
+            .broken
+            .iter()
+            .flat_map(|(date, links)| {
+                links.iter().map(|link| BrokenLinkJson {
+                    date: date.to_string(),
+                    link,
+                })
+            })
+            .collect(),
+    };
+    if json {
+        println!("{}", serde_json::to_string(&report)?);
+    } else {
+        for broken in report.broken.iter() {
+            println!(
+                "{} {} ({})",
+                broken.date, broken.link.url, broken.link.reason
+            );
+        }
+        println!(
+            "entries = {} links = {} broken = {}",
+            report.entries,
+            report.links,
+            report.broken.len()
+        );
+    }
+    if !report.broken.is_empty() {
+        bail!("{} broken links", report.broken.len());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> Date {
+        Date::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn extract_urls_test() {
+        assert_eq!(
+            extract_urls("[2021-02-03] [a](https://example.com) <https://example.org>\n\n[2021-02-03]: https://blog.bouzuya.net/2021/02/03/"),
+            vec![
+                "https://blog.bouzuya.net/2021/02/03/",
+                "https://example.com",
+                "https://example.org"
+            ]
+        );
+        assert_eq!(extract_urls("[2021-02-03]"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn link_parser_test() -> anyhow::Result<()> {
+        let parser = LinkParser::new("https://blog.bouzuya.net")?;
+        let entry = |s: &str| LinkTarget::Entry(date(s));
+        assert_eq!(
+            parser.parse("https://blog.bouzuya.net/2021/02/03/"),
+            entry("2021-02-03")
+        );
+        assert_eq!(
+            parser.parse("http://blog.bouzuya.net/2021/02/03"),
+            entry("2021-02-03")
+        );
+        assert_eq!(
+            parser.parse("https://blog.bouzuya.net/2021/02/03/#heading"),
+            entry("2021-02-03")
+        );
+        assert_eq!(parser.parse("/2021/02/03/"), entry("2021-02-03"));
+        assert_eq!(
+            parser.parse("https://blog.bouzuya.net/2021/02/30/"),
+            LinkTarget::Broken("invalid date")
+        );
+        assert_eq!(
+            parser.parse("https://blog.bouzuya.net/tags/"),
+            LinkTarget::External
+        );
+        assert_eq!(
+            parser.parse("https://example.com/2021/02/03/"),
+            LinkTarget::External
+        );
+        assert_eq!(parser.parse("mailto:m@bouzuya.net"), LinkTarget::External);
+        assert_eq!(parser.parse("#heading"), LinkTarget::External);
+        assert_eq!(
+            parser.parse("https://"),
+            LinkTarget::Broken("malformed url")
+        );
+        assert_eq!(
+            parser.parse("https:/a"),
+            LinkTarget::Broken("malformed url")
+        );
+        assert_eq!(parser.parse(""), LinkTarget::Broken("malformed url"));
+        Ok(())
+    }
+
+    #[test]
+    fn link_graph_test() -> anyhow::Result<()> {
+        let entry = |d: &str, content: &str| (date(d), format!("TITLE {d}"), content.to_string());
+        let graph = LinkGraph::new(
+            "https://blog.bouzuya.net",
+            vec![
+                entry(
+                    "2021-02-03",
+                    "[2021-02-04]\n\n[2021-02-04]: https://blog.bouzuya.net/2021/02/04/",
+                ),
+                entry(
+                    "2021-02-04",
+                    "[a](/2021/02/03/) [b](/2021/02/06/) [c](https://blog.bouzuya.net/2021/13/01/)",
+                ),
+                entry("2021-02-05", "[a](/2021/02/04/) [b](/2021/02/04/)"),
+            ],
+        )?;
+        assert_eq!(
+            graph.links.get(&date("2021-02-03")),
+            Some(&BTreeSet::from([date("2021-02-04")]))
+        );
+        assert_eq!(
+            graph.backlinks.get(&date("2021-02-04")),
+            Some(&BTreeSet::from([date("2021-02-03"), date("2021-02-05")]))
+        );
+        assert_eq!(graph.backlinks.get(&date("2021-02-05")), None);
+        assert_eq!(
+            graph.broken.get(&date("2021-02-04")),
+            Some(&vec![
+                BrokenLink {
+                    url: "/2021/02/06/".to_string(),
+                    reason: "no entry"
+                },
+                BrokenLink {
+                    url: "https://blog.bouzuya.net/2021/13/01/".to_string(),
+                    reason: "invalid date"
+                }
+            ])
+        );
+        Ok(())
+    }
+}
@@ -21,7 +21,7 @@ pub fn list(json: bool, query: Option<String>) -> anyhow::Result<()> {
 
     let bbn_repository = BbnRepository::new(data_dir);
     let query = query.unwrap_or_default();
-    let query = Query::try_from(query.as_str()).unwrap();
+    let query = Query::try_from(query.as_str())?;
     let mut entry_ids = bbn_repository.find_ids_by_query(query)?;
     entry_ids.sort();
     entry_ids.reverse();
@@ -59,7 +59,7 @@ pub fn list(json: bool, query: Option<String>) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn entry_url(entry_id: &EntryId) -> String {
+pub(crate) fn entry_url(entry_id: &EntryId) -> String {
     format!(
         "https://blog.bouzuya.net/{}/",
         entry_id.date().to_string().replace('-', "/")
@@ -0,0 +1,66 @@
+use anyhow::Context;
+
+use crate::{command::list::entry_url, config_repository::ConfigRepository};
+use bbn_repository::{snippet, BbnRepository, SearchQuery};
+
+const SNIPPET_WIDTH: usize = 80;
+
+pub fn search(json: bool, limit: usize, title: bool, words: Vec<String>) -> anyhow::Result<()> {
+    #[derive(serde::Serialize)]
+    struct OutputJson {
+        date: String,
+        score: u32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        snippet: Option<String>,
+        tags: Vec<String>,
+        title: String,
+        url: String,
+    }
+
+    let config_repository = ConfigRepository::new()?;
+    let config = config_repository
+        .load()
+        .context("The configuration file does not found. Use `bbn config` command.")?;
+    let data_dir = config.data_dir().to_path_buf();
+
+    let bbn_repository = BbnRepository::new(data_dir);
+    let query = words.iter().collect::<SearchQuery>();
+    let index = bbn_repository.search_index()?;
+    let mut output = vec![];
+    for hit in index.search(&query, title)?.into_iter().take(limit) {
+        let snippet = if title || query.words.is_empty() {
+            None
+        } else {
+            bbn_repository
+                .find_content_by_id(&hit.entry_id)?
+                .map(|content| snippet(content.as_str(), &query.words, SNIPPET_WIDTH))
+        };
+        if json {
+            output.push(serde_json::to_string(&OutputJson {
+                date: hit.entry_id.date().to_string(),
+                score: hit.score,
+                snippet,
+                tags: hit.tags,
+                title: hit.title,
+                url: entry_url(&hit.entry_id),
+            })?);
+        } else {
+            output.push(format!(
+                "{} {} <{}>",
+                hit.entry_id.date(),
+                hit.title,
+                entry_url(&hit.entry_id),
+            ));
+            if let Some(snippet) = snippet {
+                output.push(format!("  {snippet}"));
+            }
+        }
+    }
+    let output = if json {
+        format!("[{}]", output.join(","))
+    } else {
+        output.join("\n")
+    };
+    println!("{output}");
+    Ok(())
+}
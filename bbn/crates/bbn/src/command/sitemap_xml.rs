@@ -1,12 +1,17 @@
-use std::{fs::File, io::BufWriter, path::PathBuf};
+use std::{
+    fs::File,
+    io::BufWriter,
+    path::{Path, PathBuf},
+};
 
 use anyhow::Context;
+use bbn_data::{EntryId, EntryMeta};
 use bbn_repository::{BbnRepository, Query};
 use sitemap_xml_writer::{SitemapWriter, Url};
 
-use crate::config_repository::ConfigRepository;
+use crate::{command::build::entry_path, config_repository::ConfigRepository};
 
-pub fn run(out_dir: PathBuf) -> anyhow::Result<()> {
+pub fn run(out_dir: PathBuf, query: Option<String>) -> anyhow::Result<()> {
     let config_repository = ConfigRepository::new()?;
     let config = config_repository
         .load()
@@ -14,25 +19,38 @@ pub fn run(out_dir: PathBuf) -> anyhow::Result<()> {
     let data_dir = config.data_dir().to_path_buf();
 
     let bbn_repository = BbnRepository::new(data_dir);
-    let query = Query::try_from("date:1970-01-01/9999-12-31")?;
+    let query = query.unwrap_or_else(|| "date:1970-01-01/9999-12-31".to_string());
+    let query = Query::try_from(query.as_str())?;
     let entry_ids = bbn_repository.find_ids_by_query(query)?;
 
-    let path = out_dir.join("sitemap.xml");
-    let file = File::create(path)?;
-    let writer = BufWriter::new(file);
-    let mut writer = SitemapWriter::start(writer)?;
-
+    let mut entries = vec![];
     for entry_id in entry_ids {
         let meta = bbn_repository
             .find_meta_by_id(&entry_id)?
             .context("meta not found")?;
+        entries.push((entry_id, meta));
+    }
+
+    write_sitemap_xml(
+        out_dir.as_path(),
+        "https://blog.bouzuya.net",
+        entries.iter().map(|(entry_id, meta)| (entry_id, meta)),
+    )
+}
+
+pub(crate) fn write_sitemap_xml<'a>(
+    out_dir: &Path,
+    base_url: &str,
+    entries: impl IntoIterator<Item = (&'a EntryId, &'a EntryMeta)>,
+) -> anyhow::Result<()> {
+    let path = out_dir.join("sitemap.xml");
+    let file = File::create(path)?;
+    let writer = BufWriter::new(file);
+    let mut writer = SitemapWriter::start(writer)?;
 
-        let date = entry_id.date();
-        let yyyy = date.year().to_string();
-        let mm = date.month().to_string();
-        let dd = date.day_of_month().to_string();
+    for (entry_id, meta) in entries {
         writer.write(
-            Url::loc(format!("https://blog.bouzuya.net/{yyyy}/{mm}/{dd}/").as_str())?
+            Url::loc(format!("{base_url}{}", entry_path(entry_id)).as_str())?
                 .lastmod(meta.pubdate.to_string().as_str())?,
         )?;
     }
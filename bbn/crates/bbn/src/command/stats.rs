@@ -0,0 +1,448 @@
+use std::{collections::BTreeMap, convert::TryFrom, str::FromStr};
+
+use anyhow::Context;
+use bbn_data::EntryMeta;
+use bbn_repository::{BbnRepository, Query};
+use chrono::{Datelike, Days, Duration, Local, NaiveDate};
+use date_range::{date::Date, week_date::WeekDate};
+
+use crate::config_repository::ConfigRepository;
+
+pub struct Params {
+    pub json: bool,
+    pub query: Option<String>,
+    pub weeks: usize,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize)]
+struct Stats {
+    entries: usize,
+    // the number of the non-whitespace characters in the contents
+    characters: usize,
+    // the number of the whitespace-separated words in the contents
+    words: usize,
+    minutes: u64,
+    average_minutes: f64,
+    longest_streak: Streak,
+    // the streak until today (or yesterday)
+    current_streak: Streak,
+    // `YYYY` -> count
+    per_year: BTreeMap<String, usize>,
+    // `YYYY-MM` -> count
+    per_month: BTreeMap<String, usize>,
+    // `YYYY-Www` -> count
+    per_week: BTreeMap<String, usize>,
+    // most used first
+    tags: Vec<TagCount>,
+    // oldest first
+    heatmap: Vec<HeatmapWeek>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Serialize)]
+struct Streak {
+    days: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    first: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+struct TagCount {
+    name: String,
+    count: usize,
+    // `YYYY` -> count
+    per_year: BTreeMap<String, usize>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+struct HeatmapWeek {
+    // `YYYY-Www`
+    week: String,
+    // the minutes from Monday to Sunday. `None` if no entry (or the future)
+    days: Vec<Option<u64>>,
+}
+
+fn naive_date(date: Date) -> anyhow::Result<NaiveDate> {
+    Ok(NaiveDate::from_str(date.to_string().as_str())?)
+}
+
+fn date(naive_date: NaiveDate) -> anyhow::Result<Date> {
+    Ok(Date::from_str(naive_date.to_string().as_str())?)
+}
+
+fn streak(dates: &[NaiveDate]) -> Streak {
+    match (dates.first(), dates.last()) {
+        (Some(first), Some(last)) => Streak {
+            days: dates.len(),
+            first: Some(first.to_string()),
+            last: Some(last.to_string()),
+        },
+        _ => Streak::default(),
+    }
+}
+
+// `dates` must be sorted
+fn streaks(dates: &[NaiveDate], today: NaiveDate) -> (Streak, Streak) {
+    let mut runs: Vec<&[NaiveDate]> = vec![];
+    let mut from = 0;
+    for i in 1..=dates.len() {
+        if i == dates.len() || dates[i] != dates[i - 1] + Duration::days(1) {
+            runs.push(&dates[from..i]);
+            from = i;
+        }
+    }
+    let longest = runs
+        .iter()
+        .copied()
+        .fold(None::<&[NaiveDate]>, |longest, run| match longest {
+            Some(longest) if longest.len() >= run.len() => Some(longest),
+            _ => Some(run),
+        })
+        .map(streak)
+        .unwrap_or_default();
+    let current = runs
+        .last()
+        .filter(|run| {
+            run.last()
+                .map(|last| *last == today || *last + Duration::days(1) == today)
+                .unwrap_or(false)
+        })
+        .map(|run| streak(run))
+        .unwrap_or_default();
+    (longest, current)
+}
+
+fn heatmap(
+    minutes: &BTreeMap<NaiveDate, u64>,
+    today: NaiveDate,
+    weeks: usize,
+) -> anyhow::Result<Vec<HeatmapWeek>> {
+    let monday = today - Duration::days(i64::from(today.weekday().num_days_from_monday()));
+    let mut heatmap = vec![];
+    for i in (0..weeks).rev() {
+        let first = u64::try_from(i)
+            .ok()
+            .and_then(|i| i.checked_mul(7))
+            .and_then(|days| monday.checked_sub_days(Days::new(days)))
+            .with_context(|| format!("weeks is too large: {weeks}"))?;
+        let week = WeekDate::from(date(first)?).year_week();
+        let days = (0..7)
+            .map(|d| first + Duration::days(d))
+            .map(|day| {
+                if day > today {
+                    None
+                } else {
+                    minutes.get(&day).copied()
+                }
+            })
+            .collect::<Vec<Option<u64>>>();
+        heatmap.push(HeatmapWeek {
+            week: week.to_string(),
+            days,
+        });
+    }
+    Ok(heatmap)
+}
+
+fn stats<'a, I>(entries: I, today: Date, weeks: usize) -> anyhow::Result<Stats>
+where
+    I: IntoIterator<Item = (Date, &'a EntryMeta, &'a str)>,
+{
+    let mut stats = Stats::default();
+    let mut minutes = BTreeMap::new();
+    let mut tags = BTreeMap::<String, BTreeMap<String, usize>>::new();
+    for (date, meta, content) in entries {
+        stats.entries += 1;
+        stats.characters += content.chars().filter(|c| !c.is_whitespace()).count();
+        stats.words += content.split_whitespace().count();
+        stats.minutes += meta.minutes;
+        *stats.per_year.entry(date.year().to_string()).or_default() += 1;
+        *stats
+            .per_month
+            .entry(date.year_month().to_string())
+            .or_default() += 1;
+        *stats
+            .per_week
+            .entry(WeekDate::from(date).year_week().to_string())
+            .or_default() += 1;
+        for tag in meta.tags.iter() {
+            *tags
+                .entry(tag.clone())
+                .or_default()
+                .entry(date.year().to_string())
+                .or_default() += 1;
+        }
+        *minutes.entry(naive_date(date)?).or_default() += meta.minutes;
+    }
+    if stats.entries > 0 {
+        stats.average_minutes = stats.minutes as f64 / stats.entries as f64;
+    }
+
+    let today = naive_date(today)?;
+    let dates = minutes.keys().copied().collect::<Vec<NaiveDate>>();
+    let (longest_streak, current_streak) = streaks(&dates, today);
+    stats.longest_streak = longest_streak;
+    stats.current_streak = current_streak;
+
+    stats.tags = tags
+        .into_iter()
+        .map(|(name, per_year)| TagCount {
+            name,
+            count: per_year.values().sum(),
+            per_year,
+        })
+        .collect();
+    stats
+        .tags
+        .sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+
+    stats.heatmap = heatmap(&minutes, today, weeks)?;
+    Ok(stats)
+}
+
+fn heatmap_cell(minutes: Option<u64>) -> char {
+    match minutes {
+        None => '.',
+        Some(m) if m < 15 => '-',
+        Some(m) if m < 30 => '+',
+        Some(_) => '#',
+    }
+}
+
+fn format_streak(name: &str, streak: &Streak) -> String {
+    match (streak.first.as_ref(), streak.last.as_ref()) {
+        (Some(first), Some(last)) => format!("{name}: {} days ({first}/{last})", streak.days),
+        _ => format!("{name}: 0 days"),
+    }
+}
+
+fn format_stats(stats: &Stats) -> String {
+    let mut lines = vec![
+        format!("entries: {}", stats.entries),
+        format!("characters: {}", stats.characters),
+        format!("words: {}", stats.words),
+        format!(
+            "minutes: {} (average: {:.1})",
+            stats.minutes, stats.average_minutes
+        ),
+        format_streak("longest streak", &stats.longest_streak),
+        format_streak("current streak", &stats.current_streak),
+    ];
+    for (name, counts) in [
+        ("per year", &stats.per_year),
+        ("per month", &stats.per_month),
+        ("per week", &stats.per_week),
+    ] {
+        lines.push(format!("{name}:"));
+        lines.extend(counts.iter().map(|(key, count)| format!("  {key} {count}")));
+    }
+    lines.push("tags:".to_string());
+    lines.extend(stats.tags.iter().map(|tag| {
+        format!(
+            "  {} {} ({})",
+            tag.name,
+            tag.count,
+            tag.per_year
+                .iter()
+                .map(|(year, count)| format!("{year}: {count}"))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }));
+    lines.push("heatmap: (. none, - <15min, + <30min, # >=30min)".to_string());
+    lines.push("           MTWTFSS".to_string());
+    lines.extend(stats.heatmap.iter().map(|week| {
+        format!(
+            "  {} {}",
+            week.week,
+            week.days
+                .iter()
+                .map(|minutes| heatmap_cell(*minutes))
+                .collect::<String>()
+        )
+    }));
+    lines.join("\n")
+}
+
+pub fn run(Params { json, query, weeks }: Params) -> anyhow::Result<()> {
+    let config_repository = ConfigRepository::new()?;
+    let config = config_repository
+        .load()
+        .context("The configuration file does not found. Use `bbn config` command.")?;
+    let data_dir = config.data_dir().to_path_buf();
+
+    let bbn_repository = BbnRepository::new(data_dir);
+    let query = query.unwrap_or_default();
+    let query = Query::try_from(query.as_str())?;
+    let mut entries = vec![];
+    for entry_id in bbn_repository.find_ids_by_query(query)? {
+        let entry = bbn_repository
+            .find_entry_by_id(&entry_id)?
+            .with_context(|| format!("the entry is not found: {entry_id}"))?;
+        entries.push((*entry_id.date(), entry));
+    }
+    let today = date(Local::now().date_naive())?;
+    let stats = stats(
+        entries
+            .iter()
+            .map(|(date, entry)| (*date, entry.meta(), entry.content())),
+        today,
+        weeks,
+    )?;
+    if json {
+        println!("{}", serde_json::to_string(&stats)?);
+    } else {
+        println!("{}", format_stats(&stats));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use bbn_data::DateTime;
+
+    use super::*;
+
+    fn meta(date: &str, minutes: u64, tags: &[&str]) -> anyhow::Result<EntryMeta> {
+        Ok(EntryMeta::new(
+            minutes,
+            DateTime::from_str(format!("{date}T00:00:00+09:00").as_str())?,
+            tags.iter().map(|tag| tag.to_string()).collect(),
+            "TITLE".to_string(),
+        ))
+    }
+
+    #[test]
+    fn stats_test() -> anyhow::Result<()> {
+        let entries = [
+            ("2020-12-31", meta("2020-12-31", 10, &["rust"])?, "a b"),
+            (
+                "2021-01-01",
+                meta("2021-01-01", 20, &["rust", "go"])?,
+                "日本語 c",
+            ),
+            ("2021-01-02", meta("2021-01-02", 30, &[])?, ""),
+            ("2021-01-05", meta("2021-01-05", 60, &["rust"])?, "d"),
+            ("2021-01-06", meta("2021-01-06", 0, &[])?, "e"),
+        ];
+        let stats = stats(
+            entries
+                .iter()
+                .map(|(date, meta, content)| Ok((Date::from_str(date)?, meta, *content)))
+                .collect::<anyhow::Result<Vec<(Date, &EntryMeta, &str)>>>()?,
+            Date::from_str("2021-01-07")?,
+            2,
+        )?;
+        let counts = |counts: &[(&str, usize)]| {
+            counts
+                .iter()
+                .map(|(key, count)| (key.to_string(), *count))
+                .collect::<BTreeMap<String, usize>>()
+        };
+        assert_eq!(
+            stats,
+            Stats {
+                entries: 5,
+                characters: 8,
+                words: 6,
+                minutes: 120,
+                average_minutes: 24.0,
+                longest_streak: Streak {
+                    days: 3,
+                    first: Some("2020-12-31".to_string()),
+                    last: Some("2021-01-02".to_string()),
+                },
+                current_streak: Streak {
+                    days: 2,
+                    first: Some("2021-01-05".to_string()),
+                    last: Some("2021-01-06".to_string()),
+                },
+                per_year: counts(&[("2020", 1), ("2021", 4)]),
+                per_month: counts(&[("2020-12", 1), ("2021-01", 4)]),
+                per_week: counts(&[("2020-W53", 3), ("2021-W01", 2)]),
+                tags: vec![
+                    TagCount {
+                        name: "rust".to_string(),
+                        count: 3,
+                        per_year: counts(&[("2020", 1), ("2021", 2)]),
+                    },
+                    TagCount {
+                        name: "go".to_string(),
+                        count: 1,
+                        per_year: counts(&[("2021", 1)]),
+                    },
+                ],
+                heatmap: vec![
+                    HeatmapWeek {
+                        week: "2020-W53".to_string(),
+                        days: vec![None, None, None, Some(10), Some(20), Some(30), None],
+                    },
+                    HeatmapWeek {
+                        week: "2021-W01".to_string(),
+                        days: vec![None, Some(60), Some(0), None, None, None, None],
+                    },
+                ],
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn heatmap_test() -> anyhow::Result<()> {
+        let today = NaiveDate::from_str("2021-01-07")?;
+        assert_eq!(heatmap(&BTreeMap::new(), today, 0)?, vec![]);
+        assert_eq!(heatmap(&BTreeMap::new(), today, 1)?[0].week, "2021-W01");
+        assert!(heatmap(&BTreeMap::new(), today, usize::MAX).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn streaks_test() {
+        let d = |s: &str| NaiveDate::from_str(s).unwrap();
+        let today = d("2021-01-10");
+        assert_eq!(streaks(&[], today), (Streak::default(), Streak::default()));
+        let (longest, current) = streaks(&[d("2021-01-01"), d("2021-01-08")], today);
+        assert_eq!(longest.days, 1);
+        assert_eq!(longest.first.as_deref(), Some("2021-01-01"));
+        assert_eq!(current, Streak::default());
+        let (longest, current) = streaks(&[d("2021-01-08"), d("2021-01-09")], today);
+        assert_eq!(longest, current);
+        assert_eq!(current.days, 2);
+        let (_, current) = streaks(&[d("2021-01-10")], today);
+        assert_eq!(current.days, 1);
+    }
+
+    #[test]
+    fn format_stats_test() {
+        let stats = Stats {
+            entries: 1,
+            minutes: 15,
+            average_minutes: 15.0,
+            heatmap: vec![HeatmapWeek {
+                week: "2021-W01".to_string(),
+                days: vec![None, Some(0), Some(15), Some(30), None, None, None],
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            format_stats(&stats),
+            [
+                "entries: 1",
+                "characters: 0",
+                "words: 0",
+                "minutes: 15 (average: 15.0)",
+                "longest streak: 0 days",
+                "current streak: 0 days",
+                "per year:",
+                "per month:",
+                "per week:",
+                "tags:",
+                "heatmap: (. none, - <15min, + <30min, # >=30min)",
+                "           MTWTFSS",
+                "  2021-W01 .-+#...",
+            ]
+            .join("\n")
+        );
+    }
+}
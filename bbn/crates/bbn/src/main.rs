@@ -19,6 +19,45 @@ struct Opt {
 
 #[derive(Debug, clap::Subcommand)]
 enum Subcommand {
+    #[command(name = "build", about = "Builds the static HTML site")]
+    Build {
+        #[arg(name = "OUT_DIR", help = "the output dir")]
+        out_dir: PathBuf,
+        #[arg(
+            long = "author",
+            default_value = "bouzuya",
+            help = "the author name of the feed"
+        )]
+        author: String,
+        #[arg(
+            long = "base-url",
+            default_value = "https://blog.bouzuya.net",
+            help = "the base url of the site (used in the links, the feed and the sitemap)"
+        )]
+        base_url: String,
+        #[arg(
+            long = "per-page",
+            default_value_t = 10,
+            help = "the number of the entries per index page"
+        )]
+        per_page: usize,
+        #[arg(
+            long = "query",
+            help = "query. e.g. date:2021 tag:rust (see `bbn list --help`)"
+        )]
+        query: Option<String>,
+        #[arg(
+            long = "site-title",
+            default_value = "blog.bouzuya.net",
+            help = "the site title"
+        )]
+        site_title: String,
+        #[arg(
+            long = "template-dir",
+            help = "the template dir (atom.xml, atom_entry.xml, entry.html, layout.html, list.html, list_item.html, tag.html and tag_item.html)"
+        )]
+        template_dir: Option<PathBuf>,
+    },
     #[command(name = "completion", about = "Prints the shell's completion script")]
     Completion {
         #[arg(name = "SHELL", help = "the shell", value_enum)]
@@ -48,24 +87,95 @@ enum Subcommand {
         subcommand: HatenaBlogSubcommand,
     },
     #[command(name = "json", about = "...")]
-    Json { out_dir: PathBuf },
+    Json {
+        out_dir: PathBuf,
+        #[arg(
+            long = "query",
+            help = "query. e.g. date:2021 tag:rust (see `bbn list --help`)"
+        )]
+        query: Option<String>,
+    },
     #[command(name = "link-completion", about = "Completes links")]
     LinkCompletion {
         #[arg(name = "DATE_LIKE", help = "the date. e.g. 2021-02-03 or 2021-W05-3")]
         date_like: DateLike,
     },
+    #[command(
+        name = "links",
+        about = "Shows the links and the backlinks of the entry, or reports the broken links"
+    )]
+    Links {
+        #[arg(
+            name = "DATE_LIKE",
+            help = "the date. e.g. 2021-02-03 or 2021-W05-3 (reports the broken links in all entries if omitted)"
+        )]
+        date_like: Option<DateLike>,
+        #[arg(
+            long = "base-url",
+            default_value = "https://blog.bouzuya.net",
+            help = "the base url of the site (used to find the links to the entries)"
+        )]
+        base_url: String,
+        #[arg(long = "json", help = "json")]
+        json: bool,
+    },
     #[command(name = "list", about = "Lists the blog posts")]
     List {
         #[arg(long = "json", help = "json")]
         json: bool,
         #[arg(
             name = "query",
-            help = "query. e.g. date:2021 or date:2021-02 or date:2021-02-03 or date:--02-03 or date:---03"
+            help = "query. e.g. date:2021 or date:2021-02 or date:2021-02-03 or date:--02-03 or date:---03",
+            long_help = "query. e.g. date:2021 or date:2021-02 or date:2021-02-03 or date:--02-03 or date:---03 or date:2021-02-03/2022-03-04\n\
+                the other terms: tag:rust or title:foo or title:\"foo bar\" or minutes:>10 (>=, <, <=, =)\n\
+                combinators: -tag:rust (NOT tag:rust), tag:rust tag:go (tag:rust AND tag:go), tag:rust OR tag:go, (...)"
         )]
         query: Option<String>,
     },
+    #[command(name = "search", about = "Searches the blog posts")]
+    Search {
+        #[arg(long = "json", help = "json")]
+        json: bool,
+        #[arg(
+            long = "limit",
+            default_value_t = 20,
+            help = "the maximum number of the results"
+        )]
+        limit: usize,
+        #[arg(long = "title", help = "Searches only the titles")]
+        title: bool,
+        #[arg(
+            name = "WORDS",
+            required = true,
+            help = "words. e.g. rust or tag:rust (tag filter)"
+        )]
+        words: Vec<String>,
+    },
     #[command(name = "sitemap-xml", about = "...")]
-    SitemapXml { out_dir: PathBuf },
+    SitemapXml {
+        out_dir: PathBuf,
+        #[arg(
+            long = "query",
+            help = "query. e.g. date:2021 tag:rust (see `bbn list --help`)"
+        )]
+        query: Option<String>,
+    },
+    #[command(name = "stats", about = "Shows the writing statistics")]
+    Stats {
+        #[arg(long = "json", help = "json")]
+        json: bool,
+        #[arg(
+            long = "query",
+            help = "query. e.g. date:2021 tag:rust (see `bbn list --help`)"
+        )]
+        query: Option<String>,
+        #[arg(
+            long = "weeks",
+            default_value_t = 53,
+            help = "the number of the weeks in the heatmap"
+        )]
+        weeks: usize,
+    },
     #[command(name = "view", about = "Views the blog post")]
     View {
         #[arg(long = "content", help = "Prints the contents of the entry")]
@@ -97,6 +207,21 @@ pub enum HatenaBlogSubcommand {
     },
     #[command(name = "list")]
     List,
+    #[command(
+        name = "sync",
+        about = "Syncs the entries with the hatena blog (push / pull only the changed entries)"
+    )]
+    Sync {
+        #[arg(long = "draft", help = "Creates the new entries as drafts")]
+        draft: bool,
+        #[arg(long = "dry-run", help = "Shows the actions without running them")]
+        dry_run: bool,
+        #[arg(
+            long = "no-fetch",
+            help = "Uses the downloaded data file without fetching"
+        )]
+        no_fetch: bool,
+    },
     #[command(name = "upload", about = "Upload to the hatena blog")]
     Upload {
         #[arg(name = "DATE", help = "date")]
@@ -125,6 +250,23 @@ pub enum HatenaBlogSubcommand {
 async fn main() -> anyhow::Result<()> {
     let opt = <Opt as clap::Parser>::parse();
     match opt.subcommand {
+        Subcommand::Build {
+            out_dir,
+            author,
+            base_url,
+            per_page,
+            query,
+            site_title,
+            template_dir,
+        } => command::build::run(command::build::Params {
+            author,
+            base_url,
+            out_dir,
+            per_page,
+            query,
+            site_title,
+            template_dir,
+        }),
         Subcommand::Completion { shell } => {
             let mut command = <Opt as clap::CommandFactory>::command();
             generate(shell, &mut command, "bbn", &mut io::stdout());
@@ -142,6 +284,11 @@ async fn main() -> anyhow::Result<()> {
                 data_file_only,
             } => command::hatena_blog::download(data_file_only, date).await,
             HatenaBlogSubcommand::List => command::hatena_blog::list().await,
+            HatenaBlogSubcommand::Sync {
+                draft,
+                dry_run,
+                no_fetch,
+            } => command::hatena_blog::sync(dry_run, draft, no_fetch).await,
             HatenaBlogSubcommand::Upload {
                 date,
                 draft,
@@ -155,12 +302,30 @@ async fn main() -> anyhow::Result<()> {
                 web,
             } => command::hatena_blog::view(content, date, hatena_blog_id, meta, web).await,
         },
-        Subcommand::Json { out_dir } => command::json(out_dir),
+        Subcommand::Json { out_dir, query } => command::json(out_dir, query),
         Subcommand::LinkCompletion { date_like } => {
             command::link_completion::run(command::link_completion::Params { date_like })
         }
+        Subcommand::Links {
+            date_like,
+            base_url,
+            json,
+        } => command::links::run(command::links::Params {
+            base_url,
+            date_like,
+            json,
+        }),
         Subcommand::List { json, query } => command::list(json, query),
-        Subcommand::SitemapXml { out_dir } => command::sitemap_xml(out_dir),
+        Subcommand::Search {
+            json,
+            limit,
+            title,
+            words,
+        } => command::search(json, limit, title, words),
+        Subcommand::SitemapXml { out_dir, query } => command::sitemap_xml(out_dir, query),
+        Subcommand::Stats { json, query, weeks } => {
+            command::stats::run(command::stats::Params { json, query, weeks })
+        }
         Subcommand::View {
             content,
             date_like,
@@ -2,6 +2,264 @@ use assert_cmd::Command;
 use std::fs;
 use tempfile::tempdir;
 
+#[test]
+fn build_test() {
+    let temp_dir = tempdir().unwrap();
+    let config_dir = temp_dir.path().join("config");
+    let data_dir = temp_dir.path().join("data");
+    let entry_dir = data_dir.join("2021").join("02");
+    fs::create_dir_all(entry_dir.as_path()).unwrap();
+    for (date, tags, title, content) in [
+        (
+            "2021-02-03",
+            r#"["rust", "日記 2021"]"#,
+            "TITLE1",
+            "# Hello",
+        ),
+        ("2021-02-04", r#"[]"#, "<TITLE2>", "World"),
+    ] {
+        fs::write(
+            entry_dir.join(format!("{date}.json")),
+            format!(
+                r#"{{"minutes":5,"pubdate":"{date}T00:00:00+09:00","tags":{tags},"title":"{title}"}}"#
+            ),
+        )
+        .unwrap();
+        fs::write(entry_dir.join(format!("{date}.md")), content).unwrap();
+    }
+    let template_dir = temp_dir.path().join("templates");
+    fs::create_dir_all(template_dir.as_path()).unwrap();
+    fs::write(
+        template_dir.join("layout.html"),
+        "<title>{{title}}</title>{{{content}}}",
+    )
+    .unwrap();
+    let hatena_blog_data_file = temp_dir.path().join("hatena-blog.db");
+    let out_dir = temp_dir.path().join("out");
+
+    Command::cargo_bin("bbn")
+        .unwrap()
+        .arg("config")
+        .arg("--data-dir")
+        .arg(data_dir)
+        .arg("--hatena-blog-data-file")
+        .arg(hatena_blog_data_file)
+        .env(
+            "BBN_TEST_CONFIG_DIR",
+            config_dir.as_os_str().to_str().unwrap(),
+        )
+        .assert()
+        .success();
+    Command::cargo_bin("bbn")
+        .unwrap()
+        .arg("build")
+        .arg(out_dir.as_path())
+        .arg("--base-url")
+        .arg("https://example.com/blog/")
+        .arg("--per-page")
+        .arg("1")
+        .arg("--template-dir")
+        .arg(template_dir)
+        .env(
+            "BBN_TEST_CONFIG_DIR",
+            config_dir.as_os_str().to_str().unwrap(),
+        )
+        .assert()
+        .success();
+
+    let read = |path: &str| fs::read_to_string(out_dir.join(path)).unwrap();
+    let entry = read("2021/02/03/index.html");
+    assert!(entry.starts_with("<title>TITLE1</title><article>"));
+    assert!(entry.contains("<h1>Hello</h1>"));
+    assert!(entry.contains(r#"<a href="/blog/tags/rust/">rust</a>"#));
+    assert!(entry.contains(r#"<a href="/blog/tags/%E6%97%A5%E8%A8%98%202021/">日記 2021</a>"#));
+    assert!(read("2021/02/04/index.html").starts_with("<title>&lt;TITLE2&gt;</title>"));
+    let index = read("index.html");
+    assert!(index.contains(r#"<a href="/blog/2021/02/04/">2021-02-04 &lt;TITLE2&gt;</a>"#));
+    assert!(index.contains(r#"<a rel="next" href="/blog/page/2/">Older</a>"#));
+    let page2 = read("page/2/index.html");
+    assert!(page2.contains(r#"<a href="/blog/2021/02/03/">2021-02-03 TITLE1</a>"#));
+    assert!(page2.contains(r#"<a rel="prev" href="/blog/">Newer</a>"#));
+    assert!(!out_dir.join("page/3").exists());
+    for archive in ["2021/index.html", "2021/02/index.html"] {
+        let archive = read(archive);
+        assert!(archive.contains("2021-02-03 TITLE1"));
+        assert!(archive.contains("2021-02-04 &lt;TITLE2&gt;"));
+    }
+    assert!(read("tags/index.html").contains(r#"<li><a href="/blog/tags/rust/">rust</a> (1)</li>"#));
+    assert!(read("tags/rust/index.html").contains("2021-02-03 TITLE1"));
+    assert!(read("tags/日記 2021/index.html").contains("2021-02-03 TITLE1"));
+    let atom = read("atom.xml");
+    assert!(atom.contains("<updated>2021-02-04T00:00:00+09:00</updated>"));
+    assert!(atom.contains(r#"<link href="https://example.com/blog/2021/02/03/"/>"#));
+    assert!(atom.contains("<author><name>bouzuya</name></author>"));
+    assert!(out_dir.join("sitemap.xml").is_file());
+
+    fs::write(
+        entry_dir.join("2021-02-05.json"),
+        r#"{"minutes":5,"pubdate":"2021-02-05T00:00:00+09:00","tags":[".."],"title":"TITLE3"}"#,
+    )
+    .unwrap();
+    fs::write(entry_dir.join("2021-02-05.md"), "").unwrap();
+    Command::cargo_bin("bbn")
+        .unwrap()
+        .arg("build")
+        .arg(temp_dir.path().join("out2"))
+        .env(
+            "BBN_TEST_CONFIG_DIR",
+            config_dir.as_os_str().to_str().unwrap(),
+        )
+        .assert()
+        .failure();
+    assert!(!temp_dir.path().join("out2").exists());
+}
+
+#[test]
+fn build_id_title_test() {
+    let temp_dir = tempdir().unwrap();
+    let config_dir = temp_dir.path().join("config");
+    let data_dir = temp_dir.path().join("data");
+    let entry_dir = data_dir.join("2021").join("02");
+    fs::create_dir_all(entry_dir.as_path()).unwrap();
+    for (id, title) in [("2021-02-03", "TITLE1"), ("2021-02-03-second", "TITLE2")] {
+        fs::write(
+            entry_dir.join(format!("{id}.json")),
+            format!(
+                r#"{{"minutes":5,"pubdate":"2021-02-03T00:00:00+09:00","tags":[],"title":"{title}"}}"#
+            ),
+        )
+        .unwrap();
+        fs::write(entry_dir.join(format!("{id}.md")), title).unwrap();
+    }
+    let hatena_blog_data_file = temp_dir.path().join("hatena-blog.db");
+    let out_dir = temp_dir.path().join("out");
+
+    Command::cargo_bin("bbn")
+        .unwrap()
+        .arg("config")
+        .arg("--data-dir")
+        .arg(data_dir)
+        .arg("--hatena-blog-data-file")
+        .arg(hatena_blog_data_file)
+        .env(
+            "BBN_TEST_CONFIG_DIR",
+            config_dir.as_os_str().to_str().unwrap(),
+        )
+        .assert()
+        .success();
+    Command::cargo_bin("bbn")
+        .unwrap()
+        .arg("build")
+        .arg(out_dir.as_path())
+        .arg("--author")
+        .arg("AUTHOR")
+        .env(
+            "BBN_TEST_CONFIG_DIR",
+            config_dir.as_os_str().to_str().unwrap(),
+        )
+        .assert()
+        .success();
+
+    let read = |path: &str| fs::read_to_string(out_dir.join(path)).unwrap();
+    assert!(read("2021/02/03/index.html").contains("<title>TITLE1 - "));
+    assert!(read("2021/02/03/second/index.html").contains("<title>TITLE2 - "));
+    let atom = read("atom.xml");
+    assert!(atom.contains("<id>https://blog.bouzuya.net/2021/02/03/</id>"));
+    assert!(atom.contains("<id>https://blog.bouzuya.net/2021/02/03/second/</id>"));
+    assert!(atom.contains("<author><name>AUTHOR</name></author>"));
+    assert!(read("sitemap.xml").contains("https://blog.bouzuya.net/2021/02/03/second/"));
+
+    // no entries
+    let out_dir = temp_dir.path().join("out2");
+    Command::cargo_bin("bbn")
+        .unwrap()
+        .arg("build")
+        .arg(out_dir.as_path())
+        .arg("--query")
+        .arg("date:1999")
+        .env(
+            "BBN_TEST_CONFIG_DIR",
+            config_dir.as_os_str().to_str().unwrap(),
+        )
+        .assert()
+        .success();
+    let atom = fs::read_to_string(out_dir.join("atom.xml")).unwrap();
+    assert!(atom.contains("<updated>"));
+    assert!(!atom.contains("<entry>"));
+}
+
+#[test]
+fn links_test() {
+    let temp_dir = tempdir().unwrap();
+    let config_dir = temp_dir.path().join("config");
+    let data_dir = temp_dir.path().join("data");
+    let entry_dir = data_dir.join("2021").join("02");
+    fs::create_dir_all(entry_dir.as_path()).unwrap();
+    for (date, title, content) in [
+        (
+            "2021-02-03",
+            "TITLE1",
+            "[2021-02-04]\n\n[2021-02-04]: https://blog.bouzuya.net/2021/02/04/",
+        ),
+        (
+            "2021-02-04",
+            "TITLE2",
+            "[a](/2021/02/03/) [b](/2021/02/05/)",
+        ),
+    ] {
+        fs::write(
+            entry_dir.join(format!("{date}.json")),
+            format!(
+                r#"{{"minutes":5,"pubdate":"{date}T00:00:00+09:00","tags":[],"title":"{title}"}}"#
+            ),
+        )
+        .unwrap();
+        fs::write(entry_dir.join(format!("{date}.md")), content).unwrap();
+    }
+    let hatena_blog_data_file = temp_dir.path().join("hatena-blog.db");
+
+    Command::cargo_bin("bbn")
+        .unwrap()
+        .arg("config")
+        .arg("--data-dir")
+        .arg(data_dir)
+        .arg("--hatena-blog-data-file")
+        .arg(hatena_blog_data_file)
+        .env(
+            "BBN_TEST_CONFIG_DIR",
+            config_dir.as_os_str().to_str().unwrap(),
+        )
+        .assert()
+        .success();
+    Command::cargo_bin("bbn")
+        .unwrap()
+        .arg("links")
+        .arg("2021-02-04")
+        .env(
+            "BBN_TEST_CONFIG_DIR",
+            config_dir.as_os_str().to_str().unwrap(),
+        )
+        .assert()
+        .success()
+        .stdout(predicates::ord::eq(
+            b"2021-02-04 TITLE2\nlinks:\n  2021-02-03 TITLE1\nbacklinks:\n  2021-02-03 TITLE1\nbroken:\n  /2021/02/05/ (no entry)\n" as &[u8],
+        ));
+    Command::cargo_bin("bbn")
+        .unwrap()
+        .arg("links")
+        .arg("--json")
+        .env(
+            "BBN_TEST_CONFIG_DIR",
+            config_dir.as_os_str().to_str().unwrap(),
+        )
+        .assert()
+        .failure()
+        .stdout(predicates::ord::eq(
+            br#"{"entries":2,"links":2,"broken":[{"date":"2021-02-04","url":"/2021/02/05/","reason":"no entry"}]}
+"# as &[u8],
+        ));
+}
+
 #[test]
 fn list_test() {
     let temp_dir = tempdir().unwrap();
@@ -57,6 +315,163 @@ fn list_test() {
         .stdout(predicates::ord::eq(
             b"[{\"date\":\"2021-02-03\",\"title\":\"TITLE1\",\"url\":\"https://blog.bouzuya.net/2021/02/03/\"}]\n" as &[u8],
         ));
+    Command::cargo_bin("bbn")
+        .unwrap()
+        .arg("list")
+        .arg("title:title1 minutes:>=5 -tag:rust")
+        .env(
+            "BBN_TEST_CONFIG_DIR",
+            config_dir.as_os_str().to_str().unwrap(),
+        )
+        .assert()
+        .success()
+        .stdout(predicates::ord::eq(
+            b"2021-02-03 TITLE1 <https://blog.bouzuya.net/2021/02/03/>\n" as &[u8],
+        ));
+    Command::cargo_bin("bbn")
+        .unwrap()
+        .arg("list")
+        .arg("minutes:>5 OR tag:rust")
+        .env(
+            "BBN_TEST_CONFIG_DIR",
+            config_dir.as_os_str().to_str().unwrap(),
+        )
+        .assert()
+        .success()
+        .stdout(predicates::ord::eq(b"\n" as &[u8]));
+}
+
+#[test]
+fn search_test() {
+    let temp_dir = tempdir().unwrap();
+    let config_dir = temp_dir.path().join("config");
+    let data_dir = temp_dir.path().join("data");
+    let entry_dir = data_dir.join("2021").join("02");
+    fs::create_dir_all(entry_dir.as_path()).unwrap();
+    for (date, tags, title, content) in [
+        ("2021-02-03", r#"["rust"]"#, "Rust", "I wrote Rust."),
+        ("2021-02-04", r#"["diary"]"#, "DIARY", "Rust and Go"),
+    ] {
+        fs::write(
+            entry_dir.join(format!("{date}.json")),
+            format!(
+                r#"{{"minutes":5,"pubdate":"{date}T00:00:00+09:00","tags":{tags},"title":"{title}"}}"#
+            ),
+        )
+        .unwrap();
+        fs::write(entry_dir.join(format!("{date}.md")), content).unwrap();
+    }
+    let hatena_blog_data_file = temp_dir.path().join("hatena-blog.db");
+
+    Command::cargo_bin("bbn")
+        .unwrap()
+        .arg("config")
+        .arg("--data-dir")
+        .arg(data_dir.as_path())
+        .arg("--hatena-blog-data-file")
+        .arg(hatena_blog_data_file)
+        .env(
+            "BBN_TEST_CONFIG_DIR",
+            config_dir.as_os_str().to_str().unwrap(),
+        )
+        .assert()
+        .success();
+    Command::cargo_bin("bbn")
+        .unwrap()
+        .arg("search")
+        .arg("rust")
+        .env(
+            "BBN_TEST_CONFIG_DIR",
+            config_dir.as_os_str().to_str().unwrap(),
+        )
+        .assert()
+        .success()
+        .stdout(predicates::ord::eq(
+            b"2021-02-03 Rust <https://blog.bouzuya.net/2021/02/03/>\n  I wrote Rust.\n2021-02-04 DIARY <https://blog.bouzuya.net/2021/02/04/>\n  Rust and Go\n" as &[u8],
+        ));
+    assert!(data_dir.join(".search-index.json").is_file());
+    Command::cargo_bin("bbn")
+        .unwrap()
+        .arg("search")
+        .arg("--title")
+        .arg("rust")
+        .env(
+            "BBN_TEST_CONFIG_DIR",
+            config_dir.as_os_str().to_str().unwrap(),
+        )
+        .assert()
+        .success()
+        .stdout(predicates::ord::eq(
+            b"2021-02-03 Rust <https://blog.bouzuya.net/2021/02/03/>\n" as &[u8],
+        ));
+    Command::cargo_bin("bbn")
+        .unwrap()
+        .arg("search")
+        .arg("--json")
+        .arg("rust")
+        .arg("tag:diary")
+        .env(
+            "BBN_TEST_CONFIG_DIR",
+            config_dir.as_os_str().to_str().unwrap(),
+        )
+        .assert()
+        .success()
+        .stdout(predicates::ord::eq(
+            b"[{\"date\":\"2021-02-04\",\"score\":1,\"snippet\":\"Rust and Go\",\"tags\":[\"diary\"],\"title\":\"DIARY\",\"url\":\"https://blog.bouzuya.net/2021/02/04/\"}]\n" as &[u8],
+        ));
+}
+
+#[test]
+fn stats_test() {
+    let temp_dir = tempdir().unwrap();
+    let config_dir = temp_dir.path().join("config");
+    let data_dir = temp_dir.path().join("data");
+    let entry_dir = data_dir.join("2021").join("02");
+    fs::create_dir_all(entry_dir.as_path()).unwrap();
+    for (date, minutes, tags, content) in [
+        ("2021-02-03", 10, r#"["rust"]"#, "Hello World"),
+        ("2021-02-04", 20, r#"[]"#, "Hello"),
+    ] {
+        fs::write(
+            entry_dir.join(format!("{date}.json")),
+            format!(
+                r#"{{"minutes":{minutes},"pubdate":"{date}T00:00:00+09:00","tags":{tags},"title":"TITLE"}}"#
+            ),
+        )
+        .unwrap();
+        fs::write(entry_dir.join(format!("{date}.md")), content).unwrap();
+    }
+    let hatena_blog_data_file = temp_dir.path().join("hatena-blog.db");
+
+    Command::cargo_bin("bbn")
+        .unwrap()
+        .arg("config")
+        .arg("--data-dir")
+        .arg(data_dir)
+        .arg("--hatena-blog-data-file")
+        .arg(hatena_blog_data_file)
+        .env(
+            "BBN_TEST_CONFIG_DIR",
+            config_dir.as_os_str().to_str().unwrap(),
+        )
+        .assert()
+        .success();
+    Command::cargo_bin("bbn")
+        .unwrap()
+        .arg("stats")
+        .arg("--json")
+        .arg("--weeks")
+        .arg("0")
+        .env(
+            "BBN_TEST_CONFIG_DIR",
+            config_dir.as_os_str().to_str().unwrap(),
+        )
+        .assert()
+        .success()
+        .stdout(predicates::ord::eq(
+            br#"{"entries":2,"characters":15,"words":3,"minutes":30,"average_minutes":15.0,"longest_streak":{"days":2,"first":"2021-02-03","last":"2021-02-04"},"current_streak":{"days":0},"per_year":{"2021":2},"per_month":{"2021-02":2},"per_week":{"2021-W05":2},"tags":[{"name":"rust","count":1,"per_year":{"2021":1}}],"heatmap":[]}
+"# as &[u8],
+        ));
 }
 
 #[test]
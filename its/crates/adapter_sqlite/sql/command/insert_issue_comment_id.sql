@@ -1,2 +1,2 @@
-INSERT INTO issue_comment_ids (issue_comment_id, event_stream_id)
-VALUES ($1, $2)
+INSERT INTO issue_comment_ids (issue_comment_id, event_stream_id, issue_id)
+VALUES ($1, $2, $3)
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS event_stream_snapshots (
+  event_stream_id CHAR(26) NOT NULL,
+  version BIGINT NOT NULL,
+  data TEXT NOT NULL,
+  CONSTRAINT event_stream_snapshots_pk PRIMARY KEY (event_stream_id),
+  CONSTRAINT event_stream_snapshots_fk1 FOREIGN KEY (event_stream_id) REFERENCES event_streams (id)
+)
@@ -0,0 +1,2 @@
+-- This migration is used by iko.
+ALTER TABLE issue_comment_ids ADD COLUMN issue_id TEXT
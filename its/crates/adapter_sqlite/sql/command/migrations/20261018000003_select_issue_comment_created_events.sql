@@ -0,0 +1,6 @@
+-- This migration is used by iko.
+SELECT issue_comment_ids.issue_comment_id AS issue_comment_id,
+  events.data AS data
+FROM issue_comment_ids
+  INNER JOIN events ON events.event_stream_id = issue_comment_ids.event_stream_id
+WHERE events.version = 1
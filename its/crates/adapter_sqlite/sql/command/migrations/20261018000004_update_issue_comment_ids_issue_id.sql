@@ -0,0 +1,4 @@
+-- This migration is used by iko.
+UPDATE issue_comment_ids
+SET issue_id = $1
+WHERE issue_comment_id = $2
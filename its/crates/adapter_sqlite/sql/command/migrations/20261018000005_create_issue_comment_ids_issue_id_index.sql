@@ -0,0 +1,2 @@
+-- This migration is used by iko.
+CREATE INDEX IF NOT EXISTS issue_comment_ids_idx1 ON issue_comment_ids (issue_id)
@@ -0,0 +1,45 @@
+-- PostgreSQL: the same schema as the SQLite migrations (including iko) produce
+CREATE TABLE IF NOT EXISTS event_streams (
+  id CHAR(26) NOT NULL,
+  version BIGINT NOT NULL DEFAULT 0,
+  CONSTRAINT event_streams_pk PRIMARY KEY (id)
+);
+CREATE TABLE IF NOT EXISTS events (
+  seq BIGSERIAL NOT NULL,
+  id CHAR(26) NOT NULL,
+  event_stream_id CHAR(26) NOT NULL,
+  version BIGINT NOT NULL,
+  data TEXT NOT NULL,
+  CONSTRAINT events_pk PRIMARY KEY (seq),
+  CONSTRAINT events_uk1 UNIQUE (id),
+  CONSTRAINT events_uk2 UNIQUE (event_stream_id, version),
+  CONSTRAINT events_fk1 FOREIGN KEY (event_stream_id) REFERENCES event_streams (id)
+);
+CREATE TABLE IF NOT EXISTS issue_ids (
+  issue_number BIGINT NOT NULL,
+  event_stream_id CHAR(26) NOT NULL,
+  CONSTRAINT issue_ids_pk PRIMARY KEY (issue_number),
+  CONSTRAINT issue_ids_uk1 UNIQUE (event_stream_id),
+  CONSTRAINT issue_ids_fk1 FOREIGN KEY (event_stream_id) REFERENCES event_streams (id)
+);
+CREATE TABLE IF NOT EXISTS issue_block_link_ids (
+  issue_block_link_id TEXT NOT NULL,
+  event_stream_id CHAR(26) NOT NULL,
+  CONSTRAINT issue_block_link_ids_pk PRIMARY KEY (issue_block_link_id),
+  CONSTRAINT issue_block_link_ids_uk1 UNIQUE (event_stream_id),
+  CONSTRAINT issue_block_link_ids_fk1 FOREIGN KEY (event_stream_id) REFERENCES event_streams (id)
+);
+CREATE TABLE IF NOT EXISTS issue_comment_ids (
+  issue_comment_id TEXT NOT NULL,
+  event_stream_id CHAR(26) NOT NULL,
+  CONSTRAINT issue_comment_ids_pk PRIMARY KEY (issue_comment_id),
+  CONSTRAINT issue_comment_ids_uk UNIQUE (event_stream_id),
+  CONSTRAINT issue_comment_ids_fk1 FOREIGN KEY (event_stream_id) REFERENCES event_streams (id)
+);
+CREATE TABLE IF NOT EXISTS event_stream_snapshots (
+  event_stream_id CHAR(26) NOT NULL,
+  version BIGINT NOT NULL,
+  data TEXT NOT NULL,
+  CONSTRAINT event_stream_snapshots_pk PRIMARY KEY (event_stream_id),
+  CONSTRAINT event_stream_snapshots_fk1 FOREIGN KEY (event_stream_id) REFERENCES event_streams (id)
+);
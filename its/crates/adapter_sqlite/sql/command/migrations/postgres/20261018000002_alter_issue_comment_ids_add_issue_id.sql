@@ -0,0 +1,10 @@
+-- PostgreSQL: the same as migrate5 of the SQLite migrations
+ALTER TABLE issue_comment_ids ADD COLUMN IF NOT EXISTS issue_id TEXT;
+UPDATE issue_comment_ids
+SET issue_id = (
+    SELECT events.data::json->>'issue_id'
+    FROM events
+    WHERE events.event_stream_id = issue_comment_ids.event_stream_id
+      AND events.version = 1
+  );
+CREATE INDEX IF NOT EXISTS issue_comment_ids_idx1 ON issue_comment_ids (issue_id);
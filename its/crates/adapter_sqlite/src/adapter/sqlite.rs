@@ -9,3 +9,21 @@ pub use self::rdb_connection_pool::*;
 pub use self::sqlite_issue_block_link_repository::*;
 pub use self::sqlite_issue_comment_repository::SqliteIssueCommentRepository;
 pub use self::sqlite_issue_repository::*;
+
+// `sqlite::memory:` and, if `ITS_TEST_POSTGRES_URL` (e.g. `postgres://postgres@localhost:5432`) is set, a new PostgreSQL database
+#[cfg(test)]
+pub(crate) async fn test_connection_uris() -> anyhow::Result<Vec<String>> {
+    use sqlx::{migrate::MigrateDatabase, Any};
+
+    let mut connection_uris = vec!["sqlite::memory:".to_string()];
+    if let Ok(url) = std::env::var("ITS_TEST_POSTGRES_URL") {
+        let connection_uri = format!(
+            "{}/its_test_{}",
+            url.trim_end_matches('/'),
+            ulid::Ulid::new().to_string().to_lowercase()
+        );
+        Any::create_database(&connection_uri).await?;
+        connection_uris.push(connection_uri);
+    }
+    Ok(connection_uris)
+}
@@ -99,3 +99,34 @@ impl MigrationSource<'static> for CommandMigrationSource {
         })
     }
 }
+
+#[derive(Debug, Default)]
+pub struct PostgresCommandMigrationSource {}
+
+impl MigrationSource<'static> for PostgresCommandMigrationSource {
+    fn resolve(
+        self,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Migration>, BoxDynError>> + Send + 'static>> {
+        Box::pin(async move {
+            let migrations = vec![
+                Migration::new(
+                    20261018000001,
+                    Cow::from("create tables"),
+                    MigrationType::Simple,
+                    Cow::from(include_str!(
+                        "../../../sql/command/migrations/postgres/20261018000001_create_tables.sql"
+                    )),
+                ),
+                Migration::new(
+                    20261018000002,
+                    Cow::from("alter issue_comment_ids add issue_id"),
+                    MigrationType::Simple,
+                    Cow::from(include_str!(
+                        "../../../sql/command/migrations/postgres/20261018000002_alter_issue_comment_ids_add_issue_id.sql"
+                    )),
+                ),
+            ];
+            Ok(migrations)
+        })
+    }
+}
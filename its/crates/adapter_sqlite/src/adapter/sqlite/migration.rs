@@ -1,11 +1,17 @@
 mod migrate1;
 mod migrate2;
 mod migrate3;
+mod migrate4;
+mod migrate5;
 
 use migrate1::*;
 use migrate2::*;
 use migrate3::*;
-use sqlx::AnyPool;
+use migrate4::*;
+use migrate5::*;
+use sqlx::{any::AnyKind, migrate::Migrator, AnyPool};
+
+use super::command_migration_source::PostgresCommandMigrationSource;
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
@@ -15,14 +21,25 @@ pub enum Error {
     Migration(#[from] iko::MigrationsError),
     #[error("migrator {0}")]
     Migrator(#[from] iko::MigratorError),
+    #[error("postgres migrator {0}")]
+    PostgresMigrator(#[from] sqlx::migrate::MigrateError),
 }
 
 pub async fn migrate(pool: AnyPool) -> Result<()> {
+    // iko supports SQLite only. PostgreSQL starts with the latest schema.
+    if pool.any_kind() == AnyKind::Postgres {
+        let migrator = Migrator::new(PostgresCommandMigrationSource::default()).await?;
+        migrator.run(&pool).await?;
+        return Ok(());
+    }
+
     let iko_migrator = iko::Migrator::new(pool.clone());
     let mut iko_migrations = iko::Migrations::default();
     iko_migrations.push(1, migrate1)?;
     iko_migrations.push(2, migrate2)?;
     iko_migrations.push(3, migrate3)?;
+    iko_migrations.push(4, migrate4)?;
+    iko_migrations.push(5, migrate5)?;
     iko_migrator.migrate(&iko_migrations).await?;
     Ok(())
 }
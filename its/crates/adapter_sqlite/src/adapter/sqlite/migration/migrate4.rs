@@ -0,0 +1,70 @@
+use sqlx::AnyPool;
+
+pub async fn migrate4(
+    pool: AnyPool,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let mut transaction = pool.begin().await?;
+
+    sqlx::query(include_str!(
+        "../../../../sql/command/migrations/20261018000001_create_event_stream_snapshots.sql"
+    ))
+    .execute(&mut transaction)
+    .await?;
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use event_store::EventStreamId;
+    use sqlx::Row;
+
+    use crate::adapter::sqlite::migration::{migrate1, migrate2, migrate3};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = AnyPool::connect("sqlite::memory:").await?;
+        let iko_migrator = iko::Migrator::new(pool.clone());
+        let mut iko_migrations = iko::Migrations::default();
+        iko_migrations.push(1, migrate1)?;
+        iko_migrations.push(2, migrate2)?;
+        iko_migrations.push(3, migrate3)?;
+        iko_migrations.push(4, migrate4)?;
+        iko_migrator.migrate(&iko_migrations).await?;
+
+        let mut transaction = pool.begin().await?;
+        let event_stream_id = EventStreamId::generate();
+        sqlx::query("INSERT INTO event_streams(id, version) VALUES(?, ?)")
+            .bind(event_stream_id.to_string().as_str())
+            .bind(1_i64)
+            .execute(&mut transaction)
+            .await?;
+        sqlx::query(
+            "INSERT INTO event_stream_snapshots(event_stream_id, version, data) VALUES(?, ?, ?)",
+        )
+        .bind(event_stream_id.to_string().as_str())
+        .bind(1_i64)
+        .bind("data")
+        .execute(&mut transaction)
+        .await?;
+        transaction.commit().await?;
+
+        let mut transaction = pool.begin().await?;
+
+        let rows = sqlx::query("SELECT event_stream_id, version, data FROM event_stream_snapshots")
+            .fetch_all(&mut transaction)
+            .await?;
+        assert_eq!(
+            rows[0].get::<'_, String, _>("event_stream_id"),
+            event_stream_id.to_string()
+        );
+        assert_eq!(rows[0].get::<'_, i64, _>("version"), 1_i64);
+        assert_eq!(rows[0].get::<'_, String, _>("data"), "data");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use std::str::FromStr;
+
+use domain::{aggregate::issue_comment::Event, DomainEvent};
+use sqlx::{any::AnyArguments, query::Query, Any, AnyPool, Row};
+
+pub async fn migrate5(
+    pool: AnyPool,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let mut transaction = pool.begin().await?;
+
+    sqlx::query(include_str!(
+        "../../../../sql/command/migrations/20261018000002_alter_issue_comment_ids_add_issue_id.sql"
+    ))
+    .execute(&mut transaction)
+    .await?;
+
+    let rows = sqlx::query(include_str!(
+        "../../../../sql/command/migrations/20261018000003_select_issue_comment_created_events.sql"
+    ))
+    .fetch_all(&mut transaction)
+    .await?;
+
+    for row in rows {
+        let issue_comment_id = row.get::<'_, String, &str>("issue_comment_id");
+        let data = row.get::<'_, String, &str>("data");
+        let issue_id = match DomainEvent::from_str(data.as_str())?.issue_comment() {
+            Some(Event::Created(event)) => event.issue_id().to_string(),
+            _ => {
+                return Err(format!("issue_comment_created not found: {}", issue_comment_id).into())
+            }
+        };
+        let query: Query<Any, AnyArguments> = sqlx::query(include_str!(
+            "../../../../sql/command/migrations/20261018000004_update_issue_comment_ids_issue_id.sql"
+        ))
+        .bind(issue_id)
+        .bind(issue_comment_id);
+        query.execute(&mut transaction).await?;
+    }
+
+    sqlx::query(include_str!(
+        "../../../../sql/command/migrations/20261018000005_create_issue_comment_ids_issue_id_index.sql"
+    ))
+    .execute(&mut transaction)
+    .await?;
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        aggregate::issue_comment::{attribute::IssueCommentText, IssueCommentAggregate},
+        IssueCommentId,
+    };
+    use event_store::{EventId, EventStreamId};
+    use limited_date_time::Instant;
+
+    use crate::adapter::sqlite::migration::{migrate1, migrate2, migrate3, migrate4};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = AnyPool::connect("sqlite::memory:").await?;
+        let iko_migrator = iko::Migrator::new(pool.clone());
+        let mut iko_migrations = iko::Migrations::default();
+        iko_migrations.push(1, migrate1)?;
+        iko_migrations.push(2, migrate2)?;
+        iko_migrations.push(3, migrate3)?;
+        iko_migrations.push(4, migrate4)?;
+        iko_migrator.migrate(&iko_migrations).await?;
+
+        let mut transaction = pool.begin().await?;
+        let issue_comment_id = IssueCommentId::generate();
+        let issue_comment = IssueCommentAggregate::new(
+            Instant::now(),
+            issue_comment_id.clone(),
+            "123".parse()?,
+            IssueCommentText::from_str("text")?,
+        )?;
+        let event_stream_id = EventStreamId::generate();
+        sqlx::query("INSERT INTO event_streams(id, version) VALUES(?, ?)")
+            .bind(event_stream_id.to_string().as_str())
+            .bind(1_i64)
+            .execute(&mut transaction)
+            .await?;
+        sqlx::query("INSERT INTO events(id, event_stream_id, version, data) VALUES(?, ?, ?, ?)")
+            .bind(EventId::generate().to_string().as_str())
+            .bind(event_stream_id.to_string().as_str())
+            .bind(1_i64)
+            .bind(DomainEvent::from(issue_comment.events()[0].clone()).to_string())
+            .execute(&mut transaction)
+            .await?;
+        sqlx::query(
+            "INSERT INTO issue_comment_ids(issue_comment_id, event_stream_id) VALUES(?, ?)",
+        )
+        .bind(issue_comment_id.to_string().as_str())
+        .bind(event_stream_id.to_string().as_str())
+        .execute(&mut transaction)
+        .await?;
+        transaction.commit().await?;
+
+        let mut iko_migrations = iko::Migrations::default();
+        iko_migrations.push(1, migrate1)?;
+        iko_migrations.push(2, migrate2)?;
+        iko_migrations.push(3, migrate3)?;
+        iko_migrations.push(4, migrate4)?;
+        iko_migrations.push(5, migrate5)?;
+        iko_migrator.migrate(&iko_migrations).await?;
+
+        let mut transaction = pool.begin().await?;
+
+        let rows = sqlx::query("SELECT issue_comment_id, issue_id FROM issue_comment_ids")
+            .fetch_all(&mut transaction)
+            .await?;
+        assert_eq!(
+            rows[0].get::<'_, String, _>("issue_comment_id"),
+            issue_comment_id.to_string()
+        );
+        assert_eq!(rows[0].get::<'_, String, _>("issue_id"), "123");
+
+        Ok(())
+    }
+}
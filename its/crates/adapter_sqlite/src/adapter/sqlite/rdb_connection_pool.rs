@@ -1,5 +1,7 @@
 use std::str::FromStr;
 
+use domain::{DomainEvent, IssueId, ParseDomainEventError};
+use event_store::{Event, EventId, EventStreamId, EventStreamSeq};
 use sqlx::{any::AnyConnectOptions, AnyPool};
 use use_case::IssueBlockLinkRepositoryError;
 
@@ -11,6 +13,14 @@ type Result<T, E = Error> = std::result::Result<T, E>;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+    #[error("event store error: {0}")]
+    EventStore(#[from] event_store::Error),
+    #[error("import event error: {0}: {1}")]
+    ImportEvent(EventId, Box<Error>),
+    #[error("invalid domain event: {0}")]
+    InvalidDomainEvent(#[from] ParseDomainEventError),
+    #[error("invalid event stream version")]
+    InvalidEventStreamVersion,
     #[error("issue repository error: {0}")]
     IssueRepository(#[from] super::sqlite_issue_repository::Error),
     #[error("issue block link repository error: {0}")]
@@ -42,6 +52,105 @@ impl RdbConnectionPool {
         Ok(Self(pool))
     }
 
+    pub async fn export_events(&self) -> Result<Vec<Event>> {
+        let mut transaction = self.0.begin().await?;
+        let events = event_store::find_events(&mut transaction).await?;
+        transaction.commit().await?;
+        Ok(events)
+    }
+
+    // re-inserts the events in one transaction. each event must follow the current version of its stream.
+    pub async fn import_events(&self, events: Vec<Event>) -> Result<()> {
+        let issue_repository = self.issue_repository()?;
+        let issue_block_link_repository = self.issue_block_link_repository()?;
+        let issue_comment_repository = self.issue_comment_repository()?;
+        let mut transaction = self.0.begin().await?;
+        for event in events {
+            let event_id = event.id;
+            let import_event = async {
+                let current_version = match i64::from(event.stream_seq) {
+                    1 => None,
+                    n if n > 1 => Some(EventStreamSeq::try_from(n - 1)?),
+                    _ => return Err(Error::InvalidEventStreamVersion),
+                };
+                let stream_id = event.stream_id;
+                let domain_event = DomainEvent::from_str(event.data.as_str())?;
+                event_store::save(&mut transaction, current_version, event).await?;
+                if current_version.is_none() {
+                    match domain_event {
+                        DomainEvent::Issue(event) => {
+                            issue_repository
+                                .insert_issue_id(&mut transaction, event.issue_id(), stream_id)
+                                .await?
+                        }
+                        DomainEvent::IssueBlockLink(event) => {
+                            issue_block_link_repository
+                                .insert_issue_block_link_id(
+                                    &mut transaction,
+                                    event.key().0,
+                                    stream_id,
+                                )
+                                .await?
+                        }
+                        DomainEvent::IssueComment(event) => {
+                            issue_comment_repository
+                                .insert_aggregate_id(&mut transaction, &event, stream_id)
+                                .await?
+                        }
+                    }
+                }
+                Ok(())
+            };
+            import_event
+                .await
+                .map_err(|e| Error::ImportEvent(event_id, Box::new(e)))?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    // the event streams to replay the issue view: the issue, its block links, the issues on the other side of them and its comments (empty if the issue does not exist)
+    pub async fn find_issue_view_event_stream_ids(
+        &self,
+        issue_id: &IssueId,
+    ) -> Result<Vec<EventStreamId>> {
+        let issue_repository = self.issue_repository()?;
+        let issue_block_link_repository = self.issue_block_link_repository()?;
+        let issue_comment_repository = self.issue_comment_repository()?;
+        let mut transaction = self.0.begin().await?;
+        let mut event_stream_ids = match issue_repository
+            .find_event_stream_id_by_issue_id(&mut transaction, issue_id)
+            .await?
+        {
+            Some(event_stream_id) => vec![event_stream_id],
+            None => return Ok(vec![]),
+        };
+        for (issue_block_link_id, event_stream_id) in issue_block_link_repository
+            .find_event_stream_ids_by_issue_id(&mut transaction, issue_id)
+            .await?
+        {
+            event_stream_ids.push(event_stream_id);
+            let other_issue_id = if issue_block_link_id.issue_id() == issue_id {
+                issue_block_link_id.blocked_issue_id()
+            } else {
+                issue_block_link_id.issue_id()
+            };
+            if let Some(event_stream_id) = issue_repository
+                .find_event_stream_id_by_issue_id(&mut transaction, other_issue_id)
+                .await?
+            {
+                event_stream_ids.push(event_stream_id);
+            }
+        }
+        event_stream_ids.extend(
+            issue_comment_repository
+                .find_event_stream_ids_by_issue_id(&mut transaction, issue_id)
+                .await?,
+        );
+        transaction.commit().await?;
+        Ok(event_stream_ids)
+    }
+
     pub fn issue_block_link_repository(&self) -> Result<SqliteIssueBlockLinkRepository> {
         Ok(SqliteIssueBlockLinkRepository::new(self.clone())?)
     }
@@ -54,3 +163,61 @@ impl RdbConnectionPool {
         Ok(SqliteIssueRepository::new(self.clone())?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use domain::aggregate::IssueAggregate;
+    use limited_date_time::Instant;
+    use use_case::IssueRepository;
+
+    use super::*;
+    use crate::adapter::sqlite::test_connection_uris;
+
+    #[tokio::test]
+    async fn export_and_import_test() -> anyhow::Result<()> {
+        let connection_uris = test_connection_uris()
+            .await?
+            .into_iter()
+            .zip(test_connection_uris().await?)
+            .zip(test_connection_uris().await?);
+        for ((connection_uri, imported_connection_uri), fresh_connection_uri) in connection_uris {
+            let connection_pool = RdbConnectionPool::new(&connection_uri).await?;
+            let issue_repository = connection_pool.issue_repository()?;
+            let created = IssueAggregate::new(
+                Instant::now(),
+                "1".parse()?,
+                "title1".parse()?,
+                None,
+                "".parse()?,
+            )?;
+            issue_repository.save(&created).await?;
+            let updated = created
+                .truncate_events()
+                .update_title("title2".parse()?, Instant::now())?;
+            issue_repository.save(&updated).await?;
+
+            let events = connection_pool.export_events().await?;
+            assert_eq!(events.len(), 2);
+
+            let imported_pool = RdbConnectionPool::new(&imported_connection_uri).await?;
+            imported_pool.import_events(events.clone()).await?;
+            assert_eq!(imported_pool.export_events().await?, events);
+            let found = imported_pool
+                .issue_repository()?
+                .find_by_id(updated.id())
+                .await?;
+            assert_eq!(found, Some(updated.truncate_events()));
+
+            // conflicts with the existing event streams
+            assert!(imported_pool.import_events(events.clone()).await.is_err());
+            // skips a version
+            let fresh_pool = RdbConnectionPool::new(&fresh_connection_uri).await?;
+            assert!(fresh_pool
+                .import_events(events[1..].to_vec())
+                .await
+                .is_err());
+            assert!(fresh_pool.export_events().await?.is_empty());
+        }
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@ mod issue_block_link_id_row;
 use std::str::FromStr;
 
 use async_trait::async_trait;
-use domain::{aggregate::IssueBlockLinkAggregate, DomainEvent, IssueBlockLinkId, Version};
+use domain::{aggregate::IssueBlockLinkAggregate, DomainEvent, IssueBlockLinkId, IssueId, Version};
 
 use event_store::{Event, EventId, EventStreamId, EventStreamSeq};
 use sqlx::{any::AnyArguments, query::Query, Any, AnyPool, Transaction};
@@ -42,7 +42,31 @@ impl SqliteIssueBlockLinkRepository {
         Ok(issue_block_link_id_row.map(|row| row.event_stream_id()))
     }
 
-    async fn insert_issue_block_link_id(
+    // the block links from or to the issue
+    pub(super) async fn find_event_stream_ids_by_issue_id(
+        &self,
+        transaction: &mut Transaction<'_, Any>,
+        issue_id: &IssueId,
+    ) -> Result<Vec<(IssueBlockLinkId, EventStreamId)>, IssueBlockLinkRepositoryError> {
+        let issue_block_link_id_rows: Vec<IssueBlockLinkIdRow> = sqlx::query_as(include_str!(
+            "../../../sql/command/select_issue_block_link_ids_by_issue_id.sql"
+        ))
+        .bind(format!("{} -> %", issue_id))
+        .bind(format!("% -> {}", issue_id))
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|e| IssueBlockLinkRepositoryError::Unknown(e.to_string()))?;
+        Ok(issue_block_link_id_rows
+            .into_iter()
+            .map(|row| (row.issue_block_link_id(), row.event_stream_id()))
+            .filter(|(issue_block_link_id, _)| {
+                issue_block_link_id.issue_id() == issue_id
+                    || issue_block_link_id.blocked_issue_id() == issue_id
+            })
+            .collect())
+    }
+
+    pub(super) async fn insert_issue_block_link_id(
         &self,
         transaction: &mut Transaction<'_, Any>,
         issue_block_link_id: &IssueBlockLinkId,
@@ -135,7 +159,12 @@ impl IssueBlockLinkRepository for SqliteIssueBlockLinkRepository {
                     },
                 )
                 .await
-                .map_err(|e| IssueBlockLinkRepositoryError::Unknown(e.to_string()))?;
+                .map_err(|e| match e {
+                    event_store::Error::UpdateEventStream => {
+                        IssueBlockLinkRepositoryError::Conflict
+                    }
+                    e => IssueBlockLinkRepositoryError::Unknown(e.to_string()),
+                })?;
             } else {
                 // create
                 let event_stream_id = EventStreamId::generate();
@@ -179,46 +208,34 @@ fn event_stream_version_from(
 
 #[cfg(test)]
 mod tests {
-    use std::fs;
-
     use anyhow::Context;
     use limited_date_time::Instant;
-    use tempfile::tempdir;
 
     use super::*;
+    use crate::adapter::sqlite::test_connection_uris;
 
     #[tokio::test]
     async fn test() -> anyhow::Result<()> {
-        let temp_dir = tempdir()?;
-
-        let sqlite_dir = temp_dir.path().join("its");
-        let data_dir = sqlite_dir;
-        if !data_dir.exists() {
-            fs::create_dir_all(data_dir.as_path())?;
+        for connection_uri in test_connection_uris().await? {
+            let connection_pool = RdbConnectionPool::new(connection_uri.as_str()).await?;
+            let repository = connection_pool.issue_block_link_repository()?;
+
+            // save (create)
+            let created =
+                IssueBlockLinkAggregate::new(Instant::now(), "123".parse()?, "456".parse()?)?;
+            repository.save(&created).await?;
+
+            // find_by_id
+            let found = repository.find_by_id(created.id()).await?;
+            assert_eq!(Some(created.truncate_events()), found);
+            let found = found.context("found is None")?;
+
+            // save (update)
+            let updated = found.unblock(Instant::now())?;
+            repository.save(&updated).await?;
+            let found = repository.find_by_id(updated.id()).await?;
+            assert_eq!(Some(updated.truncate_events()), found);
         }
-        let path = data_dir.join("command.sqlite");
-        let connection_uri = format!(
-            "sqlite:{}?mode=rwc",
-            path.to_str().context("path is not utf-8")?
-        );
-        let connection_pool = RdbConnectionPool::new(connection_uri.as_str()).await?;
-        let repository = connection_pool.issue_block_link_repository()?;
-
-        // save (create)
-        let created = IssueBlockLinkAggregate::new(Instant::now(), "123".parse()?, "456".parse()?)?;
-        repository.save(&created).await?;
-
-        // find_by_id
-        let found = repository.find_by_id(created.id()).await?;
-        assert_eq!(Some(created.truncate_events()), found);
-        let found = found.context("found is None")?;
-
-        // save (update)
-        let updated = found.unblock(Instant::now())?;
-        repository.save(&updated).await?;
-        let found = repository.find_by_id(updated.id()).await?;
-        assert_eq!(Some(updated.truncate_events()), found);
-
         Ok(())
     }
 }
@@ -4,7 +4,7 @@ use std::{num::TryFromIntError, str::FromStr};
 
 use async_trait::async_trait;
 use domain::{
-    aggregate::issue_comment::IssueCommentAggregate, DomainEvent, IssueCommentId,
+    aggregate::issue_comment::IssueCommentAggregate, DomainEvent, IssueCommentId, IssueId,
     ParseDomainEventError, Version,
 };
 
@@ -47,6 +47,9 @@ impl From<Error> for use_case::issue_comment_repository::Error {
     fn from(e: Error) -> Self {
         use use_case::issue_comment_repository::Error as E;
         match e {
+            Error::EventStore(
+                event_store::Error::InsertEventStream | event_store::Error::UpdateEventStream,
+            ) => E::Conflict,
             Error::EventStore(e) => E::Unknown(e.to_string()),
             Error::InvalidDomainEvent(e) => E::Unknown(e.to_string()),
             Error::InvalidIssueId(e) => E::Unknown(e.to_string()),
@@ -143,17 +146,42 @@ impl SqliteIssueCommentRepository {
         Ok(issue_comment_id_row.map(|row| row.event_stream_id()))
     }
 
-    async fn insert_aggregate_id(
+    pub(super) async fn find_event_stream_ids_by_issue_id(
         &self,
         transaction: &mut Transaction<'_, Any>,
-        aggregate_id: &AggregateId,
+        issue_id: &IssueId,
+    ) -> Result<Vec<EventStreamId>> {
+        let issue_comment_id_rows: Vec<IssueCommentIdRow> = sqlx::query_as(include_str!(
+            "../../../sql/command/select_issue_comment_ids_by_issue_id.sql"
+        ))
+        .bind(issue_id.to_string())
+        .fetch_all(&mut *transaction)
+        .await?;
+        Ok(issue_comment_id_rows
+            .into_iter()
+            .map(|row| row.event_stream_id())
+            .collect())
+    }
+
+    // `event` is the first event (created) of the stream
+    pub(super) async fn insert_aggregate_id(
+        &self,
+        transaction: &mut Transaction<'_, Any>,
+        event: &AggregateEvent,
         event_stream_id: EventStreamId,
     ) -> Result<()> {
+        let issue_id = match event {
+            AggregateEvent::Created(event) => event.issue_id(),
+            AggregateEvent::Deleted(_) | AggregateEvent::Updated(_) => {
+                return Err(Error::UnknownAggregateEvent)
+            }
+        };
         let query: Query<Any, AnyArguments> = sqlx::query(include_str!(
             "../../../sql/command/insert_issue_comment_id.sql"
         ))
-        .bind(aggregate_id.to_string())
-        .bind(event_stream_id.to_string());
+        .bind(event.issue_comment_id().to_string())
+        .bind(event_stream_id.to_string())
+        .bind(issue_id.to_string());
         let rows_affected = query.execute(transaction).await?.rows_affected();
         if rows_affected != 1 {
             return Err(Error::RowsAffectedNotEqualOne);
@@ -196,12 +224,12 @@ impl SqliteIssueCommentRepository {
                     Event {
                         id: EventId::generate(),
                         stream_id: event_stream_id,
-                        data: DomainEvent::from(event).to_string(),
+                        data: DomainEvent::from(event.clone()).to_string(),
                         stream_seq: Self::version_to_event_stream_version(version)?,
                     },
                 )
                 .await?;
-                self.insert_aggregate_id(&mut transaction, &aggregate_id, event_stream_id)
+                self.insert_aggregate_id(&mut transaction, &event, event_stream_id)
                     .await?;
             }
         }
@@ -248,37 +276,39 @@ mod tests {
     use limited_date_time::Instant;
 
     use super::*;
+    use crate::adapter::sqlite::test_connection_uris;
 
     #[tokio::test]
     async fn test() -> anyhow::Result<()> {
-        let connection_pool = RdbConnectionPool::new("sqlite::memory:").await?;
-        let issue_comment_repository = connection_pool.issue_comment_repository()?;
-
-        // create
-        let created = Aggregate::new(
-            Instant::now(),
-            IssueCommentId::generate(),
-            "123".parse()?,
-            "text".parse()?,
-        )?;
-        issue_comment_repository.save(&created).await?;
-        let found = issue_comment_repository.find_by_id(created.id()).await?;
-        assert_eq!(Some(created.truncate_events()), found);
-        let found = found.ok_or_else(|| anyhow::anyhow!("found is not Some"))?;
+        for connection_uri in test_connection_uris().await? {
+            let connection_pool = RdbConnectionPool::new(&connection_uri).await?;
+            let issue_comment_repository = connection_pool.issue_comment_repository()?;
 
-        // update
-        let updated = found.update("text".parse()?, Instant::now())?;
-        issue_comment_repository.save(&updated).await?;
-        let found = issue_comment_repository.find_by_id(updated.id()).await?;
-        assert_eq!(Some(updated.truncate_events()), found);
-        let found = found.ok_or_else(|| anyhow::anyhow!("found is not Some"))?;
+            // create
+            let created = Aggregate::new(
+                Instant::now(),
+                IssueCommentId::generate(),
+                "123".parse()?,
+                "text".parse()?,
+            )?;
+            issue_comment_repository.save(&created).await?;
+            let found = issue_comment_repository.find_by_id(created.id()).await?;
+            assert_eq!(Some(created.truncate_events()), found);
+            let found = found.ok_or_else(|| anyhow::anyhow!("found is not Some"))?;
 
-        // delete
-        let deleted = found.delete(Instant::now())?;
-        issue_comment_repository.save(&deleted).await?;
-        let found = issue_comment_repository.find_by_id(deleted.id()).await?;
-        assert_eq!(None, found);
+            // update
+            let updated = found.update("text".parse()?, Instant::now())?;
+            issue_comment_repository.save(&updated).await?;
+            let found = issue_comment_repository.find_by_id(updated.id()).await?;
+            assert_eq!(Some(updated.truncate_events()), found);
+            let found = found.ok_or_else(|| anyhow::anyhow!("found is not Some"))?;
 
+            // delete
+            let deleted = found.delete(Instant::now())?;
+            issue_comment_repository.save(&deleted).await?;
+            let found = issue_comment_repository.find_by_id(deleted.id()).await?;
+            assert_eq!(None, found);
+        }
         Ok(())
     }
 }
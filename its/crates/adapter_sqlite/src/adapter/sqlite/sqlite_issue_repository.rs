@@ -4,11 +4,14 @@ use std::{num::TryFromIntError, str::FromStr};
 
 use async_trait::async_trait;
 use domain::{
-    aggregate::{IssueAggregate, IssueAggregateEvent},
+    aggregate::{
+        issue::{IssueAggregateSnapshot, ParseIssueAggregateSnapshotError},
+        IssueAggregate, IssueAggregateEvent,
+    },
     DomainEvent, IssueId, ParseDomainEventError, Version,
 };
 
-use event_store::{Event, EventId, EventStreamId, EventStreamSeq};
+use event_store::{Event, EventId, EventStreamId, EventStreamSeq, Snapshot};
 use sqlx::{any::AnyArguments, query::Query, Any, AnyPool, Transaction};
 use use_case::IssueRepository;
 
@@ -24,6 +27,8 @@ pub enum Error {
     InvalidDomainEvent(#[from] ParseDomainEventError),
     #[error("InvalidIssueId")]
     InvalidIssueId(TryFromIntError),
+    #[error("InvalidSnapshot")]
+    InvalidSnapshot(#[from] ParseIssueAggregateSnapshotError),
     #[error("InvalidVersion")]
     InvalidVersion(TryFromIntError),
     #[error("IssueAggregate")]
@@ -42,9 +47,13 @@ impl From<Error> for use_case::issue_repository::Error {
     fn from(e: Error) -> Self {
         use use_case::issue_repository::Error as E;
         match e {
+            Error::EventStore(
+                event_store::Error::InsertEventStream | event_store::Error::UpdateEventStream,
+            ) => E::Conflict,
             Error::EventStore(e) => E::Unknown(e.to_string()),
             Error::InvalidDomainEvent(e) => E::Unknown(e.to_string()),
             Error::InvalidIssueId(e) => E::Unknown(e.to_string()),
+            Error::InvalidSnapshot(e) => E::Unknown(e.to_string()),
             Error::InvalidVersion(e) => E::Unknown(e.to_string()),
             Error::IssueAggregate(e) => E::Unknown(e.to_string()),
             Error::RowsAffectedNotEqualOne => E::Unknown(e.to_string()),
@@ -57,15 +66,27 @@ impl From<Error> for use_case::issue_repository::Error {
 #[derive(Debug)]
 pub struct SqliteIssueRepository {
     pool: AnyPool,
+    snapshot_interval: u64,
 }
 
 impl SqliteIssueRepository {
+    pub const DEFAULT_SNAPSHOT_INTERVAL: u64 = 100;
+
     pub(super) fn new(connection_pool: RdbConnectionPool) -> Result<Self> {
         Ok(Self {
             pool: AnyPool::from(connection_pool),
+            snapshot_interval: Self::DEFAULT_SNAPSHOT_INTERVAL,
         })
     }
 
+    // saves a snapshot every `snapshot_interval` versions (0: never)
+    pub fn with_snapshot_interval(self, snapshot_interval: u64) -> Self {
+        Self {
+            pool: self.pool,
+            snapshot_interval,
+        }
+    }
+
     fn events_to_issue_aggregate_events(events: Vec<Event>) -> Result<Vec<IssueAggregateEvent>> {
         let mut aggregate_events = vec![];
         for event in events {
@@ -85,11 +106,37 @@ impl SqliteIssueRepository {
         {
             None => None,
             Some(event_stream_id) => {
-                let events =
-                    event_store::find_events_by_event_stream_id(&mut transaction, event_stream_id)
+                match event_store::find_snapshot_by_event_stream_id(
+                    &mut transaction,
+                    event_stream_id,
+                )
+                .await?
+                {
+                    Some(snapshot) => {
+                        let events =
+                            event_store::find_events_by_event_stream_id_and_version_greater_than(
+                                &mut transaction,
+                                event_stream_id,
+                                snapshot.stream_seq,
+                            )
+                            .await?;
+                        let issue_aggregate_events =
+                            Self::events_to_issue_aggregate_events(events)?;
+                        let snapshot = IssueAggregateSnapshot::from_str(snapshot.data.as_str())?;
+                        IssueAggregate::from_snapshot_and_events(snapshot, &issue_aggregate_events)
+                            .map(Some)?
+                    }
+                    None => {
+                        let events = event_store::find_events_by_event_stream_id(
+                            &mut transaction,
+                            event_stream_id,
+                        )
                         .await?;
-                let issue_aggregate_events = Self::events_to_issue_aggregate_events(events)?;
-                IssueAggregate::from_events(&issue_aggregate_events).map(Some)?
+                        let issue_aggregate_events =
+                            Self::events_to_issue_aggregate_events(events)?;
+                        IssueAggregate::from_events(&issue_aggregate_events).map(Some)?
+                    }
+                }
             }
         };
         Ok(found)
@@ -126,7 +173,7 @@ impl SqliteIssueRepository {
         }
     }
 
-    async fn find_event_stream_id_by_issue_id(
+    pub(super) async fn find_event_stream_id_by_issue_id(
         &self,
         transaction: &mut Transaction<'_, Any>,
         issue_id: &IssueId,
@@ -151,7 +198,7 @@ impl SqliteIssueRepository {
         Ok(issue_id_row.map(|row| row.issue_id()))
     }
 
-    async fn insert_issue_id(
+    pub(super) async fn insert_issue_id(
         &self,
         transaction: &mut Transaction<'_, Any>,
         issue_id: &IssueId,
@@ -182,17 +229,33 @@ impl SqliteIssueRepository {
     }
 
     async fn save(&self, issue: &IssueAggregate) -> Result<()> {
+        self.save_all(&[issue]).await
+    }
+
+    async fn save_all(&self, issues: &[&IssueAggregate]) -> Result<()> {
         let mut transaction = self.pool.begin().await?;
+        for issue in issues {
+            self.save_in_transaction(&mut transaction, issue).await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn save_in_transaction(
+        &self,
+        transaction: &mut Transaction<'_, Any>,
+        issue: &IssueAggregate,
+    ) -> Result<()> {
         for event in issue.events().iter().cloned() {
             let issue_id = event.issue_id().clone();
             if let Some(event_stream_id) = self
-                .find_event_stream_id_by_issue_id(&mut transaction, &issue_id)
+                .find_event_stream_id_by_issue_id(&mut *transaction, &issue_id)
                 .await?
             {
                 // update
                 let version = event.version();
                 event_store::save(
-                    &mut transaction,
+                    &mut *transaction,
                     version
                         .prev()
                         .map(Self::version_to_event_stream_version)
@@ -210,7 +273,7 @@ impl SqliteIssueRepository {
                 let event_stream_id = EventStreamId::generate();
                 let version = event.version();
                 event_store::save(
-                    &mut transaction,
+                    &mut *transaction,
                     None,
                     Event {
                         id: EventId::generate(),
@@ -220,12 +283,33 @@ impl SqliteIssueRepository {
                     },
                 )
                 .await?;
-                self.insert_issue_id(&mut transaction, &issue_id, event_stream_id)
+                self.insert_issue_id(&mut *transaction, &issue_id, event_stream_id)
                     .await?;
             }
         }
 
-        transaction.commit().await?;
+        if self.snapshot_interval > 0
+            && issue
+                .events()
+                .iter()
+                .any(|event| u64::from(event.version()) % self.snapshot_interval == 0)
+        {
+            if let Some(event_stream_id) = self
+                .find_event_stream_id_by_issue_id(&mut *transaction, issue.id())
+                .await?
+            {
+                event_store::save_snapshot(
+                    &mut *transaction,
+                    Snapshot {
+                        stream_id: event_stream_id,
+                        stream_seq: Self::version_to_event_stream_version(issue.version())?,
+                        data: issue.snapshot().to_string(),
+                    },
+                )
+                .await?;
+            }
+        }
+
         Ok(())
     }
 
@@ -260,6 +344,10 @@ impl IssueRepository for SqliteIssueRepository {
     async fn save(&self, issue: &IssueAggregate) -> use_case::issue_repository::Result<()> {
         Ok(Self::save(self, issue).await?)
     }
+
+    async fn save_all(&self, issues: &[&IssueAggregate]) -> use_case::issue_repository::Result<()> {
+        Ok(Self::save_all(self, issues).await?)
+    }
 }
 
 #[cfg(test)]
@@ -268,39 +356,188 @@ mod tests {
     use limited_date_time::Instant;
 
     use super::*;
+    use crate::adapter::sqlite::test_connection_uris;
 
     #[tokio::test]
     async fn test() -> anyhow::Result<()> {
-        let connection_pool = RdbConnectionPool::new("sqlite::memory:").await?;
-        let issue_repository = connection_pool.issue_repository()?;
-
-        // create
-        let created = IssueAggregate::new(
-            Instant::now(),
-            "123".parse()?,
-            "title".parse()?,
-            Some("2021-02-03T04:05:06Z".parse()?),
-            "desc1".parse()?,
-        )?;
-        issue_repository.save(&created).await?;
-
-        // last_created
-        let last_created = issue_repository.last_created().await?;
-        assert_eq!(Some(created.clone().truncate_events()), last_created);
-
-        // find_by_id
-        let found = issue_repository.find_by_id(created.id()).await?;
-        assert_eq!(Some(created.truncate_events()), found);
-        let found = found.ok_or_else(|| anyhow::anyhow!("found is not Some"))?;
-
-        // update
-        let resolution = IssueResolution::from_str("Duplicate")?;
-        let updated = found.finish(Some(resolution), Instant::now())?;
-        issue_repository.save(&updated).await?;
-
-        let found = issue_repository.find_by_id(updated.id()).await?;
-        assert_eq!(Some(updated.truncate_events()), found);
+        for connection_uri in test_connection_uris().await? {
+            let connection_pool = RdbConnectionPool::new(&connection_uri).await?;
+            let issue_repository = connection_pool.issue_repository()?;
+
+            // create
+            let created = IssueAggregate::new(
+                Instant::now(),
+                "123".parse()?,
+                "title".parse()?,
+                Some("2021-02-03T04:05:06Z".parse()?),
+                "desc1".parse()?,
+            )?;
+            issue_repository.save(&created).await?;
+
+            // last_created
+            let last_created = issue_repository.last_created().await?;
+            assert_eq!(Some(created.clone().truncate_events()), last_created);
+
+            // find_by_id
+            let found = issue_repository.find_by_id(created.id()).await?;
+            assert_eq!(Some(created.truncate_events()), found);
+            let found = found.ok_or_else(|| anyhow::anyhow!("found is not Some"))?;
+
+            // update
+            let resolution = IssueResolution::from_str("Duplicate")?;
+            let updated = found.finish(Some(resolution), Instant::now())?;
+            issue_repository.save(&updated).await?;
+
+            let found = issue_repository.find_by_id(updated.id()).await?;
+            assert_eq!(Some(updated.truncate_events()), found);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn conflict_test() -> anyhow::Result<()> {
+        for connection_uri in test_connection_uris().await? {
+            let connection_pool = RdbConnectionPool::new(&connection_uri).await?;
+            let issue_repository = connection_pool.issue_repository()?;
+            let created = IssueAggregate::new(
+                Instant::now(),
+                "123".parse()?,
+                "title".parse()?,
+                None,
+                "desc1".parse()?,
+            )?;
+            issue_repository.save(&created).await?;
+            let found = issue_repository
+                .find_by_id(created.id())
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("found is not Some"))?;
+
+            let updated1 = found.update_title("title1".parse()?, Instant::now())?;
+            let updated2 = found.update_title("title2".parse()?, Instant::now())?;
+            IssueRepository::save(&issue_repository, &updated1).await?;
+            assert_eq!(
+                IssueRepository::save(&issue_repository, &updated2).await,
+                Err(use_case::issue_repository::Error::Conflict)
+            );
+
+            let found = issue_repository.find_by_id(created.id()).await?;
+            assert_eq!(Some(updated1.truncate_events()), found);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn save_all_test() -> anyhow::Result<()> {
+        for connection_uri in test_connection_uris().await? {
+            let connection_pool = RdbConnectionPool::new(&connection_uri).await?;
+            let issue_repository = connection_pool.issue_repository()?;
+            let created = IssueAggregate::new(
+                Instant::now(),
+                "123".parse()?,
+                "title".parse()?,
+                None,
+                "desc1".parse()?,
+            )?;
+            issue_repository.save(&created).await?;
+
+            let updated = created
+                .clone()
+                .truncate_events()
+                .update_title("title1".parse()?, Instant::now())?;
+            let created2 = IssueAggregate::new(
+                Instant::now(),
+                "124".parse()?,
+                "title".parse()?,
+                None,
+                "desc2".parse()?,
+            )?;
+            // `created` conflicts with the saved one. nothing is saved
+            assert_eq!(
+                IssueRepository::save_all(&issue_repository, &[&updated, &created2, &created])
+                    .await,
+                Err(use_case::issue_repository::Error::Conflict)
+            );
+            assert_eq!(
+                Some(created.clone().truncate_events()),
+                issue_repository.find_by_id(created.id()).await?
+            );
+            assert_eq!(None, issue_repository.find_by_id(created2.id()).await?);
+
+            IssueRepository::save_all(&issue_repository, &[&updated, &created2]).await?;
+            assert_eq!(
+                Some(updated.clone().truncate_events()),
+                issue_repository.find_by_id(created.id()).await?
+            );
+            assert_eq!(
+                Some(created2.clone().truncate_events()),
+                issue_repository.find_by_id(created2.id()).await?
+            );
+        }
+        Ok(())
+    }
 
+    #[tokio::test]
+    async fn snapshot_test() -> anyhow::Result<()> {
+        for connection_uri in test_connection_uris().await? {
+            let connection_pool = RdbConnectionPool::new(&connection_uri).await?;
+            let issue_repository = connection_pool
+                .issue_repository()?
+                .with_snapshot_interval(3);
+            let replaying_repository = connection_pool
+                .issue_repository()?
+                .with_snapshot_interval(0);
+
+            let created = IssueAggregate::new(
+                Instant::now(),
+                "123".parse()?,
+                "title".parse()?,
+                None,
+                "desc1".parse()?,
+            )?;
+            issue_repository.save(&created).await?;
+            let mut issue = created.truncate_events();
+            for i in 2..=10 {
+                let updated = issue.update_title(format!("title{}", i).parse()?, Instant::now())?;
+                let updated = if i == 5 {
+                    updated.add_label("bug".parse()?, Instant::now())?
+                } else {
+                    updated
+                };
+                issue_repository.save(&updated).await?;
+                issue = updated.truncate_events();
+
+                // snapshot + subsequent events == full replay
+                let found = issue_repository.find_by_id(issue.id()).await?;
+                let replayed = replaying_repository.find_by_id(issue.id()).await?;
+                assert_eq!(found, replayed);
+                assert_eq!(found, Some(issue.clone()));
+            }
+
+            let mut transaction = issue_repository.pool.begin().await?;
+            let event_stream_id = issue_repository
+                .find_event_stream_id_by_issue_id(&mut transaction, issue.id())
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("event stream not found"))?;
+            let snapshot =
+                event_store::find_snapshot_by_event_stream_id(&mut transaction, event_stream_id)
+                    .await?
+                    .ok_or_else(|| anyhow::anyhow!("snapshot not found"))?;
+            // versions: 1, 2, 3, 4, 5 + 6 (label), 7, ..., 11
+            assert_eq!(snapshot.stream_seq, EventStreamSeq::from(9_u32));
+            assert_eq!(
+                IssueAggregateSnapshot::from_str(snapshot.data.as_str())?.version(),
+                Version::from(9_u64)
+            );
+
+            // find_by_id_and_version does not depend on snapshots
+            let found = issue_repository
+                .find_by_id_and_version(issue.id(), &Version::from(4_u64))
+                .await?;
+            assert_eq!(
+                found.map(|issue| issue.title().to_string()),
+                Some("title4".to_string())
+            );
+        }
         Ok(())
     }
 }
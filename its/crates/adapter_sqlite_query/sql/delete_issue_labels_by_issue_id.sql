@@ -0,0 +1,2 @@
+DELETE FROM issue_labels
+WHERE issue_id = $1
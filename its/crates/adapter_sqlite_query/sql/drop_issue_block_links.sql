@@ -1 +1 @@
-DROP TABLE IF NOT EXISTS issue_block_links;
+DROP TABLE IF EXISTS issue_block_links;
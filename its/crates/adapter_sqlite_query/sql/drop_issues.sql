@@ -1 +1 @@
-DROP TABLE IF NOT EXISTS issues;
+DROP TABLE IF EXISTS issues;
@@ -1 +0,0 @@
-DROP TABLE IF NOT EXISTS last_event_id;
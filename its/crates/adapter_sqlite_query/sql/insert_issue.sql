@@ -1,2 +1,2 @@
-INSERT INTO issues (id, resolution, status, title, due, description)
-VALUES ($1, $2, $3, $4, $5, $6);
+INSERT INTO issues (id, resolution, status, title, due, description, priority, assignee, recurrence, next_occurrence)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10);
@@ -0,0 +1,2 @@
+INSERT INTO issue_labels (issue_id, label)
+VALUES ($1, $2);
@@ -1,2 +0,0 @@
-INSERT INTO last_event_id (event_id)
-VALUES ($1);
@@ -0,0 +1,2 @@
+INSERT INTO projection_checkpoints (name, event_id)
+VALUES ($1, $2);
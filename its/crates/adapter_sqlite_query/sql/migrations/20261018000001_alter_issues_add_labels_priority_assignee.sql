@@ -0,0 +1,11 @@
+ALTER TABLE issues
+ADD COLUMN priority TEXT;
+--
+ALTER TABLE issues
+ADD COLUMN assignee TEXT;
+--
+CREATE TABLE IF NOT EXISTS issue_labels (
+  issue_id TEXT NOT NULL,
+  label TEXT NOT NULL,
+  CONSTRAINT issue_labels_pk PRIMARY KEY (issue_id, label)
+);
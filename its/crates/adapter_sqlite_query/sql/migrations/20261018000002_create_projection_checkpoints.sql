@@ -0,0 +1,22 @@
+CREATE TABLE projection_checkpoints (
+  name TEXT NOT NULL,
+  event_id CHAR(26) NOT NULL,
+  CONSTRAINT projection_checkpoints_pk PRIMARY KEY (name)
+);
+--
+INSERT INTO projection_checkpoints (name, event_id)
+SELECT 'issue_block_links',
+  event_id
+FROM last_event_id;
+--
+INSERT INTO projection_checkpoints (name, event_id)
+SELECT 'issue_comments',
+  event_id
+FROM last_event_id;
+--
+INSERT INTO projection_checkpoints (name, event_id)
+SELECT 'issues',
+  event_id
+FROM last_event_id;
+--
+DROP TABLE last_event_id;
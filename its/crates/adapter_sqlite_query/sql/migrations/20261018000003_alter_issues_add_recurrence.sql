@@ -0,0 +1,5 @@
+ALTER TABLE issues
+ADD COLUMN recurrence TEXT;
+--
+ALTER TABLE issues
+ADD COLUMN next_occurrence TEXT;
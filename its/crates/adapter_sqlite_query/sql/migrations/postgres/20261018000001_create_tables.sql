@@ -0,0 +1,42 @@
+-- PostgreSQL: the same schema as the SQLite migrations produce
+CREATE TABLE IF NOT EXISTS issues (
+  id TEXT NOT NULL,
+  resolution TEXT,
+  status TEXT NOT NULL,
+  title TEXT NOT NULL,
+  due TEXT,
+  description TEXT NOT NULL,
+  priority TEXT,
+  assignee TEXT,
+  recurrence TEXT,
+  next_occurrence TEXT,
+  CONSTRAINT issues_pk PRIMARY KEY (id)
+);
+CREATE TABLE IF NOT EXISTS issue_block_links (
+  issue_id TEXT NOT NULL,
+  issue_title TEXT NOT NULL,
+  blocked_issue_id TEXT NOT NULL,
+  blocked_issue_title TEXT NOT NULL,
+  CONSTRAINT issue_block_links_pk PRIMARY KEY (issue_id, blocked_issue_id)
+);
+CREATE INDEX IF NOT EXISTS issue_block_links_issue_id_index ON issue_block_links(issue_id);
+CREATE INDEX IF NOT EXISTS issue_block_links_blocked_issue_id_index ON issue_block_links(blocked_issue_id);
+CREATE TABLE IF NOT EXISTS issue_comments (
+  id TEXT NOT NULL,
+  issue_id TEXT NOT NULL,
+  text TEXT NOT NULL,
+  created_at TEXT NOT NULL,
+  -- NULLable
+  updated_at TEXT,
+  CONSTRAINT issue_comments_pk PRIMARY KEY (id)
+);
+CREATE TABLE IF NOT EXISTS issue_labels (
+  issue_id TEXT NOT NULL,
+  label TEXT NOT NULL,
+  CONSTRAINT issue_labels_pk PRIMARY KEY (issue_id, label)
+);
+CREATE TABLE IF NOT EXISTS projection_checkpoints (
+  name TEXT NOT NULL,
+  event_id CHAR(26) NOT NULL,
+  CONSTRAINT projection_checkpoints_pk PRIMARY KEY (name)
+);
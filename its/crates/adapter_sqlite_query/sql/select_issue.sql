@@ -3,6 +3,10 @@ SELECT id,
   status,
   title,
   due,
-  description
+  description,
+  priority,
+  assignee,
+  recurrence,
+  next_occurrence
 FROM issues
-WHERE id = ?
+WHERE id = $1
@@ -0,0 +1,5 @@
+SELECT issue_id,
+  issue_title,
+  blocked_issue_id,
+  blocked_issue_title
+FROM issue_block_links
@@ -3,4 +3,4 @@ SELECT issue_id,
   blocked_issue_id,
   blocked_issue_title
 FROM issue_block_links
-WHERE blocked_issue_id = ?
+WHERE blocked_issue_id = $1
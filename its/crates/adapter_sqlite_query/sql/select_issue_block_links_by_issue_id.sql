@@ -3,4 +3,4 @@ SELECT issue_id,
   blocked_issue_id,
   blocked_issue_title
 FROM issue_block_links
-WHERE issue_id = ?
+WHERE issue_id = $1
@@ -3,4 +3,4 @@ SELECT id,
   created_at,
   updated_at
 FROM issue_comments
-WHERE id = ?
+WHERE id = $1
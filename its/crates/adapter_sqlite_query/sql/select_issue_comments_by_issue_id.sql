@@ -3,4 +3,4 @@ SELECT id,
   created_at,
   updated_at
 FROM issue_comments
-WHERE issue_id = ?
+WHERE issue_id = $1
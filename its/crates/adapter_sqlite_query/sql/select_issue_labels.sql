@@ -0,0 +1,5 @@
+SELECT issue_id,
+  label
+FROM issue_labels
+ORDER BY issue_id,
+  label
@@ -0,0 +1,5 @@
+SELECT issue_id,
+  label
+FROM issue_labels
+WHERE issue_id = $1
+ORDER BY label
@@ -3,5 +3,9 @@ SELECT id,
   status,
   title,
   due,
-  description
+  description,
+  priority,
+  assignee,
+  recurrence,
+  next_occurrence
 FROM issues
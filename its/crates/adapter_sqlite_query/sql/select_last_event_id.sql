@@ -1,2 +0,0 @@
-SELECT event_id
-FROM last_event_id
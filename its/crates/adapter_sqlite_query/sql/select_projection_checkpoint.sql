@@ -0,0 +1,3 @@
+SELECT event_id
+FROM projection_checkpoints
+WHERE name = $1
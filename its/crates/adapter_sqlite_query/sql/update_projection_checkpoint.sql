@@ -0,0 +1,4 @@
+UPDATE projection_checkpoints
+SET event_id = $1
+WHERE name = $2
+  AND event_id = $3;
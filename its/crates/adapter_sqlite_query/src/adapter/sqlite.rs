@@ -1,4 +1,8 @@
+mod query_issue_graph;
+mod query_issue_list_filter;
 mod query_migration_source;
 mod sqlite_query_handler;
 
+pub use self::query_issue_graph::*;
+pub use self::query_issue_list_filter::*;
 pub use self::sqlite_query_handler::*;
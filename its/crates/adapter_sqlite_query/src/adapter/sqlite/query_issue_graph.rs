@@ -0,0 +1,303 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+
+use super::sqlite_query_handler::QueryIssueIdWithTitle;
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct QueryIssueGraphEdge {
+    pub issue_id: String,
+    pub blocked_issue_id: String,
+}
+
+// block links between open issues
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct QueryIssueGraph {
+    pub nodes: Vec<QueryIssueIdWithTitle>,
+    pub edges: Vec<QueryIssueGraphEdge>,
+    pub cycles: Vec<Vec<String>>,
+    pub ready: Vec<String>,
+}
+
+impl QueryIssueGraph {
+    pub(crate) fn new(nodes: Vec<QueryIssueIdWithTitle>, edges: Vec<QueryIssueGraphEdge>) -> Self {
+        let ids = nodes
+            .iter()
+            .map(|node| node.id.as_str())
+            .collect::<BTreeSet<&str>>();
+        let edges = edges
+            .into_iter()
+            .filter(|edge| {
+                ids.contains(edge.issue_id.as_str()) && ids.contains(edge.blocked_issue_id.as_str())
+            })
+            .collect::<Vec<QueryIssueGraphEdge>>();
+        let blocked = edges
+            .iter()
+            .map(|edge| edge.blocked_issue_id.as_str())
+            .collect::<BTreeSet<&str>>();
+        let ready = nodes
+            .iter()
+            .filter(|node| !blocked.contains(node.id.as_str()))
+            .map(|node| node.id.clone())
+            .collect::<Vec<String>>();
+        let cycles = find_cycles(&nodes, &edges);
+        Self {
+            nodes,
+            edges,
+            cycles,
+            ready,
+        }
+    }
+
+    pub fn to_dot(&self) -> String {
+        let escape = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+        let mut lines = vec!["digraph issues {".to_string()];
+        for cycle in self.cycles.iter() {
+            lines.push(format!("  // cycle: {}", cycle_to_string(cycle)));
+        }
+        for node in self.nodes.iter() {
+            let style = if self.ready.contains(&node.id) {
+                ", style=bold"
+            } else {
+                ""
+            };
+            lines.push(format!(
+                "  \"{}\" [label=\"#{} {}\"{}];",
+                node.id,
+                node.id,
+                escape(node.title.as_str()),
+                style
+            ));
+        }
+        for edge in self.edges.iter() {
+            let style = if self.is_in_cycle(edge) {
+                " [color=red]"
+            } else {
+                ""
+            };
+            lines.push(format!(
+                "  \"{}\" -> \"{}\"{};",
+                edge.issue_id, edge.blocked_issue_id, style
+            ));
+        }
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+
+    pub fn to_mermaid(&self) -> String {
+        let escape = |s: &str| s.replace('"', "#quot;");
+        let mut lines = vec!["graph LR".to_string()];
+        for cycle in self.cycles.iter() {
+            lines.push(format!("  %% cycle: {}", cycle_to_string(cycle)));
+        }
+        for node in self.nodes.iter() {
+            lines.push(format!(
+                "  issue{}[\"#{} {}\"]",
+                node.id,
+                node.id,
+                escape(node.title.as_str())
+            ));
+        }
+        for edge in self.edges.iter() {
+            let arrow = if self.is_in_cycle(edge) {
+                "-.->"
+            } else {
+                "-->"
+            };
+            lines.push(format!(
+                "  issue{} {} issue{}",
+                edge.issue_id, arrow, edge.blocked_issue_id
+            ));
+        }
+        if !self.ready.is_empty() {
+            lines.push("  classDef ready stroke-width:3px".to_string());
+            lines.push(format!(
+                "  class {} ready",
+                self.ready
+                    .iter()
+                    .map(|id| format!("issue{}", id))
+                    .collect::<Vec<String>>()
+                    .join(",")
+            ));
+        }
+        lines.join("\n")
+    }
+
+    fn is_in_cycle(&self, edge: &QueryIssueGraphEdge) -> bool {
+        self.cycles
+            .iter()
+            .any(|cycle| cycle.contains(&edge.issue_id) && cycle.contains(&edge.blocked_issue_id))
+    }
+}
+
+fn cycle_to_string(cycle: &[String]) -> String {
+    cycle
+        .iter()
+        .chain(cycle.first())
+        .map(|id| format!("#{}", id))
+        .collect::<Vec<String>>()
+        .join(" -> ")
+}
+
+// strongly connected components (Tarjan) with two or more issues
+fn find_cycles(nodes: &[QueryIssueIdWithTitle], edges: &[QueryIssueGraphEdge]) -> Vec<Vec<String>> {
+    struct State<'a> {
+        adjacency: BTreeMap<&'a str, Vec<&'a str>>,
+        index: usize,
+        indices: BTreeMap<&'a str, usize>,
+        low_links: BTreeMap<&'a str, usize>,
+        stack: Vec<&'a str>,
+        on_stack: BTreeSet<&'a str>,
+        components: Vec<Vec<String>>,
+    }
+
+    fn visit<'a>(state: &mut State<'a>, v: &'a str) {
+        state.indices.insert(v, state.index);
+        state.low_links.insert(v, state.index);
+        state.index += 1;
+        state.stack.push(v);
+        state.on_stack.insert(v);
+        let successors = state.adjacency.get(v).cloned().unwrap_or_default();
+        for w in successors {
+            if !state.indices.contains_key(w) {
+                visit(state, w);
+                let low_link = state.low_links[v].min(state.low_links[w]);
+                state.low_links.insert(v, low_link);
+            } else if state.on_stack.contains(w) {
+                let low_link = state.low_links[v].min(state.indices[w]);
+                state.low_links.insert(v, low_link);
+            }
+        }
+        if state.low_links[v] == state.indices[v] {
+            let mut component = vec![];
+            while let Some(w) = state.stack.pop() {
+                state.on_stack.remove(w);
+                component.push(w.to_string());
+                if w == v {
+                    break;
+                }
+            }
+            if component.len() > 1 {
+                component.reverse();
+                state.components.push(component);
+            }
+        }
+    }
+
+    let mut adjacency: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for edge in edges {
+        adjacency
+            .entry(edge.issue_id.as_str())
+            .or_default()
+            .push(edge.blocked_issue_id.as_str());
+    }
+    let mut state = State {
+        adjacency,
+        index: 0,
+        indices: BTreeMap::new(),
+        low_links: BTreeMap::new(),
+        stack: vec![],
+        on_stack: BTreeSet::new(),
+        components: vec![],
+    };
+    for node in nodes {
+        if !state.indices.contains_key(node.id.as_str()) {
+            visit(&mut state, node.id.as_str());
+        }
+    }
+    state.components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: &str) -> QueryIssueIdWithTitle {
+        QueryIssueIdWithTitle {
+            id: id.to_string(),
+            title: format!("title{}", id),
+        }
+    }
+
+    fn edge(issue_id: &str, blocked_issue_id: &str) -> QueryIssueGraphEdge {
+        QueryIssueGraphEdge {
+            issue_id: issue_id.to_string(),
+            blocked_issue_id: blocked_issue_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn new_test() {
+        let graph = QueryIssueGraph::new(
+            vec![node("1"), node("2"), node("3"), node("4"), node("5")],
+            vec![
+                edge("1", "2"),
+                edge("2", "3"),
+                edge("3", "2"),
+                edge("4", "5"),
+                // 6 is closed
+                edge("6", "4"),
+            ],
+        );
+        assert_eq!(
+            graph.edges,
+            vec![
+                edge("1", "2"),
+                edge("2", "3"),
+                edge("3", "2"),
+                edge("4", "5")
+            ]
+        );
+        assert_eq!(graph.cycles, vec![vec!["2".to_string(), "3".to_string()]]);
+        assert_eq!(graph.ready, vec!["1".to_string(), "4".to_string()]);
+    }
+
+    #[test]
+    fn to_dot_test() {
+        let graph = QueryIssueGraph::new(
+            vec![node("1"), node("2"), node("3")],
+            vec![edge("1", "2"), edge("2", "3"), edge("3", "2")],
+        );
+        assert_eq!(
+            graph.to_dot(),
+            [
+                "digraph issues {",
+                "  // cycle: #2 -> #3 -> #2",
+                "  \"1\" [label=\"#1 title1\", style=bold];",
+                "  \"2\" [label=\"#2 title2\"];",
+                "  \"3\" [label=\"#3 title3\"];",
+                "  \"1\" -> \"2\";",
+                "  \"2\" -> \"3\" [color=red];",
+                "  \"3\" -> \"2\" [color=red];",
+                "}",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn to_mermaid_test() {
+        let graph = QueryIssueGraph::new(
+            vec![
+                node("1"),
+                QueryIssueIdWithTitle {
+                    id: "2".to_string(),
+                    title: "\"quoted\"".to_string(),
+                },
+            ],
+            vec![edge("1", "2")],
+        );
+        assert_eq!(
+            graph.to_mermaid(),
+            [
+                "graph LR",
+                "  issue1[\"#1 title1\"]",
+                "  issue2[\"#2 #quot;quoted#quot;\"]",
+                "  issue1 --> issue2",
+                "  classDef ready stroke-width:3px",
+                "  class issue1 ready",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -0,0 +1,405 @@
+use std::str::FromStr;
+
+use domain::aggregate::issue::{IssueAssignee, IssueDue, IssueLabel, IssuePriority, IssueStatus};
+
+// e.g. `status:todo,in_progress due:<2026-11-01 is:blocked -label:wontfix "title words"`
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct QueryIssueListFilter {
+    terms: Vec<(bool, Term)>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Term {
+    Assignee(IssueAssignee),
+    Due(Comparison, Option<IssueDue>),
+    DueBetween(IssueDue, IssueDue),
+    IsBlocked,
+    IsClosed,
+    IsOpen,
+    Label(IssueLabel),
+    Priority(Vec<IssuePriority>),
+    Status(Vec<IssueStatus>),
+    Text(String),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Comparison {
+    Eq,
+    Ge,
+    Gt,
+    Le,
+    Lt,
+}
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum ParseQueryIssueListFilterError {
+    #[error("invalid value `{1}` for `{0}`")]
+    InvalidValue(String, String),
+    #[error("unclosed quote")]
+    UnclosedQuote,
+    #[error("unknown key `{0}`")]
+    UnknownKey(String),
+}
+
+impl FromStr for QueryIssueListFilter {
+    type Err = ParseQueryIssueListFilterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let terms = tokenize(s)?
+            .into_iter()
+            .map(|(token, quoted)| parse_term(token.as_str(), quoted))
+            .collect::<Result<Vec<(bool, Term)>, Self::Err>>()?;
+        Ok(Self { terms })
+    }
+}
+
+impl QueryIssueListFilter {
+    pub fn and(mut self, other: QueryIssueListFilter) -> Self {
+        self.terms.extend(other.terms);
+        self
+    }
+
+    pub(crate) fn open() -> Self {
+        Self {
+            terms: vec![(false, Term::IsOpen)],
+        }
+    }
+
+    // returns (where clause, bind values). placeholders are numbered (`$1`, `$2`, ...)
+    pub(crate) fn to_sql(&self) -> (String, Vec<String>) {
+        let mut conditions = vec![];
+        let mut values = vec![];
+        for (negated, term) in self.terms.iter() {
+            let (condition, mut term_values) = term_to_sql(term, values.len());
+            conditions.push(if *negated {
+                format!("NOT ({})", condition)
+            } else {
+                format!("({})", condition)
+            });
+            values.append(&mut term_values);
+        }
+        if conditions.is_empty() {
+            ("TRUE".to_string(), values)
+        } else {
+            (conditions.join(" AND "), values)
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum QueryIssueListSortKey {
+    #[default]
+    Id,
+    Due,
+    Priority,
+    Status,
+    Title,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct QueryIssueListSort {
+    pub key: QueryIssueListSortKey,
+    pub descending: bool,
+}
+
+impl QueryIssueListSort {
+    pub(crate) fn to_sql(self) -> String {
+        let order = if self.descending { "DESC" } else { "ASC" };
+        let id = format!("CAST(id AS INTEGER) {}", order);
+        match self.key {
+            QueryIssueListSortKey::Id => id,
+            // NULLs last
+            QueryIssueListSortKey::Due => format!("due IS NULL, due {}, {}", order, id),
+            QueryIssueListSortKey::Priority => format!(
+                "CASE priority WHEN 'urgent' THEN 4 WHEN 'high' THEN 3 WHEN 'medium' THEN 2 WHEN 'low' THEN 1 ELSE 0 END {}, {}",
+                order, id
+            ),
+            QueryIssueListSortKey::Status => format!(
+                "CASE status WHEN 'todo' THEN 1 WHEN 'in_progress' THEN 2 WHEN 'done' THEN 3 ELSE 4 END {}, {}",
+                order, id
+            ),
+            QueryIssueListSortKey::Title => format!("title {}, {}", order, id),
+        }
+    }
+}
+
+fn tokenize(s: &str) -> Result<Vec<(String, bool)>, ParseQueryIssueListFilterError> {
+    let mut tokens = vec![];
+    let mut token = String::new();
+    let mut quoted = false;
+    let mut in_quote = false;
+    for c in s.chars() {
+        match c {
+            '"' => {
+                in_quote = !in_quote;
+                quoted = true;
+            }
+            c if c.is_whitespace() && !in_quote => {
+                if !token.is_empty() || quoted {
+                    tokens.push((token, quoted));
+                }
+                token = String::new();
+                quoted = false;
+            }
+            c => token.push(c),
+        }
+    }
+    if in_quote {
+        return Err(ParseQueryIssueListFilterError::UnclosedQuote);
+    }
+    if !token.is_empty() || quoted {
+        tokens.push((token, quoted));
+    }
+    Ok(tokens)
+}
+
+fn parse_term(token: &str, quoted: bool) -> Result<(bool, Term), ParseQueryIssueListFilterError> {
+    let (negated, token) = match token.strip_prefix('-') {
+        Some(rest) if !rest.is_empty() => (true, rest),
+        _ => (false, token),
+    };
+    let (key, value) = match token.split_once(':') {
+        Some((key, value)) if !quoted => (key, value),
+        _ => return Ok((negated, Term::Text(token.to_string()))),
+    };
+    let invalid_value =
+        || ParseQueryIssueListFilterError::InvalidValue(key.to_string(), value.to_string());
+    let term = match key {
+        "assignee" => Term::Assignee(IssueAssignee::from_str(value).map_err(|_| invalid_value())?),
+        "due" => {
+            let (comparison, date) = parse_comparison(value);
+            if date == "none" {
+                if comparison != Comparison::Eq {
+                    return Err(invalid_value());
+                }
+                Term::Due(comparison, None)
+            } else if comparison == Comparison::Eq && date.len() == 10 {
+                // a date matches any time of that day
+                let start = parse_due(date, Comparison::Ge).ok_or_else(invalid_value)?;
+                let end = parse_due(date, Comparison::Le).ok_or_else(invalid_value)?;
+                Term::DueBetween(start, end)
+            } else {
+                let due = parse_due(date, comparison).ok_or_else(invalid_value)?;
+                Term::Due(comparison, Some(due))
+            }
+        }
+        "is" => match value {
+            "blocked" => Term::IsBlocked,
+            "closed" => Term::IsClosed,
+            "open" => Term::IsOpen,
+            _ => return Err(invalid_value()),
+        },
+        "label" => Term::Label(IssueLabel::from_str(value).map_err(|_| invalid_value())?),
+        "priority" => Term::Priority(
+            value
+                .split(',')
+                .map(IssuePriority::from_str)
+                .collect::<Result<Vec<IssuePriority>, _>>()
+                .map_err(|_| invalid_value())?,
+        ),
+        "status" => Term::Status(
+            value
+                .split(',')
+                .map(IssueStatus::from_str)
+                .collect::<Result<Vec<IssueStatus>, _>>()
+                .map_err(|_| invalid_value())?,
+        ),
+        _ => return Err(ParseQueryIssueListFilterError::UnknownKey(key.to_string())),
+    };
+    Ok((negated, term))
+}
+
+fn parse_comparison(value: &str) -> (Comparison, &str) {
+    for (prefix, comparison) in [
+        ("<=", Comparison::Le),
+        (">=", Comparison::Ge),
+        ("<", Comparison::Lt),
+        (">", Comparison::Gt),
+    ] {
+        if let Some(rest) = value.strip_prefix(prefix) {
+            return (comparison, rest);
+        }
+    }
+    (Comparison::Eq, value)
+}
+
+// `YYYY-MM-DD` is the whole day in UTC
+fn parse_due(value: &str, comparison: Comparison) -> Option<IssueDue> {
+    if value.len() == 10 {
+        let time = match comparison {
+            Comparison::Gt | Comparison::Le => "23:59:59",
+            Comparison::Eq | Comparison::Ge | Comparison::Lt => "00:00:00",
+        };
+        IssueDue::from_str(format!("{}T{}Z", value, time).as_str()).ok()
+    } else {
+        IssueDue::from_str(value).ok()
+    }
+}
+
+fn term_to_sql(term: &Term, offset: usize) -> (String, Vec<String>) {
+    let placeholder = |i: usize| format!("${}", offset + i);
+    let placeholders = |n: usize| (1..=n).map(placeholder).collect::<Vec<String>>().join(", ");
+    match term {
+        Term::Assignee(assignee) => (
+            format!("assignee = {}", placeholder(1)),
+            vec![assignee.to_string()],
+        ),
+        Term::Due(_, None) => ("due IS NULL".to_string(), vec![]),
+        Term::Due(comparison, Some(due)) => {
+            let operator = match comparison {
+                Comparison::Eq => "=",
+                Comparison::Ge => ">=",
+                Comparison::Gt => ">",
+                Comparison::Le => "<=",
+                Comparison::Lt => "<",
+            };
+            (
+                format!("due {} {}", operator, placeholder(1)),
+                vec![due.to_string()],
+            )
+        }
+        Term::DueBetween(start, end) => (
+            format!("due >= {} AND due <= {}", placeholder(1), placeholder(2)),
+            vec![start.to_string(), end.to_string()],
+        ),
+        Term::IsBlocked => (
+            [
+                "EXISTS (",
+                "SELECT 1 FROM issue_block_links",
+                "INNER JOIN issues AS blocking_issues",
+                "ON blocking_issues.id = issue_block_links.issue_id",
+                "WHERE issue_block_links.blocked_issue_id = issues.id",
+                "AND blocking_issues.status IN ('todo', 'in_progress'))",
+            ]
+            .join(" "),
+            vec![],
+        ),
+        Term::IsClosed => ("status IN ('done', 'cancelled')".to_string(), vec![]),
+        Term::IsOpen => ("status IN ('todo', 'in_progress')".to_string(), vec![]),
+        Term::Label(label) => (
+            format!("EXISTS (SELECT 1 FROM issue_labels WHERE issue_labels.issue_id = issues.id AND issue_labels.label = {})", placeholder(1)),
+            vec![label.to_string()],
+        ),
+        Term::Priority(priorities) => (
+            format!("priority IN ({})", placeholders(priorities.len())),
+            priorities.iter().map(|p| p.to_string()).collect(),
+        ),
+        Term::Status(statuses) => (
+            format!("status IN ({})", placeholders(statuses.len())),
+            statuses.iter().map(|s| s.to_string()).collect(),
+        ),
+        // `instr` is not available in PostgreSQL
+        Term::Text(text) => (
+            format!(
+                "lower(title) LIKE '%' || lower({}) || '%' ESCAPE '\\'",
+                placeholder(1)
+            ),
+            vec![text
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_")],
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenize_test() -> anyhow::Result<()> {
+        assert_eq!(
+            tokenize(r#" status:todo  "title words" due:<2026-11-01 "#)?,
+            vec![
+                ("status:todo".to_string(), false),
+                ("title words".to_string(), true),
+                ("due:<2026-11-01".to_string(), false),
+            ]
+        );
+        assert_eq!(
+            tokenize(r#"""#),
+            Err(ParseQueryIssueListFilterError::UnclosedQuote)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_str_test() -> anyhow::Result<()> {
+        assert_eq!(QueryIssueListFilter::from_str("")?.to_sql().0, "TRUE");
+        let filter = QueryIssueListFilter::from_str(
+            r#"status:todo,in_progress due:<2026-11-01 is:blocked -label:bug "title words""#,
+        )?;
+        let (sql, values) = filter.to_sql();
+        assert!(sql.starts_with("(status IN ($1, $2)) AND (due < $3) AND (EXISTS ("));
+        assert!(sql.ends_with(
+            r#"AND NOT (EXISTS (SELECT 1 FROM issue_labels WHERE issue_labels.issue_id = issues.id AND issue_labels.label = $4)) AND (lower(title) LIKE '%' || lower($5) || '%' ESCAPE '\')"#
+        ));
+        assert_eq!(
+            values,
+            vec![
+                "todo",
+                "in_progress",
+                "2026-11-01T00:00:00Z",
+                "bug",
+                "title words"
+            ]
+        );
+        assert_eq!(
+            QueryIssueListFilter::from_str("due:<=2026-11-01")?.to_sql(),
+            (
+                "(due <= $1)".to_string(),
+                vec!["2026-11-01T23:59:59Z".to_string()]
+            )
+        );
+        assert_eq!(
+            QueryIssueListFilter::from_str("due:2026-11-01")?.to_sql(),
+            (
+                "(due >= $1 AND due <= $2)".to_string(),
+                vec![
+                    "2026-11-01T00:00:00Z".to_string(),
+                    "2026-11-01T23:59:59Z".to_string()
+                ]
+            )
+        );
+        assert_eq!(
+            QueryIssueListFilter::from_str(r#""100%_\""#)?.to_sql().1,
+            vec![r#"100\%\_\\"#.to_string()]
+        );
+        assert_eq!(
+            QueryIssueListFilter::from_str("-due:none")?.to_sql(),
+            ("NOT (due IS NULL)".to_string(), vec![])
+        );
+        assert_eq!(
+            QueryIssueListFilter::from_str("foo:bar"),
+            Err(ParseQueryIssueListFilterError::UnknownKey(
+                "foo".to_string()
+            ))
+        );
+        assert_eq!(
+            QueryIssueListFilter::from_str("status:unknown"),
+            Err(ParseQueryIssueListFilterError::InvalidValue(
+                "status".to_string(),
+                "unknown".to_string()
+            ))
+        );
+        assert!(QueryIssueListFilter::from_str("due:<tomorrow").is_err());
+        assert!(QueryIssueListFilter::from_str("is:ready").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn sort_test() {
+        assert_eq!(
+            QueryIssueListSort::default().to_sql(),
+            "CAST(id AS INTEGER) ASC"
+        );
+        assert_eq!(
+            QueryIssueListSort {
+                key: QueryIssueListSortKey::Due,
+                descending: true
+            }
+            .to_sql(),
+            "due IS NULL, due DESC, CAST(id AS INTEGER) DESC"
+        );
+    }
+}
@@ -62,8 +62,53 @@ impl MigrationSource<'static> for QueryMigrationSource {
                         "../../../sql/migrations/20220806000001_create_issue_comments.sql"
                     )),
                 ),
+                Migration::new(
+                    20261018000001,
+                    Cow::from("alter_issues_add_labels_priority_assignee"),
+                    MigrationType::Simple,
+                    Cow::from(include_str!(
+                        "../../../sql/migrations/20261018000001_alter_issues_add_labels_priority_assignee.sql"
+                    )),
+                ),
+                Migration::new(
+                    20261018000002,
+                    Cow::from("create_projection_checkpoints"),
+                    MigrationType::Simple,
+                    Cow::from(include_str!(
+                        "../../../sql/migrations/20261018000002_create_projection_checkpoints.sql"
+                    )),
+                ),
+                Migration::new(
+                    20261018000003,
+                    Cow::from("alter_issues_add_recurrence"),
+                    MigrationType::Simple,
+                    Cow::from(include_str!(
+                        "../../../sql/migrations/20261018000003_alter_issues_add_recurrence.sql"
+                    )),
+                ),
             ];
             Ok(migrations)
         })
     }
 }
+
+#[derive(Debug, Default)]
+pub struct PostgresQueryMigrationSource {}
+
+impl MigrationSource<'static> for PostgresQueryMigrationSource {
+    fn resolve(
+        self,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Migration>, BoxDynError>> + Send + 'static>> {
+        Box::pin(async move {
+            let migrations = vec![Migration::new(
+                20261018000001,
+                Cow::from("create_tables"),
+                MigrationType::Simple,
+                Cow::from(include_str!(
+                    "../../../sql/migrations/postgres/20261018000001_create_tables.sql"
+                )),
+            )];
+            Ok(migrations)
+        })
+    }
+}
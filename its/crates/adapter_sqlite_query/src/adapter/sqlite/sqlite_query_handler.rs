@@ -1,30 +1,35 @@
-use std::{
-    fmt::Debug,
-    str::FromStr,
-    sync::{Arc, Mutex},
-};
+use std::{collections::BTreeMap, fmt::Debug, str::FromStr, sync::Arc};
 
 use adapter_sqlite::RdbConnectionPool;
 use domain::{
     aggregate::{
         IssueAggregate, IssueAggregateEvent, IssueBlockLinkAggregate, IssueBlockLinkAggregateEvent,
     },
-    DomainEvent, IssueCommentId, IssueId, ParseDomainEventError,
+    DomainEvent, IssueBlockLinkId, IssueCommentId, IssueId, ParseDomainEventError, Version,
 };
 use event_store::{Event, EventId};
+use limited_date_time::Instant;
 use serde::Serialize;
 use sqlx::{
-    any::AnyArguments, migrate::Migrator, query::Query, Any, AnyPool, FromRow, Transaction,
+    any::{AnyArguments, AnyKind},
+    migrate::Migrator,
+    query::Query,
+    Any, AnyPool, FromRow, Transaction,
 };
-use use_case::{IssueBlockLinkRepository, IssueRepository};
+use tokio::sync::Mutex;
+use use_case::{IssueBlockLinkRepository, IssueManagementContextEvent, IssueRepository};
 
-use super::query_migration_source::QueryMigrationSource;
+use super::{
+    query_issue_graph::{QueryIssueGraph, QueryIssueGraphEdge},
+    query_issue_list_filter::{QueryIssueListFilter, QueryIssueListSort},
+    query_migration_source::{PostgresQueryMigrationSource, QueryMigrationSource},
+};
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 // QueryIssue
 
-#[derive(Clone, Debug, Eq, FromRow, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
 pub struct QueryIssue {
     pub id: String,
     pub resolution: Option<String>,
@@ -32,6 +37,49 @@ pub struct QueryIssue {
     pub title: String,
     pub due: Option<String>,
     pub description: String,
+    pub labels: Vec<String>,
+    pub priority: Option<String>,
+    pub assignee: Option<String>,
+    pub recurrence: Option<String>,
+    pub next_occurrence: Option<String>,
+}
+
+#[derive(FromRow)]
+struct IssueRow {
+    id: String,
+    resolution: Option<String>,
+    status: String,
+    title: String,
+    due: Option<String>,
+    description: String,
+    priority: Option<String>,
+    assignee: Option<String>,
+    recurrence: Option<String>,
+    next_occurrence: Option<String>,
+}
+
+impl IssueRow {
+    fn into_query_issue(self, labels: Vec<String>) -> QueryIssue {
+        QueryIssue {
+            id: self.id,
+            resolution: self.resolution,
+            status: self.status,
+            title: self.title,
+            due: self.due,
+            description: self.description,
+            labels,
+            priority: self.priority,
+            assignee: self.assignee,
+            recurrence: self.recurrence,
+            next_occurrence: self.next_occurrence,
+        }
+    }
+}
+
+#[derive(FromRow)]
+struct IssueLabelRow {
+    issue_id: String,
+    label: String,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
@@ -48,6 +96,11 @@ pub struct QueryIssueWithLinks {
     pub title: String,
     pub due: Option<String>,
     pub description: String,
+    pub labels: Vec<String>,
+    pub priority: Option<String>,
+    pub assignee: Option<String>,
+    pub recurrence: Option<String>,
+    pub next_occurrence: Option<String>,
     pub blocks: Vec<QueryIssueIdWithTitle>,
     pub is_blocked_by: Vec<QueryIssueIdWithTitle>,
     pub comments: Vec<QueryIssueComment>,
@@ -69,6 +122,91 @@ pub struct QueryIssueBlockLink {
     pub blocked_issue_title: String,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct QueryIssueHistoryEntry {
+    pub event_id: String,
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub aggregate_id: String,
+    pub version: u64,
+    pub at: String,
+    pub changes: Vec<QueryIssueHistoryChange>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct QueryIssueHistoryChange {
+    pub field: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+impl QueryIssueHistoryChange {
+    fn new(field: &str, old: Option<String>, new: Option<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            old,
+            new,
+        }
+    }
+}
+
+// QueryProjection
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum QueryProjection {
+    IssueBlockLinks,
+    IssueComments,
+    Issues,
+}
+
+impl QueryProjection {
+    pub const ALL: [QueryProjection; 3] = [
+        QueryProjection::IssueBlockLinks,
+        QueryProjection::IssueComments,
+        QueryProjection::Issues,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            QueryProjection::IssueBlockLinks => "issue_block_links",
+            QueryProjection::IssueComments => "issue_comments",
+            QueryProjection::Issues => "issues",
+        }
+    }
+
+    fn handles(&self, domain_event: &DomainEvent) -> bool {
+        matches!(
+            (self, domain_event),
+            (
+                QueryProjection::IssueBlockLinks,
+                DomainEvent::IssueBlockLink(_)
+            ) | (QueryProjection::IssueComments, DomainEvent::IssueComment(_))
+                | (QueryProjection::Issues, DomainEvent::Issue(_))
+        )
+    }
+}
+
+impl From<&IssueManagementContextEvent> for QueryProjection {
+    fn from(event: &IssueManagementContextEvent) -> Self {
+        use IssueManagementContextEvent::*;
+        match event {
+            IssueCommentCreated { .. }
+            | IssueCommentDeleted { .. }
+            | IssueCommentUpdated { .. } => QueryProjection::IssueComments,
+            IssueCreated { .. } | IssueUpdated { .. } => QueryProjection::Issues,
+            IssueBlocked { .. } | IssueUnblocked { .. } => QueryProjection::IssueBlockLinks,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct QueryProjectionStatus {
+    pub name: String,
+    pub event_id: Option<String>,
+    pub last_event_id: Option<String>,
+    pub lag: usize,
+}
+
 // QueryHandlerError
 
 #[derive(Debug, thiserror::Error)]
@@ -98,6 +236,7 @@ impl From<event_store::Error> for Error {
 // SqliteQueryHandler
 
 pub struct SqliteQueryHandler {
+    connection_pool: RdbConnectionPool,
     event_store_pool: AnyPool,
     query_pool: AnyPool,
     issue_repository: Arc<Mutex<dyn IssueRepository + Send + Sync>>,
@@ -112,6 +251,7 @@ impl SqliteQueryHandler {
     ) -> Result<Self> {
         let query_pool = AnyPool::connect(connection_uri).await?;
         let created = Self {
+            connection_pool: event_store_pool.clone(),
             event_store_pool: AnyPool::from(event_store_pool),
             query_pool,
             issue_repository,
@@ -122,75 +262,198 @@ impl SqliteQueryHandler {
         Ok(created)
     }
 
+    // catches up the projection of `event` from its checkpoint instead of projecting `event` itself.
+    // the events are read from the event store, so the events saved by another process (or by a
+    // command whose `handle` failed) are not lost.
+    // the cost is the events after the checkpoint: usually the events of this command and the events
+    // of the other projections saved since the last update of this projection. a query database
+    // without checkpoints (e.g. a new one) reads the whole event store once.
+    pub async fn handle(&self, event: &IssueManagementContextEvent) -> Result<()> {
+        self.update_projection(QueryProjection::from(event)).await
+    }
+
     pub async fn update_database(&self) -> Result<()> {
+        for projection in QueryProjection::ALL {
+            self.update_projection(projection).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn update_projection(&self, projection: QueryProjection) -> Result<()> {
         let mut query_transaction = self.query_pool.begin().await?;
+        let checkpoint = Self::find_checkpoint(&mut query_transaction, projection).await?;
+
+        let mut event_store_transaction = self.event_store_pool.begin().await?;
+        let events = Self::find_events_after(&mut event_store_transaction, checkpoint).await?;
+        let last_event_id = match events.last() {
+            Some(event) => event.id,
+            None => return Ok(()),
+        };
+
+        // an aggregate is rebuilt once even if it has many new events
+        let mut streams = BTreeMap::new();
+        for event in events {
+            let domain_event = DomainEvent::from_str(event.data.as_str())
+                .map_err(|e| Error::Unknown(e.to_string()))?;
+            if !projection.handles(&domain_event) {
+                continue;
+            }
+            match domain_event {
+                DomainEvent::Issue(_) | DomainEvent::IssueBlockLink(_) => {
+                    streams.insert(event.stream_id, event.stream_seq);
+                }
+                DomainEvent::IssueComment(event) => {
+                    Self::apply_issue_comment_event(&mut query_transaction, event).await?;
+                }
+            }
+        }
+        for (stream_id, stream_seq) in streams {
+            let domain_events =
+                event_store::find_events_by_event_stream_id_and_version_less_than_equal(
+                    &mut event_store_transaction,
+                    stream_id,
+                    stream_seq,
+                )
+                .await?
+                .into_iter()
+                .map(|e| DomainEvent::from_str(e.data.as_str()))
+                .collect::<Result<Vec<DomainEvent>, ParseDomainEventError>>()
+                .map_err(|e| Error::Unknown(e.to_string()))?;
+            match projection {
+                QueryProjection::IssueBlockLinks => {
+                    let events = domain_events
+                        .into_iter()
+                        .filter_map(|e| e.issue_block_link())
+                        .collect::<Vec<IssueBlockLinkAggregateEvent>>();
+                    let issue_block_link = IssueBlockLinkAggregate::from_events(&events)
+                        .map_err(|e| Error::Unknown(e.to_string()))?;
+                    self.store_issue_block_link(&mut query_transaction, issue_block_link)
+                        .await?;
+                }
+                QueryProjection::IssueComments => unreachable!(),
+                QueryProjection::Issues => {
+                    let events = domain_events
+                        .into_iter()
+                        .filter_map(|e| e.issue())
+                        .collect::<Vec<IssueAggregateEvent>>();
+                    let issue = IssueAggregate::from_events(&events)
+                        .map_err(|e| Error::Unknown(e.to_string()))?;
+                    Self::store_issue(&mut query_transaction, issue).await?;
+                }
+            }
+        }
+
+        Self::save_checkpoint(
+            &mut query_transaction,
+            projection,
+            last_event_id,
+            checkpoint,
+        )
+        .await?;
+        query_transaction.commit().await?;
+        Ok(())
+    }
+
+    pub async fn status(&self) -> Result<Vec<QueryProjectionStatus>> {
+        let mut query_transaction = self.query_pool.begin().await?;
+        let mut event_store_transaction = self.event_store_pool.begin().await?;
+        let mut statuses = vec![];
+        for projection in QueryProjection::ALL {
+            let checkpoint = Self::find_checkpoint(&mut query_transaction, projection).await?;
+            let events = Self::find_events_after(&mut event_store_transaction, checkpoint).await?;
+            let last_event_id = events.last().map(|event| event.id).or(checkpoint);
+            let mut lag = 0;
+            for event in events {
+                let domain_event = DomainEvent::from_str(event.data.as_str())
+                    .map_err(|e| Error::Unknown(e.to_string()))?;
+                if projection.handles(&domain_event) {
+                    lag += 1;
+                }
+            }
+            statuses.push(QueryProjectionStatus {
+                name: projection.name().to_string(),
+                event_id: checkpoint.map(|event_id| event_id.to_string()),
+                last_event_id: last_event_id.map(|event_id| event_id.to_string()),
+                lag,
+            });
+        }
+        Ok(statuses)
+    }
 
+    async fn find_checkpoint(
+        query_transaction: &mut Transaction<'_, Any>,
+        projection: QueryProjection,
+    ) -> Result<Option<EventId>> {
         #[derive(FromRow)]
-        struct LastEventIdRow {
+        struct ProjectionCheckpointRow {
             event_id: String,
         }
-        let row: Option<LastEventIdRow> =
-            sqlx::query_as(include_str!("../../../sql/select_last_event_id.sql"))
-                .fetch_optional(&mut query_transaction)
-                .await?;
-        let mut event_id = row
-            .map(|r| r.event_id)
-            .and_then(|s| EventId::from_str(s.as_str()).ok());
+        let row: Option<ProjectionCheckpointRow> = sqlx::query_as(include_str!(
+            "../../../sql/select_projection_checkpoint.sql"
+        ))
+        .bind(projection.name())
+        .fetch_optional(&mut *query_transaction)
+        .await?;
+        row.map(|r| EventId::from_str(r.event_id.as_str()))
+            .transpose()
+            .map_err(|e| Error::Unknown(e.to_string()))
+    }
 
-        let mut event_store_transaction = self.event_store_pool.begin().await?;
-        let events = match event_id {
+    async fn find_events_after(
+        event_store_transaction: &mut Transaction<'_, Any>,
+        event_id: Option<EventId>,
+    ) -> Result<Vec<Event>> {
+        Ok(match event_id {
             Some(event_id) => {
-                event_store::find_events_by_event_id_after(&mut event_store_transaction, event_id)
+                event_store::find_events_by_event_id_after(event_store_transaction, event_id)
                     .await?
             }
-            None => event_store::find_events(&mut event_store_transaction).await?,
-        };
-        for event in events {
-            let id = event.id;
-            let mut event_store_transaction = self.event_store_pool.begin().await?;
-            self.handle_event(&mut event_store_transaction, event)
-                .await?;
-            event_store_transaction.commit().await?;
-            self.save_last_event_id(id, event_id).await?;
-            event_id = Some(id);
-        }
-
-        Ok(())
+            None => event_store::find_events(event_store_transaction).await?,
+        })
     }
 
-    async fn save_last_event_id(
-        &self,
+    async fn save_checkpoint(
+        query_transaction: &mut Transaction<'_, Any>,
+        projection: QueryProjection,
         new_event_id: EventId,
         old_event_id: Option<EventId>,
     ) -> Result<()> {
-        let mut query_transaction = self.query_pool.begin().await?;
         match old_event_id {
             Some(event_id) => {
-                let query: Query<Any, AnyArguments> =
-                    sqlx::query(include_str!("../../../sql/update_last_event_id.sql"))
-                        .bind(new_event_id.to_string())
-                        .bind(event_id.to_string());
-                let rows_affected = query.execute(&mut query_transaction).await?.rows_affected();
+                let query: Query<Any, AnyArguments> = sqlx::query(include_str!(
+                    "../../../sql/update_projection_checkpoint.sql"
+                ))
+                .bind(new_event_id.to_string())
+                .bind(projection.name())
+                .bind(event_id.to_string());
+                let rows_affected = query
+                    .execute(&mut *query_transaction)
+                    .await?
+                    .rows_affected();
                 if rows_affected != 1 {
                     return Err(Error::Unknown(
-                        "update_last_event_id rows_affected != 1".to_string(),
+                        "update_projection_checkpoint rows_affected != 1".to_string(),
                     ));
                 }
             }
             None => {
-                let query: Query<Any, AnyArguments> =
-                    sqlx::query(include_str!("../../../sql/insert_last_event_id.sql"))
-                        .bind(new_event_id.to_string());
-                query.execute(&mut query_transaction).await?;
+                let query: Query<Any, AnyArguments> = sqlx::query(include_str!(
+                    "../../../sql/insert_projection_checkpoint.sql"
+                ))
+                .bind(projection.name())
+                .bind(new_event_id.to_string());
+                query.execute(&mut *query_transaction).await?;
             }
         }
-        query_transaction.commit().await?;
         Ok(())
     }
 
     pub async fn create_database(&self) -> Result<()> {
         let mut query_transaction = self.query_pool.begin().await?;
-        let migrator = Migrator::new(QueryMigrationSource::default()).await?;
+        let migrator = match self.query_pool.any_kind() {
+            AnyKind::Postgres => Migrator::new(PostgresQueryMigrationSource::default()).await?,
+            _ => Migrator::new(QueryMigrationSource::default()).await?,
+        };
         migrator.run(&mut *query_transaction).await?;
         query_transaction.commit().await?;
         Ok(())
@@ -200,8 +463,11 @@ impl SqliteQueryHandler {
         let mut query_transaction = self.query_pool.begin().await?;
         let sqls = vec![
             include_str!("../../../sql/drop_issue_block_links.sql"),
+            include_str!("../../../sql/drop_issue_comments.sql"),
+            include_str!("../../../sql/drop_issue_labels.sql"),
             include_str!("../../../sql/drop_issues.sql"),
-            include_str!("../../../sql/drop_last_event_id.sql"),
+            include_str!("../../../sql/drop_projection_checkpoints.sql"),
+            include_str!("../../../sql/drop_sqlx_migrations.sql"),
         ];
         for sql in sqls {
             sqlx::query(sql).execute(&mut *query_transaction).await?;
@@ -217,11 +483,21 @@ impl SqliteQueryHandler {
         Ok(())
     }
 
+    #[cfg(test)]
     async fn save_issue(&self, issue: IssueAggregate) -> Result<()> {
         let mut query_transaction = self.query_pool.begin().await?;
+        Self::store_issue(&mut query_transaction, issue).await?;
+        query_transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn store_issue(
+        query_transaction: &mut Transaction<'_, Any>,
+        issue: IssueAggregate,
+    ) -> Result<()> {
         let query: Query<Any, AnyArguments> =
             sqlx::query(include_str!("../../../sql/delete_issue.sql")).bind(issue.id().to_string());
-        query.execute(&mut query_transaction).await?;
+        query.execute(&mut *query_transaction).await?;
         let query: Query<Any, AnyArguments> =
             sqlx::query(include_str!("../../../sql/insert_issue.sql"))
                 .bind(issue.id().to_string())
@@ -229,26 +505,50 @@ impl SqliteQueryHandler {
                 .bind(issue.status().to_string())
                 .bind(issue.title().to_string())
                 .bind(issue.due().map(|d| d.to_string()))
-                .bind(issue.description().to_string());
-        query.execute(&mut query_transaction).await?;
-        query_transaction.commit().await?;
+                .bind(issue.description().to_string())
+                .bind(issue.priority().map(|p| p.to_string()))
+                .bind(issue.assignee().map(|a| a.to_string()))
+                .bind(issue.recurrence().map(|r| r.to_string()))
+                .bind(issue.next_occurrence().map(|i| i.to_string()));
+        query.execute(&mut *query_transaction).await?;
+        let query: Query<Any, AnyArguments> = sqlx::query(include_str!(
+            "../../../sql/delete_issue_labels_by_issue_id.sql"
+        ))
+        .bind(issue.id().to_string());
+        query.execute(&mut *query_transaction).await?;
+        for label in issue.labels() {
+            let query: Query<Any, AnyArguments> =
+                sqlx::query(include_str!("../../../sql/insert_issue_label.sql"))
+                    .bind(issue.id().to_string())
+                    .bind(label.to_string());
+            query.execute(&mut *query_transaction).await?;
+        }
         Ok(())
     }
 
+    #[cfg(test)]
     async fn save_issue_block_link(&self, issue_block_link: IssueBlockLinkAggregate) -> Result<()> {
         let mut query_transaction = self.query_pool.begin().await?;
+        self.store_issue_block_link(&mut query_transaction, issue_block_link)
+            .await?;
+        query_transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn store_issue_block_link(
+        &self,
+        query_transaction: &mut Transaction<'_, Any>,
+        issue_block_link: IssueBlockLinkAggregate,
+    ) -> Result<()> {
         let query: Query<Any, AnyArguments> =
             sqlx::query(include_str!("../../../sql/delete_issue_block_link.sql"))
                 .bind(issue_block_link.id().issue_id().to_string())
                 .bind(issue_block_link.id().blocked_issue_id().to_string());
-        query.execute(&mut query_transaction).await?;
+        query.execute(&mut *query_transaction).await?;
 
         if issue_block_link.is_blocked() {
             // FIXME
-            let issue_repository = self
-                .issue_repository
-                .lock()
-                .map_err(|e| Error::Unknown(format!("IssueRepository can't lock: {}", e)))?;
+            let issue_repository = self.issue_repository.lock().await;
             let issue_title = issue_repository
                 .find_by_id(issue_block_link.id().issue_id())
                 .await
@@ -269,13 +569,36 @@ impl SqliteQueryHandler {
                     .bind(issue_title.to_string())
                     .bind(issue_block_link.id().blocked_issue_id().to_string())
                     .bind(blocked_issue_title.to_string());
-            let rows_affected = query.execute(&mut query_transaction).await?.rows_affected();
+            let rows_affected = query
+                .execute(&mut *query_transaction)
+                .await?
+                .rows_affected();
             if rows_affected != 1 {
                 return Err(Error::Unknown("rows_affected != 1".to_string()));
             }
         }
+        Ok(())
+    }
 
-        query_transaction.commit().await?;
+    async fn apply_issue_comment_event(
+        query_transaction: &mut Transaction<'_, Any>,
+        event: domain::aggregate::issue_comment::Event,
+    ) -> Result<()> {
+        use domain::aggregate::issue_comment::Event::*;
+        let query: Query<Any, AnyArguments> = match event {
+            Created(event) => sqlx::query(include_str!("../../../sql/insert_issue_comment.sql"))
+                .bind(event.issue_comment_id().to_string())
+                .bind(event.issue_id().to_string())
+                .bind(event.text().to_string())
+                .bind(event.at().to_string()),
+            Deleted(event) => sqlx::query(include_str!("../../../sql/delete_issue_comment.sql"))
+                .bind(event.issue_comment_id().to_string()),
+            Updated(event) => sqlx::query(include_str!("../../../sql/update_issue_comment.sql"))
+                .bind(event.text().to_string())
+                .bind(event.at().to_string())
+                .bind(event.issue_comment_id().to_string()),
+        };
+        query.execute(&mut *query_transaction).await?;
         Ok(())
     }
 
@@ -292,24 +615,89 @@ impl SqliteQueryHandler {
         )
     }
 
-    pub async fn issue_list(&self) -> Result<Vec<QueryIssue>> {
+    pub async fn issue_graph(&self) -> Result<QueryIssueGraph> {
+        let nodes = self
+            .issue_list(&QueryIssueListFilter::open(), QueryIssueListSort::default())
+            .await?
+            .into_iter()
+            .map(|issue| QueryIssueIdWithTitle {
+                id: issue.id,
+                title: issue.title,
+            })
+            .collect::<Vec<QueryIssueIdWithTitle>>();
         let mut query_transaction = self.query_pool.begin().await?;
-        let issues: Vec<QueryIssue> =
-            sqlx::query_as(include_str!("../../../sql/select_issues.sql"))
+        let issue_block_links: Vec<QueryIssueBlockLink> =
+            sqlx::query_as(include_str!("../../../sql/select_issue_block_links.sql"))
                 .fetch_all(&mut query_transaction)
                 .await?;
-        Ok(issues)
+        let edges = issue_block_links
+            .into_iter()
+            .map(|issue_block_link| QueryIssueGraphEdge {
+                issue_id: issue_block_link.issue_id,
+                blocked_issue_id: issue_block_link.blocked_issue_id,
+            })
+            .collect::<Vec<QueryIssueGraphEdge>>();
+        Ok(QueryIssueGraph::new(nodes, edges))
+    }
+
+    pub async fn issue_list(
+        &self,
+        filter: &QueryIssueListFilter,
+        sort: QueryIssueListSort,
+    ) -> Result<Vec<QueryIssue>> {
+        let mut query_transaction = self.query_pool.begin().await?;
+        let (condition, values) = filter.to_sql();
+        let sql = format!(
+            "{}\nWHERE {}\nORDER BY {}",
+            include_str!("../../../sql/select_issues.sql").trim_end(),
+            condition,
+            sort.to_sql()
+        );
+        let mut query = sqlx::query_as(sql.as_str());
+        for value in values {
+            query = query.bind(value);
+        }
+        let rows: Vec<IssueRow> = query.fetch_all(&mut query_transaction).await?;
+        let label_rows: Vec<IssueLabelRow> =
+            sqlx::query_as(include_str!("../../../sql/select_issue_labels.sql"))
+                .fetch_all(&mut query_transaction)
+                .await?;
+        let mut labels: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for label_row in label_rows {
+            labels
+                .entry(label_row.issue_id)
+                .or_default()
+                .push(label_row.label);
+        }
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let issue_labels = labels.remove(&row.id).unwrap_or_default();
+                row.into_query_issue(issue_labels)
+            })
+            .collect())
     }
 
     pub async fn issue_view(&self, issue_id: &IssueId) -> Result<Option<QueryIssueWithLinks>> {
         let mut query_transaction = self.query_pool.begin().await?;
-        let issue: Option<QueryIssue> =
-            sqlx::query_as(include_str!("../../../sql/select_issue.sql"))
+        let row: Option<IssueRow> = sqlx::query_as(include_str!("../../../sql/select_issue.sql"))
+            .bind(issue_id.to_string())
+            .fetch_optional(&mut query_transaction)
+            .await?;
+        match row {
+            Some(row) => {
+                let label_rows: Vec<IssueLabelRow> = sqlx::query_as(include_str!(
+                    "../../../sql/select_issue_labels_by_issue_id.sql"
+                ))
                 .bind(issue_id.to_string())
-                .fetch_optional(&mut query_transaction)
+                .fetch_all(&mut query_transaction)
                 .await?;
-        match issue {
-            Some(issue) => {
+                let issue = row.into_query_issue(
+                    label_rows
+                        .into_iter()
+                        .map(|label_row| label_row.label)
+                        .collect(),
+                );
                 let blocks: Vec<QueryIssueBlockLink> = sqlx::query_as(include_str!(
                     "../../../sql/select_issue_block_links_by_issue_id.sql"
                 ))
@@ -335,6 +723,11 @@ impl SqliteQueryHandler {
                     title: issue.title,
                     due: issue.due,
                     description: issue.description,
+                    labels: issue.labels,
+                    priority: issue.priority,
+                    assignee: issue.assignee,
+                    recurrence: issue.recurrence,
+                    next_occurrence: issue.next_occurrence,
                     blocks: blocks
                         .into_iter()
                         .map(|issue_block_link| QueryIssueIdWithTitle {
@@ -356,96 +749,401 @@ impl SqliteQueryHandler {
         }
     }
 
-    async fn handle_event(
+    pub async fn issue_history(
         &self,
-        event_store_transaction: &mut Transaction<'_, Any>,
-        event: Event,
-    ) -> Result<()> {
-        let domain_event = DomainEvent::from_str(event.data.as_str())
-            .map_err(|e| Error::Unknown(e.to_string()))?;
-        match domain_event {
-            DomainEvent::Issue(_) => {
-                // TODO: improve
-                let events =
-                    event_store::find_events_by_event_stream_id_and_version_less_than_equal(
-                        event_store_transaction,
-                        event.stream_id,
-                        event.stream_seq,
-                    )
-                    .await?
-                    .into_iter()
-                    .map(|e| DomainEvent::from_str(e.data.as_str()))
-                    .collect::<Result<Vec<DomainEvent>, ParseDomainEventError>>()
-                    .map_err(|e| Error::Unknown(e.to_string()))?
-                    .into_iter()
-                    .filter_map(|e| e.issue())
-                    .collect::<Vec<IssueAggregateEvent>>();
-                let issue = IssueAggregate::from_events(&events)
-                    .map_err(|e| Error::Unknown(e.to_string()))?;
-                self.save_issue(issue).await?;
-            }
-            DomainEvent::IssueBlockLink(_) => {
-                // TODO: improve
-                let events =
-                    event_store::find_events_by_event_stream_id_and_version_less_than_equal(
-                        event_store_transaction,
-                        event.stream_id,
-                        event.stream_seq,
-                    )
-                    .await?
-                    .into_iter()
-                    .map(|e| DomainEvent::from_str(e.data.as_str()))
-                    .collect::<Result<Vec<DomainEvent>, ParseDomainEventError>>()
-                    .map_err(|e| Error::Unknown(e.to_string()))?
-                    .into_iter()
-                    .filter_map(|e| e.issue_block_link())
-                    .collect::<Vec<IssueBlockLinkAggregateEvent>>();
-                let issue_block_link = IssueBlockLinkAggregate::from_events(&events)
-                    .map_err(|e| Error::Unknown(e.to_string()))?;
-                self.save_issue_block_link(issue_block_link).await?;
-            }
-            DomainEvent::IssueComment(event) => {
-                use domain::aggregate::issue_comment::Event::*;
-                match event {
-                    Created(event) => {
-                        let mut query_transaction = self.query_pool.begin().await?;
-                        let query: Query<Any, AnyArguments> =
-                            sqlx::query(include_str!("../../../sql/insert_issue_comment.sql"))
-                                .bind(event.issue_comment_id().to_string())
-                                .bind(event.issue_id().to_string())
-                                .bind(event.text().to_string())
-                                .bind(event.at().to_string());
-                        query.execute(&mut query_transaction).await?;
-                        query_transaction.commit().await?;
+        issue_id: &IssueId,
+    ) -> Result<Option<Vec<QueryIssueHistoryEntry>>> {
+        let mut entries = vec![];
+        let mut issue_events: Vec<IssueAggregateEvent> = vec![];
+        let mut issue: Option<IssueAggregate> = None;
+        let mut issue_comment_ids: Vec<IssueCommentId> = vec![];
+        for (event_id, domain_event) in self.find_domain_events(issue_id).await? {
+            let at = domain_event.at().to_string();
+            let entry = match domain_event {
+                DomainEvent::Issue(event) => {
+                    if event.issue_id() != issue_id {
+                        continue;
                     }
-                    Deleted(event) => {
-                        let mut query_transaction = self.query_pool.begin().await?;
-                        let query: Query<Any, AnyArguments> =
-                            sqlx::query(include_str!("../../../sql/delete_issue_comment.sql"))
-                                .bind(event.issue_comment_id().to_string());
-                        query.execute(&mut query_transaction).await?;
-                        query_transaction.commit().await?;
+                    let event_type = match event {
+                        IssueAggregateEvent::AssigneeUpdated(_) => "issue_assignee_updated",
+                        IssueAggregateEvent::Cancelled(_) => "issue_cancelled",
+                        IssueAggregateEvent::Created(_) | IssueAggregateEvent::CreatedV2(_) => {
+                            "issue_created"
+                        }
+                        IssueAggregateEvent::DescriptionUpdated(_) => "issue_description_updated",
+                        IssueAggregateEvent::Finished(_) => "issue_finished",
+                        IssueAggregateEvent::LabelAdded(_) => "issue_label_added",
+                        IssueAggregateEvent::LabelRemoved(_) => "issue_label_removed",
+                        IssueAggregateEvent::PriorityUpdated(_) => "issue_priority_updated",
+                        IssueAggregateEvent::Recurred(_) => "issue_recurred",
+                        IssueAggregateEvent::RecurrenceUpdated(_) => "issue_recurrence_updated",
+                        IssueAggregateEvent::Reopened(_) => "issue_reopened",
+                        IssueAggregateEvent::Started(_) => "issue_started",
+                        IssueAggregateEvent::TitleUpdated(_) => "issue_title_updated",
+                        IssueAggregateEvent::Updated(_) => "issue_updated",
+                    };
+                    let version = event.version();
+                    issue_events.push(event);
+                    let updated = IssueAggregate::from_events(&issue_events)
+                        .map_err(|e| Error::Unknown(e.to_string()))?;
+                    let changes = Self::issue_changes(issue.as_ref(), &updated);
+                    issue = Some(updated);
+                    QueryIssueHistoryEntry {
+                        event_id: event_id.to_string(),
+                        event_type: event_type.to_string(),
+                        aggregate_id: issue_id.to_string(),
+                        version: u64::from(version),
+                        at,
+                        changes,
                     }
-                    Updated(event) => {
-                        let mut query_transaction = self.query_pool.begin().await?;
-                        let query: Query<Any, AnyArguments> =
-                            sqlx::query(include_str!("../../../sql/update_issue_comment.sql"))
-                                .bind(event.text().to_string())
-                                .bind(event.at().to_string())
-                                .bind(event.issue_comment_id().to_string());
-                        query.execute(&mut query_transaction).await?;
-                        query_transaction.commit().await?;
+                }
+                DomainEvent::IssueBlockLink(event) => {
+                    let (issue_block_link_id, version) = event.key();
+                    let (field, other_issue_id) = if issue_block_link_id.issue_id() == issue_id {
+                        ("blocks", issue_block_link_id.blocked_issue_id())
+                    } else if issue_block_link_id.blocked_issue_id() == issue_id {
+                        ("is_blocked_by", issue_block_link_id.issue_id())
+                    } else {
+                        continue;
+                    };
+                    let (event_type, change) = match event {
+                        IssueBlockLinkAggregateEvent::Blocked(_) => (
+                            "issue_blocked",
+                            QueryIssueHistoryChange::new(
+                                field,
+                                None,
+                                Some(other_issue_id.to_string()),
+                            ),
+                        ),
+                        IssueBlockLinkAggregateEvent::Unblocked(_) => (
+                            "issue_unblocked",
+                            QueryIssueHistoryChange::new(
+                                field,
+                                Some(other_issue_id.to_string()),
+                                None,
+                            ),
+                        ),
+                    };
+                    QueryIssueHistoryEntry {
+                        event_id: event_id.to_string(),
+                        event_type: event_type.to_string(),
+                        aggregate_id: issue_block_link_id.to_string(),
+                        version: u64::from(version),
+                        at,
+                        changes: vec![change],
                     }
                 }
+                DomainEvent::IssueComment(event) => {
+                    use domain::aggregate::issue_comment::Event::*;
+                    let issue_comment_id = event.issue_comment_id().clone();
+                    let version = event.version();
+                    let (event_type, change) = match event {
+                        Created(event) => {
+                            if event.issue_id() != issue_id {
+                                continue;
+                            }
+                            issue_comment_ids.push(issue_comment_id.clone());
+                            (
+                                "issue_comment_created",
+                                QueryIssueHistoryChange::new(
+                                    "comment",
+                                    None,
+                                    Some(event.text().to_string()),
+                                ),
+                            )
+                        }
+                        Deleted(_) => {
+                            if !issue_comment_ids.contains(&issue_comment_id) {
+                                continue;
+                            }
+                            (
+                                "issue_comment_deleted",
+                                QueryIssueHistoryChange::new(
+                                    "comment",
+                                    Self::last_comment_text(&entries, &issue_comment_id),
+                                    None,
+                                ),
+                            )
+                        }
+                        Updated(event) => {
+                            if !issue_comment_ids.contains(&issue_comment_id) {
+                                continue;
+                            }
+                            (
+                                "issue_comment_updated",
+                                QueryIssueHistoryChange::new(
+                                    "comment",
+                                    Self::last_comment_text(&entries, &issue_comment_id),
+                                    Some(event.text().to_string()),
+                                ),
+                            )
+                        }
+                    };
+                    QueryIssueHistoryEntry {
+                        event_id: event_id.to_string(),
+                        event_type: event_type.to_string(),
+                        aggregate_id: issue_comment_id.to_string(),
+                        version: u64::from(version),
+                        at,
+                        changes: vec![change],
+                    }
+                }
+            };
+            entries.push(entry);
+        }
+        Ok(issue.map(|_| entries))
+    }
+
+    fn issue_changes(
+        old: Option<&IssueAggregate>,
+        new: &IssueAggregate,
+    ) -> Vec<QueryIssueHistoryChange> {
+        let fields = |issue: &IssueAggregate| -> Vec<(&'static str, Option<String>)> {
+            vec![
+                ("title", Some(issue.title().to_string())),
+                ("status", Some(issue.status().to_string())),
+                ("resolution", issue.resolution().map(|r| r.to_string())),
+                ("due", issue.due().map(|d| d.to_string())),
+                ("description", Some(issue.description().to_string())),
+                (
+                    "labels",
+                    Some(
+                        issue
+                            .labels()
+                            .iter()
+                            .map(|l| l.to_string())
+                            .collect::<Vec<String>>()
+                            .join(","),
+                    )
+                    .filter(|labels| !labels.is_empty()),
+                ),
+                ("priority", issue.priority().map(|p| p.to_string())),
+                ("assignee", issue.assignee().map(|a| a.to_string())),
+                ("recurrence", issue.recurrence().map(|r| r.to_string())),
+                (
+                    "next_occurrence",
+                    issue.next_occurrence().map(|i| i.to_string()),
+                ),
+            ]
+        };
+        let new_fields = fields(new);
+        let old_fields = match old {
+            Some(old) => fields(old),
+            None => new_fields
+                .iter()
+                .map(|(field, _)| (*field, None))
+                .collect::<Vec<(&'static str, Option<String>)>>(),
+        };
+        old_fields
+            .into_iter()
+            .zip(new_fields)
+            .filter(|((_, old_value), (_, new_value))| old_value != new_value)
+            .map(|((field, old_value), (_, new_value))| {
+                QueryIssueHistoryChange::new(field, old_value, new_value)
+            })
+            .collect()
+    }
+
+    fn last_comment_text(
+        entries: &[QueryIssueHistoryEntry],
+        issue_comment_id: &IssueCommentId,
+    ) -> Option<String> {
+        let aggregate_id = issue_comment_id.to_string();
+        entries
+            .iter()
+            .rev()
+            .find(|entry| entry.aggregate_id == aggregate_id)
+            .and_then(|entry| entry.changes.first())
+            .and_then(|change| change.new.clone())
+    }
+
+    pub async fn issue_view_by_instant(
+        &self,
+        issue_id: &IssueId,
+        at: Instant,
+    ) -> Result<Option<QueryIssueWithLinks>> {
+        let domain_events = self
+            .find_domain_events(issue_id)
+            .await?
+            .into_iter()
+            .map(|(_, domain_event)| domain_event)
+            .filter(|domain_event| domain_event.at() <= at)
+            .collect::<Vec<DomainEvent>>();
+        Self::replay_issue_view(issue_id, domain_events)
+    }
+
+    pub async fn issue_view_by_version(
+        &self,
+        issue_id: &IssueId,
+        version: Version,
+    ) -> Result<Option<QueryIssueWithLinks>> {
+        let mut domain_events = self
+            .find_domain_events(issue_id)
+            .await?
+            .into_iter()
+            .map(|(_, domain_event)| domain_event)
+            .collect::<Vec<DomainEvent>>();
+        let position = domain_events
+            .iter()
+            .position(|domain_event| match domain_event {
+                DomainEvent::Issue(event) => {
+                    event.issue_id() == issue_id && event.version() == version
+                }
+                DomainEvent::IssueBlockLink(_) | DomainEvent::IssueComment(_) => false,
+            });
+        match position {
+            Some(position) => {
+                domain_events.truncate(position + 1);
+                Self::replay_issue_view(issue_id, domain_events)
             }
+            None => Ok(None),
         }
-        Ok(())
+    }
+
+    // reads the streams of the issue, its block links, the issues on the other side of them and its comments
+    async fn find_domain_events(&self, issue_id: &IssueId) -> Result<Vec<(EventId, DomainEvent)>> {
+        let event_stream_ids = self
+            .connection_pool
+            .find_issue_view_event_stream_ids(issue_id)
+            .await
+            .map_err(|e| Error::Unknown(e.to_string()))?;
+        if event_stream_ids.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut event_store_transaction = self.event_store_pool.begin().await?;
+        let events = event_store::find_events_by_event_stream_ids(
+            &mut event_store_transaction,
+            &event_stream_ids,
+        )
+        .await?;
+        event_store_transaction.commit().await?;
+        events
+            .into_iter()
+            .map(|e| {
+                DomainEvent::from_str(e.data.as_str()).map(|domain_event| (e.id, domain_event))
+            })
+            .collect::<Result<Vec<(EventId, DomainEvent)>, ParseDomainEventError>>()
+            .map_err(|e| Error::Unknown(e.to_string()))
+    }
+
+    // replays the events (ordered by the event store) in the same way as `handle_event`
+    fn replay_issue_view(
+        issue_id: &IssueId,
+        domain_events: Vec<DomainEvent>,
+    ) -> Result<Option<QueryIssueWithLinks>> {
+        let mut issue_events: BTreeMap<IssueId, Vec<IssueAggregateEvent>> = BTreeMap::new();
+        let mut issue_block_link_events: BTreeMap<
+            IssueBlockLinkId,
+            Vec<IssueBlockLinkAggregateEvent>,
+        > = BTreeMap::new();
+        let mut comments: Vec<QueryIssueComment> = vec![];
+        for domain_event in domain_events {
+            match domain_event {
+                DomainEvent::Issue(event) => {
+                    issue_events
+                        .entry(event.issue_id().clone())
+                        .or_default()
+                        .push(event);
+                }
+                DomainEvent::IssueBlockLink(event) => {
+                    issue_block_link_events
+                        .entry(event.key().0.clone())
+                        .or_default()
+                        .push(event);
+                }
+                DomainEvent::IssueComment(event) => {
+                    use domain::aggregate::issue_comment::Event::*;
+                    match event {
+                        Created(event) => {
+                            if event.issue_id() == issue_id {
+                                comments.push(QueryIssueComment {
+                                    id: event.issue_comment_id().to_string(),
+                                    text: event.text().to_string(),
+                                    created_at: event.at().to_string(),
+                                    updated_at: None,
+                                });
+                            }
+                        }
+                        Deleted(event) => {
+                            let id = event.issue_comment_id().to_string();
+                            comments.retain(|comment| comment.id != id);
+                        }
+                        Updated(event) => {
+                            let id = event.issue_comment_id().to_string();
+                            if let Some(comment) =
+                                comments.iter_mut().find(|comment| comment.id == id)
+                            {
+                                comment.text = event.text().to_string();
+                                comment.updated_at = Some(event.at().to_string());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let find_issue = |issue_id: &IssueId| -> Result<Option<IssueAggregate>> {
+            issue_events
+                .get(issue_id)
+                .map(|events| IssueAggregate::from_events(events))
+                .transpose()
+                .map_err(|e| Error::Unknown(e.to_string()))
+        };
+        let find_issue_title = |issue_id: &IssueId| -> Result<String> {
+            Ok(find_issue(issue_id)?
+                .ok_or_else(|| Error::Unknown("no issue".to_string()))?
+                .title()
+                .to_string())
+        };
+
+        let issue = match find_issue(issue_id)? {
+            Some(issue) => issue,
+            None => return Ok(None),
+        };
+        let mut blocks = vec![];
+        let mut is_blocked_by = vec![];
+        for events in issue_block_link_events.values() {
+            let issue_block_link = IssueBlockLinkAggregate::from_events(events)
+                .map_err(|e| Error::Unknown(e.to_string()))?;
+            if !issue_block_link.is_blocked() {
+                continue;
+            }
+            let id = issue_block_link.id();
+            if id.issue_id() == issue_id {
+                blocks.push(QueryIssueIdWithTitle {
+                    id: id.blocked_issue_id().to_string(),
+                    title: find_issue_title(id.blocked_issue_id())?,
+                });
+            } else if id.blocked_issue_id() == issue_id {
+                is_blocked_by.push(QueryIssueIdWithTitle {
+                    id: id.issue_id().to_string(),
+                    title: find_issue_title(id.issue_id())?,
+                });
+            }
+        }
+
+        Ok(Some(QueryIssueWithLinks {
+            id: issue.id().to_string(),
+            resolution: issue.resolution().map(|s| s.to_string()),
+            status: issue.status().to_string(),
+            title: issue.title().to_string(),
+            due: issue.due().map(|d| d.to_string()),
+            description: issue.description().to_string(),
+            labels: issue.labels().iter().map(|l| l.to_string()).collect(),
+            priority: issue.priority().map(|p| p.to_string()),
+            assignee: issue.assignee().map(|a| a.to_string()),
+            recurrence: issue.recurrence().map(|r| r.to_string()),
+            next_occurrence: issue.next_occurrence().map(|i| i.to_string()),
+            blocks,
+            is_blocked_by,
+            comments,
+        }))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{fs, path::PathBuf};
+    use std::path::{Path, PathBuf};
 
     use anyhow::Context;
     use domain::{
@@ -453,265 +1151,671 @@ mod tests {
         IssueCommentId,
     };
     use limited_date_time::Instant;
+    use sqlx::migrate::MigrateDatabase;
     use use_case::IssueCommentRepository;
 
     use super::*;
+    use crate::QueryIssueListSortKey;
 
-    #[tokio::test]
-    async fn issue_test() -> anyhow::Result<()> {
-        let temp_dir = tempfile::tempdir()?;
-        let sqlite_dir = temp_dir.path().join("its");
-        let data_dir = sqlite_dir;
-        if !data_dir.exists() {
-            fs::create_dir_all(data_dir.as_path())?;
-        }
+    // (command, query) connection uris: SQLite files and, if `ITS_TEST_POSTGRES_URL` is set, new PostgreSQL databases
+    async fn test_connection_uris(dir: &Path) -> anyhow::Result<Vec<(String, String)>> {
         let new_connection_uri = |path: PathBuf| -> anyhow::Result<String> {
             Ok(format!(
                 "sqlite:{}?mode=rwc",
                 path.to_str().context("path is not utf-8")?
             ))
         };
-        let command_connection_uri = new_connection_uri(data_dir.join("command.sqlite"))?;
-        let query_connection_uri = new_connection_uri(data_dir.join("query.sqlite"))?;
-
-        let connection_pool = RdbConnectionPool::new(&command_connection_uri).await?;
-
-        let issue = IssueAggregate::new(
-            Instant::now(),
-            "123".parse()?,
-            "title".parse()?,
-            Some("2021-02-03T04:05:06Z".parse()?),
-            "desc1".parse()?,
-        )?;
-
-        let issue_repository = connection_pool.issue_repository()?;
-        issue_repository.save(&issue).await?;
-        let issue_block_link_repository = connection_pool.issue_block_link_repository()?;
-
-        let query_handler = SqliteQueryHandler::new(
-            &query_connection_uri,
-            connection_pool,
-            Arc::new(Mutex::new(issue_repository)),
-            Arc::new(Mutex::new(issue_block_link_repository)),
-        )
-        .await?;
+        let mut connection_uris = vec![(
+            new_connection_uri(dir.join("command.sqlite"))?,
+            new_connection_uri(dir.join("query.sqlite"))?,
+        )];
+        if let Ok(url) = std::env::var("ITS_TEST_POSTGRES_URL") {
+            let new_connection_uri = || async {
+                let connection_uri = format!(
+                    "{}/its_test_{}",
+                    url.trim_end_matches('/'),
+                    ulid::Ulid::new().to_string().to_lowercase()
+                );
+                Any::create_database(&connection_uri).await?;
+                anyhow::Ok(connection_uri)
+            };
+            connection_uris.push((new_connection_uri().await?, new_connection_uri().await?));
+        }
+        Ok(connection_uris)
+    }
 
-        query_handler.save_issue(issue).await?;
-
-        let issues = query_handler.issue_list().await?;
-        assert_eq!(1, issues.len());
-        let issue = issues[0].clone();
-        assert_eq!("123", issue.id);
-        assert_eq!(None, issue.resolution);
-        assert_eq!("todo", issue.status);
-        assert_eq!("title", issue.title);
-        assert_eq!(Some("2021-02-03T04:05:06Z".to_string()), issue.due);
-
-        let found = query_handler.issue_view(&"123".parse()?).await?;
-        assert_eq!(
-            Some(QueryIssueWithLinks {
-                id: "123".to_string(),
-                resolution: None,
-                status: "todo".to_string(),
-                title: "title".to_string(),
-                due: Some("2021-02-03T04:05:06Z".to_string()),
-                description: "desc1".to_string(),
-                blocks: vec![],
-                is_blocked_by: vec![],
-                comments: vec![]
-            }),
-            found
-        );
+    #[tokio::test]
+    async fn issue_test() -> anyhow::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        for (command_connection_uri, query_connection_uri) in
+            test_connection_uris(temp_dir.path()).await?
+        {
+            let connection_pool = RdbConnectionPool::new(&command_connection_uri).await?;
+
+            let issue = IssueAggregate::new(
+                Instant::now(),
+                "123".parse()?,
+                "title".parse()?,
+                Some("2021-02-03T04:05:06Z".parse()?),
+                "desc1".parse()?,
+            )?;
+
+            let issue_repository = connection_pool.issue_repository()?;
+            issue_repository.save(&issue).await?;
+            let issue_block_link_repository = connection_pool.issue_block_link_repository()?;
+
+            let query_handler = SqliteQueryHandler::new(
+                &query_connection_uri,
+                connection_pool,
+                Arc::new(Mutex::new(issue_repository)),
+                Arc::new(Mutex::new(issue_block_link_repository)),
+            )
+            .await?;
+
+            query_handler.save_issue(issue).await?;
+
+            let issues = query_handler
+                .issue_list(
+                    &QueryIssueListFilter::default(),
+                    QueryIssueListSort::default(),
+                )
+                .await?;
+            assert_eq!(1, issues.len());
+            let issue = issues[0].clone();
+            assert_eq!("123", issue.id);
+            assert_eq!(None, issue.resolution);
+            assert_eq!("todo", issue.status);
+            assert_eq!("title", issue.title);
+            assert_eq!(Some("2021-02-03T04:05:06Z".to_string()), issue.due);
+
+            let found = query_handler.issue_view(&"123".parse()?).await?;
+            assert_eq!(
+                Some(QueryIssueWithLinks {
+                    id: "123".to_string(),
+                    resolution: None,
+                    status: "todo".to_string(),
+                    title: "title".to_string(),
+                    due: Some("2021-02-03T04:05:06Z".to_string()),
+                    description: "desc1".to_string(),
+                    labels: vec![],
+                    priority: None,
+                    assignee: None,
+                    recurrence: None,
+                    next_occurrence: None,
+                    blocks: vec![],
+                    is_blocked_by: vec![],
+                    comments: vec![]
+                }),
+                found
+            );
+        }
         Ok(())
     }
 
     #[tokio::test]
-    async fn issue_block_link_test() -> anyhow::Result<()> {
+    async fn issue_list_filter_test() -> anyhow::Result<()> {
         let temp_dir = tempfile::tempdir()?;
-        let sqlite_dir = temp_dir.path().join("its");
-        let data_dir = sqlite_dir;
-        if !data_dir.exists() {
-            fs::create_dir_all(data_dir.as_path())?;
+        for (command_connection_uri, query_connection_uri) in
+            test_connection_uris(temp_dir.path()).await?
+        {
+            let connection_pool = RdbConnectionPool::new(&command_connection_uri).await?;
+
+            let issue1 = IssueAggregate::new(
+                Instant::now(),
+                "1".parse()?,
+                "title1".parse()?,
+                None,
+                "desc1".parse()?,
+            )?
+            .add_label("bug".parse()?, Instant::now())?
+            .add_label("ui".parse()?, Instant::now())?
+            .update_priority(Some("high".parse()?), Instant::now())?
+            .update_assignee(Some("bouzuya".parse()?), Instant::now())?;
+            let issue2 = IssueAggregate::new(
+                Instant::now(),
+                "2".parse()?,
+                "title2".parse()?,
+                Some("2026-10-31T12:00:00Z".parse()?),
+                "desc2".parse()?,
+            )?
+            .add_label("bug".parse()?, Instant::now())?;
+
+            let issue_repository = connection_pool.issue_repository()?;
+            issue_repository.save(&issue1).await?;
+            issue_repository.save(&issue2).await?;
+            let issue_block_link_repository = connection_pool.issue_block_link_repository()?;
+
+            let query_handler = SqliteQueryHandler::new(
+                &query_connection_uri,
+                connection_pool,
+                Arc::new(Mutex::new(issue_repository)),
+                Arc::new(Mutex::new(issue_block_link_repository)),
+            )
+            .await?;
+
+            query_handler.save_issue(issue1).await?;
+            query_handler.save_issue(issue2).await?;
+
+            let issue_ids = |issues: Vec<QueryIssue>| -> Vec<String> {
+                issues.into_iter().map(|issue| issue.id).collect()
+            };
+            let issues = query_handler
+                .issue_list(
+                    &QueryIssueListFilter::default(),
+                    QueryIssueListSort::default(),
+                )
+                .await?;
+            assert_eq!(vec!["bug".to_string(), "ui".to_string()], issues[0].labels);
+            assert_eq!(Some("high".to_string()), issues[0].priority);
+            assert_eq!(Some("bouzuya".to_string()), issues[0].assignee);
+            assert_eq!(vec!["1", "2"], issue_ids(issues));
+            for (query, expected) in [
+                ("label:bug", vec!["1", "2"]),
+                ("label:bug label:ui", vec!["1"]),
+                ("-label:ui", vec!["2"]),
+                ("priority:high,urgent", vec!["1"]),
+                ("assignee:someone", vec![]),
+                ("status:todo is:open", vec!["1", "2"]),
+                ("is:closed", vec![]),
+                ("is:blocked", vec![]),
+                ("due:<2026-11-01", vec!["2"]),
+                ("due:2026-10-31", vec!["2"]),
+                ("due:>2026-10-31", vec![]),
+                ("due:none", vec!["1"]),
+                ("TITLE1", vec!["1"]),
+                (r#""title" -title2"#, vec!["1"]),
+            ] {
+                let issues = query_handler
+                    .issue_list(&query.parse()?, QueryIssueListSort::default())
+                    .await?;
+                assert_eq!(expected, issue_ids(issues), "{}", query);
+            }
+            let issues = query_handler
+                .issue_list(
+                    &QueryIssueListFilter::default(),
+                    QueryIssueListSort {
+                        key: QueryIssueListSortKey::Due,
+                        descending: false,
+                    },
+                )
+                .await?;
+            assert_eq!(vec!["2", "1"], issue_ids(issues));
+            let issues = query_handler
+                .issue_list(
+                    &QueryIssueListFilter::default(),
+                    QueryIssueListSort {
+                        key: QueryIssueListSortKey::Id,
+                        descending: true,
+                    },
+                )
+                .await?;
+            assert_eq!(vec!["2", "1"], issue_ids(issues));
+
+            let found = query_handler
+                .issue_view(&"1".parse()?)
+                .await?
+                .context("issue not found")?;
+            assert_eq!(vec!["bug".to_string(), "ui".to_string()], found.labels);
+            assert_eq!(Some("high".to_string()), found.priority);
+            assert_eq!(Some("bouzuya".to_string()), found.assignee);
         }
-        let new_connection_uri = |path: PathBuf| -> anyhow::Result<String> {
-            Ok(format!(
-                "sqlite:{}?mode=rwc",
-                path.to_str().context("path is not utf-8")?
-            ))
-        };
-        let command_connection_uri = new_connection_uri(data_dir.join("command.sqlite"))?;
-        let query_connection_uri = new_connection_uri(data_dir.join("query.sqlite"))?;
-        let connection_pool = RdbConnectionPool::new(&command_connection_uri).await?;
-
-        let issue1 = IssueAggregate::new(
-            Instant::now(),
-            "1".parse()?,
-            "title1".parse()?,
-            None,
-            "desc1".parse()?,
-        )?;
-        let issue2 = IssueAggregate::new(
-            Instant::now(),
-            "2".parse()?,
-            "title2".parse()?,
-            None,
-            "desc2".parse()?,
-        )?;
-        let issue3 = IssueAggregate::new(
-            Instant::now(),
-            "3".parse()?,
-            "title3".parse()?,
-            None,
-            "desc3".parse()?,
-        )?;
-        let issue_block_link1 = issue1.block(issue2.clone(), Instant::now())?;
-        let issue_block_link2 = issue2.block(issue3.clone(), Instant::now())?;
-
-        let issue_repository = connection_pool.issue_repository()?;
-        issue_repository.save(&issue1).await?;
-        issue_repository.save(&issue2).await?;
-        issue_repository.save(&issue3).await?;
-        let issue_block_link_repository = connection_pool.issue_block_link_repository()?;
-
-        let query_handler = SqliteQueryHandler::new(
-            &query_connection_uri,
-            connection_pool,
-            Arc::new(Mutex::new(issue_repository)),
-            Arc::new(Mutex::new(issue_block_link_repository)),
-        )
-        .await?;
+        Ok(())
+    }
 
-        query_handler.save_issue(issue1).await?;
-        query_handler.save_issue(issue2).await?;
-        query_handler.save_issue(issue3).await?;
-        query_handler
-            .save_issue_block_link(issue_block_link1)
+    #[tokio::test]
+    async fn issue_block_link_test() -> anyhow::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        for (command_connection_uri, query_connection_uri) in
+            test_connection_uris(temp_dir.path()).await?
+        {
+            let connection_pool = RdbConnectionPool::new(&command_connection_uri).await?;
+
+            let issue1 = IssueAggregate::new(
+                Instant::now(),
+                "1".parse()?,
+                "title1".parse()?,
+                None,
+                "desc1".parse()?,
+            )?;
+            let issue2 = IssueAggregate::new(
+                Instant::now(),
+                "2".parse()?,
+                "title2".parse()?,
+                None,
+                "desc2".parse()?,
+            )?;
+            let issue3 = IssueAggregate::new(
+                Instant::now(),
+                "3".parse()?,
+                "title3".parse()?,
+                None,
+                "desc3".parse()?,
+            )?;
+            let issue_block_link1 = issue1.block(issue2.clone(), Instant::now())?;
+            let issue_block_link2 = issue2.block(issue3.clone(), Instant::now())?;
+
+            let issue_repository = connection_pool.issue_repository()?;
+            issue_repository.save(&issue1).await?;
+            issue_repository.save(&issue2).await?;
+            issue_repository.save(&issue3).await?;
+            let issue_block_link_repository = connection_pool.issue_block_link_repository()?;
+
+            let query_handler = SqliteQueryHandler::new(
+                &query_connection_uri,
+                connection_pool,
+                Arc::new(Mutex::new(issue_repository)),
+                Arc::new(Mutex::new(issue_block_link_repository)),
+            )
             .await?;
-        query_handler
-            .save_issue_block_link(issue_block_link2)
+
+            query_handler.save_issue(issue1).await?;
+            query_handler.save_issue(issue2).await?;
+            query_handler.save_issue(issue3).await?;
+            query_handler
+                .save_issue_block_link(issue_block_link1)
+                .await?;
+            query_handler
+                .save_issue_block_link(issue_block_link2)
+                .await?;
+
+            let found = query_handler.issue_view(&"2".parse()?).await?;
+            assert_eq!(
+                Some(QueryIssueWithLinks {
+                    id: "2".to_string(),
+                    resolution: None,
+                    status: "todo".to_string(),
+                    title: "title2".to_string(),
+                    due: None,
+                    description: "desc2".to_string(),
+                    labels: vec![],
+                    priority: None,
+                    assignee: None,
+                    recurrence: None,
+                    next_occurrence: None,
+                    blocks: vec![QueryIssueIdWithTitle {
+                        id: "3".to_string(),
+                        title: "title3".to_string(),
+                    }],
+                    is_blocked_by: vec![QueryIssueIdWithTitle {
+                        id: "1".to_string(),
+                        title: "title1".to_string(),
+                    }],
+                    comments: vec![]
+                }),
+                found
+            );
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn issue_comment_test() -> anyhow::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        for (command_connection_uri, query_connection_uri) in
+            test_connection_uris(temp_dir.path()).await?
+        {
+            let connection_pool = RdbConnectionPool::new(&command_connection_uri).await?;
+
+            let issue1 = IssueAggregate::new(
+                Instant::now(),
+                "1".parse()?,
+                "title1".parse()?,
+                None,
+                "desc1".parse()?,
+            )?;
+            let issue_repository = connection_pool.issue_repository()?;
+            issue_repository.save(&issue1).await?;
+
+            let issue_comment1_created_at = Instant::now();
+            let issue_comment1_id = IssueCommentId::generate();
+            let issue_comment1 = IssueCommentAggregate::new(
+                issue_comment1_created_at,
+                issue_comment1_id.clone(),
+                issue1.id().clone(),
+                IssueCommentText::from_str("text1")?,
+            )?;
+            let issue_comment_repository = connection_pool.issue_comment_repository()?;
+            issue_comment_repository.save(&issue_comment1).await?;
+
+            let issue_comment2_created_at = Instant::now();
+            let issue_comment2_id = IssueCommentId::generate();
+            let issue_comment2 = IssueCommentAggregate::new(
+                issue_comment2_created_at,
+                issue_comment2_id.clone(),
+                issue1.id().clone(),
+                IssueCommentText::from_str("text1")?,
+            )?;
+            let issue_comment2_updated_at = Instant::now();
+            let issue_comment2 =
+                issue_comment2.update("text2".parse()?, issue_comment2_updated_at)?;
+            issue_comment_repository.save(&issue_comment2).await?;
+
+            let issue_comment3 = IssueCommentAggregate::new(
+                Instant::now(),
+                IssueCommentId::generate(),
+                issue1.id().clone(),
+                IssueCommentText::from_str("text1")?,
+            )?;
+            let issue_comment3 = issue_comment3.delete(Instant::now())?;
+            issue_comment_repository.save(&issue_comment3).await?;
+
+            let query_handler = SqliteQueryHandler::new(
+                &query_connection_uri,
+                connection_pool.clone(),
+                Arc::new(Mutex::new(issue_repository)),
+                Arc::new(Mutex::new(connection_pool.issue_block_link_repository()?)),
+            )
             .await?;
+            query_handler.update_database().await?;
 
-        let found = query_handler.issue_view(&"2".parse()?).await?;
-        assert_eq!(
-            Some(QueryIssueWithLinks {
-                id: "2".to_string(),
-                resolution: None,
-                status: "todo".to_string(),
-                title: "title2".to_string(),
-                due: None,
-                description: "desc2".to_string(),
-                blocks: vec![QueryIssueIdWithTitle {
-                    id: "3".to_string(),
-                    title: "title3".to_string(),
-                }],
-                is_blocked_by: vec![QueryIssueIdWithTitle {
+            let found = query_handler.issue_view(&"1".parse()?).await?;
+            assert_eq!(
+                Some(QueryIssueWithLinks {
                     id: "1".to_string(),
+                    resolution: None,
+                    status: "todo".to_string(),
                     title: "title1".to_string(),
-                }],
-                comments: vec![]
-            }),
-            found
-        );
+                    due: None,
+                    description: "desc1".to_string(),
+                    labels: vec![],
+                    priority: None,
+                    assignee: None,
+                    recurrence: None,
+                    next_occurrence: None,
+                    blocks: vec![],
+                    is_blocked_by: vec![],
+                    comments: vec![
+                        QueryIssueComment {
+                            id: issue_comment1_id.to_string(),
+                            text: "text1".to_string(),
+                            created_at: issue_comment1_created_at.to_string(),
+                            updated_at: None
+                        },
+                        QueryIssueComment {
+                            id: issue_comment2_id.to_string(),
+                            text: "text2".to_string(),
+                            created_at: issue_comment2_created_at.to_string(),
+                            updated_at: Some(issue_comment2_updated_at.to_string()),
+                        }
+                    ]
+                }),
+                found
+            );
+        }
         Ok(())
     }
 
     #[tokio::test]
-    async fn issue_comment_test() -> anyhow::Result<()> {
+    async fn projection_test() -> anyhow::Result<()> {
         let temp_dir = tempfile::tempdir()?;
-        let sqlite_dir = temp_dir.path().join("its");
-        let data_dir = sqlite_dir;
-        if !data_dir.exists() {
-            fs::create_dir_all(data_dir.as_path())?;
+        for (command_connection_uri, query_connection_uri) in
+            test_connection_uris(temp_dir.path()).await?
+        {
+            let connection_pool = RdbConnectionPool::new(&command_connection_uri).await?;
+            let issue_repository = connection_pool.issue_repository()?;
+            let issue_comment_repository = connection_pool.issue_comment_repository()?;
+            let query_handler = SqliteQueryHandler::new(
+                &query_connection_uri,
+                connection_pool.clone(),
+                Arc::new(Mutex::new(connection_pool.issue_repository()?)),
+                Arc::new(Mutex::new(connection_pool.issue_block_link_repository()?)),
+            )
+            .await?;
+            let lags = |statuses: Vec<QueryProjectionStatus>| -> Vec<(String, usize)> {
+                statuses.into_iter().map(|s| (s.name, s.lag)).collect()
+            };
+
+            let issue = IssueAggregate::new(
+                Instant::now(),
+                "1".parse()?,
+                "title1".parse()?,
+                None,
+                "desc1".parse()?,
+            )?;
+            let updated = issue.update_title("title2".parse()?, Instant::now())?;
+            issue_repository.save(&updated).await?;
+            let issue_comment = IssueCommentAggregate::new(
+                Instant::now(),
+                IssueCommentId::generate(),
+                "1".parse()?,
+                IssueCommentText::from_str("text1")?,
+            )?;
+            issue_comment_repository.save(&issue_comment).await?;
+            assert_eq!(
+                vec![
+                    ("issue_block_links".to_string(), 0),
+                    ("issue_comments".to_string(), 1),
+                    ("issues".to_string(), 2),
+                ],
+                lags(query_handler.status().await?)
+            );
+
+            query_handler
+                .handle(&IssueManagementContextEvent::IssueUpdated {
+                    issue_id: "1".parse()?,
+                })
+                .await?;
+            let statuses = query_handler.status().await?;
+            assert_eq!(statuses[2].event_id, statuses[2].last_event_id);
+            assert_eq!(
+                vec![
+                    ("issue_block_links".to_string(), 0),
+                    ("issue_comments".to_string(), 1),
+                    ("issues".to_string(), 0),
+                ],
+                lags(statuses)
+            );
+            let found = query_handler
+                .issue_view(&"1".parse()?)
+                .await?
+                .context("issue not found")?;
+            assert_eq!("title2", found.title);
+            assert!(found.comments.is_empty());
+
+            query_handler.update_database().await?;
+            assert_eq!(
+                vec![
+                    ("issue_block_links".to_string(), 0),
+                    ("issue_comments".to_string(), 0),
+                    ("issues".to_string(), 0),
+                ],
+                lags(query_handler.status().await?)
+            );
+            let found = query_handler
+                .issue_view(&"1".parse()?)
+                .await?
+                .context("issue not found")?;
+            assert_eq!(1, found.comments.len());
+
+            query_handler.reset_database().await?;
+            let found = query_handler
+                .issue_view(&"1".parse()?)
+                .await?
+                .context("issue not found")?;
+            assert_eq!("title2", found.title);
+            assert_eq!(1, found.comments.len());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn issue_view_by_version_and_instant_test() -> anyhow::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        for (command_connection_uri, query_connection_uri) in
+            test_connection_uris(temp_dir.path()).await?
+        {
+            let connection_pool = RdbConnectionPool::new(&command_connection_uri).await?;
+
+            let issue1 = IssueAggregate::new(
+                Instant::from_str("2021-02-03T04:05:06Z")?,
+                "1".parse()?,
+                "title1".parse()?,
+                None,
+                "desc1".parse()?,
+            )?;
+            let issue2 = IssueAggregate::new(
+                Instant::from_str("2021-02-03T04:05:07Z")?,
+                "2".parse()?,
+                "title2".parse()?,
+                None,
+                "desc2".parse()?,
+            )?;
+            let issue_block_link =
+                issue1.block(issue2.clone(), Instant::from_str("2021-02-03T04:05:08Z")?)?;
+            let issue1 = issue1.update_title(
+                "title1b".parse()?,
+                Instant::from_str("2021-02-03T04:05:09Z")?,
+            )?;
+            let issue_repository = connection_pool.issue_repository()?;
+            issue_repository.save(&issue1).await?;
+            issue_repository.save(&issue2).await?;
+            let issue_block_link_repository = connection_pool.issue_block_link_repository()?;
+            issue_block_link_repository.save(&issue_block_link).await?;
+            let issue_comment = IssueCommentAggregate::new(
+                Instant::from_str("2021-02-03T04:05:10Z")?,
+                IssueCommentId::generate(),
+                issue1.id().clone(),
+                IssueCommentText::from_str("text1")?,
+            )?;
+            let issue_comment_repository = connection_pool.issue_comment_repository()?;
+            issue_comment_repository.save(&issue_comment).await?;
+
+            let query_handler = SqliteQueryHandler::new(
+                &query_connection_uri,
+                connection_pool,
+                Arc::new(Mutex::new(issue_repository)),
+                Arc::new(Mutex::new(issue_block_link_repository)),
+            )
+            .await?;
+
+            let found = query_handler
+                .issue_view_by_version(&"1".parse()?, Version::from(1_u64))
+                .await?;
+            assert_eq!(
+                Some(QueryIssueWithLinks {
+                    id: "1".to_string(),
+                    resolution: None,
+                    status: "todo".to_string(),
+                    title: "title1".to_string(),
+                    due: None,
+                    description: "desc1".to_string(),
+                    labels: vec![],
+                    priority: None,
+                    assignee: None,
+                    recurrence: None,
+                    next_occurrence: None,
+                    blocks: vec![],
+                    is_blocked_by: vec![],
+                    comments: vec![]
+                }),
+                found
+            );
+            let found = query_handler
+                .issue_view_by_version(&"1".parse()?, Version::from(3_u64))
+                .await?;
+            assert_eq!(None, found);
+
+            let found = query_handler
+                .issue_view_by_instant(&"1".parse()?, Instant::from_str("2021-02-03T04:05:08Z")?)
+                .await?;
+            assert_eq!(
+                Some(QueryIssueWithLinks {
+                    id: "1".to_string(),
+                    resolution: None,
+                    status: "todo".to_string(),
+                    title: "title1".to_string(),
+                    due: None,
+                    description: "desc1".to_string(),
+                    labels: vec![],
+                    priority: None,
+                    assignee: None,
+                    recurrence: None,
+                    next_occurrence: None,
+                    blocks: vec![QueryIssueIdWithTitle {
+                        id: "2".to_string(),
+                        title: "title2".to_string(),
+                    }],
+                    is_blocked_by: vec![],
+                    comments: vec![]
+                }),
+                found
+            );
+            let found = query_handler
+                .issue_view_by_instant(&"1".parse()?, Instant::from_str("2021-02-03T04:05:10Z")?)
+                .await?;
+            assert_eq!(
+                Some("title1b".to_string()),
+                found.as_ref().map(|issue| issue.title.clone())
+            );
+            assert_eq!(Some(1), found.map(|issue| issue.comments.len()));
+            let found = query_handler
+                .issue_view_by_instant(&"1".parse()?, Instant::from_str("2021-02-03T04:05:05Z")?)
+                .await?;
+            assert_eq!(None, found);
+
+            let history = query_handler
+                .issue_history(&"1".parse()?)
+                .await?
+                .context("issue not found")?;
+            assert_eq!(
+                vec![
+                    ("issue_created", "1".to_string(), 1_u64),
+                    ("issue_title_updated", "1".to_string(), 2_u64),
+                    ("issue_blocked", "1 -> 2".to_string(), 1_u64),
+                    (
+                        "issue_comment_created",
+                        issue_comment.id().to_string(),
+                        1_u64
+                    ),
+                ],
+                history
+                    .iter()
+                    .map(|entry| (
+                        entry.event_type.as_str(),
+                        entry.aggregate_id.clone(),
+                        entry.version
+                    ))
+                    .collect::<Vec<(&str, String, u64)>>()
+            );
+            assert_eq!("2021-02-03T04:05:06Z", history[0].at);
+            assert_eq!(
+                vec![
+                    QueryIssueHistoryChange::new("title", None, Some("title1".to_string())),
+                    QueryIssueHistoryChange::new("status", None, Some("todo".to_string())),
+                    QueryIssueHistoryChange::new("description", None, Some("desc1".to_string())),
+                ],
+                history[0].changes
+            );
+            assert_eq!(
+                vec![QueryIssueHistoryChange::new(
+                    "title",
+                    Some("title1".to_string()),
+                    Some("title1b".to_string())
+                )],
+                history[1].changes
+            );
+            assert_eq!(
+                vec![QueryIssueHistoryChange::new(
+                    "blocks",
+                    None,
+                    Some("2".to_string())
+                )],
+                history[2].changes
+            );
+            let history = query_handler
+                .issue_history(&"2".parse()?)
+                .await?
+                .context("issue not found")?;
+            assert_eq!(
+                vec![QueryIssueHistoryChange::new(
+                    "is_blocked_by",
+                    None,
+                    Some("1".to_string())
+                )],
+                history[1].changes
+            );
+            assert_eq!(None, query_handler.issue_history(&"3".parse()?).await?);
         }
-        let new_connection_uri = |path: PathBuf| -> anyhow::Result<String> {
-            Ok(format!(
-                "sqlite:{}?mode=rwc",
-                path.to_str().context("path is not utf-8")?
-            ))
-        };
-        let command_connection_uri = new_connection_uri(data_dir.join("command.sqlite"))?;
-        let query_connection_uri = new_connection_uri(data_dir.join("query.sqlite"))?;
-        let connection_pool = RdbConnectionPool::new(&command_connection_uri).await?;
-
-        let issue1 = IssueAggregate::new(
-            Instant::now(),
-            "1".parse()?,
-            "title1".parse()?,
-            None,
-            "desc1".parse()?,
-        )?;
-        let issue_repository = connection_pool.issue_repository()?;
-        issue_repository.save(&issue1).await?;
-
-        let issue_comment1_created_at = Instant::now();
-        let issue_comment1_id = IssueCommentId::generate();
-        let issue_comment1 = IssueCommentAggregate::new(
-            issue_comment1_created_at,
-            issue_comment1_id.clone(),
-            issue1.id().clone(),
-            IssueCommentText::from_str("text1")?,
-        )?;
-        let issue_comment_repository = connection_pool.issue_comment_repository()?;
-        issue_comment_repository.save(&issue_comment1).await?;
-
-        let issue_comment2_created_at = Instant::now();
-        let issue_comment2_id = IssueCommentId::generate();
-        let issue_comment2 = IssueCommentAggregate::new(
-            issue_comment2_created_at,
-            issue_comment2_id.clone(),
-            issue1.id().clone(),
-            IssueCommentText::from_str("text1")?,
-        )?;
-        let issue_comment2_updated_at = Instant::now();
-        let issue_comment2 = issue_comment2.update("text2".parse()?, issue_comment2_updated_at)?;
-        issue_comment_repository.save(&issue_comment2).await?;
-
-        let issue_comment3 = IssueCommentAggregate::new(
-            Instant::now(),
-            IssueCommentId::generate(),
-            issue1.id().clone(),
-            IssueCommentText::from_str("text1")?,
-        )?;
-        let issue_comment3 = issue_comment3.delete(Instant::now())?;
-        issue_comment_repository.save(&issue_comment3).await?;
-
-        let query_handler = SqliteQueryHandler::new(
-            &query_connection_uri,
-            connection_pool.clone(),
-            Arc::new(Mutex::new(issue_repository)),
-            Arc::new(Mutex::new(connection_pool.issue_block_link_repository()?)),
-        )
-        .await?;
-        query_handler.update_database().await?;
-
-        let found = query_handler.issue_view(&"1".parse()?).await?;
-        assert_eq!(
-            Some(QueryIssueWithLinks {
-                id: "1".to_string(),
-                resolution: None,
-                status: "todo".to_string(),
-                title: "title1".to_string(),
-                due: None,
-                description: "desc1".to_string(),
-                blocks: vec![],
-                is_blocked_by: vec![],
-                comments: vec![
-                    QueryIssueComment {
-                        id: issue_comment1_id.to_string(),
-                        text: "text1".to_string(),
-                        created_at: issue_comment1_created_at.to_string(),
-                        updated_at: None
-                    },
-                    QueryIssueComment {
-                        id: issue_comment2_id.to_string(),
-                        text: "text2".to_string(),
-                        created_at: issue_comment2_created_at.to_string(),
-                        updated_at: Some(issue_comment2_updated_at.to_string()),
-                    }
-                ]
-            }),
-            found
-        );
         Ok(())
     }
 }
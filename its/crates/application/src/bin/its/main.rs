@@ -0,0 +1,1628 @@
+mod server;
+
+use std::{
+    env, fs,
+    io::{self, BufRead, BufReader, Write},
+    net::SocketAddr,
+    path::PathBuf,
+    str::FromStr,
+    sync::Arc,
+};
+
+use adapter_sqlite::{
+    RdbConnectionPool, SqliteIssueBlockLinkRepository, SqliteIssueCommentRepository,
+    SqliteIssueRepository,
+};
+use adapter_sqlite_query::{
+    QueryIssue, QueryIssueListFilter, QueryIssueListSort, QueryIssueListSortKey, SqliteQueryHandler,
+};
+use anyhow::Context;
+use clap::{ArgEnum, Parser, Subcommand};
+use domain::{
+    aggregate::{
+        issue::{
+            IssueAssignee, IssueDescription, IssueDue, IssueLabel, IssuePriority, IssueRecurrence,
+            IssueResolution, IssueTitle,
+        },
+        issue_comment::attribute::IssueCommentText,
+    },
+    IssueBlockLinkId, IssueCommentId, IssueId, Version,
+};
+use event_store::{Event, EventId, EventStreamId, EventStreamSeq};
+use limited_date_time::OffsetDateTime;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use use_case::{
+    issue_comment_repository::HasIssueCommentRepository, AddIssueLabel, BlockIssue, CancelIssue,
+    CreateIssue, CreateIssueComment, DeleteIssueComment, FinishIssue, HasIssueBlockLinkRepository,
+    HasIssueManagementContextUseCase, HasIssueRepository, IssueManagementContextUseCase,
+    RemoveIssueLabel, ReopenIssue, StartIssue, UnblockIssue, UpdateIssue, UpdateIssueAssignee,
+    UpdateIssueComment, UpdateIssueDescription, UpdateIssuePriority, UpdateIssueTitle,
+};
+use xdg::BaseDirectories;
+
+struct App {
+    connection_pool: RdbConnectionPool,
+    issue_block_link_repository: SqliteIssueBlockLinkRepository,
+    issue_comment_repository: SqliteIssueCommentRepository,
+    issue_repository: SqliteIssueRepository,
+    query_handler: SqliteQueryHandler,
+}
+
+impl App {
+    async fn new(
+        command_database_connection_uri: Option<String>,
+        query_database_connection_uri: Option<String>,
+    ) -> anyhow::Result<Self> {
+        let new_connection_uri = |file_name: &str| -> anyhow::Result<String> {
+            let data_dir = Self::state_dir()?;
+            if !data_dir.exists() {
+                fs::create_dir_all(data_dir.as_path())?;
+            }
+            let path = data_dir.join(file_name);
+            Ok(format!(
+                "sqlite:{}?mode=rwc",
+                path.to_str().context("path is not utf-8")?
+            ))
+        };
+        let command_connection_uri = match command_database_connection_uri {
+            Some(s) => s,
+            None => new_connection_uri("command.sqlite")?,
+        };
+        let query_connection_uri = match query_database_connection_uri {
+            Some(s) => s,
+            None => new_connection_uri("query.sqlite")?,
+        };
+        let snapshot_interval = Self::snapshot_interval()?;
+        let connection_pool = RdbConnectionPool::new(&command_connection_uri).await?;
+        let issue_comment_repository = connection_pool.issue_comment_repository()?;
+        let issue_repository = connection_pool
+            .issue_repository()?
+            .with_snapshot_interval(snapshot_interval);
+        let issue_block_link_repository = connection_pool.issue_block_link_repository()?;
+        let query_handler = SqliteQueryHandler::new(
+            &query_connection_uri,
+            connection_pool.clone(),
+            Arc::new(Mutex::new(issue_repository)),
+            Arc::new(Mutex::new(issue_block_link_repository)),
+        )
+        .await?;
+        let issue_repository = connection_pool
+            .issue_repository()?
+            .with_snapshot_interval(snapshot_interval);
+        let issue_block_link_repository = connection_pool.issue_block_link_repository()?;
+        Ok(Self {
+            connection_pool,
+            issue_block_link_repository,
+            issue_comment_repository,
+            issue_repository,
+            query_handler,
+        })
+    }
+
+    fn snapshot_interval() -> anyhow::Result<u64> {
+        // $ITS_SNAPSHOT_INTERVAL: saves a snapshot of an issue every n versions (0: never)
+        match env::var("ITS_SNAPSHOT_INTERVAL") {
+            Ok(s) => s
+                .parse()
+                .with_context(|| format!("ITS_SNAPSHOT_INTERVAL is not a number: {}", s)),
+            Err(env::VarError::NotPresent) => Ok(SqliteIssueRepository::DEFAULT_SNAPSHOT_INTERVAL),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn state_dir() -> anyhow::Result<PathBuf> {
+        // $XDG_STATE_HOME/$prefix
+        // $HOME/.local/state/$prefix
+        let prefix = "net.bouzuya.rust-sandbox.its";
+        Ok(BaseDirectories::with_prefix(prefix)?.get_state_home())
+    }
+}
+
+impl HasIssueBlockLinkRepository for App {
+    type IssueBlockLinkRepository = SqliteIssueBlockLinkRepository;
+
+    fn issue_block_link_repository(&self) -> &Self::IssueBlockLinkRepository {
+        &self.issue_block_link_repository
+    }
+}
+
+impl HasIssueCommentRepository for App {
+    type IssueCommentRepository = SqliteIssueCommentRepository;
+
+    fn issue_comment_repository(&self) -> &Self::IssueCommentRepository {
+        &self.issue_comment_repository
+    }
+}
+
+impl HasIssueRepository for App {
+    type IssueRepository = SqliteIssueRepository;
+
+    fn issue_repository(&self) -> &Self::IssueRepository {
+        &self.issue_repository
+    }
+}
+
+impl HasIssueManagementContextUseCase for App {
+    type IssueManagementContextUseCase = App;
+
+    fn issue_management_context_use_case(&self) -> &Self::IssueManagementContextUseCase {
+        self
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct EventJson {
+    id: String,
+    stream_id: String,
+    version: u32,
+    data: String,
+}
+
+async fn export_events(
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let events = app.connection_pool.export_events().await?;
+    let mut stdout = io::stdout().lock();
+    for event in events {
+        let event_json = EventJson {
+            id: event.id.to_string(),
+            stream_id: event.stream_id.to_string(),
+            version: u32::try_from(i64::from(event.stream_seq))?,
+            data: event.data,
+        };
+        writeln!(stdout, "{}", serde_json::to_string(&event_json)?)?;
+    }
+    Ok(())
+}
+
+async fn graph(
+    format: GraphFormat,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let graph = app.query_handler.issue_graph().await?;
+    match format {
+        GraphFormat::Dot => println!("{}", graph.to_dot()),
+        GraphFormat::Json => println!("{}", serde_json::to_string(&graph)?),
+        GraphFormat::Mermaid => println!("{}", graph.to_mermaid()),
+    }
+    Ok(())
+}
+
+async fn import_events(
+    file: Option<PathBuf>,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let reader: Box<dyn BufRead> = match file {
+        Some(file) => Box::new(BufReader::new(fs::File::open(file)?)),
+        None => Box::new(BufReader::new(io::stdin())),
+    };
+    let mut events = vec![];
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let event_json: EventJson = serde_json::from_str(line.as_str())
+            .with_context(|| format!("invalid event at line {}", index + 1))?;
+        events.push(Event {
+            id: EventId::from_str(event_json.id.as_str())?,
+            stream_id: EventStreamId::from_str(event_json.stream_id.as_str())?,
+            stream_seq: EventStreamSeq::from(event_json.version),
+            data: event_json.data,
+        });
+    }
+
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    app.connection_pool.import_events(events).await?;
+    app.query_handler.reset_database().await?;
+    let statuses = app.query_handler.status().await?;
+    println!("{}", serde_json::to_string(&statuses)?);
+    Ok(())
+}
+
+async fn issue_add_label(
+    issue_id: String,
+    label: String,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let use_case = app.issue_management_context_use_case();
+    let issue_id = IssueId::from_str(issue_id.as_str())?;
+    let issue_label = IssueLabel::from_str(label.as_str())?;
+    let event = use_case
+        .handle(AddIssueLabel {
+            issue_id: issue_id.clone(),
+            issue_label,
+        })
+        .await?;
+    app.query_handler.handle(&event).await?;
+    let issue = app.query_handler.issue_view(&issue_id).await?.unwrap();
+    println!("{}", serde_json::to_string(&issue)?);
+    Ok(())
+}
+
+async fn issue_block(
+    issue_id: String,
+    blocked_issue_id: String,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let use_case = app.issue_management_context_use_case();
+    let issue_id = IssueId::from_str(issue_id.as_str())?;
+    let blocked_issue_id = IssueId::from_str(blocked_issue_id.as_str())?;
+    let event = use_case
+        .handle(BlockIssue {
+            issue_id,
+            blocked_issue_id,
+        })
+        .await?;
+    app.query_handler.handle(&event).await?;
+    if let use_case::IssueManagementContextEvent::IssueBlocked {
+        issue_block_link_id,
+    } = event
+    {
+        let issue_id = issue_block_link_id.issue_id();
+        let issue = app.query_handler.issue_view(issue_id).await?.unwrap();
+        println!("{}", serde_json::to_string(&issue)?);
+    }
+    Ok(())
+}
+
+async fn issue_cancel(
+    issue_id: String,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let use_case = app.issue_management_context_use_case();
+    let issue_id = IssueId::from_str(issue_id.as_str())?;
+    let event = use_case
+        .handle(CancelIssue {
+            issue_id: issue_id.clone(),
+        })
+        .await?;
+    app.query_handler.handle(&event).await?;
+    let issue = app.query_handler.issue_view(&issue_id).await?.unwrap();
+    println!("{}", serde_json::to_string(&issue)?);
+    Ok(())
+}
+
+async fn issue_comment_create(
+    issue_id: String,
+    text: String,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let use_case = app.issue_management_context_use_case();
+    let issue_id = IssueId::from_str(issue_id.as_str())?;
+    let text = IssueCommentText::from_str(text.as_str())?;
+    let event = use_case
+        .handle(CreateIssueComment { issue_id, text })
+        .await?;
+    app.query_handler.handle(&event).await?;
+    if let use_case::IssueManagementContextEvent::IssueCommentCreated { issue_comment_id } = event {
+        let issue_comment = app
+            .query_handler
+            .issue_comment_view(&issue_comment_id)
+            .await?;
+        println!("{}", serde_json::to_string(&issue_comment)?);
+    }
+    Ok(())
+}
+
+async fn issue_comment_delete(
+    issue_comment_id: String,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let use_case = app.issue_management_context_use_case();
+    let issue_comment_id = IssueCommentId::from_str(issue_comment_id.as_str())?;
+    let event = use_case
+        .handle(DeleteIssueComment { issue_comment_id })
+        .await?;
+    app.query_handler.handle(&event).await?;
+    if let use_case::IssueManagementContextEvent::IssueCommentDeleted { issue_comment_id } = event {
+        let issue_comment = app
+            .query_handler
+            .issue_comment_view(&issue_comment_id)
+            .await?;
+        println!("{}", serde_json::to_string(&issue_comment)?);
+    }
+    Ok(())
+}
+
+async fn issue_comment_update(
+    issue_comment_id: String,
+    text: String,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let use_case = app.issue_management_context_use_case();
+    let issue_comment_id = IssueCommentId::from_str(issue_comment_id.as_str())?;
+    let text = IssueCommentText::from_str(text.as_str())?;
+    let event = use_case
+        .handle(UpdateIssueComment {
+            issue_comment_id,
+            text,
+        })
+        .await?;
+    app.query_handler.handle(&event).await?;
+    if let use_case::IssueManagementContextEvent::IssueCommentUpdated { issue_comment_id } = event {
+        let issue_comment = app
+            .query_handler
+            .issue_comment_view(&issue_comment_id)
+            .await?;
+        println!("{}", serde_json::to_string(&issue_comment)?);
+    }
+    Ok(())
+}
+
+async fn issue_comment_view(
+    issue_comment_id: String,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let issue_comment_id = IssueCommentId::from_str(&issue_comment_id)?;
+    let issue_comment = app
+        .query_handler
+        .issue_comment_view(&issue_comment_id)
+        .await?;
+    println!("{}", serde_json::to_string(&issue_comment)?);
+    Ok(())
+}
+
+async fn issue_create(
+    title: Option<String>,
+    due: Option<String>,
+    description: Option<String>,
+    recurrence: Option<String>,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let use_case = app.issue_management_context_use_case();
+    let issue_title = IssueTitle::try_from(title.unwrap_or_default())?;
+    let issue_due = due.map(|s| IssueDue::from_str(s.as_str())).transpose()?;
+    let issue_description = description
+        .map(|s| IssueDescription::from_str(s.as_str()))
+        .transpose()?
+        .unwrap_or_default();
+    let issue_recurrence = recurrence
+        .map(|s| IssueRecurrence::from_str(s.as_str()))
+        .transpose()?;
+    let event = use_case
+        .handle(CreateIssue {
+            issue_title,
+            issue_due,
+            issue_description,
+            issue_recurrence,
+        })
+        .await?;
+    app.query_handler.handle(&event).await?;
+    if let use_case::IssueManagementContextEvent::IssueCreated { issue_id } = event {
+        let issue = app.query_handler.issue_view(&issue_id).await?.unwrap();
+        println!("{}", serde_json::to_string(&issue)?);
+    }
+    Ok(())
+}
+
+async fn issue_update_description(
+    issue_id: String,
+    description: String,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let use_case = app.issue_management_context_use_case();
+    let issue_id = IssueId::from_str(issue_id.as_str())?;
+    let issue_description = IssueDescription::try_from(description)?;
+    let event = use_case
+        .handle(UpdateIssueDescription {
+            issue_id: issue_id.clone(),
+            issue_description,
+        })
+        .await?;
+    app.query_handler.handle(&event).await?;
+    let issue = app.query_handler.issue_view(&issue_id).await?.unwrap();
+    println!("{}", serde_json::to_string(&issue)?);
+    Ok(())
+}
+
+async fn issue_update_title(
+    issue_id: String,
+    title: String,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let use_case = app.issue_management_context_use_case();
+    let issue_id = IssueId::from_str(issue_id.as_str())?;
+    let issue_title = IssueTitle::try_from(title)?;
+    let event = use_case
+        .handle(UpdateIssueTitle {
+            issue_id: issue_id.clone(),
+            issue_title,
+        })
+        .await?;
+    app.query_handler.handle(&event).await?;
+    let issue = app.query_handler.issue_view(&issue_id).await?.unwrap();
+    println!("{}", serde_json::to_string(&issue)?);
+    Ok(())
+}
+
+async fn issue_finish(
+    issue_id: String,
+    resolution: Option<String>,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let use_case = app.issue_management_context_use_case();
+    let issue_id = IssueId::from_str(issue_id.as_str())?;
+    let resolution = resolution
+        .as_deref()
+        .map(IssueResolution::from_str)
+        .transpose()?;
+    let event = use_case
+        .handle(FinishIssue {
+            issue_id: issue_id.clone(),
+            resolution,
+        })
+        .await?;
+    app.query_handler.handle(&event).await?;
+    let issue = app.query_handler.issue_view(&issue_id).await?.unwrap();
+    println!("{}", serde_json::to_string(&issue)?);
+    Ok(())
+}
+
+async fn issue_history(
+    issue_id: String,
+    format: Format,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let issue_id = IssueId::from_str(issue_id.as_str())?;
+
+    let entries = app
+        .query_handler
+        .issue_history(&issue_id)
+        .await?
+        .with_context(|| format!("issue not found: {}", issue_id))?;
+    match format {
+        Format::Json => println!("{}", serde_json::to_string(&entries)?),
+        Format::Text => {
+            for entry in entries {
+                println!(
+                    "{} {} {} v{} ({})",
+                    entry.at, entry.event_type, entry.aggregate_id, entry.version, entry.event_id
+                );
+                for change in entry.changes {
+                    println!(
+                        "  {}: {} -> {}",
+                        change.field,
+                        change.old.as_deref().unwrap_or("(none)"),
+                        change.new.as_deref().unwrap_or("(none)")
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn issue_list(
+    query: Option<String>,
+    labels: Vec<String>,
+    priority: Option<String>,
+    assignee: Option<String>,
+    sort: SortKey,
+    order: Order,
+    format: ListFormat,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+
+    let mut filter = QueryIssueListFilter::from_str(query.as_deref().unwrap_or_default())?;
+    for label in labels {
+        let label = IssueLabel::from_str(label.as_str())?;
+        filter = filter.and(QueryIssueListFilter::from_str(&format!("label:{}", label))?);
+    }
+    if let Some(priority) = priority {
+        let priority = IssuePriority::from_str(priority.as_str())?;
+        filter = filter.and(QueryIssueListFilter::from_str(&format!(
+            "priority:{}",
+            priority
+        ))?);
+    }
+    if let Some(assignee) = assignee {
+        let assignee = IssueAssignee::from_str(assignee.as_str())?;
+        filter = filter.and(QueryIssueListFilter::from_str(&format!(
+            "assignee:{}",
+            assignee
+        ))?);
+    }
+    let issues = app
+        .query_handler
+        .issue_list(&filter, issue_list_sort(sort, order))
+        .await?;
+    match format {
+        ListFormat::Json => println!("{}", serde_json::to_string(&issues)?),
+        ListFormat::Table => print_issue_table(&issues),
+    }
+    Ok(())
+}
+
+fn issue_list_sort(sort: SortKey, order: Order) -> QueryIssueListSort {
+    QueryIssueListSort {
+        key: match sort {
+            SortKey::Due => QueryIssueListSortKey::Due,
+            SortKey::Id => QueryIssueListSortKey::Id,
+            SortKey::Priority => QueryIssueListSortKey::Priority,
+            SortKey::Status => QueryIssueListSortKey::Status,
+            SortKey::Title => QueryIssueListSortKey::Title,
+        },
+        descending: matches!(order, Order::Desc),
+    }
+}
+
+fn print_issue_table(issues: &[QueryIssue]) {
+    let header = ["ID", "STATUS", "PRIORITY", "DUE", "LABELS", "TITLE"].map(String::from);
+    let rows = issues
+        .iter()
+        .map(|issue| {
+            [
+                issue.id.clone(),
+                issue.status.clone(),
+                issue.priority.clone().unwrap_or_else(|| "-".to_string()),
+                issue.due.clone().unwrap_or_else(|| "-".to_string()),
+                if issue.labels.is_empty() {
+                    "-".to_string()
+                } else {
+                    issue.labels.join(",")
+                },
+                issue.title.clone(),
+            ]
+        })
+        .collect::<Vec<[String; 6]>>();
+    let mut widths = [0_usize; 6];
+    for row in std::iter::once(&header).chain(rows.iter()) {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    for row in std::iter::once(&header).chain(rows.iter()) {
+        let line = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{:width$}", cell, width = width))
+            .collect::<Vec<String>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    }
+}
+
+async fn issue_remove_label(
+    issue_id: String,
+    label: String,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let use_case = app.issue_management_context_use_case();
+    let issue_id = IssueId::from_str(issue_id.as_str())?;
+    let issue_label = IssueLabel::from_str(label.as_str())?;
+    let event = use_case
+        .handle(RemoveIssueLabel {
+            issue_id: issue_id.clone(),
+            issue_label,
+        })
+        .await?;
+    app.query_handler.handle(&event).await?;
+    let issue = app.query_handler.issue_view(&issue_id).await?.unwrap();
+    println!("{}", serde_json::to_string(&issue)?);
+    Ok(())
+}
+
+async fn issue_reopen(
+    issue_id: String,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let use_case = app.issue_management_context_use_case();
+    let issue_id = IssueId::from_str(issue_id.as_str())?;
+    let event = use_case
+        .handle(ReopenIssue {
+            issue_id: issue_id.clone(),
+        })
+        .await?;
+    app.query_handler.handle(&event).await?;
+    let issue = app.query_handler.issue_view(&issue_id).await?.unwrap();
+    println!("{}", serde_json::to_string(&issue)?);
+    Ok(())
+}
+
+async fn issue_set_assignee(
+    issue_id: String,
+    assignee: Option<String>,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let use_case = app.issue_management_context_use_case();
+    let issue_id = IssueId::from_str(issue_id.as_str())?;
+    let issue_assignee = assignee
+        .as_deref()
+        .map(IssueAssignee::from_str)
+        .transpose()?;
+    let event = use_case
+        .handle(UpdateIssueAssignee {
+            issue_id: issue_id.clone(),
+            issue_assignee,
+        })
+        .await?;
+    app.query_handler.handle(&event).await?;
+    let issue = app.query_handler.issue_view(&issue_id).await?.unwrap();
+    println!("{}", serde_json::to_string(&issue)?);
+    Ok(())
+}
+
+async fn issue_set_priority(
+    issue_id: String,
+    priority: Option<String>,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let use_case = app.issue_management_context_use_case();
+    let issue_id = IssueId::from_str(issue_id.as_str())?;
+    let issue_priority = priority
+        .as_deref()
+        .map(IssuePriority::from_str)
+        .transpose()?;
+    let event = use_case
+        .handle(UpdateIssuePriority {
+            issue_id: issue_id.clone(),
+            issue_priority,
+        })
+        .await?;
+    app.query_handler.handle(&event).await?;
+    let issue = app.query_handler.issue_view(&issue_id).await?.unwrap();
+    println!("{}", serde_json::to_string(&issue)?);
+    Ok(())
+}
+
+async fn issue_start(
+    issue_id: String,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let use_case = app.issue_management_context_use_case();
+    let issue_id = IssueId::from_str(issue_id.as_str())?;
+    let event = use_case
+        .handle(StartIssue {
+            issue_id: issue_id.clone(),
+        })
+        .await?;
+    app.query_handler.handle(&event).await?;
+    let issue = app.query_handler.issue_view(&issue_id).await?.unwrap();
+    println!("{}", serde_json::to_string(&issue)?);
+    Ok(())
+}
+
+async fn issue_unblock(
+    issue_id: String,
+    blocked_issue_id: String,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let use_case = app.issue_management_context_use_case();
+    let issue_id = IssueId::from_str(issue_id.as_str())?;
+    let blocked_issue_id = IssueId::from_str(blocked_issue_id.as_str())?;
+    let issue_block_link_id = IssueBlockLinkId::new(issue_id, blocked_issue_id)?;
+    let event = use_case
+        .handle(UnblockIssue {
+            issue_block_link_id,
+        })
+        .await?;
+    app.query_handler.handle(&event).await?;
+    if let use_case::IssueManagementContextEvent::IssueUnblocked {
+        issue_block_link_id,
+    } = event
+    {
+        let issue_id = issue_block_link_id.issue_id();
+        let issue = app.query_handler.issue_view(issue_id).await?.unwrap();
+        println!("{}", serde_json::to_string(&issue)?);
+    }
+    Ok(())
+}
+
+async fn issue_update(
+    issue_id: String,
+    due: Option<String>,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let use_case = app.issue_management_context_use_case();
+    let issue_id = IssueId::from_str(issue_id.as_str())?;
+    let issue_due = due.map(|s| IssueDue::from_str(s.as_str())).transpose()?;
+    let event = use_case
+        .handle(UpdateIssue {
+            issue_id: issue_id.clone(),
+            issue_due,
+        })
+        .await?;
+    app.query_handler.handle(&event).await?;
+    let issue = app.query_handler.issue_view(&issue_id).await?.unwrap();
+    println!("{}", serde_json::to_string(&issue)?);
+    Ok(())
+}
+
+async fn issue_view(
+    issue_id: String,
+    version: Option<u64>,
+    at: Option<String>,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let issue_id = IssueId::from_str(issue_id.as_str())?;
+
+    let issue = match (version, at) {
+        (Some(version), _) => {
+            app.query_handler
+                .issue_view_by_version(&issue_id, Version::from(version))
+                .await?
+        }
+        (None, Some(at)) => {
+            let at = OffsetDateTime::from_str(at.as_str())?.instant();
+            app.query_handler
+                .issue_view_by_instant(&issue_id, at)
+                .await?
+        }
+        (None, None) => app.query_handler.issue_view(&issue_id).await?,
+    };
+    println!("{}", serde_json::to_string(&issue)?);
+    Ok(())
+}
+
+async fn query_rebuild(
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    app.query_handler.reset_database().await?;
+    let statuses = app.query_handler.status().await?;
+    println!("{}", serde_json::to_string(&statuses)?);
+    Ok(())
+}
+
+async fn query_status(
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let statuses = app.query_handler.status().await?;
+    println!("{}", serde_json::to_string(&statuses)?);
+    Ok(())
+}
+
+async fn serve(
+    host: String,
+    port: u16,
+    command_database_connection_uri: Option<String>,
+    query_database_connection_uri: Option<String>,
+) -> anyhow::Result<()> {
+    let app = App::new(
+        command_database_connection_uri,
+        query_database_connection_uri,
+    )
+    .await?;
+    let addr = SocketAddr::new(host.parse()?, port);
+    server::run(app, addr).await
+}
+
+#[derive(Clone, Copy, ArgEnum)]
+enum Format {
+    Json,
+    Text,
+}
+
+#[derive(Clone, Copy, ArgEnum)]
+enum GraphFormat {
+    Dot,
+    Json,
+    Mermaid,
+}
+
+#[derive(Clone, Copy, ArgEnum)]
+enum ListFormat {
+    Json,
+    Table,
+}
+
+#[derive(Clone, Copy, ArgEnum)]
+enum Order {
+    Asc,
+    Desc,
+}
+
+#[derive(Clone, Copy, ArgEnum)]
+enum SortKey {
+    Due,
+    Id,
+    Priority,
+    Status,
+    Title,
+}
+
+#[derive(Parser)]
+struct Opt {
+    #[clap(subcommand)]
+    resource: Resource,
+}
+
+#[derive(Subcommand)]
+enum Resource {
+    Export {
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    Graph {
+        #[clap(long, arg_enum, default_value = "dot")]
+        format: GraphFormat,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    Import {
+        file: Option<PathBuf>,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    Issue {
+        #[clap(subcommand)]
+        command: Command,
+    },
+    IssueComment {
+        #[clap(subcommand)]
+        command: IssueCommentCommand,
+    },
+    Query {
+        #[clap(subcommand)]
+        command: QueryCommand,
+    },
+    Serve {
+        #[clap(long, default_value = "127.0.0.1")]
+        host: String,
+        #[clap(long, default_value = "3000")]
+        port: u16,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum Command {
+    AddLabel {
+        issue_id: String,
+        label: String,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    Block {
+        #[clap(name = "issue-id")]
+        issue_id: String,
+        #[clap(name = "blocked-issue-id")]
+        blocked_issue_id: String,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    Cancel {
+        issue_id: String,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    Create {
+        #[clap(long = "title")]
+        title: Option<String>,
+        #[clap(long = "due")]
+        due: Option<String>,
+        #[clap(long = "description")]
+        description: Option<String>,
+        #[clap(long = "recurrence")]
+        recurrence: Option<String>,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    Finish {
+        issue_id: String,
+        #[clap(long)]
+        resolution: Option<String>,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    History {
+        issue_id: String,
+        #[clap(long, arg_enum, default_value = "text")]
+        format: Format,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    List {
+        query: Option<String>,
+        #[clap(long = "label")]
+        labels: Vec<String>,
+        #[clap(long)]
+        priority: Option<String>,
+        #[clap(long)]
+        assignee: Option<String>,
+        #[clap(long, arg_enum, default_value = "id")]
+        sort: SortKey,
+        #[clap(long, arg_enum, default_value = "asc")]
+        order: Order,
+        #[clap(long, arg_enum, default_value = "json")]
+        format: ListFormat,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    RemoveLabel {
+        issue_id: String,
+        label: String,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    Reopen {
+        issue_id: String,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    SetAssignee {
+        issue_id: String,
+        assignee: Option<String>,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    SetPriority {
+        issue_id: String,
+        #[clap(possible_values = ["low", "medium", "high", "urgent"])]
+        priority: Option<String>,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    Start {
+        issue_id: String,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    Unblock {
+        #[clap(name = "issue-id")]
+        issue_id: String,
+        #[clap(name = "blocked-issue-id")]
+        blocked_issue_id: String,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    Update {
+        issue_id: String,
+        #[clap(long = "due")]
+        due: Option<String>,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    UpdateDescription {
+        issue_id: String,
+        description: String,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    UpdateTitle {
+        issue_id: String,
+        title: String,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    View {
+        issue_id: String,
+        #[clap(long, conflicts_with = "at")]
+        version: Option<u64>,
+        #[clap(long)]
+        at: Option<String>,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum IssueCommentCommand {
+    Create {
+        issue_id: String,
+        text: String,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    Delete {
+        issue_comment_id: String,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    Update {
+        issue_comment_id: String,
+        text: String,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    View {
+        issue_comment_id: String,
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum QueryCommand {
+    Rebuild {
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+    Status {
+        #[clap(long)]
+        command_database_connection_uri: Option<String>,
+        #[clap(long)]
+        query_database_connection_uri: Option<String>,
+    },
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let opt = Opt::parse();
+    match opt.resource {
+        Resource::Export {
+            command_database_connection_uri,
+            query_database_connection_uri,
+        } => {
+            export_events(
+                command_database_connection_uri,
+                query_database_connection_uri,
+            )
+            .await
+        }
+        Resource::Graph {
+            format,
+            command_database_connection_uri,
+            query_database_connection_uri,
+        } => {
+            graph(
+                format,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            )
+            .await
+        }
+        Resource::Import {
+            file,
+            command_database_connection_uri,
+            query_database_connection_uri,
+        } => {
+            import_events(
+                file,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            )
+            .await
+        }
+        Resource::Issue { command } => match command {
+            Command::AddLabel {
+                issue_id,
+                label,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_add_label(
+                    issue_id,
+                    label,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            Command::Block {
+                issue_id,
+                blocked_issue_id,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_block(
+                    issue_id,
+                    blocked_issue_id,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            Command::Cancel {
+                issue_id,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_cancel(
+                    issue_id,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            Command::Create {
+                title,
+                due,
+                description,
+                recurrence,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_create(
+                    title,
+                    due,
+                    description,
+                    recurrence,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            Command::Finish {
+                issue_id,
+                resolution,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_finish(
+                    issue_id,
+                    resolution,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            Command::History {
+                issue_id,
+                format,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_history(
+                    issue_id,
+                    format,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            Command::List {
+                query,
+                labels,
+                priority,
+                assignee,
+                sort,
+                order,
+                format,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_list(
+                    query,
+                    labels,
+                    priority,
+                    assignee,
+                    sort,
+                    order,
+                    format,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            Command::RemoveLabel {
+                issue_id,
+                label,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_remove_label(
+                    issue_id,
+                    label,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            Command::Reopen {
+                issue_id,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_reopen(
+                    issue_id,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            Command::SetAssignee {
+                issue_id,
+                assignee,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_set_assignee(
+                    issue_id,
+                    assignee,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            Command::SetPriority {
+                issue_id,
+                priority,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_set_priority(
+                    issue_id,
+                    priority,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            Command::Start {
+                issue_id,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_start(
+                    issue_id,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            Command::Unblock {
+                issue_id,
+                blocked_issue_id,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_unblock(
+                    issue_id,
+                    blocked_issue_id,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            Command::Update {
+                issue_id,
+                due,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_update(
+                    issue_id,
+                    due,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            Command::UpdateDescription {
+                issue_id,
+                description,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_update_description(
+                    issue_id,
+                    description,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            Command::UpdateTitle {
+                issue_id,
+                title,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_update_title(
+                    issue_id,
+                    title,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            Command::View {
+                issue_id,
+                version,
+                at,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_view(
+                    issue_id,
+                    version,
+                    at,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+        },
+        Resource::IssueComment { command } => match command {
+            IssueCommentCommand::Create {
+                issue_id,
+                text,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_comment_create(
+                    issue_id,
+                    text,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            IssueCommentCommand::Delete {
+                issue_comment_id,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_comment_delete(
+                    issue_comment_id,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            IssueCommentCommand::Update {
+                issue_comment_id,
+                text,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_comment_update(
+                    issue_comment_id,
+                    text,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            IssueCommentCommand::View {
+                issue_comment_id,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                issue_comment_view(
+                    issue_comment_id,
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+        },
+        Resource::Query { command } => match command {
+            QueryCommand::Rebuild {
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                query_rebuild(
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+            QueryCommand::Status {
+                command_database_connection_uri,
+                query_database_connection_uri,
+            } => {
+                query_status(
+                    command_database_connection_uri,
+                    query_database_connection_uri,
+                )
+                .await
+            }
+        },
+        Resource::Serve {
+            host,
+            port,
+            command_database_connection_uri,
+            query_database_connection_uri,
+        } => {
+            serve(
+                host,
+                port,
+                command_database_connection_uri,
+                query_database_connection_uri,
+            )
+            .await
+        }
+    }
+}
@@ -0,0 +1,787 @@
+use std::{fmt::Display, net::SocketAddr, str::FromStr, sync::Arc};
+
+use adapter_sqlite_query::{QueryIssueComment, QueryIssueListFilter, QueryIssueWithLinks};
+use axum::{
+    extract::{Path, Query},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing, Extension, Json, Router,
+};
+use clap::ArgEnum;
+use domain::{
+    aggregate::{
+        issue::{IssueDescription, IssueDue, IssueRecurrence, IssueResolution, IssueTitle},
+        issue_comment::attribute::IssueCommentText,
+    },
+    IssueBlockLinkId, IssueCommentId, IssueId, Version,
+};
+use serde::{Deserialize, Deserializer};
+use use_case::{
+    issue_comment_repository, issue_repository, BlockIssue, CancelIssue, CreateIssue,
+    CreateIssueComment, DeleteIssueComment, EditIssue, FinishIssue,
+    HasIssueManagementContextUseCase, HasIssueRepository, IssueBlockLinkRepositoryError,
+    IssueManagementContextEvent, IssueManagementContextUseCase, IssueRepository, ReopenIssue,
+    StartIssue, UnblockIssue, UpdateIssueComment,
+};
+
+use crate::{issue_list_sort, App, Order, SortKey};
+
+pub(crate) async fn run(app: App, addr: SocketAddr) -> anyhow::Result<()> {
+    let router = router().layer(Extension(Arc::new(app)));
+    axum::Server::bind(&addr)
+        .serve(router.into_make_service())
+        .await?;
+    Ok(())
+}
+
+fn router() -> Router {
+    Router::new()
+        .route("/issues", routing::get(issue_list).post(issue_create))
+        .route(
+            "/issues/:issue_id",
+            routing::get(issue_view).patch(issue_update),
+        )
+        .route(
+            "/issues/:issue_id/blocks/:blocked_issue_id",
+            routing::put(issue_block).delete(issue_unblock),
+        )
+        .route("/issues/:issue_id/cancel", routing::post(issue_cancel))
+        .route(
+            "/issues/:issue_id/comments",
+            routing::post(issue_comment_create),
+        )
+        .route("/issues/:issue_id/finish", routing::post(issue_finish))
+        .route("/issues/:issue_id/reopen", routing::post(issue_reopen))
+        .route("/issues/:issue_id/start", routing::post(issue_start))
+        .route(
+            "/issue_comments/:issue_comment_id",
+            routing::get(issue_comment_view)
+                .patch(issue_comment_update)
+                .delete(issue_comment_delete),
+        )
+}
+
+#[derive(Debug)]
+struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+impl ApiError {
+    fn bad_request<E: Display>(e: E) -> Self {
+        Self {
+            status: StatusCode::BAD_REQUEST,
+            message: e.to_string(),
+        }
+    }
+
+    fn not_found(message: &str) -> Self {
+        Self {
+            status: StatusCode::NOT_FOUND,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl From<use_case::Error> for ApiError {
+    fn from(e: use_case::Error) -> Self {
+        let mut status = StatusCode::INTERNAL_SERVER_ERROR;
+        let mut source: Option<&(dyn std::error::Error + 'static)> = Some(&e);
+        while let Some(error) = source {
+            if matches!(
+                error.downcast_ref::<issue_repository::Error>(),
+                Some(issue_repository::Error::Conflict)
+            ) || matches!(
+                error.downcast_ref::<issue_comment_repository::Error>(),
+                Some(issue_comment_repository::Error::Conflict)
+            ) || matches!(
+                error.downcast_ref::<IssueBlockLinkRepositoryError>(),
+                Some(IssueBlockLinkRepositoryError::Conflict)
+            ) {
+                status = StatusCode::CONFLICT;
+                break;
+            }
+            if error
+                .downcast_ref::<domain::aggregate::issue::Error>()
+                .is_some()
+                || error
+                    .downcast_ref::<domain::aggregate::issue_block_link::Error>()
+                    .is_some()
+                || error
+                    .downcast_ref::<domain::aggregate::issue_comment::Error>()
+                    .is_some()
+            {
+                status = StatusCode::BAD_REQUEST;
+                break;
+            }
+            source = error.source();
+        }
+        Self {
+            status,
+            message: e.to_string(),
+        }
+    }
+}
+
+impl From<adapter_sqlite_query::Error> for ApiError {
+    fn from(e: adapter_sqlite_query::Error) -> Self {
+        Self {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: e.to_string(),
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (
+            self.status,
+            Json(serde_json::json!({ "error": self.message })),
+        )
+            .into_response()
+    }
+}
+
+type Result<T, E = ApiError> = std::result::Result<T, E>;
+
+// distinguishes a missing field (None) from `null` (Some(None))
+fn deserialize_some<'de, T, D>(deserializer: D) -> std::result::Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+async fn find_issue(app: &App, issue_id: &IssueId) -> Result<QueryIssueWithLinks> {
+    app.query_handler
+        .issue_view(issue_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("issue not found"))
+}
+
+// `"{version}"` of the issue aggregate
+async fn find_issue_etag(app: &App, issue_id: &IssueId) -> Result<String> {
+    let issue = app
+        .issue_repository()
+        .find_by_id(issue_id)
+        .await
+        .map_err(use_case::Error::from)?
+        .ok_or_else(|| ApiError::not_found("issue not found"))?;
+    Ok(format!("\"{}\"", u64::from(issue.version())))
+}
+
+// `If-Match: "{version}"` (`None` if missing or `*`)
+fn parse_if_match(headers: &HeaderMap) -> Result<Option<Version>> {
+    let value = match headers.get(header::IF_MATCH) {
+        None => return Ok(None),
+        Some(value) => value.to_str().map_err(ApiError::bad_request)?.trim(),
+    };
+    if value == "*" {
+        return Ok(None);
+    }
+    value
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .ok_or_else(|| ApiError::bad_request("invalid If-Match"))?
+        .parse::<u64>()
+        .map(|version| Some(Version::from(version)))
+        .map_err(ApiError::bad_request)
+}
+
+async fn find_issue_comment(
+    app: &App,
+    issue_comment_id: &IssueCommentId,
+) -> Result<QueryIssueComment> {
+    app.query_handler
+        .issue_comment_view(issue_comment_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("issue comment not found"))
+}
+
+async fn handle<C>(app: &App, command: C) -> Result<IssueManagementContextEvent>
+where
+    C: Into<use_case::IssueManagementContextCommand> + Send,
+{
+    let event = app
+        .issue_management_context_use_case()
+        .handle(command)
+        .await?;
+    app.query_handler.handle(&event).await?;
+    Ok(event)
+}
+
+fn parse_issue_id(issue_id: &str) -> Result<IssueId> {
+    IssueId::from_str(issue_id).map_err(ApiError::bad_request)
+}
+
+fn parse_issue_comment_id(issue_comment_id: &str) -> Result<IssueCommentId> {
+    IssueCommentId::from_str(issue_comment_id).map_err(ApiError::bad_request)
+}
+
+#[derive(Deserialize)]
+struct IssueListParams {
+    q: Option<String>,
+    sort: Option<String>,
+    order: Option<String>,
+}
+
+async fn issue_list(
+    Extension(app): Extension<Arc<App>>,
+    Query(params): Query<IssueListParams>,
+) -> Result<impl IntoResponse> {
+    let filter = QueryIssueListFilter::from_str(params.q.as_deref().unwrap_or_default())
+        .map_err(ApiError::bad_request)?;
+    let sort = params
+        .sort
+        .as_deref()
+        .map(|s| SortKey::from_str(s, true))
+        .transpose()
+        .map_err(ApiError::bad_request)?
+        .unwrap_or(SortKey::Id);
+    let order = params
+        .order
+        .as_deref()
+        .map(|s| Order::from_str(s, true))
+        .transpose()
+        .map_err(ApiError::bad_request)?
+        .unwrap_or(Order::Asc);
+    let issues = app
+        .query_handler
+        .issue_list(&filter, issue_list_sort(sort, order))
+        .await?;
+    Ok(Json(issues))
+}
+
+#[derive(Deserialize)]
+struct IssueCreateRequest {
+    title: String,
+    due: Option<String>,
+    description: Option<String>,
+    recurrence: Option<String>,
+}
+
+async fn issue_create(
+    Extension(app): Extension<Arc<App>>,
+    Json(request): Json<IssueCreateRequest>,
+) -> Result<impl IntoResponse> {
+    let issue_title = IssueTitle::try_from(request.title).map_err(ApiError::bad_request)?;
+    let issue_due = request
+        .due
+        .map(|s| IssueDue::from_str(s.as_str()))
+        .transpose()
+        .map_err(ApiError::bad_request)?;
+    let issue_description = request
+        .description
+        .map(|s| IssueDescription::from_str(s.as_str()))
+        .transpose()
+        .map_err(ApiError::bad_request)?
+        .unwrap_or_default();
+    let issue_recurrence = request
+        .recurrence
+        .map(|s| IssueRecurrence::from_str(s.as_str()))
+        .transpose()
+        .map_err(ApiError::bad_request)?;
+    let event = handle(
+        &app,
+        CreateIssue {
+            issue_title,
+            issue_due,
+            issue_description,
+            issue_recurrence,
+        },
+    )
+    .await?;
+    match event {
+        IssueManagementContextEvent::IssueCreated { issue_id } => {
+            let issue = find_issue(&app, &issue_id).await?;
+            Ok((StatusCode::CREATED, Json(issue)))
+        }
+        _ => unreachable!(),
+    }
+}
+
+async fn issue_view(
+    Extension(app): Extension<Arc<App>>,
+    Path(issue_id): Path<String>,
+) -> Result<impl IntoResponse> {
+    let issue_id = parse_issue_id(issue_id.as_str())?;
+    let issue = find_issue(&app, &issue_id).await?;
+    let etag = find_issue_etag(&app, &issue_id).await?;
+    Ok(([(header::ETAG, etag)], Json(issue)))
+}
+
+#[derive(Deserialize)]
+struct IssueUpdateRequest {
+    title: Option<String>,
+    description: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_some")]
+    due: Option<Option<String>>,
+}
+
+async fn issue_update(
+    Extension(app): Extension<Arc<App>>,
+    Path(issue_id): Path<String>,
+    headers: HeaderMap,
+    Json(request): Json<IssueUpdateRequest>,
+) -> Result<impl IntoResponse> {
+    let issue_id = parse_issue_id(issue_id.as_str())?;
+    let issue_version = parse_if_match(&headers)?;
+    let issue_title = request
+        .title
+        .map(IssueTitle::try_from)
+        .transpose()
+        .map_err(ApiError::bad_request)?;
+    let issue_description = request
+        .description
+        .map(IssueDescription::try_from)
+        .transpose()
+        .map_err(ApiError::bad_request)?;
+    let issue_due = request
+        .due
+        .map(|due| due.map(|s| IssueDue::from_str(s.as_str())).transpose())
+        .transpose()
+        .map_err(ApiError::bad_request)?;
+    find_issue(&app, &issue_id).await?;
+    handle(
+        &app,
+        EditIssue {
+            issue_id: issue_id.clone(),
+            issue_version,
+            issue_title,
+            issue_description,
+            issue_due,
+        },
+    )
+    .await?;
+    let issue = find_issue(&app, &issue_id).await?;
+    let etag = find_issue_etag(&app, &issue_id).await?;
+    Ok(([(header::ETAG, etag)], Json(issue)))
+}
+
+async fn issue_block(
+    Extension(app): Extension<Arc<App>>,
+    Path((issue_id, blocked_issue_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse> {
+    let issue_id = parse_issue_id(issue_id.as_str())?;
+    let blocked_issue_id = parse_issue_id(blocked_issue_id.as_str())?;
+    find_issue(&app, &issue_id).await?;
+    find_issue(&app, &blocked_issue_id).await?;
+    handle(
+        &app,
+        BlockIssue {
+            issue_id: issue_id.clone(),
+            blocked_issue_id,
+        },
+    )
+    .await?;
+    Ok(Json(find_issue(&app, &issue_id).await?))
+}
+
+async fn issue_unblock(
+    Extension(app): Extension<Arc<App>>,
+    Path((issue_id, blocked_issue_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse> {
+    let issue_id = parse_issue_id(issue_id.as_str())?;
+    let blocked_issue_id = parse_issue_id(blocked_issue_id.as_str())?;
+    let issue_block_link_id =
+        IssueBlockLinkId::new(issue_id.clone(), blocked_issue_id).map_err(ApiError::bad_request)?;
+    find_issue(&app, &issue_id).await?;
+    handle(
+        &app,
+        UnblockIssue {
+            issue_block_link_id,
+        },
+    )
+    .await?;
+    Ok(Json(find_issue(&app, &issue_id).await?))
+}
+
+async fn issue_cancel(
+    Extension(app): Extension<Arc<App>>,
+    Path(issue_id): Path<String>,
+) -> Result<impl IntoResponse> {
+    let issue_id = parse_issue_id(issue_id.as_str())?;
+    find_issue(&app, &issue_id).await?;
+    handle(
+        &app,
+        CancelIssue {
+            issue_id: issue_id.clone(),
+        },
+    )
+    .await?;
+    Ok(Json(find_issue(&app, &issue_id).await?))
+}
+
+#[derive(Default, Deserialize)]
+struct IssueFinishRequest {
+    resolution: Option<String>,
+}
+
+async fn issue_finish(
+    Extension(app): Extension<Arc<App>>,
+    Path(issue_id): Path<String>,
+    request: Option<Json<IssueFinishRequest>>,
+) -> Result<impl IntoResponse> {
+    let issue_id = parse_issue_id(issue_id.as_str())?;
+    let Json(request) = request.unwrap_or_default();
+    let resolution = request
+        .resolution
+        .as_deref()
+        .map(IssueResolution::from_str)
+        .transpose()
+        .map_err(ApiError::bad_request)?;
+    find_issue(&app, &issue_id).await?;
+    handle(
+        &app,
+        FinishIssue {
+            issue_id: issue_id.clone(),
+            resolution,
+        },
+    )
+    .await?;
+    Ok(Json(find_issue(&app, &issue_id).await?))
+}
+
+async fn issue_reopen(
+    Extension(app): Extension<Arc<App>>,
+    Path(issue_id): Path<String>,
+) -> Result<impl IntoResponse> {
+    let issue_id = parse_issue_id(issue_id.as_str())?;
+    find_issue(&app, &issue_id).await?;
+    handle(
+        &app,
+        ReopenIssue {
+            issue_id: issue_id.clone(),
+        },
+    )
+    .await?;
+    Ok(Json(find_issue(&app, &issue_id).await?))
+}
+
+async fn issue_start(
+    Extension(app): Extension<Arc<App>>,
+    Path(issue_id): Path<String>,
+) -> Result<impl IntoResponse> {
+    let issue_id = parse_issue_id(issue_id.as_str())?;
+    find_issue(&app, &issue_id).await?;
+    handle(
+        &app,
+        StartIssue {
+            issue_id: issue_id.clone(),
+        },
+    )
+    .await?;
+    Ok(Json(find_issue(&app, &issue_id).await?))
+}
+
+#[derive(Deserialize)]
+struct IssueCommentRequest {
+    text: String,
+}
+
+async fn issue_comment_create(
+    Extension(app): Extension<Arc<App>>,
+    Path(issue_id): Path<String>,
+    Json(request): Json<IssueCommentRequest>,
+) -> Result<impl IntoResponse> {
+    let issue_id = parse_issue_id(issue_id.as_str())?;
+    let text = IssueCommentText::from_str(request.text.as_str()).map_err(ApiError::bad_request)?;
+    find_issue(&app, &issue_id).await?;
+    let event = handle(&app, CreateIssueComment { issue_id, text }).await?;
+    match event {
+        IssueManagementContextEvent::IssueCommentCreated { issue_comment_id } => {
+            let issue_comment = find_issue_comment(&app, &issue_comment_id).await?;
+            Ok((StatusCode::CREATED, Json(issue_comment)))
+        }
+        _ => unreachable!(),
+    }
+}
+
+async fn issue_comment_view(
+    Extension(app): Extension<Arc<App>>,
+    Path(issue_comment_id): Path<String>,
+) -> Result<impl IntoResponse> {
+    let issue_comment_id = parse_issue_comment_id(issue_comment_id.as_str())?;
+    Ok(Json(find_issue_comment(&app, &issue_comment_id).await?))
+}
+
+async fn issue_comment_update(
+    Extension(app): Extension<Arc<App>>,
+    Path(issue_comment_id): Path<String>,
+    Json(request): Json<IssueCommentRequest>,
+) -> Result<impl IntoResponse> {
+    let issue_comment_id = parse_issue_comment_id(issue_comment_id.as_str())?;
+    let text = IssueCommentText::from_str(request.text.as_str()).map_err(ApiError::bad_request)?;
+    find_issue_comment(&app, &issue_comment_id).await?;
+    handle(
+        &app,
+        UpdateIssueComment {
+            issue_comment_id: issue_comment_id.clone(),
+            text,
+        },
+    )
+    .await?;
+    Ok(Json(find_issue_comment(&app, &issue_comment_id).await?))
+}
+
+async fn issue_comment_delete(
+    Extension(app): Extension<Arc<App>>,
+    Path(issue_comment_id): Path<String>,
+) -> Result<impl IntoResponse> {
+    let issue_comment_id = parse_issue_comment_id(issue_comment_id.as_str())?;
+    find_issue_comment(&app, &issue_comment_id).await?;
+    handle(&app, DeleteIssueComment { issue_comment_id }).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, http::Request};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    async fn test_app() -> anyhow::Result<Router> {
+        let app = App::new(
+            Some("sqlite::memory:".to_string()),
+            Some("sqlite::memory:".to_string()),
+        )
+        .await?;
+        Ok(router().layer(Extension(Arc::new(app))))
+    }
+
+    async fn request(
+        router: &Router,
+        method: &str,
+        uri: &str,
+        body: Option<&str>,
+    ) -> anyhow::Result<(StatusCode, String)> {
+        let (status, _, body) = request_with_headers(router, method, uri, &[], body).await?;
+        Ok((status, body))
+    }
+
+    async fn request_with_headers(
+        router: &Router,
+        method: &str,
+        uri: &str,
+        headers: &[(&str, &str)],
+        body: Option<&str>,
+    ) -> anyhow::Result<(StatusCode, HeaderMap, String)> {
+        let mut request = Request::builder().method(method).uri(uri);
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        let request = match body {
+            Some(body) => request
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))?,
+            None => request.body(Body::empty())?,
+        };
+        let response = router.clone().oneshot(request).await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = hyper::body::to_bytes(response.into_body()).await?;
+        Ok((status, headers, String::from_utf8(body.to_vec())?))
+    }
+
+    #[tokio::test]
+    async fn issue_test() -> anyhow::Result<()> {
+        let router = test_app().await?;
+
+        let (status, body) = request(
+            &router,
+            "POST",
+            "/issues",
+            Some(r#"{"title":"title1","due":"2021-02-03T04:05:06Z"}"#),
+        )
+        .await?;
+        assert_eq!(status, StatusCode::CREATED);
+        assert!(body.contains(r#""id":"1""#));
+        assert!(body.contains(r#""title":"title1""#));
+        assert!(body.contains(r#""due":"2021-02-03T04:05:06Z""#));
+
+        let (status, _) =
+            request(&router, "POST", "/issues", Some(r#"{"title":"title2"}"#)).await?;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (status, body) = request(&router, "GET", "/issues/1", None).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains(r#""status":"todo""#));
+
+        let (status, body) = request(
+            &router,
+            "PATCH",
+            "/issues/1",
+            Some(r#"{"title":"title1b","description":"desc1","due":null}"#),
+        )
+        .await?;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains(r#""title":"title1b""#));
+        assert!(body.contains(r#""description":"desc1""#));
+        assert!(body.contains(r#""due":null"#));
+
+        let (status, body) = request(&router, "PUT", "/issues/1/blocks/2", None).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains(r#""blocks":[{"id":"2","title":"title2"}]"#));
+        let (status, body) = request(&router, "DELETE", "/issues/1/blocks/2", None).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains(r#""blocks":[]"#));
+
+        let (status, body) = request(&router, "POST", "/issues/1/start", None).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains(r#""status":"in_progress""#));
+        let (status, body) = request(
+            &router,
+            "POST",
+            "/issues/1/finish",
+            Some(r#"{"resolution":"duplicate"}"#),
+        )
+        .await?;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains(r#""status":"done""#));
+        assert!(body.contains(r#""resolution":"duplicate""#));
+        let (status, _) = request(&router, "POST", "/issues/1/finish", None).await?;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, body) = request(&router, "POST", "/issues/2/finish", None).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains(r#""resolution":null"#));
+
+        let (status, body) = request(&router, "GET", "/issues?q=is:closed", None).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.starts_with(r#"[{"id":"1""#));
+        let (status, body) = request(&router, "GET", "/issues?sort=id&order=desc", None).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.starts_with(r#"[{"id":"2""#));
+        let (status, _) = request(&router, "GET", "/issues?sort=unknown", None).await?;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = request(&router, "GET", "/issues/3", None).await?;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = request(&router, "POST", "/issues/3/start", None).await?;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = request(&router, "GET", "/issues/abc", None).await?;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn issue_update_if_match_test() -> anyhow::Result<()> {
+        let router = test_app().await?;
+        request(&router, "POST", "/issues", Some(r#"{"title":"title1"}"#)).await?;
+
+        let (status, headers, _) =
+            request_with_headers(&router, "GET", "/issues/1", &[], None).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(headers[header::ETAG], r#""1""#);
+
+        let (status, headers, body) = request_with_headers(
+            &router,
+            "PATCH",
+            "/issues/1",
+            &[("if-match", r#""1""#)],
+            Some(r#"{"title":"title1b","description":"desc1"}"#),
+        )
+        .await?;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(headers[header::ETAG], r#""3""#);
+        assert!(body.contains(r#""title":"title1b""#));
+
+        // the stale version updates nothing
+        let (status, _, _) = request_with_headers(
+            &router,
+            "PATCH",
+            "/issues/1",
+            &[("if-match", r#""1""#)],
+            Some(r#"{"title":"title1c","due":"2021-02-03T04:05:06Z"}"#),
+        )
+        .await?;
+        assert_eq!(status, StatusCode::CONFLICT);
+        let (_, headers, body) =
+            request_with_headers(&router, "GET", "/issues/1", &[], None).await?;
+        assert_eq!(headers[header::ETAG], r#""3""#);
+        assert!(body.contains(r#""title":"title1b""#));
+        assert!(body.contains(r#""due":null"#));
+
+        let (status, _, _) = request_with_headers(
+            &router,
+            "PATCH",
+            "/issues/1",
+            &[("if-match", "1")],
+            Some(r#"{"title":"title1c"}"#),
+        )
+        .await?;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn issue_comment_test() -> anyhow::Result<()> {
+        let router = test_app().await?;
+        request(&router, "POST", "/issues", Some(r#"{"title":"title1"}"#)).await?;
+
+        let (status, body) = request(
+            &router,
+            "POST",
+            "/issues/1/comments",
+            Some(r#"{"text":"comment1"}"#),
+        )
+        .await?;
+        assert_eq!(status, StatusCode::CREATED);
+        assert!(body.contains(r#""text":"comment1""#));
+        let issue_comment_id = serde_json::from_str::<serde_json::Value>(body.as_str())?["id"]
+            .as_str()
+            .map(|s| s.to_string())
+            .ok_or_else(|| anyhow::anyhow!("no id"))?;
+        let uri = format!("/issue_comments/{}", issue_comment_id);
+
+        let (status, body) = request(&router, "GET", uri.as_str(), None).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains(r#""text":"comment1""#));
+
+        let (status, body) = request(
+            &router,
+            "PATCH",
+            uri.as_str(),
+            Some(r#"{"text":"comment2"}"#),
+        )
+        .await?;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains(r#""text":"comment2""#));
+
+        let (status, _) = request(&router, "DELETE", uri.as_str(), None).await?;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (status, _) = request(&router, "GET", uri.as_str(), None).await?;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, _) = request(
+            &router,
+            "POST",
+            "/issues/2/comments",
+            Some(r#"{"text":"comment3"}"#),
+        )
+        .await?;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        Ok(())
+    }
+
+    #[test]
+    fn api_error_from_use_case_error_test() {
+        let e = ApiError::from(use_case::Error::IssueRepository(
+            issue_repository::Error::Conflict,
+        ));
+        assert_eq!(e.status, StatusCode::CONFLICT);
+        let e = ApiError::from(use_case::Error::IssueBlockLinkRepository(
+            IssueBlockLinkRepositoryError::Conflict,
+        ));
+        assert_eq!(e.status, StatusCode::CONFLICT);
+        let e = ApiError::from(use_case::Error::IssueAggregate(
+            domain::aggregate::issue::Error::Unknown,
+        ));
+        assert_eq!(e.status, StatusCode::BAD_REQUEST);
+        let e = ApiError::from(use_case::Error::IssueRepository(
+            issue_repository::Error::IO,
+        ));
+        assert_eq!(e.status, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -1,4 +1,5 @@
 use assert_cmd::Command;
+use predicates::prelude::*;
 
 #[test]
 fn its_no_args() -> anyhow::Result<()> {
@@ -11,6 +12,157 @@ fn its_no_args() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn its_export() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    Command::cargo_bin("its")?
+        .args(&["issue", "create", "--title", "title1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue-comment", "create", "1", "comment1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    let output = Command::cargo_bin("its")?
+        .args(&["export"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+    let lines = stdout.lines().collect::<Vec<&str>>();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with(r#"{"id":""#));
+    assert!(lines[0].contains(r#""version":1,"data":"#));
+    assert!(lines[0].contains("title1"));
+    assert!(lines[1].contains("comment1"));
+    Ok(())
+}
+
+#[test]
+fn its_graph() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    for title in ["title1", "title2", "title3", "title4"] {
+        Command::cargo_bin("its")?
+            .args(&["issue", "create", "--title", title])
+            .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+            .assert();
+    }
+    for (issue_id, blocked_issue_id) in [("1", "2"), ("2", "3"), ("3", "2"), ("4", "1")] {
+        Command::cargo_bin("its")?
+            .args(&["issue", "block", issue_id, blocked_issue_id])
+            .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+            .assert();
+    }
+    Command::cargo_bin("its")?
+        .args(&["issue", "finish", "4"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["graph"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains("digraph issues {"))
+        .stdout(predicates::str::contains("// cycle: #2 -> #3 -> #2"))
+        .stdout(predicates::str::contains(
+            r##""1" [label="#1 title1", style=bold];"##,
+        ))
+        .stdout(predicates::str::contains(r#""1" -> "2";"#))
+        .stdout(predicates::str::contains(r#""4""#).not())
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["graph", "--format", "mermaid"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains("graph LR"))
+        .stdout(predicates::str::contains("issue1 --> issue2"))
+        .stdout(predicates::str::contains("class issue1 ready"))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["graph", "--format", "json"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(
+            r#""cycles":[["2","3"]],"ready":["1"]"#,
+        ))
+        .success();
+    Ok(())
+}
+
+#[test]
+fn its_import() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    Command::cargo_bin("its")?
+        .args(&["issue", "create", "--title", "title1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "update-title", "1", "title2"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    let exported = Command::cargo_bin("its")?
+        .args(&["export"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .output()?
+        .stdout;
+
+    let fresh_dir = tempfile::tempdir()?;
+    Command::cargo_bin("its")?
+        .args(&["import"])
+        .env("XDG_STATE_HOME", fresh_dir.path().as_os_str())
+        .write_stdin(exported.clone())
+        .assert()
+        .stdout(predicates::str::contains(r#""name":"issues""#))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "view", "1"])
+        .env("XDG_STATE_HOME", fresh_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""title":"title2""#))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "create", "--title", "title3"])
+        .env("XDG_STATE_HOME", fresh_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""id":"2""#))
+        .success();
+
+    // conflicts with the imported event streams
+    let file = fresh_dir.path().join("events.jsonl");
+    std::fs::write(file.as_path(), exported)?;
+    Command::cargo_bin("its")?
+        .args(&["import", file.to_str().unwrap()])
+        .env("XDG_STATE_HOME", fresh_dir.path().as_os_str())
+        .assert()
+        .failure();
+    Ok(())
+}
+
+#[test]
+fn its_issue_add_label() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    Command::cargo_bin("its")?
+        .args(&["issue", "create", "--title", "title1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "add-label", "1", "bug"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""labels":["bug"]"#))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "add-label", "1", "bug"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .failure();
+    Command::cargo_bin("its")?
+        .args(&["issue", "add-label", "1", "a b"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .failure();
+    Ok(())
+}
+
 #[test]
 fn its_issue_block() -> anyhow::Result<()> {
     let temp_dir = tempfile::tempdir()?;
@@ -32,6 +184,33 @@ fn its_issue_block() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn its_issue_cancel() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    Command::cargo_bin("its")?
+        .args(&["issue", "create", "--title", "title1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "start", "1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""status":"in_progress""#))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "cancel", "1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""status":"cancelled""#))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "cancel", "1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .failure();
+    Ok(())
+}
+
 #[test]
 fn its_issue_comment_create() -> anyhow::Result<()> {
     #[derive(Debug, serde::Deserialize)]
@@ -224,6 +403,89 @@ fn its_issue_finish() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn its_issue_finish_recurring() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    Command::cargo_bin("its")?
+        .args(&[
+            "issue",
+            "create",
+            "--title",
+            "title1",
+            "--due",
+            "2021-02-03T04:05:06Z",
+            "--recurrence",
+            "weekly",
+        ])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""recurrence":"FREQ=WEEKLY""#))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "finish", "1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""next_occurrence":"2""#))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "view", "2"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""title":"title1""#))
+        .stdout(predicates::str::contains(r#""due":"2021-02-10T04:05:06Z""#))
+        .stdout(predicates::str::contains(r#""recurrence":"FREQ=WEEKLY""#))
+        .stdout(predicates::str::contains(r#""status":"todo""#))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&[
+            "issue",
+            "create",
+            "--title",
+            "title3",
+            "--recurrence",
+            "yearly",
+        ])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .failure();
+    Ok(())
+}
+
+#[test]
+fn its_issue_history() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    Command::cargo_bin("its")?
+        .args(&["issue", "create", "--title", "title1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "update-title", "1", "title2"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "history", "1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains("issue_created 1 v1"))
+        .stdout(predicates::str::contains("issue_title_updated 1 v2"))
+        .stdout(predicates::str::contains("  title: title1 -> title2"))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "history", "--format", "json", "1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(
+            r#""changes":[{"field":"title","old":"title1","new":"title2"}]"#,
+        ))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "history", "2"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .failure();
+    Ok(())
+}
+
 #[test]
 fn its_issue_list() -> anyhow::Result<()> {
     let temp_dir = tempfile::tempdir()?;
@@ -245,6 +507,223 @@ fn its_issue_list() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn its_issue_list_with_filter() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    Command::cargo_bin("its")?
+        .args(&["issue", "create", "--title", "title1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "create", "--title", "title2"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "add-label", "1", "bug"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "set-priority", "2", "high"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "list", "--label", "bug"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""id":"1""#))
+        .stdout(predicates::str::contains(r#""id":"2""#).not())
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "list", "--priority", "high"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""id":"2""#))
+        .stdout(predicates::str::contains(r#""id":"1""#).not())
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "list", "--assignee", "bouzuya"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#"[]"#))
+        .success();
+    Ok(())
+}
+
+#[test]
+fn its_issue_list_with_query() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    Command::cargo_bin("its")?
+        .args(&["issue", "create", "--title", "foo bar"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&[
+            "issue",
+            "create",
+            "--title",
+            "baz",
+            "--due",
+            "2026-10-31T00:00:00Z",
+        ])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "block", "1", "2"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "list", "status:todo due:<2026-11-01 is:blocked"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""id":"2""#))
+        .stdout(predicates::str::contains(r#""id":"1""#).not())
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "list", r#""FOO BAR""#])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""id":"1""#))
+        .stdout(predicates::str::contains(r#""id":"2""#).not())
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "list", "--sort", "id", "--order", "desc"])
+        .args(&["--format", "table"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::starts_with(
+            "ID  STATUS  PRIORITY  DUE                   LABELS  TITLE\n2   todo    -         2026-10-31T00:00:00Z  -       baz\n1   todo",
+        ))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "list", "unknown:value"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .failure();
+    Ok(())
+}
+
+#[test]
+fn its_issue_remove_label() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    Command::cargo_bin("its")?
+        .args(&["issue", "create", "--title", "title1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "add-label", "1", "bug"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "remove-label", "1", "bug"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""labels":[]"#))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "remove-label", "1", "bug"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .failure();
+    Ok(())
+}
+
+#[test]
+fn its_issue_reopen() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    Command::cargo_bin("its")?
+        .args(&["issue", "create", "--title", "title1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "reopen", "1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .failure();
+    Command::cargo_bin("its")?
+        .args(&["issue", "finish", "1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""status":"done""#))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "reopen", "1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""status":"todo""#))
+        .success();
+    Ok(())
+}
+
+#[test]
+fn its_issue_set_assignee() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    Command::cargo_bin("its")?
+        .args(&["issue", "create", "--title", "title1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "set-assignee", "1", "bouzuya"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""assignee":"bouzuya""#))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "set-assignee", "1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""assignee":null"#))
+        .success();
+    Ok(())
+}
+
+#[test]
+fn its_issue_set_priority() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    Command::cargo_bin("its")?
+        .args(&["issue", "create", "--title", "title1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "set-priority", "1", "urgent"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""priority":"urgent""#))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "set-priority", "1", "normal"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .failure();
+    Command::cargo_bin("its")?
+        .args(&["issue", "set-priority", "1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""priority":null"#))
+        .success();
+    Ok(())
+}
+
+#[test]
+fn its_issue_start() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    Command::cargo_bin("its")?
+        .args(&["issue", "create", "--title", "title1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "start", "1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""status":"in_progress""#))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "start", "1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .failure();
+    Ok(())
+}
+
 #[test]
 fn its_issue_unblock() -> anyhow::Result<()> {
     let temp_dir = tempfile::tempdir()?;
@@ -350,3 +829,116 @@ fn its_issue_view() -> anyhow::Result<()> {
         .success();
     Ok(())
 }
+
+#[test]
+fn its_issue_view_with_version() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    Command::cargo_bin("its")?
+        .args(&["issue", "create", "--title", "title1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "update-title", "1", "title2"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue", "view", "--version", "1", "1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""title":"title1""#))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "view", "--version", "3", "1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout("null\n")
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "view", "--at", "1970-01-01T00:00:00Z", "1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout("null\n")
+        .success();
+    Ok(())
+}
+
+#[test]
+fn its_query_rebuild() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    Command::cargo_bin("its")?
+        .args(&["issue", "create", "--title", "title1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["issue-comment", "create", "1", "comment1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["query", "rebuild"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""name":"issues""#))
+        .stdout(predicates::str::contains(r#""lag":1"#).not())
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "view", "1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(r#""title":"title1""#))
+        .stdout(predicates::str::contains(r#""text":"comment1""#))
+        .success();
+    Ok(())
+}
+
+#[test]
+fn its_query_status() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    Command::cargo_bin("its")?
+        .args(&["query", "status"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(
+            r#"{"name":"issues","event_id":null,"last_event_id":null,"lag":0}"#,
+        ))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "create", "--title", "title1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert();
+    Command::cargo_bin("its")?
+        .args(&["query", "status"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .assert()
+        .stdout(predicates::str::contains(
+            r#"{"name":"issues","event_id":""#,
+        ))
+        .stdout(predicates::str::contains(r#""lag":0}"#))
+        .success();
+    Ok(())
+}
+
+#[test]
+fn its_snapshot_interval() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    Command::cargo_bin("its")?
+        .args(&["issue", "create", "--title", "title1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .env("ITS_SNAPSHOT_INTERVAL", "1")
+        .assert()
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "update-title", "1", "title2"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .env("ITS_SNAPSHOT_INTERVAL", "1")
+        .assert()
+        .stdout(predicates::str::contains(r#""title":"title2""#))
+        .success();
+    Command::cargo_bin("its")?
+        .args(&["issue", "view", "1"])
+        .env("XDG_STATE_HOME", temp_dir.path().as_os_str())
+        .env("ITS_SNAPSHOT_INTERVAL", "x")
+        .assert()
+        .stderr(predicates::str::contains("ITS_SNAPSHOT_INTERVAL"))
+        .failure();
+    Ok(())
+}
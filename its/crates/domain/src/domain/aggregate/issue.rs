@@ -2,20 +2,36 @@ pub mod attribute;
 mod entity;
 mod error;
 mod event;
+mod snapshot;
 
 use limited_date_time::Instant;
 
 pub use self::attribute::issue_description::IssueDescription;
+pub use self::attribute::IssueAssignee;
 pub use self::attribute::IssueDue;
+pub use self::attribute::IssueLabel;
+pub use self::attribute::IssuePriority;
+pub use self::attribute::IssueRecurrence;
+pub use self::attribute::IssueRecurrenceFrequency;
 pub use self::attribute::IssueResolution;
 pub use self::attribute::IssueStatus;
 pub use self::attribute::IssueTitle;
 use self::entity::issue::Issue;
 pub use self::error::*;
 pub use self::event::*;
+pub use self::snapshot::*;
+use crate::IssueAssigneeUpdated;
+use crate::IssueCancelled;
 use crate::IssueCreatedV2;
 use crate::IssueDescriptionUpdated;
+use crate::IssueLabelAdded;
+use crate::IssueLabelRemoved;
 use crate::IssueNumber;
+use crate::IssuePriorityUpdated;
+use crate::IssueRecurred;
+use crate::IssueRecurrenceUpdated;
+use crate::IssueReopened;
+use crate::IssueStarted;
 use crate::IssueTitleUpdated;
 use crate::IssueUpdated;
 use crate::{domain::event::IssueFinished, IssueId, Version};
@@ -36,10 +52,19 @@ impl IssueAggregate {
     pub fn from_events(events: &[IssueAggregateEvent]) -> Result<Self> {
         let first_event = match events.first() {
             Some(event) => match event {
+                IssueAggregateEvent::AssigneeUpdated(_) => Err(Error::InvalidEventSequence),
+                IssueAggregateEvent::Cancelled(_) => Err(Error::InvalidEventSequence),
                 IssueAggregateEvent::Created(event) => Ok(IssueCreatedV2::from(event.clone())),
                 IssueAggregateEvent::CreatedV2(event) => Ok(event.clone()),
                 IssueAggregateEvent::DescriptionUpdated(_) => Err(Error::InvalidEventSequence),
                 IssueAggregateEvent::Finished(_) => Err(Error::InvalidEventSequence),
+                IssueAggregateEvent::LabelAdded(_) => Err(Error::InvalidEventSequence),
+                IssueAggregateEvent::LabelRemoved(_) => Err(Error::InvalidEventSequence),
+                IssueAggregateEvent::PriorityUpdated(_) => Err(Error::InvalidEventSequence),
+                IssueAggregateEvent::Recurred(_) => Err(Error::InvalidEventSequence),
+                IssueAggregateEvent::RecurrenceUpdated(_) => Err(Error::InvalidEventSequence),
+                IssueAggregateEvent::Reopened(_) => Err(Error::InvalidEventSequence),
+                IssueAggregateEvent::Started(_) => Err(Error::InvalidEventSequence),
                 IssueAggregateEvent::TitleUpdated(_) => Err(Error::InvalidEventSequence),
                 IssueAggregateEvent::Updated(_) => Err(Error::InvalidEventSequence),
             },
@@ -47,13 +72,65 @@ impl IssueAggregate {
         }?;
         let version = first_event.version;
         let issue = Issue::from_event(first_event);
-        let mut issue = IssueAggregate {
+        IssueAggregate {
             events: vec![],
             issue,
             version,
-        };
-        for event in events.iter().skip(1) {
+        }
+        .apply_events(&events[1..])
+    }
+
+    pub fn from_snapshot_and_events(
+        snapshot: IssueAggregateSnapshot,
+        events: &[IssueAggregateEvent],
+    ) -> Result<Self> {
+        IssueAggregate::from(snapshot).apply_events(events)
+    }
+
+    fn apply_events(self, events: &[IssueAggregateEvent]) -> Result<Self> {
+        let mut issue = self;
+        for event in events.iter() {
             match event {
+                IssueAggregateEvent::AssigneeUpdated(IssueAssigneeUpdated {
+                    at: _,
+                    issue_id,
+                    issue_assignee,
+                    version,
+                }) => {
+                    if issue.issue.id() != issue_id {
+                        return Err(Error::InvalidEventSequence);
+                    }
+                    if issue.version.next() != Some(*version) {
+                        return Err(Error::InvalidEventSequence);
+                    }
+
+                    issue = IssueAggregate {
+                        events: vec![],
+                        issue: issue.issue.change_assignee(issue_assignee.clone()),
+                        version: *version,
+                    }
+                }
+                IssueAggregateEvent::Cancelled(IssueCancelled {
+                    at: _,
+                    issue_id,
+                    version,
+                }) => {
+                    if issue.issue.id() != issue_id {
+                        return Err(Error::InvalidEventSequence);
+                    }
+                    if issue.version.next() != Some(*version) {
+                        return Err(Error::InvalidEventSequence);
+                    }
+
+                    issue = IssueAggregate {
+                        events: vec![],
+                        issue: issue
+                            .issue
+                            .cancel()
+                            .map_err(|_| Error::InvalidEventSequence)?,
+                        version: *version,
+                    }
+                }
                 IssueAggregateEvent::Created(_) => {
                     return Err(Error::InvalidEventSequence);
                 }
@@ -101,6 +178,152 @@ impl IssueAggregate {
                         version: *version,
                     }
                 }
+                IssueAggregateEvent::LabelAdded(IssueLabelAdded {
+                    at: _,
+                    issue_id,
+                    issue_label,
+                    version,
+                }) => {
+                    if issue.issue.id() != issue_id {
+                        return Err(Error::InvalidEventSequence);
+                    }
+                    if issue.version.next() != Some(*version) {
+                        return Err(Error::InvalidEventSequence);
+                    }
+
+                    issue = IssueAggregate {
+                        events: vec![],
+                        issue: issue
+                            .issue
+                            .add_label(issue_label.clone())
+                            .map_err(|_| Error::InvalidEventSequence)?,
+                        version: *version,
+                    }
+                }
+                IssueAggregateEvent::LabelRemoved(IssueLabelRemoved {
+                    at: _,
+                    issue_id,
+                    issue_label,
+                    version,
+                }) => {
+                    if issue.issue.id() != issue_id {
+                        return Err(Error::InvalidEventSequence);
+                    }
+                    if issue.version.next() != Some(*version) {
+                        return Err(Error::InvalidEventSequence);
+                    }
+
+                    issue = IssueAggregate {
+                        events: vec![],
+                        issue: issue
+                            .issue
+                            .remove_label(issue_label)
+                            .map_err(|_| Error::InvalidEventSequence)?,
+                        version: *version,
+                    }
+                }
+                IssueAggregateEvent::PriorityUpdated(IssuePriorityUpdated {
+                    at: _,
+                    issue_id,
+                    issue_priority,
+                    version,
+                }) => {
+                    if issue.issue.id() != issue_id {
+                        return Err(Error::InvalidEventSequence);
+                    }
+                    if issue.version.next() != Some(*version) {
+                        return Err(Error::InvalidEventSequence);
+                    }
+
+                    issue = IssueAggregate {
+                        events: vec![],
+                        issue: issue.issue.change_priority(*issue_priority),
+                        version: *version,
+                    }
+                }
+                IssueAggregateEvent::Recurred(IssueRecurred {
+                    at: _,
+                    issue_id,
+                    next_issue_id,
+                    version,
+                }) => {
+                    if issue.issue.id() != issue_id {
+                        return Err(Error::InvalidEventSequence);
+                    }
+                    if issue.version.next() != Some(*version) {
+                        return Err(Error::InvalidEventSequence);
+                    }
+
+                    issue = IssueAggregate {
+                        events: vec![],
+                        issue: issue
+                            .issue
+                            .recur(next_issue_id.clone())
+                            .map_err(|_| Error::InvalidEventSequence)?,
+                        version: *version,
+                    }
+                }
+                IssueAggregateEvent::RecurrenceUpdated(IssueRecurrenceUpdated {
+                    at: _,
+                    issue_id,
+                    issue_recurrence,
+                    version,
+                }) => {
+                    if issue.issue.id() != issue_id {
+                        return Err(Error::InvalidEventSequence);
+                    }
+                    if issue.version.next() != Some(*version) {
+                        return Err(Error::InvalidEventSequence);
+                    }
+
+                    issue = IssueAggregate {
+                        events: vec![],
+                        issue: issue.issue.change_recurrence(*issue_recurrence),
+                        version: *version,
+                    }
+                }
+                IssueAggregateEvent::Reopened(IssueReopened {
+                    at: _,
+                    issue_id,
+                    version,
+                }) => {
+                    if issue.issue.id() != issue_id {
+                        return Err(Error::InvalidEventSequence);
+                    }
+                    if issue.version.next() != Some(*version) {
+                        return Err(Error::InvalidEventSequence);
+                    }
+
+                    issue = IssueAggregate {
+                        events: vec![],
+                        issue: issue
+                            .issue
+                            .reopen()
+                            .map_err(|_| Error::InvalidEventSequence)?,
+                        version: *version,
+                    }
+                }
+                IssueAggregateEvent::Started(IssueStarted {
+                    at: _,
+                    issue_id,
+                    version,
+                }) => {
+                    if issue.issue.id() != issue_id {
+                        return Err(Error::InvalidEventSequence);
+                    }
+                    if issue.version.next() != Some(*version) {
+                        return Err(Error::InvalidEventSequence);
+                    }
+
+                    issue = IssueAggregate {
+                        events: vec![],
+                        issue: issue
+                            .issue
+                            .start()
+                            .map_err(|_| Error::InvalidEventSequence)?,
+                        version: *version,
+                    }
+                }
                 IssueAggregateEvent::TitleUpdated(IssueTitleUpdated {
                     at: _,
                     issue_id,
@@ -177,6 +400,44 @@ impl IssueAggregate {
         Ok(issue)
     }
 
+    pub fn add_label(&self, issue_label: IssueLabel, at: Instant) -> Result<Self> {
+        let updated_issue = self
+            .issue
+            .add_label(issue_label.clone())
+            .map_err(|_| Error::Unknown)?;
+        let updated_version = self.version.next().ok_or(Error::Unknown)?;
+        let event = IssueLabelAdded {
+            at,
+            issue_id: self.id().clone(),
+            issue_label,
+            version: updated_version,
+        }
+        .into();
+        let events = [self.events.as_slice(), &[event]].concat();
+        Ok(IssueAggregate {
+            events,
+            issue: updated_issue,
+            version: updated_version,
+        })
+    }
+
+    pub fn cancel(&self, at: Instant) -> Result<Self> {
+        let updated_issue = self.issue.cancel().map_err(|_| Error::Unknown)?;
+        let updated_version = self.version.next().ok_or(Error::Unknown)?;
+        let event = IssueCancelled {
+            at,
+            issue_id: self.id().clone(),
+            version: updated_version,
+        }
+        .into();
+        let events = [self.events.as_slice(), &[event]].concat();
+        Ok(IssueAggregate {
+            events,
+            issue: updated_issue,
+            version: updated_version,
+        })
+    }
+
     pub fn finish(&self, resolution: Option<IssueResolution>, at: Instant) -> Result<Self> {
         let updated_issue = self
             .issue
@@ -198,6 +459,106 @@ impl IssueAggregate {
         })
     }
 
+    // finishes the occurrence and creates the next one
+    pub fn recur(&self, next_issue_number: IssueNumber, at: Instant) -> Result<(Self, Self)> {
+        let recurrence = self.recurrence().ok_or(Error::Unknown)?;
+        let next_due = recurrence
+            .next_due(self.due().unwrap_or_else(|| IssueDue::from(at)))
+            .ok_or(Error::Unknown)?;
+        let mut next = IssueAggregate::new(
+            at,
+            next_issue_number,
+            self.title().clone(),
+            Some(next_due),
+            self.description().clone(),
+        )?
+        .update_recurrence(Some(recurrence), at)?;
+        for label in self.labels() {
+            next = next.add_label(label.clone(), at)?;
+        }
+        if self.priority().is_some() {
+            next = next.update_priority(self.priority(), at)?;
+        }
+        if self.assignee().is_some() {
+            next = next.update_assignee(self.assignee().cloned(), at)?;
+        }
+
+        let updated_issue = self
+            .issue
+            .recur(next.id().clone())
+            .map_err(|_| Error::Unknown)?;
+        let updated_version = self.version.next().ok_or(Error::Unknown)?;
+        let event = IssueRecurred {
+            at,
+            issue_id: self.id().clone(),
+            next_issue_id: next.id().clone(),
+            version: updated_version,
+        }
+        .into();
+        let events = [self.events.as_slice(), &[event]].concat();
+        let updated = IssueAggregate {
+            events,
+            issue: updated_issue,
+            version: updated_version,
+        };
+        Ok((updated, next))
+    }
+
+    pub fn remove_label(&self, issue_label: IssueLabel, at: Instant) -> Result<Self> {
+        let updated_issue = self
+            .issue
+            .remove_label(&issue_label)
+            .map_err(|_| Error::Unknown)?;
+        let updated_version = self.version.next().ok_or(Error::Unknown)?;
+        let event = IssueLabelRemoved {
+            at,
+            issue_id: self.id().clone(),
+            issue_label,
+            version: updated_version,
+        }
+        .into();
+        let events = [self.events.as_slice(), &[event]].concat();
+        Ok(IssueAggregate {
+            events,
+            issue: updated_issue,
+            version: updated_version,
+        })
+    }
+
+    pub fn reopen(&self, at: Instant) -> Result<Self> {
+        let updated_issue = self.issue.reopen().map_err(|_| Error::Unknown)?;
+        let updated_version = self.version.next().ok_or(Error::Unknown)?;
+        let event = IssueReopened {
+            at,
+            issue_id: self.id().clone(),
+            version: updated_version,
+        }
+        .into();
+        let events = [self.events.as_slice(), &[event]].concat();
+        Ok(IssueAggregate {
+            events,
+            issue: updated_issue,
+            version: updated_version,
+        })
+    }
+
+    pub fn start(&self, at: Instant) -> Result<Self> {
+        let updated_issue = self.issue.start().map_err(|_| Error::Unknown)?;
+        let updated_version = self.version.next().ok_or(Error::Unknown)?;
+        let event = IssueStarted {
+            at,
+            issue_id: self.id().clone(),
+            version: updated_version,
+        }
+        .into();
+        let events = [self.events.as_slice(), &[event]].concat();
+        Ok(IssueAggregate {
+            events,
+            issue: updated_issue,
+            version: updated_version,
+        })
+    }
+
     pub fn update(&self, issue_due: Option<IssueDue>, at: Instant) -> Result<Self> {
         let updated_issue = self.issue.change_due(issue_due);
         let updated_version = self.version.next().ok_or(Error::Unknown)?;
@@ -216,6 +577,28 @@ impl IssueAggregate {
         })
     }
 
+    pub fn update_assignee(
+        &self,
+        issue_assignee: Option<IssueAssignee>,
+        at: Instant,
+    ) -> Result<Self> {
+        let updated_issue = self.issue.change_assignee(issue_assignee);
+        let updated_version = self.version.next().ok_or(Error::Unknown)?;
+        let event = IssueAssigneeUpdated {
+            at,
+            issue_id: self.id().clone(),
+            issue_assignee: updated_issue.assignee().cloned(),
+            version: updated_version,
+        }
+        .into();
+        let events = [self.events.as_slice(), &[event]].concat();
+        Ok(IssueAggregate {
+            events,
+            issue: updated_issue,
+            version: updated_version,
+        })
+    }
+
     pub fn update_description(
         &self,
         issue_description: IssueDescription,
@@ -238,6 +621,50 @@ impl IssueAggregate {
         })
     }
 
+    pub fn update_priority(
+        &self,
+        issue_priority: Option<IssuePriority>,
+        at: Instant,
+    ) -> Result<Self> {
+        let updated_issue = self.issue.change_priority(issue_priority);
+        let updated_version = self.version.next().ok_or(Error::Unknown)?;
+        let event = IssuePriorityUpdated {
+            at,
+            issue_id: self.id().clone(),
+            issue_priority: updated_issue.priority(),
+            version: updated_version,
+        }
+        .into();
+        let events = [self.events.as_slice(), &[event]].concat();
+        Ok(IssueAggregate {
+            events,
+            issue: updated_issue,
+            version: updated_version,
+        })
+    }
+
+    pub fn update_recurrence(
+        &self,
+        issue_recurrence: Option<IssueRecurrence>,
+        at: Instant,
+    ) -> Result<Self> {
+        let updated_issue = self.issue.change_recurrence(issue_recurrence);
+        let updated_version = self.version.next().ok_or(Error::Unknown)?;
+        let event = IssueRecurrenceUpdated {
+            at,
+            issue_id: self.id().clone(),
+            issue_recurrence: updated_issue.recurrence(),
+            version: updated_version,
+        }
+        .into();
+        let events = [self.events.as_slice(), &[event]].concat();
+        Ok(IssueAggregate {
+            events,
+            issue: updated_issue,
+            version: updated_version,
+        })
+    }
+
     pub fn update_title(&self, issue_title: IssueTitle, at: Instant) -> Result<Self> {
         let updated_issue = self.issue.change_title(issue_title);
         let updated_version = self.version.next().ok_or(Error::Unknown)?;
@@ -256,6 +683,10 @@ impl IssueAggregate {
         })
     }
 
+    pub fn snapshot(&self) -> IssueAggregateSnapshot {
+        IssueAggregateSnapshot::from(self)
+    }
+
     pub fn truncate_events(self) -> Self {
         Self {
             events: vec![],
@@ -292,6 +723,26 @@ impl IssueAggregate {
         self.issue.due()
     }
 
+    pub fn labels(&self) -> Vec<&IssueLabel> {
+        self.issue.labels().iter().collect()
+    }
+
+    pub fn priority(&self) -> Option<IssuePriority> {
+        self.issue.priority()
+    }
+
+    pub fn assignee(&self) -> Option<&IssueAssignee> {
+        self.issue.assignee()
+    }
+
+    pub fn recurrence(&self) -> Option<IssueRecurrence> {
+        self.issue.recurrence()
+    }
+
+    pub fn next_occurrence(&self) -> Option<&IssueId> {
+        self.issue.next_occurrence()
+    }
+
     pub fn version(&self) -> Version {
         self.version
     }
@@ -347,6 +798,154 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn start_cancel_reopen_test() -> anyhow::Result<()> {
+        let issue = IssueAggregate::new(
+            Instant::now(),
+            IssueNumber::from_str("123")?,
+            IssueTitle::from_str("title")?,
+            None,
+            IssueDescription::from_str("desc1")?,
+        )?;
+        let started = issue.start(Instant::now())?;
+        assert_eq!(started.status(), IssueStatus::InProgress);
+        assert!(started.start(Instant::now()).is_err());
+        let cancelled = started.cancel(Instant::now())?;
+        assert_eq!(cancelled.status(), IssueStatus::Cancelled);
+        assert!(cancelled.finish(None, Instant::now()).is_err());
+        let reopened = cancelled.reopen(Instant::now())?;
+        assert_eq!(reopened.status(), IssueStatus::Todo);
+        assert_eq!(reopened.version(), Version::from(4_u64));
+        assert!(reopened.reopen(Instant::now()).is_err());
+
+        let replayed = IssueAggregate::from_events(reopened.events())?;
+        assert_eq!(replayed, reopened.truncate_events());
+        Ok(())
+    }
+
+    #[test]
+    fn from_snapshot_and_events_test() -> anyhow::Result<()> {
+        let issue = IssueAggregate::new(
+            Instant::now(),
+            IssueNumber::from_str("123")?,
+            IssueTitle::from_str("title")?,
+            None,
+            IssueDescription::from_str("desc1")?,
+        )?
+        .start(Instant::now())?
+        .add_label(IssueLabel::from_str("bug")?, Instant::now())?
+        .update_title(IssueTitle::from_str("title2")?, Instant::now())?
+        .update_priority(Some(IssuePriority::Low), Instant::now())?
+        .update_recurrence(Some(IssueRecurrence::from_str("weekly")?), Instant::now())?
+        .finish(None, Instant::now())?;
+        let (issue, _) = issue.recur(IssueNumber::from_str("124")?, Instant::now())?;
+        let issue = issue.reopen(Instant::now())?.cancel(Instant::now())?;
+        let events = issue.events();
+        let replayed = IssueAggregate::from_events(events)?;
+        for i in 1..=events.len() {
+            let snapshot = IssueAggregate::from_events(&events[0..i])?.snapshot();
+            let snapshot = IssueAggregateSnapshot::from_str(snapshot.to_string().as_str())?;
+            assert_eq!(
+                IssueAggregate::from_snapshot_and_events(snapshot, &events[i..])?,
+                replayed
+            );
+        }
+
+        let snapshot = IssueAggregate::from_events(&events[0..2])?.snapshot();
+        assert!(IssueAggregate::from_snapshot_and_events(snapshot, &events[3..]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn labels_priority_assignee_test() -> anyhow::Result<()> {
+        let issue = IssueAggregate::new(
+            Instant::now(),
+            IssueNumber::from_str("123")?,
+            IssueTitle::from_str("title")?,
+            None,
+            IssueDescription::from_str("desc1")?,
+        )?;
+        let bug = IssueLabel::from_str("bug")?;
+        let ui = IssueLabel::from_str("ui")?;
+        let assignee = IssueAssignee::from_str("bouzuya")?;
+        let updated = issue
+            .add_label(ui.clone(), Instant::now())?
+            .add_label(bug.clone(), Instant::now())?
+            .update_priority(Some(IssuePriority::High), Instant::now())?
+            .update_assignee(Some(assignee.clone()), Instant::now())?;
+        assert_eq!(updated.labels(), vec![&bug, &ui]);
+        assert_eq!(updated.priority(), Some(IssuePriority::High));
+        assert_eq!(updated.assignee(), Some(&assignee));
+        assert!(updated.add_label(bug.clone(), Instant::now()).is_err());
+        let updated = updated.remove_label(bug.clone(), Instant::now())?;
+        assert_eq!(updated.labels(), vec![&ui]);
+        assert!(updated.remove_label(bug, Instant::now()).is_err());
+
+        let replayed = IssueAggregate::from_events(updated.events())?;
+        assert_eq!(replayed, updated.truncate_events());
+        Ok(())
+    }
+
+    #[test]
+    fn recur_test() -> anyhow::Result<()> {
+        let at = Instant::from_str("2021-02-10T00:00:00Z")?;
+        let issue = IssueAggregate::new(
+            at,
+            IssueNumber::from_str("1")?,
+            IssueTitle::from_str("weekly review")?,
+            Some(IssueDue::from_str("2021-02-05T09:00:00Z")?),
+            IssueDescription::from_str("desc1")?,
+        )?;
+        let finished = issue.finish(None, at)?;
+        assert!(finished.recur(IssueNumber::from_str("2")?, at).is_err());
+
+        let finished = issue
+            .update_recurrence(Some(IssueRecurrence::from_str("weekly")?), at)?
+            .add_label(IssueLabel::from_str("review")?, at)?
+            .update_assignee(Some(IssueAssignee::from_str("bouzuya")?), at)?
+            .finish(None, at)?;
+        let (recurred, next) = finished.recur(IssueNumber::from_str("2")?, at)?;
+        assert_eq!(recurred.next_occurrence(), Some(next.id()));
+        assert_eq!(recurred.version(), Version::from(6_u64));
+        assert!(recurred.recur(IssueNumber::from_str("3")?, at).is_err());
+        assert_eq!(next.id(), &IssueId::from_str("2")?);
+        assert_eq!(next.status(), IssueStatus::Todo);
+        assert_eq!(next.title(), issue.title());
+        assert_eq!(
+            next.due(),
+            Some(IssueDue::from_str("2021-02-12T09:00:00Z")?)
+        );
+        assert_eq!(next.recurrence(), finished.recurrence());
+        assert_eq!(next.labels(), finished.labels());
+        assert_eq!(next.assignee(), finished.assignee());
+        assert_eq!(next.next_occurrence(), None);
+        assert_eq!(
+            IssueAggregate::from_events(recurred.events())?,
+            recurred.clone().truncate_events()
+        );
+        assert_eq!(
+            IssueAggregate::from_events(next.events())?,
+            next.clone().truncate_events()
+        );
+
+        // without due
+        let (_, next) = IssueAggregate::new(
+            at,
+            IssueNumber::from_str("3")?,
+            IssueTitle::from_str("daily")?,
+            None,
+            IssueDescription::from_str("")?,
+        )?
+        .update_recurrence(Some(IssueRecurrence::from_str("daily")?), at)?
+        .finish(None, at)?
+        .recur(IssueNumber::from_str("4")?, at)?;
+        assert_eq!(
+            next.due(),
+            Some(IssueDue::from_str("2021-02-11T00:00:00Z")?)
+        );
+        Ok(())
+    }
+
     #[test]
     fn update_test() -> anyhow::Result<()> {
         let issue = IssueAggregate::new(
@@ -1,11 +1,19 @@
+pub mod issue_assignee;
 pub mod issue_description;
 pub mod issue_due;
+pub mod issue_label;
+pub mod issue_priority;
+pub mod issue_recurrence;
 pub mod issue_resolution;
 pub mod issue_status;
 pub mod issue_title;
 
+pub use self::issue_assignee::IssueAssignee;
 pub use self::issue_description::IssueDescription;
 pub use self::issue_due::IssueDue;
+pub use self::issue_label::IssueLabel;
+pub use self::issue_priority::IssuePriority;
+pub use self::issue_recurrence::{IssueRecurrence, IssueRecurrenceFrequency};
 pub use self::issue_resolution::IssueResolution;
 pub use self::issue_status::IssueStatus;
 pub use self::issue_title::IssueTitle;
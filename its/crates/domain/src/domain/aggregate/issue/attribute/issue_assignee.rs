@@ -0,0 +1,60 @@
+use thiserror::Error;
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct IssueAssignee(String);
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum Error {
+    #[error("invalid format")]
+    InvalidFormat,
+    #[error("invalid length {0}")]
+    InvalidLength(usize),
+}
+
+impl std::fmt::Display for IssueAssignee {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::str::FromStr for IssueAssignee {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s.to_string())
+    }
+}
+
+impl TryFrom<String> for IssueAssignee {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.is_empty() || value.len() > 255 {
+            Err(Error::InvalidLength(value.len()))
+        } else if value.chars().any(|c| c.is_whitespace() || c == ',') {
+            Err(Error::InvalidFormat)
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn string_conversion_test() -> anyhow::Result<()> {
+        assert!(IssueAssignee::from_str("").is_err());
+        assert!(IssueAssignee::from_str("a".repeat(256).as_str()).is_err());
+        assert!(IssueAssignee::from_str("a".repeat(255).as_str()).is_ok());
+        assert!(IssueAssignee::from_str("a b").is_err());
+        assert!(IssueAssignee::from_str("a,b").is_err());
+        assert_eq!(IssueAssignee::from_str("a-b")?.to_string(), "a-b");
+        assert!(IssueAssignee::try_from("a".repeat(256)).is_err());
+        assert_eq!(IssueAssignee::try_from("a".to_string())?.to_string(), "a");
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use thiserror::Error;
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum IssuePriority {
+    Low,
+    Medium,
+    High,
+    Urgent,
+}
+
+impl std::fmt::Display for IssuePriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                IssuePriority::Low => "low",
+                IssuePriority::Medium => "medium",
+                IssuePriority::High => "high",
+                IssuePriority::Urgent => "urgent",
+            }
+        )
+    }
+}
+
+impl std::str::FromStr for IssuePriority {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "low" => Ok(IssuePriority::Low),
+            "medium" => Ok(IssuePriority::Medium),
+            "high" => Ok(IssuePriority::High),
+            "urgent" => Ok(IssuePriority::Urgent),
+            _ => Err(Error::InvalidFormat),
+        }
+    }
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum Error {
+    #[error("invalid format")]
+    InvalidFormat,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn string_conversion_test() -> anyhow::Result<()> {
+        assert!(IssuePriority::from_str("normal").is_err());
+        for priority in [
+            IssuePriority::Low,
+            IssuePriority::Medium,
+            IssuePriority::High,
+            IssuePriority::Urgent,
+        ] {
+            assert_eq!(
+                IssuePriority::from_str(priority.to_string().as_str())?,
+                priority
+            );
+        }
+        assert!(IssuePriority::Low < IssuePriority::Urgent);
+        Ok(())
+    }
+}
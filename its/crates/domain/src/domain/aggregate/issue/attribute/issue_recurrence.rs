@@ -0,0 +1,244 @@
+use std::str::FromStr;
+
+use limited_date_time::Instant;
+use thiserror::Error;
+
+use super::IssueDue;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IssueRecurrenceFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+// RRULE subset: `FREQ=(DAILY|WEEKLY|MONTHLY)[;INTERVAL=n]` or `daily` / `weekly` / `monthly`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IssueRecurrence {
+    frequency: IssueRecurrenceFrequency,
+    interval: u32,
+}
+
+#[derive(Debug, Eq, Error, PartialEq)]
+pub enum Error {
+    #[error("invalid format")]
+    InvalidFormat,
+    #[error("unsupported rule part {0}")]
+    UnsupportedRulePart(String),
+}
+
+impl IssueRecurrence {
+    pub fn new(frequency: IssueRecurrenceFrequency, interval: u32) -> Result<Self, Error> {
+        if interval == 0 {
+            return Err(Error::InvalidFormat);
+        }
+        Ok(Self {
+            frequency,
+            interval,
+        })
+    }
+
+    pub fn frequency(&self) -> IssueRecurrenceFrequency {
+        self.frequency
+    }
+
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    // the day of month is clamped to the end of the month (e.g. 01-31 -> 02-28)
+    pub fn next_due(&self, due: IssueDue) -> Option<IssueDue> {
+        // Instant is formatted as `YYYY-MM-DDThh:mm:ssZ`
+        let s = Instant::from(due).to_string();
+        let (date, time) = s.split_once('T')?;
+        let mut parts = date.splitn(3, '-');
+        let year = parts.next()?.parse::<i64>().ok()?;
+        let month = parts.next()?.parse::<i64>().ok()?;
+        let day = parts.next()?.parse::<i64>().ok()?;
+        let interval = i64::from(self.interval);
+        let (year, month, day) = match self.frequency {
+            IssueRecurrenceFrequency::Daily => {
+                civil_from_days(days_from_civil(year, month, day) + interval)
+            }
+            IssueRecurrenceFrequency::Weekly => {
+                civil_from_days(days_from_civil(year, month, day) + interval * 7)
+            }
+            IssueRecurrenceFrequency::Monthly => {
+                let months = year * 12 + (month - 1) + interval;
+                let (year, month) = (months.div_euclid(12), months.rem_euclid(12) + 1);
+                (year, month, day.min(days_in_month(year, month)))
+            }
+        };
+        let instant =
+            Instant::from_str(&format!("{:04}-{:02}-{:02}T{}", year, month, day, time)).ok()?;
+        Some(IssueDue::from(instant))
+    }
+}
+
+impl std::fmt::Display for IssueRecurrence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let frequency = match self.frequency {
+            IssueRecurrenceFrequency::Daily => "DAILY",
+            IssueRecurrenceFrequency::Weekly => "WEEKLY",
+            IssueRecurrenceFrequency::Monthly => "MONTHLY",
+        };
+        if self.interval == 1 {
+            write!(f, "FREQ={}", frequency)
+        } else {
+            write!(f, "FREQ={};INTERVAL={}", frequency, self.interval)
+        }
+    }
+}
+
+impl FromStr for IssueRecurrence {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "daily" => return Self::new(IssueRecurrenceFrequency::Daily, 1),
+            "weekly" => return Self::new(IssueRecurrenceFrequency::Weekly, 1),
+            "monthly" => return Self::new(IssueRecurrenceFrequency::Monthly, 1),
+            _ => {}
+        }
+        let rule = s.strip_prefix("RRULE:").unwrap_or(s);
+        let mut frequency = None;
+        let mut interval = None;
+        for part in rule.split(';') {
+            let (name, value) = part.split_once('=').ok_or(Error::InvalidFormat)?;
+            match name.to_ascii_uppercase().as_str() {
+                "FREQ" if frequency.is_none() => {
+                    frequency = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => IssueRecurrenceFrequency::Daily,
+                        "WEEKLY" => IssueRecurrenceFrequency::Weekly,
+                        "MONTHLY" => IssueRecurrenceFrequency::Monthly,
+                        _ => return Err(Error::UnsupportedRulePart(part.to_string())),
+                    });
+                }
+                "INTERVAL" if interval.is_none() => {
+                    interval = Some(u32::from_str(value).map_err(|_| Error::InvalidFormat)?);
+                }
+                "FREQ" | "INTERVAL" => return Err(Error::InvalidFormat),
+                _ => return Err(Error::UnsupportedRulePart(part.to_string())),
+            }
+        }
+        Self::new(
+            frequency.ok_or(Error::InvalidFormat)?,
+            interval.unwrap_or(1),
+        )
+    }
+}
+
+// <http://howardhinnant.github.io/date_algorithms.html>
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_conversion_test() -> anyhow::Result<()> {
+        assert_eq!(
+            IssueRecurrence::from_str("daily")?.to_string(),
+            "FREQ=DAILY"
+        );
+        assert_eq!(
+            IssueRecurrence::from_str("weekly")?.to_string(),
+            "FREQ=WEEKLY"
+        );
+        assert_eq!(
+            IssueRecurrence::from_str("monthly")?.to_string(),
+            "FREQ=MONTHLY"
+        );
+        assert_eq!(
+            IssueRecurrence::from_str("RRULE:FREQ=WEEKLY;INTERVAL=2")?,
+            IssueRecurrence::new(IssueRecurrenceFrequency::Weekly, 2)?
+        );
+        assert_eq!(
+            IssueRecurrence::from_str("FREQ=MONTHLY;INTERVAL=3")?.to_string(),
+            "FREQ=MONTHLY;INTERVAL=3"
+        );
+        assert_eq!(
+            IssueRecurrence::from_str("FREQ=DAILY;INTERVAL=1")?.to_string(),
+            "FREQ=DAILY"
+        );
+        assert!(IssueRecurrence::from_str("").is_err());
+        assert!(IssueRecurrence::from_str("yearly").is_err());
+        assert!(IssueRecurrence::from_str("FREQ=YEARLY").is_err());
+        assert!(IssueRecurrence::from_str("FREQ=WEEKLY;BYDAY=MO").is_err());
+        assert!(IssueRecurrence::from_str("FREQ=WEEKLY;INTERVAL=0").is_err());
+        assert!(IssueRecurrence::from_str("INTERVAL=2").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn next_due_test() -> anyhow::Result<()> {
+        let next_due = |rule: &str, due: &str| -> anyhow::Result<Option<String>> {
+            Ok(IssueRecurrence::from_str(rule)?
+                .next_due(IssueDue::from_str(due)?)
+                .map(|due| due.to_string()))
+        };
+        assert_eq!(
+            next_due("daily", "2021-02-28T04:05:06Z")?,
+            Some("2021-03-01T04:05:06Z".to_string())
+        );
+        assert_eq!(
+            next_due("FREQ=DAILY;INTERVAL=3", "2021-12-30T00:00:00Z")?,
+            Some("2022-01-02T00:00:00Z".to_string())
+        );
+        assert_eq!(
+            next_due("weekly", "2021-02-03T04:05:06Z")?,
+            Some("2021-02-10T04:05:06Z".to_string())
+        );
+        assert_eq!(
+            next_due("FREQ=WEEKLY;INTERVAL=2", "2024-02-20T00:00:00Z")?,
+            Some("2024-03-05T00:00:00Z".to_string())
+        );
+        assert_eq!(
+            next_due("monthly", "2021-01-31T04:05:06Z")?,
+            Some("2021-02-28T04:05:06Z".to_string())
+        );
+        assert_eq!(
+            next_due("monthly", "2024-01-31T04:05:06Z")?,
+            Some("2024-02-29T04:05:06Z".to_string())
+        );
+        assert_eq!(
+            next_due("FREQ=MONTHLY;INTERVAL=12", "2021-11-15T00:00:00Z")?,
+            Some("2022-11-15T00:00:00Z".to_string())
+        );
+        assert_eq!(
+            next_due("monthly", "2021-12-15T00:00:00Z")?,
+            Some("2022-01-15T00:00:00Z".to_string())
+        );
+        Ok(())
+    }
+}
@@ -3,7 +3,9 @@ use thiserror::Error;
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub enum IssueStatus {
     Todo,
+    InProgress,
     Done,
+    Cancelled,
 }
 
 impl std::fmt::Display for IssueStatus {
@@ -13,7 +15,9 @@ impl std::fmt::Display for IssueStatus {
             "{}",
             match self {
                 IssueStatus::Todo => "todo",
+                IssueStatus::InProgress => "in_progress",
                 IssueStatus::Done => "done",
+                IssueStatus::Cancelled => "cancelled",
             }
         )
     }
@@ -25,7 +29,9 @@ impl std::str::FromStr for IssueStatus {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "todo" => Ok(IssueStatus::Todo),
+            "in_progress" => Ok(IssueStatus::InProgress),
             "done" => Ok(IssueStatus::Done),
+            "cancelled" => Ok(IssueStatus::Cancelled),
             _ => Err(Error::InvalidFormat),
         }
     }
@@ -47,7 +53,20 @@ mod tests {
     fn string_conversion_test() -> anyhow::Result<()> {
         assert!(IssueStatus::from_str("in progress").is_err());
         assert_eq!(IssueStatus::from_str("todo")?, IssueStatus::Todo);
+        assert_eq!(
+            IssueStatus::from_str("in_progress")?,
+            IssueStatus::InProgress
+        );
         assert_eq!(IssueStatus::from_str("done")?, IssueStatus::Done);
+        assert_eq!(IssueStatus::from_str("cancelled")?, IssueStatus::Cancelled);
+        for status in [
+            IssueStatus::Todo,
+            IssueStatus::InProgress,
+            IssueStatus::Done,
+            IssueStatus::Cancelled,
+        ] {
+            assert_eq!(IssueStatus::from_str(status.to_string().as_str())?, status);
+        }
         Ok(())
     }
 }
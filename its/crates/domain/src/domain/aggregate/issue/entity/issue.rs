@@ -1,5 +1,11 @@
+use std::collections::BTreeSet;
+
+use super::super::attribute::IssueAssignee;
 use super::super::attribute::IssueDescription;
 use super::super::attribute::IssueDue;
+use super::super::attribute::IssueLabel;
+use super::super::attribute::IssuePriority;
+use super::super::attribute::IssueRecurrence;
 use super::super::attribute::IssueResolution;
 use crate::aggregate::issue::attribute::IssueStatus;
 use crate::aggregate::issue::IssueTitle;
@@ -7,8 +13,24 @@ use crate::{IssueCreatedV2, IssueId, IssueNumber};
 
 #[derive(Debug, Eq, PartialEq, thiserror::Error)]
 pub enum Error {
+    #[error("AlreadyCancelled")]
+    AlreadyCancelled,
     #[error("AlreadyFinished")]
     AlreadyFinished,
+    #[error("AlreadyStarted")]
+    AlreadyStarted,
+    #[error("LabelAlreadyAdded")]
+    LabelAlreadyAdded,
+    #[error("AlreadyRecurred")]
+    AlreadyRecurred,
+    #[error("LabelNotFound")]
+    LabelNotFound,
+    #[error("NotClosed")]
+    NotClosed,
+    #[error("NotFinished")]
+    NotFinished,
+    #[error("NotRecurring")]
+    NotRecurring,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -19,6 +41,11 @@ pub(crate) struct Issue {
     title: IssueTitle,
     due: Option<IssueDue>,
     description: IssueDescription,
+    labels: BTreeSet<IssueLabel>,
+    priority: Option<IssuePriority>,
+    assignee: Option<IssueAssignee>,
+    recurrence: Option<IssueRecurrence>,
+    next_occurrence: Option<IssueId>,
 }
 
 impl Issue {
@@ -30,6 +57,11 @@ impl Issue {
             title: event.issue_title,
             due: event.issue_due,
             description: event.issue_description,
+            labels: BTreeSet::new(),
+            priority: None,
+            assignee: None,
+            recurrence: None,
+            next_occurrence: None,
         }
     }
 
@@ -46,13 +78,33 @@ impl Issue {
             title,
             due,
             description,
+            labels: BTreeSet::new(),
+            priority: None,
+            assignee: None,
+            recurrence: None,
+            next_occurrence: None,
         }
     }
 
+    pub(crate) fn cancel(&self) -> Result<Self, Error> {
+        self.check_not_closed()?;
+        Ok(Self {
+            id: self.id.clone(),
+            resolution: self.resolution.clone(),
+            status: IssueStatus::Cancelled,
+            title: self.title.clone(),
+            due: self.due,
+            description: self.description.clone(),
+            labels: self.labels.clone(),
+            priority: self.priority,
+            assignee: self.assignee.clone(),
+            recurrence: self.recurrence,
+            next_occurrence: self.next_occurrence.clone(),
+        })
+    }
+
     pub(crate) fn finish(&self, resolution: Option<IssueResolution>) -> Result<Self, Error> {
-        if self.status == IssueStatus::Done {
-            return Err(Error::AlreadyFinished);
-        }
+        self.check_not_closed()?;
         Ok(Self {
             id: self.id.clone(),
             resolution,
@@ -60,9 +112,119 @@ impl Issue {
             title: self.title.clone(),
             due: self.due,
             description: self.description.clone(),
+            labels: self.labels.clone(),
+            priority: self.priority,
+            assignee: self.assignee.clone(),
+            recurrence: self.recurrence,
+            next_occurrence: self.next_occurrence.clone(),
+        })
+    }
+
+    pub(crate) fn reopen(&self) -> Result<Self, Error> {
+        if !matches!(self.status, IssueStatus::Done | IssueStatus::Cancelled) {
+            return Err(Error::NotClosed);
+        }
+        Ok(Self {
+            id: self.id.clone(),
+            resolution: None,
+            status: IssueStatus::Todo,
+            title: self.title.clone(),
+            due: self.due,
+            description: self.description.clone(),
+            labels: self.labels.clone(),
+            priority: self.priority,
+            assignee: self.assignee.clone(),
+            recurrence: self.recurrence,
+            next_occurrence: self.next_occurrence.clone(),
+        })
+    }
+
+    pub(crate) fn start(&self) -> Result<Self, Error> {
+        self.check_not_closed()?;
+        if self.status == IssueStatus::InProgress {
+            return Err(Error::AlreadyStarted);
+        }
+        Ok(Self {
+            id: self.id.clone(),
+            resolution: self.resolution.clone(),
+            status: IssueStatus::InProgress,
+            title: self.title.clone(),
+            due: self.due,
+            description: self.description.clone(),
+            labels: self.labels.clone(),
+            priority: self.priority,
+            assignee: self.assignee.clone(),
+            recurrence: self.recurrence,
+            next_occurrence: self.next_occurrence.clone(),
         })
     }
 
+    fn check_not_closed(&self) -> Result<(), Error> {
+        match self.status {
+            IssueStatus::Todo | IssueStatus::InProgress => Ok(()),
+            IssueStatus::Done => Err(Error::AlreadyFinished),
+            IssueStatus::Cancelled => Err(Error::AlreadyCancelled),
+        }
+    }
+
+    pub(crate) fn add_label(&self, label: IssueLabel) -> Result<Self, Error> {
+        if self.labels.contains(&label) {
+            return Err(Error::LabelAlreadyAdded);
+        }
+        let mut labels = self.labels.clone();
+        labels.insert(label);
+        Ok(Self {
+            id: self.id.clone(),
+            resolution: self.resolution.clone(),
+            status: self.status,
+            title: self.title.clone(),
+            due: self.due,
+            description: self.description.clone(),
+            labels,
+            priority: self.priority,
+            assignee: self.assignee.clone(),
+            recurrence: self.recurrence,
+            next_occurrence: self.next_occurrence.clone(),
+        })
+    }
+
+    pub(crate) fn remove_label(&self, label: &IssueLabel) -> Result<Self, Error> {
+        if !self.labels.contains(label) {
+            return Err(Error::LabelNotFound);
+        }
+        let mut labels = self.labels.clone();
+        labels.remove(label);
+        Ok(Self {
+            id: self.id.clone(),
+            resolution: self.resolution.clone(),
+            status: self.status,
+            title: self.title.clone(),
+            due: self.due,
+            description: self.description.clone(),
+            labels,
+            priority: self.priority,
+            assignee: self.assignee.clone(),
+            recurrence: self.recurrence,
+            next_occurrence: self.next_occurrence.clone(),
+        })
+    }
+
+    pub(crate) fn change_assignee(&self, assignee: Option<IssueAssignee>) -> Self {
+        Self {
+            id: self.id.clone(),
+            resolution: self.resolution.clone(),
+            status: self.status,
+            title: self.title.clone(),
+            due: self.due,
+            description: self.description.clone(),
+            labels: self.labels.clone(),
+            priority: self.priority,
+            assignee,
+            recurrence: self.recurrence,
+            next_occurrence: self.next_occurrence.clone(),
+        }
+    }
+
     pub(crate) fn change_description(&self, description: IssueDescription) -> Self {
         Self {
             id: self.id.clone(),
@@ -71,6 +233,11 @@ impl Issue {
             title: self.title.clone(),
             due: self.due(),
             description,
+            labels: self.labels.clone(),
+            priority: self.priority,
+            assignee: self.assignee.clone(),
+            recurrence: self.recurrence,
+            next_occurrence: self.next_occurrence.clone(),
         }
     }
 
@@ -82,6 +249,59 @@ impl Issue {
             title: self.title.clone(),
             due,
             description: self.description.clone(),
+            labels: self.labels.clone(),
+            priority: self.priority,
+            assignee: self.assignee.clone(),
+            recurrence: self.recurrence,
+            next_occurrence: self.next_occurrence.clone(),
+        }
+    }
+
+    pub(crate) fn change_priority(&self, priority: Option<IssuePriority>) -> Self {
+        Self {
+            id: self.id.clone(),
+            resolution: self.resolution.clone(),
+            status: self.status,
+            title: self.title.clone(),
+            due: self.due,
+            description: self.description.clone(),
+            labels: self.labels.clone(),
+            priority,
+            assignee: self.assignee.clone(),
+            recurrence: self.recurrence,
+            next_occurrence: self.next_occurrence.clone(),
+        }
+    }
+
+    pub(crate) fn change_next_occurrence(&self, next_occurrence: Option<IssueId>) -> Self {
+        Self {
+            id: self.id.clone(),
+            resolution: self.resolution.clone(),
+            status: self.status,
+            title: self.title.clone(),
+            due: self.due,
+            description: self.description.clone(),
+            labels: self.labels.clone(),
+            priority: self.priority,
+            assignee: self.assignee.clone(),
+            recurrence: self.recurrence,
+            next_occurrence,
+        }
+    }
+
+    pub(crate) fn change_recurrence(&self, recurrence: Option<IssueRecurrence>) -> Self {
+        Self {
+            id: self.id.clone(),
+            resolution: self.resolution.clone(),
+            status: self.status,
+            title: self.title.clone(),
+            due: self.due,
+            description: self.description.clone(),
+            labels: self.labels.clone(),
+            priority: self.priority,
+            assignee: self.assignee.clone(),
+            recurrence,
+            next_occurrence: self.next_occurrence.clone(),
         }
     }
 
@@ -93,7 +313,25 @@ impl Issue {
             title,
             due: self.due(),
             description: self.description.clone(),
+            labels: self.labels.clone(),
+            priority: self.priority,
+            assignee: self.assignee.clone(),
+            recurrence: self.recurrence,
+            next_occurrence: self.next_occurrence.clone(),
+        }
+    }
+
+    pub(crate) fn recur(&self, next_issue_id: IssueId) -> Result<Self, Error> {
+        if self.status != IssueStatus::Done {
+            return Err(Error::NotFinished);
+        }
+        if self.recurrence.is_none() {
+            return Err(Error::NotRecurring);
+        }
+        if self.next_occurrence.is_some() {
+            return Err(Error::AlreadyRecurred);
         }
+        Ok(self.change_next_occurrence(Some(next_issue_id)))
     }
 
     pub(crate) fn id(&self) -> &IssueId {
@@ -123,6 +361,26 @@ impl Issue {
     pub(crate) fn description(&self) -> &IssueDescription {
         &self.description
     }
+
+    pub(crate) fn labels(&self) -> &BTreeSet<IssueLabel> {
+        &self.labels
+    }
+
+    pub(crate) fn priority(&self) -> Option<IssuePriority> {
+        self.priority
+    }
+
+    pub(crate) fn assignee(&self) -> Option<&IssueAssignee> {
+        self.assignee.as_ref()
+    }
+
+    pub(crate) fn recurrence(&self) -> Option<IssueRecurrence> {
+        self.recurrence
+    }
+
+    pub(crate) fn next_occurrence(&self) -> Option<&IssueId> {
+        self.next_occurrence.as_ref()
+    }
 }
 
 #[cfg(test)]
@@ -168,6 +426,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn add_label_and_remove_label_test() -> anyhow::Result<()> {
+        let issue = new()?;
+        let label = IssueLabel::from_str("bug")?;
+        let added = issue.add_label(label.clone())?;
+        assert!(added.labels().contains(&label));
+        assert_eq!(
+            added.add_label(label.clone()),
+            Err(Error::LabelAlreadyAdded)
+        );
+        let removed = added.remove_label(&label)?;
+        assert!(removed.labels().is_empty());
+        assert_eq!(removed.remove_label(&label), Err(Error::LabelNotFound));
+        Ok(())
+    }
+
+    #[test]
+    fn change_assignee_test() -> anyhow::Result<()> {
+        let issue = new()?;
+        let assignee = IssueAssignee::from_str("bouzuya")?;
+        assert_eq!(
+            issue.change_assignee(Some(assignee.clone())).assignee(),
+            Some(&assignee)
+        );
+        assert_eq!(issue.change_assignee(None).assignee(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn change_priority_test() -> anyhow::Result<()> {
+        let issue = new()?;
+        assert_eq!(
+            issue.change_priority(Some(IssuePriority::High)).priority(),
+            Some(IssuePriority::High)
+        );
+        assert_eq!(issue.change_priority(None).priority(), None);
+        Ok(())
+    }
+
     #[test]
     fn change_title_test() -> anyhow::Result<()> {
         let issue = new()?;
@@ -189,6 +486,64 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn recur_test() -> anyhow::Result<()> {
+        let issue = new()?;
+        let next_issue_id = IssueId::new(IssueNumber::try_from(2_usize)?);
+        assert_eq!(
+            issue.finish(None)?.recur(next_issue_id.clone()),
+            Err(Error::NotRecurring)
+        );
+        let recurrence = IssueRecurrence::from_str("weekly")?;
+        let issue = issue.change_recurrence(Some(recurrence));
+        assert_eq!(issue.recurrence(), Some(recurrence));
+        assert_eq!(issue.recur(next_issue_id.clone()), Err(Error::NotFinished));
+        let recurred = issue.finish(None)?.recur(next_issue_id.clone())?;
+        assert_eq!(recurred.next_occurrence(), Some(&next_issue_id));
+        assert_eq!(
+            recurred.reopen()?.finish(None)?.recur(next_issue_id),
+            Err(Error::AlreadyRecurred)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn start_test() -> anyhow::Result<()> {
+        let issue = new()?;
+        let started = issue.start()?;
+        assert_eq!(started.status(), IssueStatus::InProgress);
+        assert_eq!(started.start(), Err(Error::AlreadyStarted));
+        let finished = started.finish(None)?;
+        assert_eq!(finished.status(), IssueStatus::Done);
+        assert_eq!(finished.start(), Err(Error::AlreadyFinished));
+        Ok(())
+    }
+
+    #[test]
+    fn cancel_test() -> anyhow::Result<()> {
+        let issue = new()?;
+        let cancelled = issue.cancel()?;
+        assert_eq!(cancelled.status(), IssueStatus::Cancelled);
+        assert_eq!(cancelled.cancel(), Err(Error::AlreadyCancelled));
+        assert_eq!(cancelled.finish(None), Err(Error::AlreadyCancelled));
+        assert_eq!(cancelled.start(), Err(Error::AlreadyCancelled));
+        assert_eq!(issue.start()?.cancel()?.status(), IssueStatus::Cancelled);
+        Ok(())
+    }
+
+    #[test]
+    fn reopen_test() -> anyhow::Result<()> {
+        let issue = new()?;
+        assert_eq!(issue.reopen(), Err(Error::NotClosed));
+        assert_eq!(issue.start()?.reopen(), Err(Error::NotClosed));
+        let resolution = IssueResolution::from_str("Duplicate")?;
+        let reopened = issue.finish(Some(resolution))?.reopen()?;
+        assert_eq!(reopened.status(), IssueStatus::Todo);
+        assert_eq!(reopened.resolution(), None);
+        assert_eq!(issue.cancel()?.reopen()?.status(), IssueStatus::Todo);
+        Ok(())
+    }
+
     fn new() -> anyhow::Result<Issue> {
         let number = IssueNumber::try_from(1_usize)?;
         let title = IssueTitle::from_str("title1")?;
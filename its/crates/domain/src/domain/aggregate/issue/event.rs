@@ -1,6 +1,10 @@
+use limited_date_time::Instant;
+
 use crate::{
     domain::event::{IssueCreated, IssueCreatedV2, IssueFinished},
-    IssueDescriptionUpdated, IssueId, IssueTitleUpdated, IssueUpdated, Version,
+    IssueAssigneeUpdated, IssueCancelled, IssueDescriptionUpdated, IssueId, IssueLabelAdded,
+    IssueLabelRemoved, IssuePriorityUpdated, IssueRecurred, IssueRecurrenceUpdated, IssueReopened,
+    IssueStarted, IssueTitleUpdated, IssueUpdated, Version,
 };
 
 macro_rules! impl_from_ty_for_issue_aggregate_event {
@@ -15,30 +19,79 @@ macro_rules! impl_from_ty_for_issue_aggregate_event {
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IssueAggregateEvent {
+    AssigneeUpdated(IssueAssigneeUpdated),
+    Cancelled(IssueCancelled),
     Created(IssueCreated),
     CreatedV2(IssueCreatedV2),
     DescriptionUpdated(IssueDescriptionUpdated),
     Finished(IssueFinished),
+    LabelAdded(IssueLabelAdded),
+    LabelRemoved(IssueLabelRemoved),
+    PriorityUpdated(IssuePriorityUpdated),
+    Recurred(IssueRecurred),
+    RecurrenceUpdated(IssueRecurrenceUpdated),
+    Reopened(IssueReopened),
+    Started(IssueStarted),
     Updated(IssueUpdated),
     TitleUpdated(IssueTitleUpdated),
 }
 
+impl_from_ty_for_issue_aggregate_event!(IssueAssigneeUpdated, Self::AssigneeUpdated);
+impl_from_ty_for_issue_aggregate_event!(IssueCancelled, Self::Cancelled);
 impl_from_ty_for_issue_aggregate_event!(IssueCreated, Self::Created);
 impl_from_ty_for_issue_aggregate_event!(IssueCreatedV2, Self::CreatedV2);
 impl_from_ty_for_issue_aggregate_event!(IssueDescriptionUpdated, Self::DescriptionUpdated);
 impl_from_ty_for_issue_aggregate_event!(IssueFinished, Self::Finished);
+impl_from_ty_for_issue_aggregate_event!(IssueLabelAdded, Self::LabelAdded);
+impl_from_ty_for_issue_aggregate_event!(IssueLabelRemoved, Self::LabelRemoved);
+impl_from_ty_for_issue_aggregate_event!(IssuePriorityUpdated, Self::PriorityUpdated);
+impl_from_ty_for_issue_aggregate_event!(IssueRecurred, Self::Recurred);
+impl_from_ty_for_issue_aggregate_event!(IssueRecurrenceUpdated, Self::RecurrenceUpdated);
+impl_from_ty_for_issue_aggregate_event!(IssueReopened, Self::Reopened);
+impl_from_ty_for_issue_aggregate_event!(IssueStarted, Self::Started);
 impl_from_ty_for_issue_aggregate_event!(IssueTitleUpdated, Self::TitleUpdated);
 impl_from_ty_for_issue_aggregate_event!(IssueUpdated, Self::Updated);
 
 impl IssueAggregateEvent {
+    pub fn at(&self) -> Instant {
+        match self {
+            IssueAggregateEvent::AssigneeUpdated(IssueAssigneeUpdated { at, .. }) => *at,
+            IssueAggregateEvent::Cancelled(IssueCancelled { at, .. }) => *at,
+            IssueAggregateEvent::Created(IssueCreated { at, .. }) => *at,
+            IssueAggregateEvent::CreatedV2(IssueCreatedV2 { at, .. }) => *at,
+            IssueAggregateEvent::DescriptionUpdated(IssueDescriptionUpdated { at, .. }) => *at,
+            IssueAggregateEvent::Finished(IssueFinished { at, .. }) => *at,
+            IssueAggregateEvent::LabelAdded(IssueLabelAdded { at, .. }) => *at,
+            IssueAggregateEvent::LabelRemoved(IssueLabelRemoved { at, .. }) => *at,
+            IssueAggregateEvent::PriorityUpdated(IssuePriorityUpdated { at, .. }) => *at,
+            IssueAggregateEvent::Recurred(IssueRecurred { at, .. }) => *at,
+            IssueAggregateEvent::RecurrenceUpdated(IssueRecurrenceUpdated { at, .. }) => *at,
+            IssueAggregateEvent::Reopened(IssueReopened { at, .. }) => *at,
+            IssueAggregateEvent::Started(IssueStarted { at, .. }) => *at,
+            IssueAggregateEvent::TitleUpdated(IssueTitleUpdated { at, .. }) => *at,
+            IssueAggregateEvent::Updated(IssueUpdated { at, .. }) => *at,
+        }
+    }
+
     pub fn issue_id(&self) -> &IssueId {
         match self {
+            IssueAggregateEvent::AssigneeUpdated(IssueAssigneeUpdated { issue_id, .. }) => issue_id,
+            IssueAggregateEvent::Cancelled(IssueCancelled { issue_id, .. }) => issue_id,
             IssueAggregateEvent::Created(IssueCreated { issue_id, .. }) => issue_id,
             IssueAggregateEvent::CreatedV2(IssueCreatedV2 { issue_id, .. }) => issue_id,
             IssueAggregateEvent::DescriptionUpdated(IssueDescriptionUpdated {
                 issue_id, ..
             }) => issue_id,
             IssueAggregateEvent::Finished(IssueFinished { issue_id, .. }) => issue_id,
+            IssueAggregateEvent::LabelAdded(IssueLabelAdded { issue_id, .. }) => issue_id,
+            IssueAggregateEvent::LabelRemoved(IssueLabelRemoved { issue_id, .. }) => issue_id,
+            IssueAggregateEvent::PriorityUpdated(IssuePriorityUpdated { issue_id, .. }) => issue_id,
+            IssueAggregateEvent::Recurred(IssueRecurred { issue_id, .. }) => issue_id,
+            IssueAggregateEvent::RecurrenceUpdated(IssueRecurrenceUpdated { issue_id, .. }) => {
+                issue_id
+            }
+            IssueAggregateEvent::Reopened(IssueReopened { issue_id, .. }) => issue_id,
+            IssueAggregateEvent::Started(IssueStarted { issue_id, .. }) => issue_id,
             IssueAggregateEvent::TitleUpdated(IssueTitleUpdated { issue_id, .. }) => issue_id,
             IssueAggregateEvent::Updated(IssueUpdated { issue_id, .. }) => issue_id,
         }
@@ -46,12 +99,23 @@ impl IssueAggregateEvent {
 
     pub fn version(&self) -> Version {
         match self {
+            IssueAggregateEvent::AssigneeUpdated(IssueAssigneeUpdated { version, .. }) => *version,
+            IssueAggregateEvent::Cancelled(IssueCancelled { version, .. }) => *version,
             IssueAggregateEvent::Created(IssueCreated { version, .. }) => *version,
             IssueAggregateEvent::CreatedV2(IssueCreatedV2 { version, .. }) => *version,
             IssueAggregateEvent::DescriptionUpdated(IssueDescriptionUpdated {
                 version, ..
             }) => *version,
             IssueAggregateEvent::Finished(IssueFinished { version, .. }) => *version,
+            IssueAggregateEvent::LabelAdded(IssueLabelAdded { version, .. }) => *version,
+            IssueAggregateEvent::LabelRemoved(IssueLabelRemoved { version, .. }) => *version,
+            IssueAggregateEvent::PriorityUpdated(IssuePriorityUpdated { version, .. }) => *version,
+            IssueAggregateEvent::Recurred(IssueRecurred { version, .. }) => *version,
+            IssueAggregateEvent::RecurrenceUpdated(IssueRecurrenceUpdated { version, .. }) => {
+                *version
+            }
+            IssueAggregateEvent::Reopened(IssueReopened { version, .. }) => *version,
+            IssueAggregateEvent::Started(IssueStarted { version, .. }) => *version,
             IssueAggregateEvent::TitleUpdated(IssueTitleUpdated { version, .. }) => *version,
             IssueAggregateEvent::Updated(IssueUpdated { version, .. }) => *version,
         }
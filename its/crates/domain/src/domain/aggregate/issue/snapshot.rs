@@ -0,0 +1,205 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    attribute::{
+        IssueAssignee, IssueDescription, IssueDue, IssueLabel, IssuePriority, IssueRecurrence,
+        IssueResolution, IssueStatus, IssueTitle,
+    },
+    entity::issue::Issue,
+    IssueAggregate,
+};
+use crate::{IssueId, Version};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParseIssueAggregateSnapshotError {
+    #[error("invalid json {0}")]
+    InvalidJson(#[from] serde_json::Error),
+    #[error("invalid state")]
+    InvalidState,
+    #[error("invalid value {0}")]
+    InvalidValue(String),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssueAggregateSnapshot {
+    issue: Issue,
+    version: Version,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct IssueAggregateSnapshotJson {
+    id: String,
+    resolution: Option<String>,
+    status: String,
+    title: String,
+    due: Option<String>,
+    description: String,
+    labels: Vec<String>,
+    priority: Option<String>,
+    assignee: Option<String>,
+    recurrence: Option<String>,
+    next_occurrence: Option<String>,
+    version: u64,
+}
+
+impl IssueAggregateSnapshot {
+    pub fn version(&self) -> Version {
+        self.version
+    }
+}
+
+impl std::fmt::Display for IssueAggregateSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let json = IssueAggregateSnapshotJson {
+            id: self.issue.id().to_string(),
+            resolution: self.issue.resolution().map(|r| r.to_string()),
+            status: self.issue.status().to_string(),
+            title: self.issue.title().to_string(),
+            due: self.issue.due().map(|d| d.to_string()),
+            description: self.issue.description().to_string(),
+            labels: self.issue.labels().iter().map(|l| l.to_string()).collect(),
+            priority: self.issue.priority().map(|p| p.to_string()),
+            assignee: self.issue.assignee().map(|a| a.to_string()),
+            recurrence: self.issue.recurrence().map(|r| r.to_string()),
+            next_occurrence: self.issue.next_occurrence().map(|i| i.to_string()),
+            version: u64::from(self.version),
+        };
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(&json).map_err(|_| std::fmt::Error)?
+        )
+    }
+}
+
+impl FromStr for IssueAggregateSnapshot {
+    type Err = ParseIssueAggregateSnapshotError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn parse<T: FromStr>(s: &str) -> Result<T, ParseIssueAggregateSnapshotError> {
+            T::from_str(s)
+                .map_err(|_| ParseIssueAggregateSnapshotError::InvalidValue(s.to_string()))
+        }
+
+        let json: IssueAggregateSnapshotJson = serde_json::from_str(s)?;
+        let mut issue = Issue::new(
+            parse::<IssueId>(json.id.as_str())?,
+            parse::<IssueTitle>(json.title.as_str())?,
+            json.due.as_deref().map(parse::<IssueDue>).transpose()?,
+            parse::<IssueDescription>(json.description.as_str())?,
+        );
+        for label in json.labels {
+            issue = issue
+                .add_label(parse::<IssueLabel>(label.as_str())?)
+                .map_err(|_| ParseIssueAggregateSnapshotError::InvalidState)?;
+        }
+        issue = issue
+            .change_priority(
+                json.priority
+                    .as_deref()
+                    .map(parse::<IssuePriority>)
+                    .transpose()?,
+            )
+            .change_assignee(
+                json.assignee
+                    .as_deref()
+                    .map(parse::<IssueAssignee>)
+                    .transpose()?,
+            )
+            .change_recurrence(
+                json.recurrence
+                    .as_deref()
+                    .map(parse::<IssueRecurrence>)
+                    .transpose()?,
+            )
+            .change_next_occurrence(
+                json.next_occurrence
+                    .as_deref()
+                    .map(parse::<IssueId>)
+                    .transpose()?,
+            );
+        let resolution = json
+            .resolution
+            .as_deref()
+            .map(parse::<IssueResolution>)
+            .transpose()?;
+        issue = match parse::<IssueStatus>(json.status.as_str())? {
+            IssueStatus::Todo if resolution.is_none() => Ok(issue),
+            IssueStatus::InProgress if resolution.is_none() => issue.start(),
+            IssueStatus::Done => issue.finish(resolution),
+            IssueStatus::Cancelled if resolution.is_none() => issue.cancel(),
+            _ => return Err(ParseIssueAggregateSnapshotError::InvalidState),
+        }
+        .map_err(|_| ParseIssueAggregateSnapshotError::InvalidState)?;
+        Ok(Self {
+            issue,
+            version: Version::from(json.version),
+        })
+    }
+}
+
+impl From<&IssueAggregate> for IssueAggregateSnapshot {
+    fn from(aggregate: &IssueAggregate) -> Self {
+        Self {
+            issue: aggregate.issue.clone(),
+            version: aggregate.version,
+        }
+    }
+}
+
+impl From<IssueAggregateSnapshot> for IssueAggregate {
+    fn from(snapshot: IssueAggregateSnapshot) -> Self {
+        Self {
+            events: vec![],
+            issue: snapshot.issue,
+            version: snapshot.version,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use limited_date_time::Instant;
+
+    use super::*;
+
+    #[test]
+    fn string_conversion_test() -> anyhow::Result<()> {
+        let issue = IssueAggregate::new(
+            Instant::now(),
+            "123".parse()?,
+            "title1".parse()?,
+            Some("2021-02-03T04:05:06Z".parse()?),
+            "desc1".parse()?,
+        )?
+        .add_label("bug".parse()?, Instant::now())?
+        .update_priority(Some("high".parse()?), Instant::now())?
+        .update_assignee(Some("bouzuya".parse()?), Instant::now())?
+        .finish(Some("Duplicate".parse()?), Instant::now())?;
+        let snapshot = issue.snapshot();
+        assert_eq!(
+            snapshot.to_string(),
+            r#"{"id":"123","resolution":"Duplicate","status":"done","title":"title1","due":"2021-02-03T04:05:06Z","description":"desc1","labels":["bug"],"priority":"high","assignee":"bouzuya","recurrence":null,"next_occurrence":null,"version":5}"#
+        );
+        assert_eq!(
+            IssueAggregateSnapshot::from_str(&snapshot.to_string())?,
+            snapshot
+        );
+        assert!(IssueAggregateSnapshot::from_str("{}").is_err());
+        // written before recurrence was added
+        assert_eq!(
+            IssueAggregateSnapshot::from_str(
+                r#"{"id":"123","resolution":"Duplicate","status":"done","title":"title1","due":"2021-02-03T04:05:06Z","description":"desc1","labels":["bug"],"priority":"high","assignee":"bouzuya","version":5}"#
+            )?,
+            snapshot
+        );
+        assert_eq!(IssueAggregate::from(snapshot), issue.truncate_events());
+        assert!(IssueAggregateSnapshot::from_str(
+            r#"{"id":"123","resolution":"Duplicate","status":"todo","title":"title1","due":null,"description":"","labels":[],"priority":null,"assignee":null,"version":1}"#
+        )
+        .is_err());
+        Ok(())
+    }
+}
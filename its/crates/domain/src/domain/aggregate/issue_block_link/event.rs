@@ -1,3 +1,5 @@
+use limited_date_time::Instant;
+
 use crate::{IssueBlockLinkId, IssueBlocked, IssueUnblocked, Version};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -7,6 +9,13 @@ pub enum IssueBlockLinkAggregateEvent {
 }
 
 impl IssueBlockLinkAggregateEvent {
+    pub fn at(&self) -> Instant {
+        match self {
+            IssueBlockLinkAggregateEvent::Blocked(event) => event.at(),
+            IssueBlockLinkAggregateEvent::Unblocked(event) => event.at(),
+        }
+    }
+
     pub fn key(&self) -> (&IssueBlockLinkId, Version) {
         match self {
             IssueBlockLinkAggregateEvent::Blocked(event) => event.key(),
@@ -2,6 +2,8 @@ pub mod issue_comment_created;
 pub mod issue_comment_deleted;
 pub mod issue_comment_updated;
 
+use limited_date_time::Instant;
+
 use crate::{IssueCommentId, Version};
 
 pub use self::{
@@ -36,6 +38,14 @@ impl_from_t1_for_t2!(IssueCommentUpdated, Event, Self::Updated);
 // TODO: impl TryFrom<String> for Event
 
 impl Event {
+    pub fn at(&self) -> Instant {
+        match self {
+            Event::Created(IssueCommentCreated { at, .. }) => *at,
+            Event::Deleted(IssueCommentDeleted { at, .. }) => *at,
+            Event::Updated(IssueCommentUpdated { at, .. }) => *at,
+        }
+    }
+
     pub fn issue_comment_id(&self) -> &IssueCommentId {
         match self {
             Event::Created(IssueCommentCreated {
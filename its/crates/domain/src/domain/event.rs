@@ -10,11 +10,20 @@
 //! - 永続化に使用される
 //! - domain crate 以外に対して文字列との相互変換を提供する
 mod event_dto;
+mod issue_assignee_updated;
 mod issue_blocked;
+mod issue_cancelled;
 mod issue_created;
 mod issue_created_v2;
 mod issue_description_updated;
 mod issue_finished;
+mod issue_label_added;
+mod issue_label_removed;
+mod issue_priority_updated;
+mod issue_recurred;
+mod issue_recurrence_updated;
+mod issue_reopened;
+mod issue_started;
 mod issue_title_updated;
 mod issue_unblocked;
 mod issue_updated;
@@ -22,17 +31,27 @@ mod issue_updated;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use limited_date_time::Instant;
 use thiserror::Error;
 
 use self::event_dto::*;
 use crate::aggregate::IssueAggregateEvent;
 use crate::aggregate::IssueBlockLinkAggregateEvent;
 
+pub use self::issue_assignee_updated::*;
 pub use self::issue_blocked::*;
+pub use self::issue_cancelled::*;
 pub use self::issue_created::*;
 pub use self::issue_created_v2::*;
 pub use self::issue_description_updated::*;
 pub use self::issue_finished::*;
+pub use self::issue_label_added::*;
+pub use self::issue_label_removed::*;
+pub use self::issue_priority_updated::*;
+pub use self::issue_recurred::*;
+pub use self::issue_recurrence_updated::*;
+pub use self::issue_reopened::*;
+pub use self::issue_started::*;
 pub use self::issue_title_updated::*;
 pub use self::issue_unblocked::*;
 pub use self::issue_updated::*;
@@ -86,6 +105,14 @@ impl From<crate::aggregate::issue_comment::Event> for DomainEvent {
 }
 
 impl DomainEvent {
+    pub fn at(&self) -> Instant {
+        match self {
+            DomainEvent::Issue(event) => event.at(),
+            DomainEvent::IssueBlockLink(event) => event.at(),
+            DomainEvent::IssueComment(event) => event.at(),
+        }
+    }
+
     pub fn issue(self) -> Option<IssueAggregateEvent> {
         if let Self::Issue(event) = self {
             Some(event)
@@ -3,7 +3,10 @@ use std::str::FromStr;
 use crate::{
     aggregate::{
         issue::{
-            attribute::{IssueDue, IssueResolution},
+            attribute::{
+                IssueAssignee, IssueDue, IssueLabel, IssuePriority, IssueRecurrence,
+                IssueResolution,
+            },
             IssueDescription, IssueTitle,
         },
         issue_comment::event::{
@@ -14,8 +17,10 @@ use crate::{
         },
         IssueAggregateEvent, IssueBlockLinkAggregateEvent,
     },
-    DomainEvent, IssueBlockLinkId, IssueBlocked, IssueCreatedV2, IssueDescriptionUpdated,
-    IssueFinished, IssueId, IssueTitleUpdated, IssueUnblocked, IssueUpdated, Version,
+    DomainEvent, IssueAssigneeUpdated, IssueBlockLinkId, IssueBlocked, IssueCancelled,
+    IssueCreatedV2, IssueDescriptionUpdated, IssueFinished, IssueId, IssueLabelAdded,
+    IssueLabelRemoved, IssuePriorityUpdated, IssueRecurred, IssueRecurrenceUpdated, IssueReopened,
+    IssueStarted, IssueTitleUpdated, IssueUnblocked, IssueUpdated, Version,
 };
 use limited_date_time::{Instant, ParseInstantError};
 use serde::{Deserialize, Serialize};
@@ -25,14 +30,22 @@ use thiserror::Error;
 pub enum TryFromEventDtoError {
     #[error("Instant")]
     Instant(#[from] ParseInstantError),
+    #[error("IssueAssignee")]
+    IssueAssignee(#[from] crate::aggregate::issue::attribute::issue_assignee::Error),
     #[error("IssueDescription")]
     IssueDescription(#[from] crate::aggregate::issue::attribute::issue_description::Error),
     #[error("IssueDue")]
     IssueDue(#[from] crate::aggregate::issue::attribute::issue_due::Error),
     #[error("IssueId")]
     IssueId(#[from] crate::issue_id::ParseIssueIdError),
+    #[error("IssueLabel")]
+    IssueLabel(#[from] crate::aggregate::issue::attribute::issue_label::Error),
     #[error("IssueNumber")]
     IssueNumber(#[from] crate::issue_number::Error),
+    #[error("IssuePriority")]
+    IssuePriority(#[from] crate::aggregate::issue::attribute::issue_priority::Error),
+    #[error("IssueRecurrence")]
+    IssueRecurrence(#[from] crate::aggregate::issue::attribute::issue_recurrence::Error),
     #[error("IssueResolution")]
     IssueResolution(#[from] crate::aggregate::issue::attribute::issue_resolution::Error),
     #[error("IssueTitle")]
@@ -59,6 +72,13 @@ pub enum TryFromEventDtoError {
 #[serde(tag = "type")]
 #[allow(clippy::enum_variant_names)]
 pub enum EventDto {
+    #[serde(rename = "issue_assignee_updated")]
+    IssueAssigneeUpdated {
+        at: String,
+        issue_id: String,
+        issue_assignee: Option<String>,
+        version: u64,
+    },
     #[serde(rename = "issue_blocked")]
     IssueBlocked {
         at: String,
@@ -66,6 +86,12 @@ pub enum EventDto {
         blocked_issue_id: String,
         version: u64,
     },
+    #[serde(rename = "issue_cancelled")]
+    IssueCancelled {
+        at: String,
+        issue_id: String,
+        version: u64,
+    },
     #[serde(rename = "issue_comment_created")]
     IssueCommentCreated(IssueCommentCreatedJson),
     #[serde(rename = "issue_comment_deleted")]
@@ -95,6 +121,53 @@ pub enum EventDto {
         resolution: Option<String>,
         version: u64,
     },
+    #[serde(rename = "issue_label_added")]
+    IssueLabelAdded {
+        at: String,
+        issue_id: String,
+        issue_label: String,
+        version: u64,
+    },
+    #[serde(rename = "issue_label_removed")]
+    IssueLabelRemoved {
+        at: String,
+        issue_id: String,
+        issue_label: String,
+        version: u64,
+    },
+    #[serde(rename = "issue_priority_updated")]
+    IssuePriorityUpdated {
+        at: String,
+        issue_id: String,
+        issue_priority: Option<String>,
+        version: u64,
+    },
+    #[serde(rename = "issue_recurred")]
+    IssueRecurred {
+        at: String,
+        issue_id: String,
+        next_issue_id: String,
+        version: u64,
+    },
+    #[serde(rename = "issue_recurrence_updated")]
+    IssueRecurrenceUpdated {
+        at: String,
+        issue_id: String,
+        issue_recurrence: Option<String>,
+        version: u64,
+    },
+    #[serde(rename = "issue_reopened")]
+    IssueReopened {
+        at: String,
+        issue_id: String,
+        version: u64,
+    },
+    #[serde(rename = "issue_started")]
+    IssueStarted {
+        at: String,
+        issue_id: String,
+        version: u64,
+    },
     #[serde(rename = "issue_unblocked")]
     IssueUnblocked {
         at: String,
@@ -132,6 +205,17 @@ impl From<IssueAggregateEvent> for EventDto {
     fn from(event: IssueAggregateEvent) -> Self {
         use crate::aggregate::issue::IssueAggregateEvent::*;
         match event {
+            AssigneeUpdated(event) => EventDto::IssueAssigneeUpdated {
+                at: event.at().to_string(),
+                issue_id: event.issue_id().to_string(),
+                issue_assignee: event.issue_assignee().map(|a| a.to_string()),
+                version: u64::from(event.version()),
+            },
+            Cancelled(event) => EventDto::IssueCancelled {
+                at: event.at().to_string(),
+                issue_id: event.issue_id().to_string(),
+                version: u64::from(event.version()),
+            },
             Created(event) => EventDto::from(DomainEvent::from(IssueAggregateEvent::CreatedV2(
                 IssueCreatedV2::from(event),
             ))),
@@ -155,6 +239,46 @@ impl From<IssueAggregateEvent> for EventDto {
                 resolution: event.resolution().map(|r| r.to_string()),
                 version: u64::from(event.version()),
             },
+            LabelAdded(event) => EventDto::IssueLabelAdded {
+                at: event.at().to_string(),
+                issue_id: event.issue_id().to_string(),
+                issue_label: event.issue_label().to_string(),
+                version: u64::from(event.version()),
+            },
+            LabelRemoved(event) => EventDto::IssueLabelRemoved {
+                at: event.at().to_string(),
+                issue_id: event.issue_id().to_string(),
+                issue_label: event.issue_label().to_string(),
+                version: u64::from(event.version()),
+            },
+            PriorityUpdated(event) => EventDto::IssuePriorityUpdated {
+                at: event.at().to_string(),
+                issue_id: event.issue_id().to_string(),
+                issue_priority: event.issue_priority().map(|p| p.to_string()),
+                version: u64::from(event.version()),
+            },
+            Recurred(event) => EventDto::IssueRecurred {
+                at: event.at().to_string(),
+                issue_id: event.issue_id().to_string(),
+                next_issue_id: event.next_issue_id().to_string(),
+                version: u64::from(event.version()),
+            },
+            RecurrenceUpdated(event) => EventDto::IssueRecurrenceUpdated {
+                at: event.at().to_string(),
+                issue_id: event.issue_id().to_string(),
+                issue_recurrence: event.issue_recurrence().map(|r| r.to_string()),
+                version: u64::from(event.version()),
+            },
+            Reopened(event) => EventDto::IssueReopened {
+                at: event.at().to_string(),
+                issue_id: event.issue_id().to_string(),
+                version: u64::from(event.version()),
+            },
+            Started(event) => EventDto::IssueStarted {
+                at: event.at().to_string(),
+                issue_id: event.issue_id().to_string(),
+                version: u64::from(event.version()),
+            },
             Updated(event) => EventDto::IssueUpdated {
                 at: event.at().to_string(),
                 issue_id: event.issue_id().to_string(),
@@ -207,6 +331,22 @@ impl TryFrom<EventDto> for DomainEvent {
 
     fn try_from(value: EventDto) -> Result<Self, Self::Error> {
         match value {
+            EventDto::IssueAssigneeUpdated {
+                at,
+                issue_id,
+                issue_assignee,
+                version,
+            } => Ok(
+                IssueAggregateEvent::AssigneeUpdated(IssueAssigneeUpdated::from_trusted_data(
+                    Instant::from_str(at.as_str())?,
+                    IssueId::from_str(issue_id.as_str())?,
+                    issue_assignee
+                        .map(|s| IssueAssignee::from_str(s.as_str()))
+                        .transpose()?,
+                    Version::from(version),
+                ))
+                .into(),
+            ),
             EventDto::IssueBlocked {
                 at,
                 issue_id,
@@ -223,6 +363,18 @@ impl TryFrom<EventDto> for DomainEvent {
                 ))
                 .into(),
             ),
+            EventDto::IssueCancelled {
+                at,
+                issue_id,
+                version,
+            } => Ok(
+                IssueAggregateEvent::Cancelled(IssueCancelled::from_trusted_data(
+                    Instant::from_str(at.as_str())?,
+                    IssueId::from_str(issue_id.as_str())?,
+                    Version::from(version),
+                ))
+                .into(),
+            ),
             EventDto::IssueCommentCreated(dto) => Ok(crate::aggregate::issue_comment::Event::from(
                 IssueCommentCreated::try_from(dto)?,
             )
@@ -289,6 +441,104 @@ impl TryFrom<EventDto> for DomainEvent {
                 ))
                 .into(),
             ),
+            EventDto::IssueLabelAdded {
+                at,
+                issue_id,
+                issue_label,
+                version,
+            } => Ok(
+                IssueAggregateEvent::LabelAdded(IssueLabelAdded::from_trusted_data(
+                    Instant::from_str(at.as_str())?,
+                    IssueId::from_str(issue_id.as_str())?,
+                    IssueLabel::from_str(issue_label.as_str())?,
+                    Version::from(version),
+                ))
+                .into(),
+            ),
+            EventDto::IssueLabelRemoved {
+                at,
+                issue_id,
+                issue_label,
+                version,
+            } => Ok(
+                IssueAggregateEvent::LabelRemoved(IssueLabelRemoved::from_trusted_data(
+                    Instant::from_str(at.as_str())?,
+                    IssueId::from_str(issue_id.as_str())?,
+                    IssueLabel::from_str(issue_label.as_str())?,
+                    Version::from(version),
+                ))
+                .into(),
+            ),
+            EventDto::IssuePriorityUpdated {
+                at,
+                issue_id,
+                issue_priority,
+                version,
+            } => Ok(
+                IssueAggregateEvent::PriorityUpdated(IssuePriorityUpdated::from_trusted_data(
+                    Instant::from_str(at.as_str())?,
+                    IssueId::from_str(issue_id.as_str())?,
+                    issue_priority
+                        .map(|s| IssuePriority::from_str(s.as_str()))
+                        .transpose()?,
+                    Version::from(version),
+                ))
+                .into(),
+            ),
+            EventDto::IssueRecurred {
+                at,
+                issue_id,
+                next_issue_id,
+                version,
+            } => Ok(
+                IssueAggregateEvent::Recurred(IssueRecurred::from_trusted_data(
+                    Instant::from_str(at.as_str())?,
+                    IssueId::from_str(issue_id.as_str())?,
+                    IssueId::from_str(next_issue_id.as_str())?,
+                    Version::from(version),
+                ))
+                .into(),
+            ),
+            EventDto::IssueRecurrenceUpdated {
+                at,
+                issue_id,
+                issue_recurrence,
+                version,
+            } => Ok(IssueAggregateEvent::RecurrenceUpdated(
+                IssueRecurrenceUpdated::from_trusted_data(
+                    Instant::from_str(at.as_str())?,
+                    IssueId::from_str(issue_id.as_str())?,
+                    issue_recurrence
+                        .map(|s| IssueRecurrence::from_str(s.as_str()))
+                        .transpose()?,
+                    Version::from(version),
+                ),
+            )
+            .into()),
+            EventDto::IssueReopened {
+                at,
+                issue_id,
+                version,
+            } => Ok(
+                IssueAggregateEvent::Reopened(IssueReopened::from_trusted_data(
+                    Instant::from_str(at.as_str())?,
+                    IssueId::from_str(issue_id.as_str())?,
+                    Version::from(version),
+                ))
+                .into(),
+            ),
+            EventDto::IssueStarted {
+                at,
+                issue_id,
+                version,
+            } => Ok(
+                IssueAggregateEvent::Started(IssueStarted::from_trusted_data(
+                    Instant::from_str(at.as_str())?,
+                    IssueId::from_str(issue_id.as_str())?,
+                    Version::from(version),
+                ))
+                .into(),
+            ),
             EventDto::IssueUnblocked {
                 at,
                 issue_id,
@@ -348,6 +598,53 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn issue_assignee_updated_conversion_test() -> anyhow::Result<()> {
+        let event = DomainEvent::from(IssueAggregateEvent::AssigneeUpdated(
+            IssueAssigneeUpdated::from_trusted_data(
+                Instant::from_str("2021-02-03T04:05:06Z")?,
+                IssueId::new(IssueNumber::try_from(2_usize)?),
+                Some(IssueAssignee::from_str("bouzuya")?),
+                Version::from(2_u64),
+            ),
+        ));
+        let dto = EventDto::IssueAssigneeUpdated {
+            at: "2021-02-03T04:05:06Z".to_string(),
+            issue_id: "2".to_string(),
+            issue_assignee: Some("bouzuya".to_string()),
+            version: 2_u64,
+        };
+        let serialized = r#"{"type":"issue_assignee_updated","at":"2021-02-03T04:05:06Z","issue_id":"2","issue_assignee":"bouzuya","version":2}"#;
+        assert_eq!(EventDto::from(event.clone()), dto);
+        assert_eq!(DomainEvent::try_from(EventDto::from(event.clone()))?, event);
+        assert_eq!(serde_json::to_string(&dto)?, serialized);
+        assert_eq!(serde_json::from_str::<'_, EventDto>(serialized)?, dto);
+        Ok(())
+    }
+
+    #[test]
+    fn issue_cancelled_conversion_test() -> anyhow::Result<()> {
+        let event = DomainEvent::from(IssueAggregateEvent::Cancelled(
+            IssueCancelled::from_trusted_data(
+                Instant::from_str("2021-02-03T04:05:06Z")?,
+                IssueId::new(IssueNumber::try_from(2_usize)?),
+                Version::from(2_u64),
+            ),
+        ));
+        let dto = EventDto::IssueCancelled {
+            at: "2021-02-03T04:05:06Z".to_string(),
+            issue_id: "2".to_string(),
+            version: 2_u64,
+        };
+        let serialized =
+            r#"{"type":"issue_cancelled","at":"2021-02-03T04:05:06Z","issue_id":"2","version":2}"#;
+        assert_eq!(EventDto::from(event.clone()), dto);
+        assert_eq!(DomainEvent::try_from(EventDto::from(event.clone()))?, event);
+        assert_eq!(serde_json::to_string(&dto)?, serialized);
+        assert_eq!(serde_json::from_str::<'_, EventDto>(serialized)?, dto);
+        Ok(())
+    }
+
     #[test]
     fn issue_created_dto_v1_and_issue_created_serialized_v1_0_conversion_test() -> anyhow::Result<()>
     {
@@ -541,6 +838,172 @@ mod tests {
 
         Ok(())
     }
+    #[test]
+    fn issue_label_added_conversion_test() -> anyhow::Result<()> {
+        let event = DomainEvent::from(IssueAggregateEvent::LabelAdded(
+            IssueLabelAdded::from_trusted_data(
+                Instant::from_str("2021-02-03T04:05:06Z")?,
+                IssueId::new(IssueNumber::try_from(2_usize)?),
+                IssueLabel::from_str("bug")?,
+                Version::from(2_u64),
+            ),
+        ));
+        let dto = EventDto::IssueLabelAdded {
+            at: "2021-02-03T04:05:06Z".to_string(),
+            issue_id: "2".to_string(),
+            issue_label: "bug".to_string(),
+            version: 2_u64,
+        };
+        let serialized = r#"{"type":"issue_label_added","at":"2021-02-03T04:05:06Z","issue_id":"2","issue_label":"bug","version":2}"#;
+        assert_eq!(EventDto::from(event.clone()), dto);
+        assert_eq!(DomainEvent::try_from(EventDto::from(event.clone()))?, event);
+        assert_eq!(serde_json::to_string(&dto)?, serialized);
+        assert_eq!(serde_json::from_str::<'_, EventDto>(serialized)?, dto);
+        Ok(())
+    }
+
+    #[test]
+    fn issue_label_removed_conversion_test() -> anyhow::Result<()> {
+        let event = DomainEvent::from(IssueAggregateEvent::LabelRemoved(
+            IssueLabelRemoved::from_trusted_data(
+                Instant::from_str("2021-02-03T04:05:06Z")?,
+                IssueId::new(IssueNumber::try_from(2_usize)?),
+                IssueLabel::from_str("bug")?,
+                Version::from(2_u64),
+            ),
+        ));
+        let dto = EventDto::IssueLabelRemoved {
+            at: "2021-02-03T04:05:06Z".to_string(),
+            issue_id: "2".to_string(),
+            issue_label: "bug".to_string(),
+            version: 2_u64,
+        };
+        let serialized = r#"{"type":"issue_label_removed","at":"2021-02-03T04:05:06Z","issue_id":"2","issue_label":"bug","version":2}"#;
+        assert_eq!(EventDto::from(event.clone()), dto);
+        assert_eq!(DomainEvent::try_from(EventDto::from(event.clone()))?, event);
+        assert_eq!(serde_json::to_string(&dto)?, serialized);
+        assert_eq!(serde_json::from_str::<'_, EventDto>(serialized)?, dto);
+        Ok(())
+    }
+
+    #[test]
+    fn issue_priority_updated_conversion_test() -> anyhow::Result<()> {
+        let event = DomainEvent::from(IssueAggregateEvent::PriorityUpdated(
+            IssuePriorityUpdated::from_trusted_data(
+                Instant::from_str("2021-02-03T04:05:06Z")?,
+                IssueId::new(IssueNumber::try_from(2_usize)?),
+                None,
+                Version::from(2_u64),
+            ),
+        ));
+        let dto = EventDto::IssuePriorityUpdated {
+            at: "2021-02-03T04:05:06Z".to_string(),
+            issue_id: "2".to_string(),
+            issue_priority: None,
+            version: 2_u64,
+        };
+        let serialized = r#"{"type":"issue_priority_updated","at":"2021-02-03T04:05:06Z","issue_id":"2","issue_priority":null,"version":2}"#;
+        assert_eq!(EventDto::from(event.clone()), dto);
+        assert_eq!(DomainEvent::try_from(EventDto::from(event.clone()))?, event);
+        assert_eq!(serde_json::to_string(&dto)?, serialized);
+        assert_eq!(serde_json::from_str::<'_, EventDto>(serialized)?, dto);
+        Ok(())
+    }
+
+    #[test]
+    fn issue_recurred_conversion_test() -> anyhow::Result<()> {
+        let event = DomainEvent::from(IssueAggregateEvent::Recurred(
+            IssueRecurred::from_trusted_data(
+                Instant::from_str("2021-02-03T04:05:06Z")?,
+                IssueId::new(IssueNumber::try_from(2_usize)?),
+                IssueId::new(IssueNumber::try_from(3_usize)?),
+                Version::from(3_u64),
+            ),
+        ));
+        let dto = EventDto::IssueRecurred {
+            at: "2021-02-03T04:05:06Z".to_string(),
+            issue_id: "2".to_string(),
+            next_issue_id: "3".to_string(),
+            version: 3_u64,
+        };
+        let serialized = r#"{"type":"issue_recurred","at":"2021-02-03T04:05:06Z","issue_id":"2","next_issue_id":"3","version":3}"#;
+        assert_eq!(EventDto::from(event.clone()), dto);
+        assert_eq!(DomainEvent::try_from(EventDto::from(event.clone()))?, event);
+        assert_eq!(serde_json::to_string(&dto)?, serialized);
+        assert_eq!(serde_json::from_str::<'_, EventDto>(serialized)?, dto);
+        Ok(())
+    }
+
+    #[test]
+    fn issue_recurrence_updated_conversion_test() -> anyhow::Result<()> {
+        let event = DomainEvent::from(IssueAggregateEvent::RecurrenceUpdated(
+            IssueRecurrenceUpdated::from_trusted_data(
+                Instant::from_str("2021-02-03T04:05:06Z")?,
+                IssueId::new(IssueNumber::try_from(2_usize)?),
+                Some(IssueRecurrence::from_str("FREQ=WEEKLY;INTERVAL=2")?),
+                Version::from(2_u64),
+            ),
+        ));
+        let dto = EventDto::IssueRecurrenceUpdated {
+            at: "2021-02-03T04:05:06Z".to_string(),
+            issue_id: "2".to_string(),
+            issue_recurrence: Some("FREQ=WEEKLY;INTERVAL=2".to_string()),
+            version: 2_u64,
+        };
+        let serialized = r#"{"type":"issue_recurrence_updated","at":"2021-02-03T04:05:06Z","issue_id":"2","issue_recurrence":"FREQ=WEEKLY;INTERVAL=2","version":2}"#;
+        assert_eq!(EventDto::from(event.clone()), dto);
+        assert_eq!(DomainEvent::try_from(EventDto::from(event.clone()))?, event);
+        assert_eq!(serde_json::to_string(&dto)?, serialized);
+        assert_eq!(serde_json::from_str::<'_, EventDto>(serialized)?, dto);
+        Ok(())
+    }
+
+    #[test]
+    fn issue_reopened_conversion_test() -> anyhow::Result<()> {
+        let event = DomainEvent::from(IssueAggregateEvent::Reopened(
+            IssueReopened::from_trusted_data(
+                Instant::from_str("2021-02-03T04:05:06Z")?,
+                IssueId::new(IssueNumber::try_from(2_usize)?),
+                Version::from(2_u64),
+            ),
+        ));
+        let dto = EventDto::IssueReopened {
+            at: "2021-02-03T04:05:06Z".to_string(),
+            issue_id: "2".to_string(),
+            version: 2_u64,
+        };
+        let serialized =
+            r#"{"type":"issue_reopened","at":"2021-02-03T04:05:06Z","issue_id":"2","version":2}"#;
+        assert_eq!(EventDto::from(event.clone()), dto);
+        assert_eq!(DomainEvent::try_from(EventDto::from(event.clone()))?, event);
+        assert_eq!(serde_json::to_string(&dto)?, serialized);
+        assert_eq!(serde_json::from_str::<'_, EventDto>(serialized)?, dto);
+        Ok(())
+    }
+
+    #[test]
+    fn issue_started_conversion_test() -> anyhow::Result<()> {
+        let event = DomainEvent::from(IssueAggregateEvent::Started(
+            IssueStarted::from_trusted_data(
+                Instant::from_str("2021-02-03T04:05:06Z")?,
+                IssueId::new(IssueNumber::try_from(2_usize)?),
+                Version::from(2_u64),
+            ),
+        ));
+        let dto = EventDto::IssueStarted {
+            at: "2021-02-03T04:05:06Z".to_string(),
+            issue_id: "2".to_string(),
+            version: 2_u64,
+        };
+        let serialized =
+            r#"{"type":"issue_started","at":"2021-02-03T04:05:06Z","issue_id":"2","version":2}"#;
+        assert_eq!(EventDto::from(event.clone()), dto);
+        assert_eq!(DomainEvent::try_from(EventDto::from(event.clone()))?, event);
+        assert_eq!(serde_json::to_string(&dto)?, serialized);
+        assert_eq!(serde_json::from_str::<'_, EventDto>(serialized)?, dto);
+        Ok(())
+    }
+
     #[test]
     fn issue_updated_conversion_test() -> anyhow::Result<()> {
         let event = DomainEvent::from(IssueAggregateEvent::Updated(
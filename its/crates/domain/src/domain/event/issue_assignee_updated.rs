@@ -0,0 +1,84 @@
+use limited_date_time::Instant;
+
+use crate::{aggregate::issue::attribute::IssueAssignee, IssueId, Version};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssueAssigneeUpdated {
+    pub(crate) at: Instant,
+    pub(crate) issue_id: IssueId,
+    pub(crate) issue_assignee: Option<IssueAssignee>,
+    pub(crate) version: Version,
+}
+
+impl IssueAssigneeUpdated {
+    pub(crate) fn from_trusted_data(
+        at: Instant,
+        issue_id: IssueId,
+        issue_assignee: Option<IssueAssignee>,
+        version: Version,
+    ) -> Self {
+        Self::new(at, issue_id, issue_assignee, version)
+    }
+
+    pub(crate) fn new(
+        at: Instant,
+        issue_id: IssueId,
+        issue_assignee: Option<IssueAssignee>,
+        version: Version,
+    ) -> Self {
+        Self {
+            at,
+            issue_id,
+            issue_assignee,
+            version,
+        }
+    }
+
+    pub fn at(&self) -> Instant {
+        self.at
+    }
+
+    pub fn issue_id(&self) -> &IssueId {
+        &self.issue_id
+    }
+
+    pub fn issue_assignee(&self) -> Option<&IssueAssignee> {
+        self.issue_assignee.as_ref()
+    }
+
+    pub fn version(&self) -> Version {
+        self.version
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::IssueNumber;
+
+    use super::*;
+
+    #[test]
+    fn test() -> anyhow::Result<()> {
+        let at = Instant::now();
+        let issue_id = IssueId::new(IssueNumber::start_number());
+        let issue_assignee = IssueAssignee::from_str("bouzuya")?;
+        let version = Version::from(2_u64);
+        let issue_assignee_updated = IssueAssigneeUpdated::from_trusted_data(
+            at,
+            issue_id.clone(),
+            Some(issue_assignee.clone()),
+            version,
+        );
+        // TODO: new
+        assert_eq!(issue_assignee_updated.at(), at);
+        assert_eq!(issue_assignee_updated.issue_id(), &issue_id);
+        assert_eq!(
+            issue_assignee_updated.issue_assignee(),
+            Some(&issue_assignee)
+        );
+        assert_eq!(issue_assignee_updated.version(), version);
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use limited_date_time::Instant;
+
+use crate::{aggregate::issue::attribute::IssueLabel, IssueId, Version};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssueLabelRemoved {
+    pub(crate) at: Instant,
+    pub(crate) issue_id: IssueId,
+    pub(crate) issue_label: IssueLabel,
+    pub(crate) version: Version,
+}
+
+impl IssueLabelRemoved {
+    pub(crate) fn from_trusted_data(
+        at: Instant,
+        issue_id: IssueId,
+        issue_label: IssueLabel,
+        version: Version,
+    ) -> Self {
+        Self::new(at, issue_id, issue_label, version)
+    }
+
+    pub(crate) fn new(
+        at: Instant,
+        issue_id: IssueId,
+        issue_label: IssueLabel,
+        version: Version,
+    ) -> Self {
+        Self {
+            at,
+            issue_id,
+            issue_label,
+            version,
+        }
+    }
+
+    pub fn at(&self) -> Instant {
+        self.at
+    }
+
+    pub fn issue_id(&self) -> &IssueId {
+        &self.issue_id
+    }
+
+    pub fn issue_label(&self) -> &IssueLabel {
+        &self.issue_label
+    }
+
+    pub fn version(&self) -> Version {
+        self.version
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::IssueNumber;
+
+    use super::*;
+
+    #[test]
+    fn test() -> anyhow::Result<()> {
+        let at = Instant::now();
+        let issue_id = IssueId::new(IssueNumber::start_number());
+        let issue_label = IssueLabel::from_str("bug")?;
+        let version = Version::from(2_u64);
+        let issue_label_removed = IssueLabelRemoved::from_trusted_data(
+            at,
+            issue_id.clone(),
+            issue_label.clone(),
+            version,
+        );
+        // TODO: new
+        assert_eq!(issue_label_removed.at(), at);
+        assert_eq!(issue_label_removed.issue_id(), &issue_id);
+        assert_eq!(issue_label_removed.issue_label(), &issue_label);
+        assert_eq!(issue_label_removed.version(), version);
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use limited_date_time::Instant;
+
+use crate::{aggregate::issue::attribute::IssuePriority, IssueId, Version};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssuePriorityUpdated {
+    pub(crate) at: Instant,
+    pub(crate) issue_id: IssueId,
+    pub(crate) issue_priority: Option<IssuePriority>,
+    pub(crate) version: Version,
+}
+
+impl IssuePriorityUpdated {
+    pub(crate) fn from_trusted_data(
+        at: Instant,
+        issue_id: IssueId,
+        issue_priority: Option<IssuePriority>,
+        version: Version,
+    ) -> Self {
+        Self::new(at, issue_id, issue_priority, version)
+    }
+
+    pub(crate) fn new(
+        at: Instant,
+        issue_id: IssueId,
+        issue_priority: Option<IssuePriority>,
+        version: Version,
+    ) -> Self {
+        Self {
+            at,
+            issue_id,
+            issue_priority,
+            version,
+        }
+    }
+
+    pub fn at(&self) -> Instant {
+        self.at
+    }
+
+    pub fn issue_id(&self) -> &IssueId {
+        &self.issue_id
+    }
+
+    pub fn issue_priority(&self) -> Option<IssuePriority> {
+        self.issue_priority
+    }
+
+    pub fn version(&self) -> Version {
+        self.version
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::IssueNumber;
+
+    use super::*;
+
+    #[test]
+    fn test() -> anyhow::Result<()> {
+        let at = Instant::now();
+        let issue_id = IssueId::new(IssueNumber::start_number());
+        let issue_priority = IssuePriority::from_str("high")?;
+        let version = Version::from(2_u64);
+        let issue_priority_updated = IssuePriorityUpdated::from_trusted_data(
+            at,
+            issue_id.clone(),
+            Some(issue_priority),
+            version,
+        );
+        // TODO: new
+        assert_eq!(issue_priority_updated.at(), at);
+        assert_eq!(issue_priority_updated.issue_id(), &issue_id);
+        assert_eq!(
+            issue_priority_updated.issue_priority(),
+            Some(issue_priority)
+        );
+        assert_eq!(issue_priority_updated.version(), version);
+        Ok(())
+    }
+}
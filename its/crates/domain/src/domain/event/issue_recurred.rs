@@ -0,0 +1,75 @@
+use limited_date_time::Instant;
+
+use crate::{IssueId, Version};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssueRecurred {
+    pub(crate) at: Instant,
+    pub(crate) issue_id: IssueId,
+    pub(crate) next_issue_id: IssueId,
+    pub(crate) version: Version,
+}
+
+impl IssueRecurred {
+    pub(crate) fn from_trusted_data(
+        at: Instant,
+        issue_id: IssueId,
+        next_issue_id: IssueId,
+        version: Version,
+    ) -> Self {
+        Self::new(at, issue_id, next_issue_id, version)
+    }
+
+    pub(crate) fn new(
+        at: Instant,
+        issue_id: IssueId,
+        next_issue_id: IssueId,
+        version: Version,
+    ) -> Self {
+        Self {
+            at,
+            issue_id,
+            next_issue_id,
+            version,
+        }
+    }
+
+    pub fn at(&self) -> Instant {
+        self.at
+    }
+
+    pub fn issue_id(&self) -> &IssueId {
+        &self.issue_id
+    }
+
+    pub fn next_issue_id(&self) -> &IssueId {
+        &self.next_issue_id
+    }
+
+    pub fn version(&self) -> Version {
+        self.version
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IssueNumber;
+
+    use super::*;
+
+    #[test]
+    fn test() -> anyhow::Result<()> {
+        let at = Instant::now();
+        let issue_id = IssueId::new(IssueNumber::start_number());
+        let next_issue_id = IssueId::new(IssueNumber::try_from(2_usize)?);
+        let version = Version::from(3_u64);
+        let issue_recurred =
+            IssueRecurred::from_trusted_data(at, issue_id.clone(), next_issue_id.clone(), version);
+        // TODO: new
+        assert_eq!(issue_recurred.at(), at);
+        assert_eq!(issue_recurred.issue_id(), &issue_id);
+        assert_eq!(issue_recurred.next_issue_id(), &next_issue_id);
+        assert_eq!(issue_recurred.version(), version);
+        Ok(())
+    }
+}
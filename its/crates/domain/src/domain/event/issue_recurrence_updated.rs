@@ -0,0 +1,84 @@
+use limited_date_time::Instant;
+
+use crate::{aggregate::issue::attribute::IssueRecurrence, IssueId, Version};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssueRecurrenceUpdated {
+    pub(crate) at: Instant,
+    pub(crate) issue_id: IssueId,
+    pub(crate) issue_recurrence: Option<IssueRecurrence>,
+    pub(crate) version: Version,
+}
+
+impl IssueRecurrenceUpdated {
+    pub(crate) fn from_trusted_data(
+        at: Instant,
+        issue_id: IssueId,
+        issue_recurrence: Option<IssueRecurrence>,
+        version: Version,
+    ) -> Self {
+        Self::new(at, issue_id, issue_recurrence, version)
+    }
+
+    pub(crate) fn new(
+        at: Instant,
+        issue_id: IssueId,
+        issue_recurrence: Option<IssueRecurrence>,
+        version: Version,
+    ) -> Self {
+        Self {
+            at,
+            issue_id,
+            issue_recurrence,
+            version,
+        }
+    }
+
+    pub fn at(&self) -> Instant {
+        self.at
+    }
+
+    pub fn issue_id(&self) -> &IssueId {
+        &self.issue_id
+    }
+
+    pub fn issue_recurrence(&self) -> Option<IssueRecurrence> {
+        self.issue_recurrence
+    }
+
+    pub fn version(&self) -> Version {
+        self.version
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::IssueNumber;
+
+    use super::*;
+
+    #[test]
+    fn test() -> anyhow::Result<()> {
+        let at = Instant::now();
+        let issue_id = IssueId::new(IssueNumber::start_number());
+        let issue_recurrence = IssueRecurrence::from_str("weekly")?;
+        let version = Version::from(2_u64);
+        let issue_recurrence_updated = IssueRecurrenceUpdated::from_trusted_data(
+            at,
+            issue_id.clone(),
+            Some(issue_recurrence),
+            version,
+        );
+        // TODO: new
+        assert_eq!(issue_recurrence_updated.at(), at);
+        assert_eq!(issue_recurrence_updated.issue_id(), &issue_id);
+        assert_eq!(
+            issue_recurrence_updated.issue_recurrence(),
+            Some(issue_recurrence)
+        );
+        assert_eq!(issue_recurrence_updated.version(), version);
+        Ok(())
+    }
+}
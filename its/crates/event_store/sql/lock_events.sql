@@ -0,0 +1 @@
+LOCK TABLE events IN EXCLUSIVE MODE
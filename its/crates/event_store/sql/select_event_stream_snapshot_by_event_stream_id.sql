@@ -0,0 +1,5 @@
+SELECT event_stream_snapshots.event_stream_id AS event_stream_id,
+  event_stream_snapshots.version AS version,
+  event_stream_snapshots.data AS data
+FROM event_stream_snapshots
+WHERE event_stream_snapshots.event_stream_id = $1
@@ -0,0 +1,7 @@
+SELECT events.id AS id,
+  events.event_stream_id AS event_stream_id,
+  events.version AS version,
+  events.data AS data
+FROM events
+WHERE events.event_stream_id IN ($1)
+ORDER BY events.seq ASC
@@ -0,0 +1,6 @@
+INSERT INTO event_stream_snapshots (event_stream_id, version, data)
+VALUES ($1, $2, $3) ON CONFLICT (event_stream_id) DO
+UPDATE
+SET version = excluded.version,
+  data = excluded.data
+WHERE event_stream_snapshots.version < excluded.version
@@ -5,6 +5,8 @@ mod event_row;
 mod event_stream_id;
 mod event_stream_row;
 mod event_stream_seq;
+mod snapshot;
+mod snapshot_row;
 
 pub use self::error::Error;
 pub use self::event::Event;
@@ -13,9 +15,15 @@ use self::event_row::EventRow;
 pub use self::event_stream_id::*;
 use self::event_stream_row::EventStreamRow;
 pub use self::event_stream_seq::*;
+pub use self::snapshot::Snapshot;
+use self::snapshot_row::SnapshotRow;
 
 use sqlx::Transaction;
-use sqlx::{any::AnyArguments, query::Query, Any};
+use sqlx::{
+    any::{AnyArguments, AnyKind},
+    query::Query,
+    Any,
+};
 
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
@@ -62,6 +70,26 @@ pub async fn find_events_by_event_stream_id(
     Ok(event_rows.into_iter().map(Event::from).collect())
 }
 
+// `event_stream_ids` must not be empty
+pub async fn find_events_by_event_stream_ids(
+    transaction: &mut Transaction<'_, Any>,
+    event_stream_ids: &[EventStreamId],
+) -> Result<Vec<Event>> {
+    // expands `IN ($1)` to `IN ($1, $2, ...)`
+    let placeholders = (1..=event_stream_ids.len())
+        .map(|i| format!("${}", i))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let sql = include_str!("../../../sql/select_events_by_event_stream_ids.sql")
+        .replace("$1", placeholders.as_str());
+    let mut query = sqlx::query_as(sql.as_str());
+    for event_stream_id in event_stream_ids {
+        query = query.bind(event_stream_id.to_string());
+    }
+    let event_rows: Vec<EventRow> = query.fetch_all(&mut *transaction).await?;
+    Ok(event_rows.into_iter().map(Event::from).collect())
+}
+
 pub async fn find_events_by_event_stream_id_and_version_less_than_equal(
     transaction: &mut Transaction<'_, Any>,
     event_stream_id: EventStreamId,
@@ -77,11 +105,48 @@ pub async fn find_events_by_event_stream_id_and_version_less_than_equal(
     Ok(event_rows.into_iter().map(Event::from).collect())
 }
 
+pub async fn find_events_by_event_stream_id_and_version_greater_than(
+    transaction: &mut Transaction<'_, Any>,
+    event_stream_id: EventStreamId,
+    version: EventStreamSeq,
+) -> Result<Vec<Event>> {
+    let event_rows: Vec<EventRow> = sqlx::query_as(include_str!(
+        "../../../sql/select_events_by_event_stream_id_and_version_greater_than.sql"
+    ))
+    .bind(event_stream_id.to_string())
+    .bind(i64::from(version))
+    .fetch_all(&mut *transaction)
+    .await?;
+    Ok(event_rows.into_iter().map(Event::from).collect())
+}
+
+pub async fn find_snapshot_by_event_stream_id(
+    transaction: &mut Transaction<'_, Any>,
+    event_stream_id: EventStreamId,
+) -> Result<Option<Snapshot>> {
+    let snapshot_row: Option<SnapshotRow> = sqlx::query_as(include_str!(
+        "../../../sql/select_event_stream_snapshot_by_event_stream_id.sql"
+    ))
+    .bind(event_stream_id.to_string())
+    .fetch_optional(&mut *transaction)
+    .await?;
+    Ok(snapshot_row.map(Snapshot::from))
+}
+
 pub async fn save(
     transaction: &mut Transaction<'_, Any>,
     current_version: Option<EventStreamSeq>,
     event: Event,
 ) -> Result<()> {
+    // PostgreSQL assigns `events.seq` (BIGSERIAL) before commit, so concurrent writers may commit
+    // them out of order and a checkpoint (`find_events_by_event_id_after`) would skip an event.
+    // the writers are serialized until commit. SQLite has only one writer at a time.
+    if transaction.kind() == AnyKind::Postgres {
+        sqlx::query(include_str!("../../../sql/lock_events.sql"))
+            .execute(&mut *transaction)
+            .await?;
+    }
+
     if let Some(current_version) = current_version {
         let query: Query<Any, AnyArguments> =
             sqlx::query(include_str!("../../../sql/update_event_stream.sql"))
@@ -117,6 +182,20 @@ pub async fn save(
     Ok(())
 }
 
+pub async fn save_snapshot(
+    transaction: &mut Transaction<'_, Any>,
+    snapshot: Snapshot,
+) -> Result<()> {
+    let query: Query<Any, AnyArguments> = sqlx::query(include_str!(
+        "../../../sql/upsert_event_stream_snapshot.sql"
+    ))
+    .bind(snapshot.stream_id.to_string())
+    .bind(i64::from(snapshot.stream_seq))
+    .bind(snapshot.data);
+    query.execute(&mut *transaction).await?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use sqlx::AnyPool;
@@ -152,6 +231,18 @@ CREATE TABLE IF NOT EXISTS events (
     CONSTRAINT events_uk1 UNIQUE (id),
     CONSTRAINT events_uk2 UNIQUE (event_stream_id, version),
     CONSTRAINT events_fk1 FOREIGN KEY (event_stream_id) REFERENCES event_streams (id)
+)"#,
+        )
+        .execute(&mut transaction)
+        .await?;
+        sqlx::query(
+            r#"
+CREATE TABLE IF NOT EXISTS event_stream_snapshots (
+    event_stream_id CHAR(26) NOT NULL,
+    version BIGINT NOT NULL,
+    data TEXT NOT NULL,
+    CONSTRAINT event_stream_snapshots_pk PRIMARY KEY (event_stream_id),
+    CONSTRAINT event_stream_snapshots_fk1 FOREIGN KEY (event_stream_id) REFERENCES event_streams (id)
 )"#,
         )
         .execute(&mut transaction)
@@ -203,6 +294,46 @@ CREATE TABLE IF NOT EXISTS events (
 
         let mut transaction = pool.begin().await?;
         assert_eq!(find_events(&mut transaction).await?.len(), 2);
+        let events = find_events_by_event_stream_id_and_version_greater_than(
+            &mut transaction,
+            event_stream_id,
+            version,
+        )
+        .await?;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].stream_seq, EventStreamSeq::from(2_u32));
+        let events = find_events_by_event_stream_ids(
+            &mut transaction,
+            &[EventStreamId::generate(), event_stream_id],
+        )
+        .await?;
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].stream_seq, EventStreamSeq::from(2_u32));
+
+        assert_eq!(
+            find_snapshot_by_event_stream_id(&mut transaction, event_stream_id).await?,
+            None
+        );
+        let snapshot = Snapshot {
+            stream_id: event_stream_id,
+            stream_seq: EventStreamSeq::from(2_u32),
+            data: r#"{"version":2}"#.to_string(),
+        };
+        save_snapshot(&mut transaction, snapshot.clone()).await?;
+        // an older snapshot does not replace the newer one
+        save_snapshot(
+            &mut transaction,
+            Snapshot {
+                stream_id: event_stream_id,
+                stream_seq: EventStreamSeq::from(1_u32),
+                data: r#"{"version":1}"#.to_string(),
+            },
+        )
+        .await?;
+        assert_eq!(
+            find_snapshot_by_event_stream_id(&mut transaction, event_stream_id).await?,
+            Some(snapshot)
+        );
 
         Ok(())
     }
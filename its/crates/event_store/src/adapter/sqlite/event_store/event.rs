@@ -1,6 +1,6 @@
 use super::{event_id::EventId, event_stream_id::EventStreamId, event_stream_seq::EventStreamSeq};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Event {
     pub id: EventId,
     pub stream_id: EventStreamId,
@@ -0,0 +1,8 @@
+use super::{event_stream_id::EventStreamId, event_stream_seq::EventStreamSeq};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Snapshot {
+    pub stream_id: EventStreamId,
+    pub stream_seq: EventStreamSeq,
+    pub data: String,
+}
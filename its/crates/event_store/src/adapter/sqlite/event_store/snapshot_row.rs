@@ -0,0 +1,48 @@
+use std::str::FromStr;
+
+use sqlx::{any::AnyRow, FromRow, Row};
+
+use super::{EventStreamId, EventStreamSeq, Snapshot};
+
+#[derive(Debug)]
+pub(super) struct SnapshotRow {
+    event_stream_id: String,
+    data: String,
+    version: i64,
+}
+
+impl SnapshotRow {
+    fn event_stream_id(&self) -> EventStreamId {
+        EventStreamId::from_str(self.event_stream_id.as_str())
+            .expect("event_stream_snapshots.event_stream_id is not well-formed")
+    }
+
+    fn data(&self) -> String {
+        self.data.to_owned()
+    }
+
+    fn version(&self) -> EventStreamSeq {
+        EventStreamSeq::try_from(self.version)
+            .expect("event_stream_snapshots.version is not well-formed")
+    }
+}
+
+impl<'r> FromRow<'r, AnyRow> for SnapshotRow {
+    fn from_row(row: &'r AnyRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            event_stream_id: row.get("event_stream_id"),
+            data: row.get("data"),
+            version: row.get("version"),
+        })
+    }
+}
+
+impl From<SnapshotRow> for Snapshot {
+    fn from(row: SnapshotRow) -> Self {
+        Self {
+            stream_id: row.event_stream_id(),
+            stream_seq: row.version(),
+            data: row.data(),
+        }
+    }
+}
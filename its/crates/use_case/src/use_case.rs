@@ -32,24 +32,40 @@ pub enum Error {
     IssueRepository(#[from] crate::use_case::issue_repository::Error),
     #[error("InvalidIssueBlockLinkId")]
     InvalidIssueBlockLinkId(#[from] domain::issue_block_link_id::Error),
+    #[error("add issue label {0}")]
+    AddIssueLabel(#[from] command_handler::add_issue_label::Error),
     #[error("block issue {0}")]
     BlockIssue(#[from] command_handler::block_issue::Error),
+    #[error("cancel issue {0}")]
+    CancelIssue(#[from] command_handler::cancel_issue::Error),
     #[error("create issue {0}")]
     CreateIssue(#[from] command_handler::create_issue::Error),
     #[error("create issue comment {0}")]
     CreateIssueComment(#[from] command_handler::create_issue_comment::Error),
     #[error("delete issue comment {0}")]
     DeleteIssueComment(#[from] command_handler::delete_issue_comment::Error),
+    #[error("edit issue {0}")]
+    EditIssue(#[from] command_handler::edit_issue::Error),
     #[error("finish issue {0}")]
     FinishIssue(#[from] command_handler::finish_issue::Error),
+    #[error("remove issue label {0}")]
+    RemoveIssueLabel(#[from] command_handler::remove_issue_label::Error),
+    #[error("reopen issue {0}")]
+    ReopenIssue(#[from] command_handler::reopen_issue::Error),
+    #[error("start issue {0}")]
+    StartIssue(#[from] command_handler::start_issue::Error),
     #[error("unblock issue {0}")]
     UnblockIssue(#[from] command_handler::unblock_issue::Error),
     #[error("update issue {0}")]
     UpdateIssue(#[from] command_handler::update_issue::Error),
+    #[error("update issue assignee {0}")]
+    UpdateIssueAssignee(#[from] command_handler::update_issue_assignee::Error),
     #[error("update issue comment {0}")]
     UpdateIssueComment(#[from] command_handler::update_issue_comment::Error),
     #[error("update issue description {0}")]
     UpdateIssueDescription(#[from] command_handler::update_issue_description::Error),
+    #[error("update issue priority {0}")]
+    UpdateIssuePriority(#[from] command_handler::update_issue_priority::Error),
     #[error("update issue title {0}")]
     UpdateIssueTitle(#[from] command_handler::update_issue_title::Error),
 }
@@ -64,9 +80,15 @@ pub trait IssueManagementContextUseCase:
     ) -> Result<IssueManagementContextEvent> {
         use IssueManagementContextCommand::*;
         match command.into() {
+            AddIssueLabel(command) => {
+                Ok(command_handler::add_issue_label::add_issue_label(self, command).await?)
+            }
             BlockIssue(command) => {
                 Ok(command_handler::block_issue::block_issue(self, command).await?)
             }
+            CancelIssue(command) => {
+                Ok(command_handler::cancel_issue::cancel_issue(self, command).await?)
+            }
             CreateIssue(command) => {
                 Ok(command_handler::create_issue::create_issue(self, command).await?)
             }
@@ -76,18 +98,36 @@ pub trait IssueManagementContextUseCase:
             DeleteIssueComment(command) => Ok(
                 command_handler::delete_issue_comment::delete_issue_comment(self, command).await?,
             ),
+            EditIssue(command) => Ok(command_handler::edit_issue::edit_issue(self, command).await?),
             FinishIssue(command) => {
                 Ok(command_handler::finish_issue::finish_issue(self, command).await?)
             }
+            RemoveIssueLabel(command) => {
+                Ok(command_handler::remove_issue_label::remove_issue_label(self, command).await?)
+            }
+            ReopenIssue(command) => {
+                Ok(command_handler::reopen_issue::reopen_issue(self, command).await?)
+            }
+            StartIssue(command) => {
+                Ok(command_handler::start_issue::start_issue(self, command).await?)
+            }
             UnblockIssue(command) => {
                 Ok(command_handler::unblock_issue::unblock_issue(self, command).await?)
             }
             UpdateIssue(command) => {
                 Ok(command_handler::update_issue::update_issue(self, command).await?)
             }
+            UpdateIssueAssignee(command) => Ok(
+                command_handler::update_issue_assignee::update_issue_assignee(self, command)
+                    .await?,
+            ),
             UpdateIssueComment(command) => Ok(
                 command_handler::update_issue_comment::update_issue_comment(self, command).await?,
             ),
+            UpdateIssuePriority(command) => Ok(
+                command_handler::update_issue_priority::update_issue_priority(self, command)
+                    .await?,
+            ),
             UpdateIssueTitle(command) => {
                 Ok(command_handler::update_issue_title::update_issue_title(self, command).await?)
             }
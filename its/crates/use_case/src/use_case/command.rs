@@ -1,25 +1,41 @@
+pub use super::command_handler::add_issue_label::AddIssueLabel;
 pub use super::command_handler::block_issue::BlockIssue;
+pub use super::command_handler::cancel_issue::CancelIssue;
 pub use super::command_handler::create_issue::CreateIssue;
 pub use super::command_handler::create_issue_comment::CreateIssueComment;
 pub use super::command_handler::delete_issue_comment::DeleteIssueComment;
+pub use super::command_handler::edit_issue::EditIssue;
 pub use super::command_handler::finish_issue::FinishIssue;
+pub use super::command_handler::remove_issue_label::RemoveIssueLabel;
+pub use super::command_handler::reopen_issue::ReopenIssue;
+pub use super::command_handler::start_issue::StartIssue;
 pub use super::command_handler::unblock_issue::UnblockIssue;
 pub use super::command_handler::update_issue::UpdateIssue;
+pub use super::command_handler::update_issue_assignee::UpdateIssueAssignee;
 pub use super::command_handler::update_issue_comment::UpdateIssueComment;
 pub use super::command_handler::update_issue_description::UpdateIssueDescription;
+pub use super::command_handler::update_issue_priority::UpdateIssuePriority;
 pub use super::command_handler::update_issue_title::UpdateIssueTitle;
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum IssueManagementContextCommand {
+    AddIssueLabel(AddIssueLabel),
     BlockIssue(BlockIssue),
+    CancelIssue(CancelIssue),
     CreateIssue(CreateIssue),
     CreateIssueComment(CreateIssueComment),
     DeleteIssueComment(DeleteIssueComment),
+    EditIssue(EditIssue),
     FinishIssue(FinishIssue),
+    RemoveIssueLabel(RemoveIssueLabel),
+    ReopenIssue(ReopenIssue),
+    StartIssue(StartIssue),
     UnblockIssue(UnblockIssue),
     UpdateIssue(UpdateIssue),
+    UpdateIssueAssignee(UpdateIssueAssignee),
     UpdateIssueComment(UpdateIssueComment),
     UpdateIssueDescription(UpdateIssueDescription),
+    UpdateIssuePriority(UpdateIssuePriority),
     UpdateIssueTitle(UpdateIssueTitle),
 }
 
@@ -29,6 +45,12 @@ impl From<BlockIssue> for IssueManagementContextCommand {
     }
 }
 
+impl From<CancelIssue> for IssueManagementContextCommand {
+    fn from(command: CancelIssue) -> Self {
+        Self::CancelIssue(command)
+    }
+}
+
 impl From<CreateIssue> for IssueManagementContextCommand {
     fn from(command: CreateIssue) -> Self {
         Self::CreateIssue(command)
@@ -47,12 +69,30 @@ impl From<DeleteIssueComment> for IssueManagementContextCommand {
     }
 }
 
+impl From<EditIssue> for IssueManagementContextCommand {
+    fn from(command: EditIssue) -> Self {
+        Self::EditIssue(command)
+    }
+}
+
 impl From<FinishIssue> for IssueManagementContextCommand {
     fn from(command: FinishIssue) -> Self {
         Self::FinishIssue(command)
     }
 }
 
+impl From<ReopenIssue> for IssueManagementContextCommand {
+    fn from(command: ReopenIssue) -> Self {
+        Self::ReopenIssue(command)
+    }
+}
+
+impl From<StartIssue> for IssueManagementContextCommand {
+    fn from(command: StartIssue) -> Self {
+        Self::StartIssue(command)
+    }
+}
+
 impl From<UnblockIssue> for IssueManagementContextCommand {
     fn from(command: UnblockIssue) -> Self {
         Self::UnblockIssue(command)
@@ -82,3 +122,27 @@ impl From<UpdateIssueDescription> for IssueManagementContextCommand {
         Self::UpdateIssueDescription(command)
     }
 }
+
+impl From<AddIssueLabel> for IssueManagementContextCommand {
+    fn from(command: AddIssueLabel) -> Self {
+        Self::AddIssueLabel(command)
+    }
+}
+
+impl From<RemoveIssueLabel> for IssueManagementContextCommand {
+    fn from(command: RemoveIssueLabel) -> Self {
+        Self::RemoveIssueLabel(command)
+    }
+}
+
+impl From<UpdateIssueAssignee> for IssueManagementContextCommand {
+    fn from(command: UpdateIssueAssignee) -> Self {
+        Self::UpdateIssueAssignee(command)
+    }
+}
+
+impl From<UpdateIssuePriority> for IssueManagementContextCommand {
+    fn from(command: UpdateIssuePriority) -> Self {
+        Self::UpdateIssuePriority(command)
+    }
+}
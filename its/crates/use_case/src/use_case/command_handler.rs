@@ -1,10 +1,18 @@
+pub mod add_issue_label;
 pub mod block_issue;
+pub mod cancel_issue;
 pub mod create_issue;
 pub mod create_issue_comment;
 pub mod delete_issue_comment;
+pub mod edit_issue;
 pub mod finish_issue;
+pub mod remove_issue_label;
+pub mod reopen_issue;
+pub mod start_issue;
 pub mod unblock_issue;
 pub mod update_issue;
+pub mod update_issue_assignee;
 pub mod update_issue_comment;
 pub mod update_issue_description;
+pub mod update_issue_priority;
 pub mod update_issue_title;
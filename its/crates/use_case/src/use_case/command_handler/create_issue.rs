@@ -1,6 +1,6 @@
 use domain::{
     aggregate::{
-        issue::{attribute::IssueDue, IssueDescription, IssueTitle},
+        issue::{attribute::IssueDue, IssueDescription, IssueRecurrence, IssueTitle},
         IssueAggregate,
     },
     IssueNumber,
@@ -22,6 +22,7 @@ pub struct CreateIssue {
     pub issue_title: IssueTitle,
     pub issue_due: Option<IssueDue>,
     pub issue_description: IssueDescription,
+    pub issue_recurrence: Option<IssueRecurrence>,
 }
 
 pub async fn create_issue<C: HasIssueRepository + ?Sized>(
@@ -45,6 +46,10 @@ pub async fn create_issue<C: HasIssueRepository + ?Sized>(
         command.issue_due,
         command.issue_description,
     )?;
+    let created = match command.issue_recurrence {
+        Some(issue_recurrence) => created.update_recurrence(Some(issue_recurrence), at)?,
+        None => created,
+    };
 
     // io
     context.issue_repository().save(&created).await?;
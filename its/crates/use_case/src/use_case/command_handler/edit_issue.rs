@@ -0,0 +1,69 @@
+use domain::{
+    aggregate::issue::{attribute::IssueDue, IssueDescription, IssueTitle},
+    IssueId, Version,
+};
+use limited_date_time::Instant;
+
+use crate::{issue_repository, HasIssueRepository, IssueManagementContextEvent, IssueRepository};
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+#[allow(clippy::enum_variant_names)]
+pub enum Error {
+    #[error("issue aggregate {0}")]
+    IssueAggregate(#[from] domain::aggregate::issue::Error),
+    #[error("issue not found {0}")]
+    IssueNotFound(IssueId),
+    #[error("issue repository {0}")]
+    IssueRepository(#[from] crate::use_case::issue_repository::Error),
+}
+
+// updates the fields in one save
+#[derive(Debug, Eq, PartialEq)]
+pub struct EditIssue {
+    pub issue_id: IssueId,
+    // the expected version. `None` skips the check
+    pub issue_version: Option<Version>,
+    pub issue_title: Option<IssueTitle>,
+    pub issue_description: Option<IssueDescription>,
+    pub issue_due: Option<Option<IssueDue>>,
+}
+
+pub async fn edit_issue<C: HasIssueRepository + ?Sized>(
+    context: &C,
+    command: EditIssue,
+) -> Result<IssueManagementContextEvent, Error> {
+    // io
+    let issue = context
+        .issue_repository()
+        .find_by_id(&command.issue_id)
+        .await?
+        .ok_or_else(|| Error::IssueNotFound(command.issue_id.clone()))?;
+    let at = Instant::now();
+
+    // pure
+    if let Some(issue_version) = command.issue_version {
+        if issue_version != issue.version() {
+            // same as the repository's optimistic lock failure
+            return Err(Error::IssueRepository(issue_repository::Error::Conflict));
+        }
+    }
+    let mut updated = issue;
+    if let Some(issue_title) = command.issue_title {
+        updated = updated.update_title(issue_title, at)?;
+    }
+    if let Some(issue_description) = command.issue_description {
+        updated = updated.update_description(issue_description, at)?;
+    }
+    if let Some(issue_due) = command.issue_due {
+        updated = updated.update(issue_due, at)?;
+    }
+
+    // io
+    if !updated.events().is_empty() {
+        context.issue_repository().save(&updated).await?;
+    }
+
+    Ok(IssueManagementContextEvent::IssueUpdated {
+        issue_id: command.issue_id,
+    })
+}
@@ -1,4 +1,4 @@
-use domain::{aggregate::issue::IssueResolution, IssueId};
+use domain::{aggregate::issue::IssueResolution, IssueId, IssueNumber};
 use limited_date_time::Instant;
 
 use crate::{HasIssueRepository, IssueManagementContextEvent, IssueRepository};
@@ -37,7 +37,28 @@ pub async fn finish_issue<C: HasIssueRepository + ?Sized>(
     let updated = issue.finish(resolution, at)?;
 
     // io
-    context.issue_repository().save(&updated).await?;
+    let updated = if updated.recurrence().is_some() && updated.next_occurrence().is_none() {
+        let next_issue_number = context
+            .issue_repository()
+            .last_created()
+            .await?
+            .map(|issue| issue.id().issue_number().next_number())
+            .unwrap_or_else(IssueNumber::start_number);
+
+        // pure
+        let (updated, next) = updated.recur(next_issue_number, at)?;
+
+        // io
+        // in one transaction: if the number is taken by a concurrent create, neither is saved
+        context
+            .issue_repository()
+            .save_all(&[&updated, &next])
+            .await?;
+        updated
+    } else {
+        context.issue_repository().save(&updated).await?;
+        updated
+    };
 
     let issue_id = updated
         .events()
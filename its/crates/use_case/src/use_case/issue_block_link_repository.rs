@@ -4,6 +4,8 @@ use thiserror::Error;
 
 #[derive(Debug, Eq, PartialEq, Error)]
 pub enum IssueBlockLinkRepositoryError {
+    #[error("Conflict")]
+    Conflict,
     #[error("IO")]
     IO,
     #[error("Unknown: {0}")]
@@ -6,6 +6,8 @@ use thiserror::Error;
 
 #[derive(Debug, Eq, PartialEq, Error)]
 pub enum Error {
+    #[error("conflict")]
+    Conflict,
     #[error("unknown: {0}")]
     Unknown(String),
 }
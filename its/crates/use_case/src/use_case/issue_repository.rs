@@ -6,6 +6,8 @@ use thiserror::Error;
 
 #[derive(Debug, Eq, PartialEq, Error)]
 pub enum Error {
+    #[error("Conflict")]
+    Conflict,
     #[error("IO")]
     IO,
     #[error("Unknown: {0}")]
@@ -27,6 +29,9 @@ pub trait IssueRepository {
     async fn last_created(&self) -> Result<Option<IssueAggregate>>;
 
     async fn save(&self, issue: &IssueAggregate) -> Result<()>;
+
+    // saves the issues in one transaction
+    async fn save_all(&self, issues: &[&IssueAggregate]) -> Result<()>;
 }
 
 pub trait HasIssueRepository {
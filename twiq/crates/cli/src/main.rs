@@ -0,0 +1,98 @@
+use query_handler::tweet::Tweet;
+
+#[derive(Debug, clap::Parser)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    #[arg(long, env = "TWIQ_BASE_URL", default_value = "http://localhost:8080")]
+    base_url: String,
+    #[command(subcommand)]
+    subcommand: Subcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum Subcommand {
+    /// Searches tweets (`GET /tweets/search`)
+    Search {
+        /// Words (Japanese text is matched as a phrase)
+        query: Option<String>,
+        /// `YYYY-MM-DD` or ISO 8601 date and time (inclusive)
+        #[arg(long)]
+        since: Option<String>,
+        /// `YYYY-MM-DD` or ISO 8601 date and time (a date is inclusive)
+        #[arg(long)]
+        until: Option<String>,
+        #[arg(long)]
+        hashtag: Option<String>,
+        /// Retweets only
+        #[arg(long, conflicts_with = "original")]
+        retweet: bool,
+        /// Original tweets only
+        #[arg(long)]
+        original: bool,
+        #[arg(long)]
+        author_id: Option<String>,
+        #[arg(long)]
+        limit: Option<usize>,
+        /// Prints the tweets as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+    match args.subcommand {
+        Subcommand::Search {
+            query,
+            since,
+            until,
+            hashtag,
+            retweet,
+            original,
+            author_id,
+            limit,
+            json,
+        } => {
+            let retweet = match (retweet, original) {
+                (true, _) => Some(true),
+                (_, true) => Some(false),
+                _ => None,
+            };
+            let params = [
+                ("q", query),
+                ("since", since),
+                ("until", until),
+                ("hashtag", hashtag),
+                ("retweet", retweet.map(|b| b.to_string())),
+                ("author_id", author_id),
+                ("limit", limit.map(|n| n.to_string())),
+            ]
+            .into_iter()
+            .filter_map(|(name, value)| value.map(|value| (name, value)))
+            .collect::<Vec<(&str, String)>>();
+            let url = format!("{}/tweets/search", args.base_url.trim_end_matches('/'));
+            let tweets = reqwest::Client::new()
+                .get(url)
+                .query(&params)
+                .send()
+                .await?
+                .error_for_status()?
+                .json::<Vec<Tweet>>()
+                .await?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&tweets)?);
+            } else {
+                for tweet in tweets {
+                    println!(
+                        "{} https://twitter.com/i/web/status/{}",
+                        tweet.at, tweet.tweet_id
+                    );
+                    println!("{}", tweet.text);
+                    println!();
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,7 +1,10 @@
 use async_trait::async_trait;
 use domain::aggregate::user::{At, TwitterUserId, User};
 
-use crate::user_repository::{HasUserRepository, UserRepository};
+use crate::{
+    retry::{retry_on_conflict, ConflictError, MAX_ATTEMPTS},
+    user_repository::{HasUserRepository, UserRepository},
+};
 
 #[derive(Debug, Eq, PartialEq, thiserror::Error)]
 pub enum Error {
@@ -13,6 +16,12 @@ pub enum Error {
     Unknown(String),
 }
 
+impl ConflictError for Error {
+    fn is_conflict(&self) -> bool {
+        matches!(self, Error::UserRepository(e) if e.is_conflict())
+    }
+}
+
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 pub struct Command {
@@ -32,17 +41,20 @@ pub trait Has: Context + Sized {
 
 pub async fn handler<C: Context>(context: &C, command: Command) -> Result<()> {
     let user_repository = context.user_repository();
-    let twitter_user_id = command.twitter_user_id;
-    let found = user_repository
-        .find_by_twitter_user_id(&twitter_user_id)
-        .await?;
-    let user = match found.clone() {
-        None => User::create(twitter_user_id)?,
-        Some(user) => user,
-    };
-    let requested = user.request(At::now())?;
-    user_repository.store(found, requested).await?;
-    Ok(())
+    let twitter_user_id = &command.twitter_user_id;
+    retry_on_conflict(MAX_ATTEMPTS, || async move {
+        let found = user_repository
+            .find_by_twitter_user_id(twitter_user_id)
+            .await?;
+        let user = match found.clone() {
+            None => User::create(twitter_user_id.clone())?,
+            Some(user) => user,
+        };
+        let requested = user.request(At::now())?;
+        user_repository.store(found, requested).await?;
+        Ok(())
+    })
+    .await
 }
 
 #[cfg(test)]
@@ -53,7 +53,7 @@ async fn get_user(bearer_token: &str, user_id: &str) -> Result<(u16, String), re
     response.text().await.map(|body| (status, body))
 }
 
-async fn handle<C: Context>(
+pub async fn handle<C: Context>(
     context: &C,
     event: domain::Event,
 ) -> Result<(), Box<dyn std::error::Error>> {
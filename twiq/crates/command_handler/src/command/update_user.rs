@@ -1,6 +1,8 @@
 use async_trait::async_trait;
+use domain::aggregate::user::TwitterUserName;
 
 use crate::{
+    retry::{retry_on_conflict, ConflictError, MAX_ATTEMPTS},
     user_repository::{HasUserRepository, UserRepository},
     user_request_repository::{HasUserRequestRepository, UserRequestRepository},
 };
@@ -24,6 +26,12 @@ pub enum Error {
     WorkerHelper(#[from] worker_helper::Error),
 }
 
+impl ConflictError for Error {
+    fn is_conflict(&self) -> bool {
+        matches!(self, Error::UserRepository(e) if e.is_conflict())
+    }
+}
+
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 pub struct Command;
@@ -39,28 +47,36 @@ pub trait Has: Context + Sized {
     }
 }
 
-async fn handle<C: Context>(
+pub async fn handle<C: Context>(
     context: &C,
     event: domain::Event,
 ) -> Result<(), Box<dyn std::error::Error>> {
     if let domain::Event::UserRequestFinished(event) = event {
         let user_repository = context.user_repository();
         let user_request_repository = context.user_request_repository();
-        let user_request = user_request_repository
+        if user_request_repository
             .find(event.user_request_id())
-            .await?;
-        let user = user_repository.find(event.user_id()).await?;
-        match (user_request, user) {
-            (None, _) => Err(worker_helper::Error::UserRequestNotFound(
-                event.user_request_id(),
-            )),
-            (_, None) => Err(worker_helper::Error::UserNotFound(event.user_id())),
-            (Some(_), Some(user)) => {
-                let twitter_user_name = event.user_response().parse()?;
+            .await?
+            .is_none()
+        {
+            return Err(worker_helper::Error::UserRequestNotFound(event.user_request_id()).into());
+        }
+        let user_id = event.user_id();
+        let twitter_user_name: TwitterUserName = event.user_response().parse()?;
+        // the user may be updated by another request (e.g. request_user)
+        retry_on_conflict(MAX_ATTEMPTS, || {
+            let twitter_user_name = twitter_user_name.clone();
+            async move {
+                let user = user_repository
+                    .find(user_id)
+                    .await?
+                    .ok_or(worker_helper::Error::UserNotFound(user_id))?;
                 let updated = user.update(twitter_user_name)?;
-                Ok(user_repository.store(Some(user), updated).await?)
+                user_repository.store(Some(user), updated).await?;
+                Ok::<(), Error>(())
             }
-        }?;
+        })
+        .await?;
     }
     Ok(())
 }
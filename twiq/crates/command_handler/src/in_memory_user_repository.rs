@@ -74,7 +74,13 @@ impl UserRepository for InMemoryUserRepository {
                 event_stream,
             )
             .await
-            .map_err(|e| Error::Unknown(e.to_string()))?;
+            .map_err(|e| {
+                if e.is_conflict() {
+                    Error::Conflict(aggregate_id)
+                } else {
+                    Error::Unknown(e.to_string())
+                }
+            })?;
         aggregate_ids.insert(aggregate_id, event_stream_id);
         index.insert(unique_key1, aggregate_id);
         Ok(())
@@ -100,7 +106,13 @@ mod tests {
         repository
             .store(Some(user.clone()), updated.clone())
             .await?;
-        assert_eq!(repository.find(user.id()).await?, Some(updated));
+        assert_eq!(repository.find(user.id()).await?, Some(updated.clone()));
+
+        // store twice
+        assert_eq!(
+            repository.store(Some(user.clone()), updated).await,
+            Err(Error::Conflict(user.id()))
+        );
         Ok(())
     }
 }
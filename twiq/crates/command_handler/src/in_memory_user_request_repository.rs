@@ -53,7 +53,7 @@ impl UserRequestRepository for InMemoryUserRequestRepository {
         // check the uniqueness of the aggregate_id
         match (&before, aggregate_ids.get(&aggregate_id)) {
             (None, None) => Ok(()),
-            (None, Some(_)) => return Err(Error::Unknown("already exists".to_owned())),
+            (None, Some(_)) => return Err(Error::Conflict(aggregate_id)),
             (Some(_), None) => return Err(Error::Unknown("not found".to_owned())),
             (Some(_), Some(_)) => Ok(()),
         }?;
@@ -64,7 +64,13 @@ impl UserRequestRepository for InMemoryUserRequestRepository {
                 event_stream,
             )
             .await
-            .map_err(|e| Error::Unknown(e.to_string()))?;
+            .map_err(|e| {
+                if e.is_conflict() {
+                    Error::Conflict(aggregate_id)
+                } else {
+                    Error::Unknown(e.to_string())
+                }
+            })?;
         aggregate_ids.insert(aggregate_id, event_stream_id);
         Ok(())
     }
@@ -101,16 +107,21 @@ mod tests {
         );
 
         // store twice
-        assert!(repository
-            .store(Some(user_request.clone()), started.clone())
-            .await
-            .is_err());
+        assert_eq!(
+            repository
+                .store(Some(user_request.clone()), started.clone())
+                .await,
+            Err(Error::Conflict(id))
+        );
 
         // duplicate id
         let user_id = UserId::generate();
         let twitter_user_id = TwitterUserId::from_str("125962981")?;
         let user_request2 = UserRequest::create(id, twitter_user_id, user_id)?;
-        assert!(repository.store(None, user_request2.clone()).await.is_err());
+        assert_eq!(
+            repository.store(None, user_request2.clone()).await,
+            Err(Error::Conflict(id))
+        );
 
         Ok(())
     }
@@ -2,5 +2,6 @@ pub mod command;
 pub mod event_store;
 pub mod in_memory_user_repository;
 pub mod in_memory_user_request_repository;
+pub mod retry;
 pub mod user_repository;
 pub mod user_request_repository;
@@ -0,0 +1,94 @@
+use std::future::Future;
+
+use tracing::warn;
+
+pub const MAX_ATTEMPTS: usize = 3;
+
+pub trait ConflictError {
+    fn is_conflict(&self) -> bool;
+}
+
+impl ConflictError for crate::event_store::Error {
+    fn is_conflict(&self) -> bool {
+        self.is_conflict()
+    }
+}
+
+impl ConflictError for crate::user_repository::Error {
+    fn is_conflict(&self) -> bool {
+        self.is_conflict()
+    }
+}
+
+impl ConflictError for crate::user_request_repository::Error {
+    fn is_conflict(&self) -> bool {
+        self.is_conflict()
+    }
+}
+
+/// Calls `f` again while it fails with a conflict (at most `max_attempts` times).
+/// `f` must find the aggregate again, because the stored one has been changed.
+pub async fn retry_on_conflict<T, E, F, Fut>(max_attempts: usize, mut f: F) -> Result<T, E>
+where
+    E: ConflictError + std::fmt::Display,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut attempt = 1;
+    loop {
+        match f().await {
+            Err(e) if e.is_conflict() && attempt < max_attempts => {
+                warn!("conflict (attempt {}/{}) : {}", attempt, max_attempts, e);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use domain::aggregate::user::UserId;
+
+    use crate::user_repository::Error;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let user_id = UserId::generate();
+        let calls = AtomicUsize::new(0);
+        let f = || async {
+            match calls.fetch_add(1, Ordering::SeqCst) {
+                0 => Err(Error::Conflict(user_id)),
+                n => Ok(n),
+            }
+        };
+        assert_eq!(retry_on_conflict(MAX_ATTEMPTS, f).await, Ok(1));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // gives up
+        let calls = AtomicUsize::new(0);
+        let f = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(Error::Conflict(user_id))
+        };
+        assert_eq!(
+            retry_on_conflict(MAX_ATTEMPTS, f).await,
+            Err(Error::Conflict(user_id))
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), MAX_ATTEMPTS);
+
+        // does not retry other errors
+        let calls = AtomicUsize::new(0);
+        let f = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(Error::Unknown("unknown".to_owned()))
+        };
+        assert!(retry_on_conflict(MAX_ATTEMPTS, f).await.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+}
@@ -3,10 +3,19 @@ use domain::aggregate::user::{TwitterUserId, User, UserId};
 
 #[derive(Debug, Eq, PartialEq, thiserror::Error)]
 pub enum Error {
+    /// the aggregate was stored concurrently. find it again and retry
+    #[error("conflict {0}")]
+    Conflict(UserId),
     #[error("unknown {0}")]
     Unknown(String),
 }
 
+impl Error {
+    pub fn is_conflict(&self) -> bool {
+        matches!(self, Error::Conflict(_))
+    }
+}
+
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 #[async_trait]
@@ -0,0 +1,55 @@
+CREATE TABLE IF NOT EXISTS event_streams (
+  id TEXT NOT NULL PRIMARY KEY,
+  seq INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS events (
+  seq INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+  id TEXT NOT NULL UNIQUE,
+  type TEXT NOT NULL,
+  stream_id TEXT NOT NULL,
+  stream_seq INTEGER NOT NULL,
+  at TEXT NOT NULL,
+  payload TEXT NOT NULL,
+  UNIQUE (stream_id, stream_seq),
+  FOREIGN KEY (stream_id) REFERENCES event_streams (id)
+);
+
+CREATE TABLE IF NOT EXISTS user_ids (
+  user_id TEXT NOT NULL PRIMARY KEY,
+  event_stream_id TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS twitter_user_ids (
+  twitter_user_id TEXT NOT NULL PRIMARY KEY,
+  user_id TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS user_request_ids (
+  user_request_id TEXT NOT NULL PRIMARY KEY,
+  event_stream_id TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS workers (
+  name TEXT NOT NULL PRIMARY KEY,
+  event_id TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS dead_letters (
+  worker_name TEXT NOT NULL,
+  event_id TEXT NOT NULL,
+  attempts INTEGER NOT NULL,
+  error TEXT NOT NULL,
+  PRIMARY KEY (worker_name, event_id)
+);
+
+CREATE TABLE IF NOT EXISTS query_users (
+  user_id TEXT NOT NULL PRIMARY KEY,
+  twitter_user_id TEXT NOT NULL,
+  twitter_user_name TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS query_twitter_user_ids (
+  twitter_user_id TEXT NOT NULL PRIMARY KEY,
+  user_id TEXT NOT NULL
+);
@@ -1,5 +1,36 @@
 use std::env;
 
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("unknown DATABASE_URL scheme: {0}")]
+    UnknownDatabaseUrlScheme(String),
+}
+
+/// `DATABASE_URL` (e.g. `sqlite:twiq.sqlite`, `sqlite::memory:`) selects SQLite instead of Firestore
+#[derive(Clone, Debug)]
+pub enum Backend {
+    Firestore(Config),
+    Sqlite(String),
+}
+
+impl Backend {
+    pub fn load_from_env() -> Result<Self, Error> {
+        Ok(match Self::sqlite_url(env::var("DATABASE_URL").ok())? {
+            Some(url) => Self::Sqlite(url),
+            None => Self::Firestore(Config::load_from_env()),
+        })
+    }
+
+    // `None` (Firestore) if `DATABASE_URL` is not set
+    fn sqlite_url(database_url: Option<String>) -> Result<Option<String>, Error> {
+        match database_url {
+            None => Ok(None),
+            Some(url) if url.starts_with("sqlite:") => Ok(Some(url)),
+            Some(url) => Err(Error::UnknownDatabaseUrlScheme(url)),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Config {
     database_id: String,
@@ -29,7 +60,24 @@ impl Config {
 mod tests {
     use std::env;
 
-    use crate::config::Config;
+    use crate::config::{Backend, Config, Error};
+
+    #[test]
+    fn backend_test() {
+        assert!(matches!(Backend::sqlite_url(None), Ok(None)));
+        assert!(matches!(
+            Backend::sqlite_url(Some("sqlite::memory:".to_owned())),
+            Ok(Some(url)) if url == "sqlite::memory:"
+        ));
+        assert!(matches!(
+            Backend::sqlite_url(Some("sqlite:twiq.sqlite".to_owned())),
+            Ok(Some(url)) if url == "sqlite:twiq.sqlite"
+        ));
+        assert!(matches!(
+            Backend::sqlite_url(Some("postgres://localhost/twiq".to_owned())),
+            Err(Error::UnknownDatabaseUrlScheme(url)) if url == "postgres://localhost/twiq"
+        ));
+    }
 
     #[test]
     fn test() {
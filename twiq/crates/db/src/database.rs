@@ -0,0 +1,302 @@
+use async_trait::async_trait;
+use command_handler::{
+    user_repository::{self, UserRepository},
+    user_request_repository::{self, UserRequestRepository},
+};
+use domain::aggregate::{
+    user::{TwitterUserId, User, UserId, UserRequestId},
+    user_request::UserRequest,
+};
+use event_store_core::{Event, EventId};
+use query_handler::{user, user_store};
+use worker_helper::worker_repository::{self, DeadLetter, WorkerName, WorkerRepository};
+
+use crate::{
+    config::{Backend, Config},
+    firestore_user_repository::FirestoreUserRepository,
+    firestore_user_request_repository::FirestoreUserRequestRepository,
+    firestore_user_store::FirestoreUserStore,
+    firestore_worker_repository::FirestoreWorkerRepository,
+    sqlite_database::{self, SqliteDatabase},
+    sqlite_user_repository::SqliteUserRepository,
+    sqlite_user_request_repository::SqliteUserRequestRepository,
+    sqlite_user_store::SqliteUserStore,
+    sqlite_worker_repository::SqliteWorkerRepository,
+};
+
+#[derive(Clone, Debug)]
+pub enum Database {
+    Firestore(Config),
+    Sqlite(SqliteDatabase),
+}
+
+impl Database {
+    pub async fn connect(backend: Backend) -> Result<Self, sqlite_database::Error> {
+        Ok(match backend {
+            Backend::Firestore(config) => Self::Firestore(config),
+            Backend::Sqlite(url) => Self::Sqlite(SqliteDatabase::connect(&url).await?),
+        })
+    }
+
+    pub fn user_repository(&self) -> AnyUserRepository {
+        match self {
+            Self::Firestore(config) => {
+                AnyUserRepository::Firestore(FirestoreUserRepository::new(config.clone()))
+            }
+            Self::Sqlite(database) => {
+                AnyUserRepository::Sqlite(SqliteUserRepository::new(database.clone()))
+            }
+        }
+    }
+
+    pub fn user_request_repository(&self) -> AnyUserRequestRepository {
+        match self {
+            Self::Firestore(config) => AnyUserRequestRepository::Firestore(
+                FirestoreUserRequestRepository::new(config.clone()),
+            ),
+            Self::Sqlite(database) => {
+                AnyUserRequestRepository::Sqlite(SqliteUserRequestRepository::new(database.clone()))
+            }
+        }
+    }
+
+    pub fn user_store(&self) -> AnyUserStore {
+        match self {
+            Self::Firestore(config) => {
+                AnyUserStore::Firestore(FirestoreUserStore::new(config.clone()))
+            }
+            Self::Sqlite(database) => AnyUserStore::Sqlite(SqliteUserStore::new(database.clone())),
+        }
+    }
+
+    pub fn worker_repository(&self) -> AnyWorkerRepository {
+        match self {
+            Self::Firestore(config) => {
+                AnyWorkerRepository::Firestore(FirestoreWorkerRepository::new(config.clone()))
+            }
+            Self::Sqlite(database) => {
+                AnyWorkerRepository::Sqlite(SqliteWorkerRepository::new(database.clone()))
+            }
+        }
+    }
+}
+
+pub enum AnyUserRepository {
+    Firestore(FirestoreUserRepository),
+    Sqlite(SqliteUserRepository),
+}
+
+#[async_trait]
+impl UserRepository for AnyUserRepository {
+    async fn find(&self, id: UserId) -> user_repository::Result<Option<User>> {
+        match self {
+            Self::Firestore(r) => r.find(id).await,
+            Self::Sqlite(r) => r.find(id).await,
+        }
+    }
+
+    async fn find_by_twitter_user_id(
+        &self,
+        twitter_user_id: &TwitterUserId,
+    ) -> user_repository::Result<Option<User>> {
+        match self {
+            Self::Firestore(r) => r.find_by_twitter_user_id(twitter_user_id).await,
+            Self::Sqlite(r) => r.find_by_twitter_user_id(twitter_user_id).await,
+        }
+    }
+
+    async fn store(&self, before: Option<User>, after: User) -> user_repository::Result<()> {
+        match self {
+            Self::Firestore(r) => r.store(before, after).await,
+            Self::Sqlite(r) => r.store(before, after).await,
+        }
+    }
+}
+
+pub enum AnyUserRequestRepository {
+    Firestore(FirestoreUserRequestRepository),
+    Sqlite(SqliteUserRequestRepository),
+}
+
+#[async_trait]
+impl UserRequestRepository for AnyUserRequestRepository {
+    async fn find(
+        &self,
+        id: UserRequestId,
+    ) -> user_request_repository::Result<Option<UserRequest>> {
+        match self {
+            Self::Firestore(r) => r.find(id).await,
+            Self::Sqlite(r) => r.find(id).await,
+        }
+    }
+
+    async fn store(
+        &self,
+        before: Option<UserRequest>,
+        after: UserRequest,
+    ) -> user_request_repository::Result<()> {
+        match self {
+            Self::Firestore(r) => r.store(before, after).await,
+            Self::Sqlite(r) => r.store(before, after).await,
+        }
+    }
+}
+
+pub enum AnyUserStore {
+    Firestore(FirestoreUserStore),
+    Sqlite(SqliteUserStore),
+}
+
+#[async_trait]
+impl user_store::UserStore for AnyUserStore {
+    async fn find_by_twitter_user_id(
+        &self,
+        twitter_user_id: &str,
+    ) -> user_store::Result<Option<user::User>> {
+        match self {
+            Self::Firestore(s) => s.find_by_twitter_user_id(twitter_user_id).await,
+            Self::Sqlite(s) => s.find_by_twitter_user_id(twitter_user_id).await,
+        }
+    }
+
+    async fn store(&self, before: Option<user::User>, after: user::User) -> user_store::Result<()> {
+        match self {
+            Self::Firestore(s) => s.store(before, after).await,
+            Self::Sqlite(s) => s.store(before, after).await,
+        }
+    }
+}
+
+pub enum AnyWorkerRepository {
+    Firestore(FirestoreWorkerRepository),
+    Sqlite(SqliteWorkerRepository),
+}
+
+#[async_trait]
+impl WorkerRepository for AnyWorkerRepository {
+    async fn find_last_event_id(
+        &self,
+        worker_name: WorkerName,
+    ) -> worker_repository::Result<Option<EventId>> {
+        match self {
+            Self::Firestore(r) => r.find_last_event_id(worker_name).await,
+            Self::Sqlite(r) => r.find_last_event_id(worker_name).await,
+        }
+    }
+
+    async fn store_last_event_id(
+        &self,
+        worker_name: WorkerName,
+        before: Option<EventId>,
+        after: EventId,
+    ) -> worker_repository::Result<()> {
+        match self {
+            Self::Firestore(r) => r.store_last_event_id(worker_name, before, after).await,
+            Self::Sqlite(r) => r.store_last_event_id(worker_name, before, after).await,
+        }
+    }
+
+    async fn store_dead_letter(&self, dead_letter: DeadLetter) -> worker_repository::Result<()> {
+        match self {
+            Self::Firestore(r) => r.store_dead_letter(dead_letter).await,
+            Self::Sqlite(r) => r.store_dead_letter(dead_letter).await,
+        }
+    }
+
+    async fn find_event_ids(
+        &self,
+        event_id: Option<EventId>,
+    ) -> worker_repository::Result<Vec<EventId>> {
+        match self {
+            Self::Firestore(r) => r.find_event_ids(event_id).await,
+            Self::Sqlite(r) => r.find_event_ids(event_id).await,
+        }
+    }
+
+    async fn find_event(&self, event_id: EventId) -> worker_repository::Result<Option<Event>> {
+        match self {
+            Self::Firestore(r) => r.find_event(event_id).await,
+            Self::Sqlite(r) => r.find_event(event_id).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use command_handler::{
+        command::{create_user_request, request_user},
+        user_repository::HasUserRepository,
+        user_request_repository::HasUserRequestRepository,
+    };
+    use worker_helper::worker_repository::HasWorkerRepository;
+
+    use super::*;
+
+    struct App {
+        user_repository: AnyUserRepository,
+        user_request_repository: AnyUserRequestRepository,
+        worker_repository: AnyWorkerRepository,
+    }
+
+    impl HasUserRepository for App {
+        type UserRepository = AnyUserRepository;
+
+        fn user_repository(&self) -> &Self::UserRepository {
+            &self.user_repository
+        }
+    }
+
+    impl HasUserRequestRepository for App {
+        type UserRequestRepository = AnyUserRequestRepository;
+
+        fn user_request_repository(&self) -> &Self::UserRequestRepository {
+            &self.user_request_repository
+        }
+    }
+
+    impl HasWorkerRepository for App {
+        type WorkerRepository = AnyWorkerRepository;
+
+        fn worker_repository(&self) -> &Self::WorkerRepository {
+            &self.worker_repository
+        }
+    }
+
+    impl create_user_request::Has for App {}
+    impl request_user::Has for App {}
+
+    #[tokio::test]
+    async fn sqlite_test() -> anyhow::Result<()> {
+        let database = Database::connect(Backend::Sqlite("sqlite::memory:".to_owned())).await?;
+        let app = App {
+            user_repository: database.user_repository(),
+            user_request_repository: database.user_request_repository(),
+            worker_repository: database.worker_repository(),
+        };
+        let twitter_user_id = TwitterUserId::from_str("125962981")?;
+        request_user::handler(
+            &app,
+            request_user::Command {
+                twitter_user_id: twitter_user_id.clone(),
+            },
+        )
+        .await?;
+        let user = app
+            .user_repository
+            .find_by_twitter_user_id(&twitter_user_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("user not found"))?;
+        assert_eq!(user.twitter_user_id(), &twitter_user_id);
+
+        // the worker reads the events stored by the user repository
+        create_user_request::handler(&app, create_user_request::Command).await?;
+        assert!(app
+            .worker_repository
+            .find_last_event_id(WorkerName::CreateUserRequest)
+            .await?
+            .is_some());
+        Ok(())
+    }
+}
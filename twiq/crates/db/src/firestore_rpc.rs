@@ -22,8 +22,8 @@ pub mod helper {
     use google_cloud_auth::{Credential, CredentialConfig};
     use prost_types::Timestamp;
     use tonic::{
-        codegen::InterceptedService, metadata::AsciiMetadataValue, transport::Channel, Request,
-        Status,
+        codegen::InterceptedService, metadata::AsciiMetadataValue, transport::Channel, Code,
+        Request, Status,
     };
 
     pub mod path {
@@ -86,6 +86,20 @@ pub mod helper {
         TonicTransport(#[from] tonic::transport::Error),
     }
 
+    impl Error {
+        /// a precondition failed or the transaction was aborted by another transaction
+        pub fn is_conflict(&self) -> bool {
+            matches!(
+                self,
+                Error::TonicStatus(status)
+                    if matches!(
+                        status.code(),
+                        Code::Aborted | Code::AlreadyExists | Code::FailedPrecondition
+                    )
+            )
+        }
+    }
+
     pub type Result<T, E = Error> = std::result::Result<T, E>;
 
     pub async fn client(
@@ -38,6 +38,8 @@ use crate::{
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+    #[error("conflict {0}")]
+    Conflict(EventStreamId),
     #[error("event_store_core::event_at {0}")]
     EventAt(#[from] event_store_core::event_at::Error),
     #[error("event_store_core::event_id {0}")]
@@ -58,6 +60,8 @@ pub enum Error {
     FirestoreRpcHelperGetField(#[from] crate::firestore_rpc::helper::GetFieldError),
     #[error("google_cloud_auth {0}")]
     GoogleCloudAuth(#[from] google_cloud_auth::Error),
+    #[error("not found {0}")]
+    NotFound(EventStreamId),
     #[error("prost_types::TimestampError {0}")]
     ProstTypesTimestamp(#[from] prost_types::TimestampError),
     #[error("time::error::Format {0}")]
@@ -70,7 +74,21 @@ pub enum Error {
 
 impl From<Error> for event_store::Error {
     fn from(e: Error) -> Self {
-        event_store::Error::Unknown(e.to_string())
+        match e {
+            Error::Conflict(event_stream_id) => event_store::Error::Conflict(event_stream_id),
+            Error::NotFound(event_stream_id) => event_store::Error::NotFound(event_stream_id),
+            Error::EventAt(_)
+            | Error::EventId(_)
+            | Error::EventPayload(_)
+            | Error::EventStream(_)
+            | Error::EventStreamId(_)
+            | Error::EventStreamSeq(_)
+            | Error::EventType(_)
+            | Error::FirestoreRpcHelperGetField(_) => {
+                event_store::Error::Serialization(e.to_string())
+            }
+            _ => event_store::Error::Unknown(e.to_string()),
+        }
     }
 }
 
@@ -270,26 +288,28 @@ impl FirestoreRpcEventStore {
             create_time: None,
             update_time: None,
         };
-        let precondition = match current {
-            Some(expected_event_stream_seq) => {
-                // get event_stream
-                let document = self
-                    .transaction
-                    .get_document("event_streams", &event_stream.id().to_string())
-                    .await?
-                    .ok_or_else(|| Error::Unknown("not found".to_owned()))?;
+        // get event_stream
+        let document = self
+            .transaction
+            .get_document("event_streams", &event_stream.id().to_string())
+            .await?;
+        // the preconditions are checked again on commit
+        let precondition = match (current, document) {
+            (Some(_), None) => return Err(Error::NotFound(event_stream.id())),
+            (None, Some(_)) => return Err(Error::Conflict(event_stream.id())),
+            (Some(expected_event_stream_seq), Some(document)) => {
                 let field = get_field_as_i64(&document, "seq")?;
                 let event_stream_seq = EventStreamSeq::try_from(field)?;
                 let update_time = document.update_time.expect("output contains update_time");
 
                 if event_stream_seq != expected_event_stream_seq {
-                    return Err(Error::Unknown("conflict".to_owned()));
+                    return Err(Error::Conflict(event_stream.id()));
                 }
                 Precondition {
                     condition_type: Some(ConditionType::UpdateTime(update_time)),
                 }
             }
-            None => Precondition {
+            (None, None) => Precondition {
                 condition_type: Some(ConditionType::Exists(false)),
             },
         };
@@ -23,6 +23,8 @@ pub struct FirestoreUserRepository {
 
 #[derive(Debug, thiserror::Error)]
 enum Error {
+    #[error("conflict {0}")]
+    Conflict(UserId),
     #[error("event_store_core::event_store {0}")]
     EventStore(#[from] event_store_core::event_store::Error),
     #[error("event_store_core::event_stream_id {0}")]
@@ -39,7 +41,10 @@ enum Error {
 
 impl From<Error> for user_repository::Error {
     fn from(e: Error) -> Self {
-        user_repository::Error::Unknown(e.to_string())
+        match e {
+            Error::Conflict(id) => user_repository::Error::Conflict(id),
+            _ => user_repository::Error::Unknown(e.to_string()),
+        }
     }
 }
 
@@ -221,9 +226,22 @@ impl FirestoreUserRepository {
                 before.map(|aggregate| EventStream::from(aggregate).seq()),
                 event_stream,
             )
-            .await?;
+            .await
+            .map_err(|e| {
+                if e.is_conflict() {
+                    Error::Conflict(user_id)
+                } else {
+                    Error::from(e)
+                }
+            })?;
 
-        transaction.commit().await?;
+        transaction.commit().await.map_err(|e| {
+            if e.is_conflict() {
+                Error::Conflict(user_id)
+            } else {
+                Error::from(e)
+            }
+        })?;
         Ok(())
     }
 }
@@ -18,6 +18,8 @@ use event_store_core::{event_store::EventStore, EventStream, EventStreamId};
 
 #[derive(Debug, thiserror::Error)]
 enum Error {
+    #[error("conflict {0}")]
+    Conflict(UserRequestId),
     #[error("event_store_core::event_store {0}")]
     EventStore(#[from] event_store_core::event_store::Error),
     #[error("event_store_core::event_stream_id {0}")]
@@ -36,7 +38,10 @@ enum Error {
 
 impl From<Error> for user_request_repository::Error {
     fn from(e: Error) -> Self {
-        user_request_repository::Error::Unknown(e.to_string())
+        match e {
+            Error::Conflict(id) => user_request_repository::Error::Conflict(id),
+            _ => user_request_repository::Error::Unknown(e.to_string()),
+        }
     }
 }
 
@@ -136,9 +141,22 @@ impl FirestoreUserRequestRepository {
                 before.map(|aggregate| EventStream::from(aggregate).seq()),
                 event_stream,
             )
-            .await?;
+            .await
+            .map_err(|e| {
+                if e.is_conflict() {
+                    Error::Conflict(user_request_id)
+                } else {
+                    Error::from(e)
+                }
+            })?;
 
-        transaction.commit().await?;
+        transaction.commit().await.map_err(|e| {
+            if e.is_conflict() {
+                Error::Conflict(user_request_id)
+            } else {
+                Error::from(e)
+            }
+        })?;
         Ok(())
     }
 }
@@ -2,7 +2,7 @@ use std::{collections::HashMap, str::FromStr};
 
 use async_trait::async_trait;
 use event_store_core::{event_store::EventStore, Event, EventId};
-use worker_helper::worker_repository::{self, WorkerName, WorkerRepository};
+use worker_helper::worker_repository::{self, DeadLetter, WorkerName, WorkerRepository};
 
 use crate::{
     config::Config,
@@ -10,7 +10,7 @@ use crate::{
         google::firestore::v1::{
             precondition::ConditionType, write::Operation, Document, Precondition, Write,
         },
-        helper::{get_field_as_str, value_from_string},
+        helper::{get_field_as_str, value_from_i64, value_from_string},
     },
     firestore_rpc_event_store::FirestoreRpcEventStore,
     firestore_transaction::FirestoreTransaction,
@@ -43,6 +43,7 @@ pub struct FirestoreWorkerRepository {
 }
 
 impl FirestoreWorkerRepository {
+    const DEAD_LETTERS: &'static str = "dead_letters";
     const WORKERS: &'static str = "workers";
 
     pub fn new(config: Config) -> Self {
@@ -123,6 +124,42 @@ impl FirestoreWorkerRepository {
             .await?;
         Ok(transaction.commit().await?)
     }
+
+    async fn store_dead_letter(&self, dead_letter: DeadLetter) -> Result<()> {
+        let transaction = self.begin_transaction().await?;
+        let document_id = format!("{}_{}", dead_letter.worker_name, dead_letter.event_id);
+        let document = Document {
+            name: transaction.document_path(Self::DEAD_LETTERS, &document_id),
+            fields: {
+                let mut fields = HashMap::new();
+                fields.insert(
+                    "worker_name".to_owned(),
+                    value_from_string(dead_letter.worker_name.to_string()),
+                );
+                fields.insert(
+                    "event_id".to_owned(),
+                    value_from_string(dead_letter.event_id.to_string()),
+                );
+                fields.insert(
+                    "attempts".to_owned(),
+                    value_from_i64(i64::from(dead_letter.attempts)),
+                );
+                fields.insert("error".to_owned(), value_from_string(dead_letter.error));
+                fields
+            },
+            create_time: None,
+            update_time: None,
+        };
+        transaction
+            .push_write(Write {
+                update_mask: None,
+                update_transforms: vec![],
+                current_document: None,
+                operation: Some(Operation::Update(document)),
+            })
+            .await?;
+        Ok(transaction.commit().await?)
+    }
 }
 
 #[async_trait]
@@ -153,4 +190,8 @@ impl WorkerRepository for FirestoreWorkerRepository {
     ) -> worker_repository::Result<()> {
         Ok(self.store_last_event_id(worker_name, before, after).await?)
     }
+
+    async fn store_dead_letter(&self, dead_letter: DeadLetter) -> worker_repository::Result<()> {
+        Ok(self.store_dead_letter(dead_letter).await?)
+    }
 }
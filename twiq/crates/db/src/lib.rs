@@ -1,4 +1,5 @@
 pub mod config;
+pub mod database;
 pub mod firestore_rpc;
 pub mod firestore_rpc_event_store;
 pub mod firestore_transaction;
@@ -6,6 +7,12 @@ pub mod firestore_user_repository;
 pub mod firestore_user_request_repository;
 pub mod firestore_user_store;
 pub mod firestore_worker_repository;
+pub mod sqlite_database;
+pub mod sqlite_event_store;
+pub mod sqlite_user_repository;
+pub mod sqlite_user_request_repository;
+pub mod sqlite_user_store;
+pub mod sqlite_worker_repository;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,61 @@
+use std::str::FromStr;
+
+use sqlx::{
+    sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+    Executor, SqlitePool,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("sqlx {0}")]
+    Sqlx(#[from] sqlx::Error),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Clone, Debug)]
+pub struct SqliteDatabase {
+    pool: SqlitePool,
+}
+
+impl SqliteDatabase {
+    /// `url` is `sqlite:twiq.sqlite` or `sqlite::memory:`. Creates the tables if they do not exist.
+    pub async fn connect(url: &str) -> Result<Self> {
+        let options = SqliteConnectOptions::from_str(url)?.create_if_missing(true);
+        // a single connection that is never closed.
+        // `sqlite::memory:` is a new database per connection, and the writes never hit `SQLITE_BUSY`.
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect_with(options)
+            .await?;
+        pool.execute(include_str!("../sql/sqlite/create_tables.sql"))
+            .await?;
+        Ok(Self { pool })
+    }
+
+    pub(crate) fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let database = SqliteDatabase::connect("sqlite::memory:").await?;
+        sqlx::query("INSERT INTO workers (name, event_id) VALUES (?, ?)")
+            .bind("update_user")
+            .bind("event_id1")
+            .execute(database.pool())
+            .await?;
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM workers")
+            .fetch_one(database.clone().pool())
+            .await?;
+        assert_eq!(count, 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,331 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use event_store_core::{
+    event_store::{self, EventStore},
+    Event, EventAt, EventId, EventPayload, EventStream, EventStreamId, EventStreamSeq, EventType,
+};
+use sqlx::SqliteConnection;
+
+use crate::sqlite_database::SqliteDatabase;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("conflict {0}")]
+    Conflict(EventStreamId),
+    #[error("event_store_core::event_at {0}")]
+    EventAt(#[from] event_store_core::event_at::Error),
+    #[error("event_store_core::event_id {0}")]
+    EventId(#[from] event_store_core::event_id::Error),
+    #[error("event_store_core::event_payload {0}")]
+    EventPayload(#[from] event_store_core::event_payload::Error),
+    #[error("event_store_core::event_stream {0}")]
+    EventStream(#[from] event_store_core::event_stream::Error),
+    #[error("event_store_core::event_stream_id {0}")]
+    EventStreamId(#[from] event_store_core::event_stream_id::Error),
+    #[error("event_store_core::event_stream_seq {0}")]
+    EventStreamSeq(#[from] event_store_core::event_stream_seq::Error),
+    #[error("event_store_core::event_type {0}")]
+    EventType(#[from] event_store_core::event_type::Error),
+    #[error("not found {0}")]
+    NotFound(EventStreamId),
+    #[error("sqlx {0}")]
+    Sqlx(#[from] sqlx::Error),
+}
+
+impl From<Error> for event_store::Error {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::Conflict(event_stream_id) => event_store::Error::Conflict(event_stream_id),
+            Error::NotFound(event_stream_id) => event_store::Error::NotFound(event_stream_id),
+            Error::EventAt(_)
+            | Error::EventId(_)
+            | Error::EventPayload(_)
+            | Error::EventStream(_)
+            | Error::EventStreamId(_)
+            | Error::EventStreamSeq(_)
+            | Error::EventType(_) => event_store::Error::Serialization(e.to_string()),
+            Error::Sqlx(_) => event_store::Error::Unknown(e.to_string()),
+        }
+    }
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Clone, Debug)]
+pub struct SqliteEventStore {
+    database: SqliteDatabase,
+}
+
+impl SqliteEventStore {
+    pub fn new(database: SqliteDatabase) -> Self {
+        Self { database }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct EventRow {
+    id: String,
+    r#type: String,
+    stream_id: String,
+    stream_seq: i64,
+    at: String,
+    payload: String,
+}
+
+impl TryFrom<EventRow> for Event {
+    type Error = Error;
+
+    fn try_from(row: EventRow) -> Result<Self, Self::Error> {
+        Ok(Event::new(
+            EventId::from_str(&row.id)?,
+            EventType::from_str(&row.r#type)?,
+            EventStreamId::from_str(&row.stream_id)?,
+            EventStreamSeq::try_from(row.stream_seq)?,
+            EventAt::from_str(&row.at)?,
+            EventPayload::from_str(&row.payload)?,
+        ))
+    }
+}
+
+// the following functions are also used by the repositories in their transactions
+
+pub(crate) async fn find_event(
+    connection: &mut SqliteConnection,
+    event_id: EventId,
+) -> Result<Option<Event>> {
+    let row: Option<EventRow> = sqlx::query_as(
+        "SELECT id, type, stream_id, stream_seq, at, payload FROM events WHERE id = ?",
+    )
+    .bind(event_id.to_string())
+    .fetch_optional(connection)
+    .await?;
+    row.map(Event::try_from).transpose()
+}
+
+pub(crate) async fn find_event_stream(
+    connection: &mut SqliteConnection,
+    event_stream_id: EventStreamId,
+) -> Result<Option<EventStream>> {
+    let rows: Vec<EventRow> = sqlx::query_as(
+        "SELECT id, type, stream_id, stream_seq, at, payload FROM events WHERE stream_id = ? ORDER BY stream_seq",
+    )
+    .bind(event_stream_id.to_string())
+    .fetch_all(connection)
+    .await?;
+    let events = rows
+        .into_iter()
+        .map(Event::try_from)
+        .collect::<Result<Vec<Event>>>()?;
+    Ok(if events.is_empty() {
+        None
+    } else {
+        Some(EventStream::new(events)?)
+    })
+}
+
+// includes the `after` event (same as the other implementations)
+pub(crate) async fn find_events(
+    connection: &mut SqliteConnection,
+    after: Option<EventId>,
+) -> Result<Vec<Event>> {
+    let rows: Vec<EventRow> = sqlx::query_as(
+        "SELECT id, type, stream_id, stream_seq, at, payload FROM events WHERE seq >= COALESCE((SELECT seq FROM events WHERE id = ?), 0) ORDER BY seq",
+    )
+    .bind(after.map(|event_id| event_id.to_string()))
+    .fetch_all(connection)
+    .await?;
+    rows.into_iter().map(Event::try_from).collect()
+}
+
+pub(crate) async fn store(
+    connection: &mut SqliteConnection,
+    current: Option<EventStreamSeq>,
+    event_stream: EventStream,
+) -> Result<()> {
+    let result = match current {
+        Some(expected_event_stream_seq) => {
+            sqlx::query("UPDATE event_streams SET seq = ? WHERE id = ? AND seq = ?")
+                .bind(i64::from(event_stream.seq()))
+                .bind(event_stream.id().to_string())
+                .bind(i64::from(expected_event_stream_seq))
+                .execute(&mut *connection)
+                .await?
+        }
+        None => {
+            sqlx::query(
+                "INSERT INTO event_streams (id, seq) VALUES (?, ?) ON CONFLICT (id) DO NOTHING",
+            )
+            .bind(event_stream.id().to_string())
+            .bind(i64::from(event_stream.seq()))
+            .execute(&mut *connection)
+            .await?
+        }
+    };
+    if result.rows_affected() != 1 {
+        let exists = sqlx::query_scalar::<_, i64>("SELECT 1 FROM event_streams WHERE id = ?")
+            .bind(event_stream.id().to_string())
+            .fetch_optional(&mut *connection)
+            .await?
+            .is_some();
+        return Err(if current.is_some() && !exists {
+            Error::NotFound(event_stream.id())
+        } else {
+            Error::Conflict(event_stream.id())
+        });
+    }
+
+    for event in event_stream.events() {
+        if let Some(c) = current {
+            if event.stream_seq() <= c {
+                continue;
+            }
+        }
+        sqlx::query(
+            "INSERT INTO events (id, type, stream_id, stream_seq, at, payload) VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(event.id().to_string())
+        .bind(event.r#type().to_string())
+        .bind(event.stream_id().to_string())
+        .bind(i64::from(event.stream_seq()))
+        .bind(event.at().to_string())
+        .bind(event.payload().to_string())
+        .execute(&mut *connection)
+        .await?;
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl EventStore for SqliteEventStore {
+    async fn find_event(&self, event_id: EventId) -> event_store::Result<Option<Event>> {
+        let mut connection = self.database.pool().acquire().await.map_err(Error::from)?;
+        Ok(find_event(&mut connection, event_id).await?)
+    }
+
+    async fn find_event_ids(&self, after: Option<EventId>) -> event_store::Result<Vec<EventId>> {
+        Ok(self
+            .find_events(after)
+            .await?
+            .into_iter()
+            .map(|event| event.id())
+            .collect::<Vec<EventId>>())
+    }
+
+    async fn find_event_stream(
+        &self,
+        event_stream_id: EventStreamId,
+    ) -> event_store::Result<Option<EventStream>> {
+        let mut connection = self.database.pool().acquire().await.map_err(Error::from)?;
+        Ok(find_event_stream(&mut connection, event_stream_id).await?)
+    }
+
+    async fn find_events(&self, after: Option<EventId>) -> event_store::Result<Vec<Event>> {
+        let mut connection = self.database.pool().acquire().await.map_err(Error::from)?;
+        Ok(find_events(&mut connection, after).await?)
+    }
+
+    async fn store(
+        &self,
+        current: Option<EventStreamSeq>,
+        event_stream: EventStream,
+    ) -> event_store::Result<()> {
+        let mut transaction = self.database.pool().begin().await.map_err(Error::from)?;
+        store(&mut transaction, current, event_stream).await?;
+        transaction.commit().await.map_err(Error::from)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let database = SqliteDatabase::connect("sqlite::memory:").await?;
+        let event_store = SqliteEventStore::new(database);
+
+        let stream_id = EventStreamId::generate();
+        let stream_seq = EventStreamSeq::from(1_u32);
+        let event1 = Event::new(
+            EventId::generate(),
+            EventType::from_str("created")?,
+            stream_id,
+            stream_seq,
+            EventAt::now(),
+            EventPayload::try_from("{}".to_owned())?,
+        );
+        let mut event_stream = EventStream::new(vec![event1.clone()])?;
+        assert_eq!(
+            event_store
+                .store(Some(stream_seq), event_stream.clone())
+                .await,
+            Err(event_store::Error::NotFound(stream_id))
+        );
+        event_store.store(None, event_stream.clone()).await?;
+        assert_eq!(
+            event_store.store(None, event_stream.clone()).await,
+            Err(event_store::Error::Conflict(stream_id))
+        );
+
+        let event2 = Event::new(
+            EventId::generate(),
+            EventType::from_str("updated")?,
+            stream_id,
+            stream_seq.next()?,
+            EventAt::now(),
+            EventPayload::try_from(r#"{"foo":"bar"}"#.to_owned())?,
+        );
+        event_stream.push_event(event2.clone())?;
+        event_store
+            .store(Some(stream_seq), event_stream.clone())
+            .await?;
+        assert_eq!(
+            event_store
+                .store(Some(stream_seq), event_stream.clone())
+                .await,
+            Err(event_store::Error::Conflict(stream_id))
+        );
+        assert_eq!(
+            event_store.find_event_stream(stream_id).await?,
+            Some(event_stream)
+        );
+        assert_eq!(
+            event_store
+                .find_event_stream(EventStreamId::generate())
+                .await?,
+            None
+        );
+        assert_eq!(
+            event_store.find_event(event2.id()).await?,
+            Some(event2.clone())
+        );
+        assert_eq!(event_store.find_event(EventId::generate()).await?, None);
+
+        let event3 = Event::new(
+            EventId::generate(),
+            EventType::from_str("created")?,
+            EventStreamId::generate(),
+            EventStreamSeq::from(1_u32),
+            EventAt::now(),
+            EventPayload::try_from("{}".to_owned())?,
+        );
+        event_store
+            .store(None, EventStream::new(vec![event3.clone()])?)
+            .await?;
+        assert_eq!(
+            event_store.find_events(None).await?,
+            vec![event1.clone(), event2.clone(), event3.clone()]
+        );
+        assert_eq!(
+            event_store.find_events(Some(event2.id())).await?,
+            vec![event2.clone(), event3.clone()]
+        );
+        assert_eq!(
+            event_store.find_event_ids(Some(event3.id())).await?,
+            vec![event3.id()]
+        );
+        Ok(())
+    }
+}
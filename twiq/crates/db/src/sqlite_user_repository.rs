@@ -0,0 +1,223 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use command_handler::user_repository::{self, UserRepository};
+use domain::aggregate::user::{TwitterUserId, User, UserId};
+use event_store_core::{EventStream, EventStreamId};
+use sqlx::SqliteConnection;
+
+use crate::{sqlite_database::SqliteDatabase, sqlite_event_store};
+
+#[derive(Debug, thiserror::Error)]
+enum Error {
+    #[error("conflict {0}")]
+    Conflict(UserId),
+    #[error("event_store_core::event_stream_id {0}")]
+    EventStreamId(#[from] event_store_core::event_stream_id::Error),
+    #[error("sqlite_event_store {0}")]
+    SqliteEventStore(#[from] sqlite_event_store::Error),
+    #[error("sqlx {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("domain::aggregate::user {0}")]
+    User(#[from] domain::aggregate::user::Error),
+    #[error("unknown {0}")]
+    Unknown(String),
+}
+
+impl From<Error> for user_repository::Error {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::Conflict(id) => user_repository::Error::Conflict(id),
+            _ => user_repository::Error::Unknown(e.to_string()),
+        }
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Clone, Debug)]
+pub struct SqliteUserRepository {
+    database: SqliteDatabase,
+}
+
+impl SqliteUserRepository {
+    pub fn new(database: SqliteDatabase) -> Self {
+        Self { database }
+    }
+
+    async fn find_by_event_stream_id(
+        connection: &mut SqliteConnection,
+        event_stream_id: Option<String>,
+    ) -> Result<Option<User>> {
+        let event_stream_id = match event_stream_id {
+            None => return Ok(None),
+            Some(s) => EventStreamId::from_str(&s)?,
+        };
+        let event_stream =
+            sqlite_event_store::find_event_stream(connection, event_stream_id).await?;
+        match event_stream {
+            None => Ok(None),
+            Some(event_stream) => Ok(User::try_from(event_stream).map(Some)?),
+        }
+    }
+
+    async fn find(&self, id: UserId) -> Result<Option<User>> {
+        let mut connection = self.database.pool().acquire().await?;
+        let event_stream_id: Option<String> =
+            sqlx::query_scalar("SELECT event_stream_id FROM user_ids WHERE user_id = ?")
+                .bind(id.to_string())
+                .fetch_optional(&mut connection)
+                .await?;
+        Self::find_by_event_stream_id(&mut connection, event_stream_id).await
+    }
+
+    async fn find_by_twitter_user_id(
+        &self,
+        twitter_user_id: &TwitterUserId,
+    ) -> Result<Option<User>> {
+        let mut connection = self.database.pool().acquire().await?;
+        let event_stream_id: Option<String> = sqlx::query_scalar(
+            "SELECT user_ids.event_stream_id FROM twitter_user_ids INNER JOIN user_ids ON user_ids.user_id = twitter_user_ids.user_id WHERE twitter_user_ids.twitter_user_id = ?",
+        )
+        .bind(twitter_user_id.to_string())
+        .fetch_optional(&mut connection)
+        .await?;
+        Self::find_by_event_stream_id(&mut connection, event_stream_id).await
+    }
+
+    async fn store(&self, before: Option<User>, after: User) -> Result<()> {
+        let mut transaction = self.database.pool().begin().await?;
+
+        let twitter_user_id = after.twitter_user_id().clone();
+        let user_id = after.id();
+        let event_stream = EventStream::from(after);
+        let event_stream_id = event_stream.id();
+
+        match before {
+            Some(ref before_user) => {
+                if before_user.id() != user_id {
+                    return Err(Error::Unknown("user_id not match".to_owned()));
+                }
+                if before_user.twitter_user_id() != &twitter_user_id {
+                    return Err(Error::Unknown("twitter_user_id not match".to_owned()));
+                }
+                let before_event_stream_id: String =
+                    sqlx::query_scalar("SELECT event_stream_id FROM user_ids WHERE user_id = ?")
+                        .bind(user_id.to_string())
+                        .fetch_optional(&mut transaction)
+                        .await?
+                        .ok_or_else(|| Error::Unknown("not found".to_owned()))?;
+                if before_event_stream_id != event_stream_id.to_string() {
+                    return Err(Error::Unknown("event_stream_id not match".to_owned()));
+                }
+                let before_user_id: String = sqlx::query_scalar(
+                    "SELECT user_id FROM twitter_user_ids WHERE twitter_user_id = ?",
+                )
+                .bind(twitter_user_id.to_string())
+                .fetch_optional(&mut transaction)
+                .await?
+                .ok_or_else(|| Error::Unknown("not found".to_owned()))?;
+                if before_user_id != user_id.to_string() {
+                    return Err(Error::Unknown("user_id not match".to_owned()));
+                }
+            }
+            None => {
+                let result = sqlx::query(
+                    "INSERT INTO user_ids (user_id, event_stream_id) VALUES (?, ?) ON CONFLICT (user_id) DO NOTHING",
+                )
+                .bind(user_id.to_string())
+                .bind(event_stream_id.to_string())
+                .execute(&mut transaction)
+                .await?;
+                if result.rows_affected() != 1 {
+                    return Err(Error::Conflict(user_id));
+                }
+                // another user has the twitter_user_id
+                let result = sqlx::query(
+                    "INSERT INTO twitter_user_ids (twitter_user_id, user_id) VALUES (?, ?) ON CONFLICT (twitter_user_id) DO NOTHING",
+                )
+                .bind(twitter_user_id.to_string())
+                .bind(user_id.to_string())
+                .execute(&mut transaction)
+                .await?;
+                if result.rows_affected() != 1 {
+                    return Err(Error::Conflict(user_id));
+                }
+            }
+        }
+
+        sqlite_event_store::store(
+            &mut transaction,
+            before.map(|aggregate| EventStream::from(aggregate).seq()),
+            event_stream,
+        )
+        .await
+        .map_err(|e| match e {
+            sqlite_event_store::Error::Conflict(_) => Error::Conflict(user_id),
+            e => Error::from(e),
+        })?;
+
+        transaction.commit().await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UserRepository for SqliteUserRepository {
+    async fn find(&self, id: UserId) -> user_repository::Result<Option<User>> {
+        Ok(self.find(id).await?)
+    }
+
+    async fn find_by_twitter_user_id(
+        &self,
+        twitter_user_id: &TwitterUserId,
+    ) -> user_repository::Result<Option<User>> {
+        Ok(self.find_by_twitter_user_id(twitter_user_id).await?)
+    }
+
+    async fn store(&self, before: Option<User>, after: User) -> user_repository::Result<()> {
+        Ok(self.store(before, after).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::aggregate::user::TwitterUserName;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let database = SqliteDatabase::connect("sqlite::memory:").await?;
+        let repository = SqliteUserRepository::new(database);
+        let user = User::create(TwitterUserId::from_str("125962981")?)?;
+        assert!(repository.find(user.id()).await?.is_none());
+        repository.store(None, user.clone()).await?;
+        assert_eq!(repository.find(user.id()).await?, Some(user.clone()));
+        let updated = user.update(TwitterUserName::from_str("bouzuya")?)?;
+        repository
+            .store(Some(user.clone()), updated.clone())
+            .await?;
+        assert_eq!(repository.find(user.id()).await?, Some(updated.clone()));
+        assert_eq!(
+            repository
+                .find_by_twitter_user_id(user.twitter_user_id())
+                .await?,
+            Some(updated.clone())
+        );
+
+        // store twice
+        assert_eq!(
+            UserRepository::store(&repository, Some(user.clone()), updated.clone()).await,
+            Err(user_repository::Error::Conflict(user.id()))
+        );
+        // duplicate twitter_user_id (the transaction is rolled back)
+        let user2 = User::create(TwitterUserId::from_str("125962981")?)?;
+        assert_eq!(
+            UserRepository::store(&repository, None, user2.clone()).await,
+            Err(user_repository::Error::Conflict(user2.id()))
+        );
+        assert!(repository.find(user2.id()).await?.is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use command_handler::user_request_repository::{self, UserRequestRepository};
+use domain::aggregate::{user::UserRequestId, user_request::UserRequest};
+use event_store_core::{EventStream, EventStreamId};
+
+use crate::{sqlite_database::SqliteDatabase, sqlite_event_store};
+
+#[derive(Debug, thiserror::Error)]
+enum Error {
+    #[error("conflict {0}")]
+    Conflict(UserRequestId),
+    #[error("event_store_core::event_stream_id {0}")]
+    EventStreamId(#[from] event_store_core::event_stream_id::Error),
+    #[error("sqlite_event_store {0}")]
+    SqliteEventStore(#[from] sqlite_event_store::Error),
+    #[error("sqlx {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("domain::aggregate::user_request {0}")]
+    UserRequest(#[from] domain::aggregate::user_request::Error),
+    #[error("user_request_id not found {0}")]
+    UserRequestIdNotFound(UserRequestId),
+    #[error("unknown {0}")]
+    Unknown(String),
+}
+
+impl From<Error> for user_request_repository::Error {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::Conflict(id) => user_request_repository::Error::Conflict(id),
+            _ => user_request_repository::Error::Unknown(e.to_string()),
+        }
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Clone, Debug)]
+pub struct SqliteUserRequestRepository {
+    database: SqliteDatabase,
+}
+
+impl SqliteUserRequestRepository {
+    pub fn new(database: SqliteDatabase) -> Self {
+        Self { database }
+    }
+
+    async fn find(&self, id: UserRequestId) -> Result<Option<UserRequest>> {
+        let mut connection = self.database.pool().acquire().await?;
+        let event_stream_id: Option<String> = sqlx::query_scalar(
+            "SELECT event_stream_id FROM user_request_ids WHERE user_request_id = ?",
+        )
+        .bind(id.to_string())
+        .fetch_optional(&mut connection)
+        .await?;
+        let event_stream_id = match event_stream_id {
+            None => return Ok(None),
+            Some(s) => EventStreamId::from_str(&s)?,
+        };
+        let event_stream =
+            sqlite_event_store::find_event_stream(&mut connection, event_stream_id).await?;
+        match event_stream {
+            None => Ok(None),
+            Some(event_stream) => Ok(UserRequest::try_from(event_stream).map(Some)?),
+        }
+    }
+
+    async fn store(&self, before: Option<UserRequest>, after: UserRequest) -> Result<()> {
+        let mut transaction = self.database.pool().begin().await?;
+
+        let user_request_id = after.id();
+        let event_stream = EventStream::from(after);
+        let event_stream_id = event_stream.id();
+
+        match before {
+            Some(ref before_user_request) => {
+                if before_user_request.id() != user_request_id {
+                    return Err(Error::Unknown("user_request_id not match".to_owned()));
+                }
+                let before_event_stream_id: String = sqlx::query_scalar(
+                    "SELECT event_stream_id FROM user_request_ids WHERE user_request_id = ?",
+                )
+                .bind(user_request_id.to_string())
+                .fetch_optional(&mut transaction)
+                .await?
+                .ok_or(Error::UserRequestIdNotFound(user_request_id))?;
+                if before_event_stream_id != event_stream_id.to_string() {
+                    return Err(Error::Unknown("event_stream_id not match".to_owned()));
+                }
+            }
+            None => {
+                let result = sqlx::query(
+                    "INSERT INTO user_request_ids (user_request_id, event_stream_id) VALUES (?, ?) ON CONFLICT (user_request_id) DO NOTHING",
+                )
+                .bind(user_request_id.to_string())
+                .bind(event_stream_id.to_string())
+                .execute(&mut transaction)
+                .await?;
+                if result.rows_affected() != 1 {
+                    return Err(Error::Conflict(user_request_id));
+                }
+            }
+        }
+
+        sqlite_event_store::store(
+            &mut transaction,
+            before.map(|aggregate| EventStream::from(aggregate).seq()),
+            event_stream,
+        )
+        .await
+        .map_err(|e| match e {
+            sqlite_event_store::Error::Conflict(_) => Error::Conflict(user_request_id),
+            e => Error::from(e),
+        })?;
+
+        transaction.commit().await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UserRequestRepository for SqliteUserRequestRepository {
+    async fn find(
+        &self,
+        id: UserRequestId,
+    ) -> user_request_repository::Result<Option<UserRequest>> {
+        Ok(self.find(id).await?)
+    }
+
+    async fn store(
+        &self,
+        before: Option<UserRequest>,
+        after: UserRequest,
+    ) -> user_request_repository::Result<()> {
+        Ok(self.store(before, after).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::aggregate::user::{TwitterUserId, UserId};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let database = SqliteDatabase::connect("sqlite::memory:").await?;
+        let repository = SqliteUserRequestRepository::new(database);
+        let id = UserRequestId::generate();
+        let twitter_user_id = TwitterUserId::from_str("125962981")?;
+        let user_request = UserRequest::create(id, twitter_user_id, UserId::generate())?;
+        assert!(repository.find(user_request.id()).await?.is_none());
+        repository.store(None, user_request.clone()).await?;
+        assert_eq!(
+            repository.find(user_request.id()).await?,
+            Some(user_request.clone())
+        );
+        let started = user_request.start()?;
+        repository
+            .store(Some(user_request.clone()), started.clone())
+            .await?;
+        assert_eq!(
+            repository.find(user_request.id()).await?,
+            Some(started.clone())
+        );
+
+        // store twice
+        assert_eq!(
+            UserRequestRepository::store(&repository, Some(user_request.clone()), started.clone())
+                .await,
+            Err(user_request_repository::Error::Conflict(id))
+        );
+
+        // duplicate id
+        let twitter_user_id = TwitterUserId::from_str("125962981")?;
+        let user_request2 = UserRequest::create(id, twitter_user_id, UserId::generate())?;
+        assert_eq!(
+            UserRequestRepository::store(&repository, None, user_request2).await,
+            Err(user_request_repository::Error::Conflict(id))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use async_trait::async_trait;
+use query_handler::{user::User, user_store};
+
+use crate::sqlite_database::SqliteDatabase;
+
+#[derive(Debug, thiserror::Error)]
+enum Error {
+    #[error("sqlx {0}")]
+    Sqlx(#[from] sqlx::Error),
+}
+
+impl From<Error> for user_store::Error {
+    fn from(e: Error) -> Self {
+        user_store::Error::Unknown(e.to_string())
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Clone, Debug)]
+pub struct SqliteUserStore {
+    database: SqliteDatabase,
+}
+
+impl SqliteUserStore {
+    pub fn new(database: SqliteDatabase) -> Self {
+        Self { database }
+    }
+
+    async fn find_by_twitter_user_id(&self, twitter_user_id: &str) -> Result<Option<User>> {
+        let row: Option<(String, String, String)> = sqlx::query_as(
+            "SELECT query_users.user_id, query_users.twitter_user_id, query_users.twitter_user_name FROM query_twitter_user_ids INNER JOIN query_users ON query_users.user_id = query_twitter_user_ids.user_id WHERE query_twitter_user_ids.twitter_user_id = ?",
+        )
+        .bind(twitter_user_id)
+        .fetch_optional(self.database.pool())
+        .await?;
+        Ok(
+            row.map(|(user_id, twitter_user_id, twitter_user_name)| User {
+                user_id,
+                twitter_user_id,
+                twitter_user_name,
+            }),
+        )
+    }
+
+    async fn store(&self, _before: Option<User>, after: User) -> Result<()> {
+        let mut transaction = self.database.pool().begin().await?;
+
+        // store query_twitter_user_id
+        sqlx::query(
+            "INSERT INTO query_twitter_user_ids (twitter_user_id, user_id) VALUES (?, ?) ON CONFLICT (twitter_user_id) DO UPDATE SET user_id = excluded.user_id",
+        )
+        .bind(after.twitter_user_id.as_str())
+        .bind(after.user_id.as_str())
+        .execute(&mut transaction)
+        .await?;
+
+        // store query_user
+        sqlx::query(
+            "INSERT INTO query_users (user_id, twitter_user_id, twitter_user_name) VALUES (?, ?, ?) ON CONFLICT (user_id) DO UPDATE SET twitter_user_id = excluded.twitter_user_id, twitter_user_name = excluded.twitter_user_name",
+        )
+        .bind(after.user_id)
+        .bind(after.twitter_user_id)
+        .bind(after.twitter_user_name)
+        .execute(&mut transaction)
+        .await?;
+
+        transaction.commit().await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl user_store::UserStore for SqliteUserStore {
+    async fn find_by_twitter_user_id(
+        &self,
+        twitter_user_id: &str,
+    ) -> user_store::Result<Option<User>> {
+        Ok(self.find_by_twitter_user_id(twitter_user_id).await?)
+    }
+
+    async fn store(&self, before: Option<User>, after: User) -> user_store::Result<()> {
+        Ok(self.store(before, after).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let database = SqliteDatabase::connect("sqlite::memory:").await?;
+        let user_store = SqliteUserStore::new(database);
+        assert_eq!(user_store.find_by_twitter_user_id("125962981").await?, None);
+        let user = User {
+            user_id: "user_id1".to_owned(),
+            twitter_user_id: "125962981".to_owned(),
+            twitter_user_name: "".to_owned(),
+        };
+        user_store.store(None, user.clone()).await?;
+        assert_eq!(
+            user_store.find_by_twitter_user_id("125962981").await?,
+            Some(user.clone())
+        );
+        let updated = User {
+            twitter_user_name: "bouzuya".to_owned(),
+            ..user.clone()
+        };
+        user_store.store(Some(user), updated.clone()).await?;
+        assert_eq!(
+            user_store.find_by_twitter_user_id("125962981").await?,
+            Some(updated)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,213 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use event_store_core::{Event, EventId};
+use worker_helper::worker_repository::{self, DeadLetter, WorkerName, WorkerRepository};
+
+use crate::{sqlite_database::SqliteDatabase, sqlite_event_store};
+
+#[derive(Debug, thiserror::Error)]
+enum Error {
+    #[error("event_store_core::event_id {0}")]
+    EventId(#[from] event_store_core::event_id::Error),
+    #[error("sqlite_event_store {0}")]
+    SqliteEventStore(#[from] sqlite_event_store::Error),
+    #[error("sqlx {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("conflict {0}")]
+    Conflict(WorkerName),
+}
+
+impl From<Error> for worker_repository::Error {
+    fn from(e: Error) -> Self {
+        worker_repository::Error::Unknown(e.to_string())
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Clone, Debug)]
+pub struct SqliteWorkerRepository {
+    database: SqliteDatabase,
+}
+
+impl SqliteWorkerRepository {
+    pub fn new(database: SqliteDatabase) -> Self {
+        Self { database }
+    }
+
+    async fn find_event_ids(&self, event_id: Option<EventId>) -> Result<Vec<EventId>> {
+        let mut connection = self.database.pool().acquire().await?;
+        Ok(sqlite_event_store::find_events(&mut connection, event_id)
+            .await?
+            .into_iter()
+            .map(|event| event.id())
+            .collect::<Vec<EventId>>())
+    }
+
+    async fn find_event(&self, event_id: EventId) -> Result<Option<Event>> {
+        let mut connection = self.database.pool().acquire().await?;
+        Ok(sqlite_event_store::find_event(&mut connection, event_id).await?)
+    }
+
+    async fn find_last_event_id(&self, worker_name: WorkerName) -> Result<Option<EventId>> {
+        let event_id: Option<String> =
+            sqlx::query_scalar("SELECT event_id FROM workers WHERE name = ?")
+                .bind(worker_name.to_string())
+                .fetch_optional(self.database.pool())
+                .await?;
+        Ok(event_id
+            .map(|event_id| EventId::from_str(&event_id))
+            .transpose()?)
+    }
+
+    async fn store_last_event_id(
+        &self,
+        worker_name: WorkerName,
+        before: Option<EventId>,
+        after: EventId,
+    ) -> Result<()> {
+        let result = match before {
+            Some(before) => {
+                sqlx::query("UPDATE workers SET event_id = ? WHERE name = ? AND event_id = ?")
+                    .bind(after.to_string())
+                    .bind(worker_name.to_string())
+                    .bind(before.to_string())
+                    .execute(self.database.pool())
+                    .await?
+            }
+            None => sqlx::query(
+                "INSERT INTO workers (name, event_id) VALUES (?, ?) ON CONFLICT (name) DO NOTHING",
+            )
+            .bind(worker_name.to_string())
+            .bind(after.to_string())
+            .execute(self.database.pool())
+            .await?,
+        };
+        if result.rows_affected() != 1 {
+            return Err(Error::Conflict(worker_name));
+        }
+        Ok(())
+    }
+
+    async fn store_dead_letter(&self, dead_letter: DeadLetter) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO dead_letters (worker_name, event_id, attempts, error) VALUES (?, ?, ?, ?) ON CONFLICT (worker_name, event_id) DO UPDATE SET attempts = excluded.attempts, error = excluded.error",
+        )
+        .bind(dead_letter.worker_name.to_string())
+        .bind(dead_letter.event_id.to_string())
+        .bind(dead_letter.attempts)
+        .bind(dead_letter.error)
+        .execute(self.database.pool())
+        .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl WorkerRepository for SqliteWorkerRepository {
+    async fn find_event_ids(
+        &self,
+        event_id: Option<EventId>,
+    ) -> worker_repository::Result<Vec<EventId>> {
+        Ok(self.find_event_ids(event_id).await?)
+    }
+
+    async fn find_event(&self, event_id: EventId) -> worker_repository::Result<Option<Event>> {
+        Ok(self.find_event(event_id).await?)
+    }
+
+    async fn find_last_event_id(
+        &self,
+        worker_name: WorkerName,
+    ) -> worker_repository::Result<Option<EventId>> {
+        Ok(self.find_last_event_id(worker_name).await?)
+    }
+
+    async fn store_last_event_id(
+        &self,
+        worker_name: WorkerName,
+        before: Option<EventId>,
+        after: EventId,
+    ) -> worker_repository::Result<()> {
+        Ok(self.store_last_event_id(worker_name, before, after).await?)
+    }
+
+    async fn store_dead_letter(&self, dead_letter: DeadLetter) -> worker_repository::Result<()> {
+        Ok(self.store_dead_letter(dead_letter).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::aggregate::user::{TwitterUserId, User};
+    use event_store_core::{event_store::EventStore, EventStream};
+
+    use crate::sqlite_event_store::SqliteEventStore;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let database = SqliteDatabase::connect("sqlite::memory:").await?;
+        let event_store = SqliteEventStore::new(database.clone());
+        let repository = SqliteWorkerRepository::new(database);
+
+        let user = User::create(TwitterUserId::from_str("125962981")?)?;
+        let event_stream = EventStream::from(user);
+        event_store.store(None, event_stream.clone()).await?;
+        let event_ids = event_stream
+            .events()
+            .into_iter()
+            .map(|event| event.id())
+            .collect::<Vec<EventId>>();
+        assert_eq!(repository.find_event_ids(None).await?, event_ids);
+        let event_id = event_ids[0];
+        assert_eq!(
+            repository.find_event(event_id).await?,
+            event_stream.events().first().cloned()
+        );
+
+        let worker_name = WorkerName::UpdateUser;
+        assert_eq!(repository.find_last_event_id(worker_name).await?, None);
+        repository
+            .store_last_event_id(worker_name, None, event_id)
+            .await?;
+        assert_eq!(
+            repository.find_last_event_id(worker_name).await?,
+            Some(event_id)
+        );
+        let event_id2 = EventId::generate();
+        repository
+            .store_last_event_id(worker_name, Some(event_id), event_id2)
+            .await?;
+        assert_eq!(
+            repository.find_last_event_id(worker_name).await?,
+            Some(event_id2)
+        );
+
+        // conflict
+        assert!(repository
+            .store_last_event_id(worker_name, None, event_id)
+            .await
+            .is_err());
+        assert!(repository
+            .store_last_event_id(worker_name, Some(event_id), event_id2)
+            .await
+            .is_err());
+
+        let dead_letter = DeadLetter {
+            worker_name,
+            event_id,
+            attempts: 5,
+            error: "error".to_owned(),
+        };
+        repository.store_dead_letter(dead_letter.clone()).await?;
+        repository.store_dead_letter(dead_letter).await?;
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM dead_letters")
+            .fetch_one(repository.database.pool())
+            .await?;
+        assert_eq!(count, 1);
+        Ok(())
+    }
+}
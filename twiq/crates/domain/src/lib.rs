@@ -7,6 +7,8 @@ pub use self::event::Event;
 #[derive(Debug)]
 pub struct MyTweet {
     pub id_str: String,
+    // the owner of the timeline (the retweeter of a retweet)
+    pub user_id: String,
     pub retweet: bool,
     pub at: String,
     pub author_id: String,
@@ -4,10 +4,25 @@ use crate::{Event, EventId, EventStream, EventStreamId, EventStreamSeq};
 
 #[derive(Debug, Eq, PartialEq, thiserror::Error)]
 pub enum Error {
+    /// the event stream already exists (`current` is `None`) or its seq is not `current`
+    #[error("conflict : {0}")]
+    Conflict(EventStreamId),
+    /// the event stream does not exist (`current` is `Some`)
+    #[error("not found : {0}")]
+    NotFound(EventStreamId),
+    /// the stored event can not be converted into `Event`
+    #[error("serialization error : {0}")]
+    Serialization(String),
     #[error("unknown error : {0}")]
     Unknown(String),
 }
 
+impl Error {
+    pub fn is_conflict(&self) -> bool {
+        matches!(self, Error::Conflict(_))
+    }
+}
+
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 #[async_trait]
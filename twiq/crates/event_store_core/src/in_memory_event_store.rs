@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{hash_map::Entry, HashMap},
     sync::{Arc, Mutex},
 };
 
@@ -81,11 +81,11 @@ impl EventStore for InMemoryEventStore {
             (None, None) => {
                 event_streams.insert(event_stream_id, event_stream_seq);
             }
-            (None, Some(_)) => return Err(Error::Unknown("already exists".to_owned())),
-            (Some(_), None) => return Err(Error::Unknown("not found".to_owned())),
+            (None, Some(_)) => return Err(Error::Conflict(event_stream_id)),
+            (Some(_), None) => return Err(Error::NotFound(event_stream_id)),
             (Some(expected), Some(actual)) => {
                 if expected != actual {
-                    return Err(Error::Unknown("does not match".to_owned()));
+                    return Err(Error::Conflict(event_stream_id));
                 }
 
                 event_streams.insert(event_stream_id, event_stream_seq);
@@ -93,8 +93,8 @@ impl EventStore for InMemoryEventStore {
         }
 
         for event in event_stream.events() {
-            let index = events.len();
-            if event_ids.insert(event.id(), index).is_none() {
+            if let Entry::Vacant(entry) = event_ids.entry(event.id()) {
+                entry.insert(events.len());
                 events.push(event);
             }
         }
@@ -102,3 +102,56 @@ impl EventStore for InMemoryEventStore {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{EventAt, EventPayload, EventType};
+
+    use super::*;
+
+    fn event(stream_id: EventStreamId, stream_seq: EventStreamSeq) -> anyhow::Result<Event> {
+        Ok(Event::new(
+            EventId::generate(),
+            EventType::from_str("created")?,
+            stream_id,
+            stream_seq,
+            EventAt::now(),
+            EventPayload::try_from("{}".to_owned())?,
+        ))
+    }
+
+    #[tokio::test]
+    async fn store_test() -> anyhow::Result<()> {
+        let event_store = InMemoryEventStore::default();
+        let stream_id = EventStreamId::generate();
+        let seq1 = EventStreamSeq::from(1_u32);
+        let mut event_stream = EventStream::new(vec![event(stream_id, seq1)?])?;
+
+        assert_eq!(
+            event_store.store(Some(seq1), event_stream.clone()).await,
+            Err(Error::NotFound(stream_id))
+        );
+        event_store.store(None, event_stream.clone()).await?;
+        assert_eq!(
+            event_store.store(None, event_stream.clone()).await,
+            Err(Error::Conflict(stream_id))
+        );
+
+        let seq2 = seq1.next()?;
+        event_stream.push_event(event(stream_id, seq2)?)?;
+        event_store.store(Some(seq1), event_stream.clone()).await?;
+        let result = event_store.store(Some(seq1), event_stream.clone()).await;
+        assert_eq!(result, Err(Error::Conflict(stream_id)));
+        assert!(result.unwrap_err().is_conflict());
+        assert_eq!(
+            event_store.find_event_stream(stream_id).await?,
+            Some(event_stream.clone())
+        );
+        for event in event_stream.events() {
+            assert_eq!(event_store.find_event(event.id()).await?, Some(event));
+        }
+        Ok(())
+    }
+}
@@ -150,6 +150,7 @@ impl Item {
         };
         domain::MyTweet {
             id_str: tweet.id_str,
+            user_id: user_id.to_string(),
             retweet,
             at: OffsetDateTime::parse(tweet.created_at.as_str(), &asctime)
                 .with_context(|| tweet.created_at)
@@ -206,13 +207,10 @@ fn main() -> anyhow::Result<()> {
     let file = &args[1];
     let s = read_to_string(file)?;
     let json: Vec<Item> = serde_json::from_str(s.trim_start_matches("window.YTD.tweet.part0 = "))?;
-    // TODO: import
-    // println!("{}", serde_json::to_string_pretty(&json)?);
-    let tweets = json
-        .into_iter()
-        .take(10)
-        .map(|item| item.parse("125962981"))
-        .collect::<Vec<_>>();
-    println!("{:?}", tweets);
+    // JSON Lines of `query_handler::tweet::Tweet` (e.g. `TWEETS_FILE` for web)
+    for item in json {
+        let tweet = query_handler::tweet::Tweet::from(item.parse("125962981"));
+        println!("{}", serde_json::to_string(&tweet)?);
+    }
     Ok(())
 }
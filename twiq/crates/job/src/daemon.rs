@@ -0,0 +1,96 @@
+use std::{
+    collections::BTreeMap,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use axum::{routing::get, Extension, Json, Router, Server};
+use command_handler::command::{create_user_request, send_user_request, update_user};
+use query_handler::update_query_user;
+use tracing::{error, info};
+use worker_helper::{
+    daemon::{poll, RetryPolicy, WorkerStatus},
+    worker_repository::WorkerName,
+};
+
+use crate::App;
+
+type Statuses = Arc<Mutex<BTreeMap<String, WorkerStatus>>>;
+
+const WORKER_NAMES: [WorkerName; 4] = [
+    WorkerName::CreateUserRequest,
+    WorkerName::SendUserRequest,
+    WorkerName::UpdateUser,
+    WorkerName::UpdateQueryUser,
+];
+
+async fn healthz(Extension(statuses): Extension<Statuses>) -> Json<BTreeMap<String, WorkerStatus>> {
+    Json(statuses.lock().unwrap().clone())
+}
+
+async fn poll_worker(
+    app: &App,
+    worker_name: WorkerName,
+    policy: &RetryPolicy,
+    status: &mut WorkerStatus,
+) -> worker_helper::worker_helper::Result<()> {
+    match worker_name {
+        WorkerName::CreateUserRequest => {
+            poll(
+                app,
+                worker_name,
+                create_user_request::handle,
+                policy,
+                status,
+            )
+            .await
+        }
+        WorkerName::SendUserRequest => {
+            poll(app, worker_name, send_user_request::handle, policy, status).await
+        }
+        WorkerName::UpdateUser => poll(app, worker_name, update_user::handle, policy, status).await,
+        WorkerName::UpdateQueryUser => {
+            poll(app, worker_name, update_query_user::handle, policy, status).await
+        }
+    }
+}
+
+/// Polls all workers every `interval` until Ctrl-C, serving their statuses on `GET /healthz`.
+pub(crate) async fn run(
+    app: App,
+    interval: Duration,
+    policy: RetryPolicy,
+    addr: SocketAddr,
+) -> anyhow::Result<()> {
+    let statuses = Statuses::default();
+    let router = Router::new()
+        .route("/healthz", get(healthz))
+        .layer(Extension(statuses.clone()));
+    info!("Listening on {}", addr);
+    let server = tokio::spawn(Server::bind(&addr).serve(router.into_make_service()));
+
+    loop {
+        for worker_name in WORKER_NAMES {
+            let key = worker_name.to_string();
+            let mut status = statuses
+                .lock()
+                .unwrap()
+                .get(&key)
+                .cloned()
+                .unwrap_or_default();
+            if let Err(e) = poll_worker(&app, worker_name, &policy, &mut status).await {
+                error!("{} {}", worker_name, e);
+            }
+            statuses.lock().unwrap().insert(key, status);
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(interval) => {}
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+
+    server.abort();
+    Ok(())
+}
@@ -1,16 +1,20 @@
+mod daemon;
+
+use std::{net::SocketAddr, time::Duration};
+
 use command_handler::{
     command::{create_user_request, send_user_request, update_user},
     user_repository::HasUserRepository,
     user_request_repository::HasUserRequestRepository,
 };
 use db::{
-    config::Config, firestore_user_repository::FirestoreUserRepository,
-    firestore_user_request_repository::FirestoreUserRequestRepository,
-    firestore_user_store::FirestoreUserStore,
-    firestore_worker_repository::FirestoreWorkerRepository,
+    config::Backend,
+    database::{
+        AnyUserRepository, AnyUserRequestRepository, AnyUserStore, AnyWorkerRepository, Database,
+    },
 };
 use query_handler::{update_query_user, user_store::HasUserStore};
-use worker_helper::worker_repository::HasWorkerRepository;
+use worker_helper::{daemon::RetryPolicy, worker_repository::HasWorkerRepository};
 
 #[derive(Debug, clap::Parser)]
 #[command(author, version, about, long_about = None)]
@@ -25,33 +29,45 @@ enum Subcommand {
     SendUserRequest,
     UpdateQueryUser,
     UpdateUser,
+    /// Runs all workers continuously and serves their statuses on `GET /healthz`
+    Daemon {
+        /// Seconds between polls
+        #[arg(long, default_value_t = 10)]
+        interval: u64,
+        /// Failed attempts before an event is moved to the dead letters
+        #[arg(long, default_value_t = 5)]
+        max_attempts: u32,
+        /// Seconds before the first retry (doubled on each retry)
+        #[arg(long, default_value_t = 1)]
+        initial_backoff: u64,
+        /// Maximum seconds between retries
+        #[arg(long, default_value_t = 300)]
+        max_backoff: u64,
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+    },
 }
 
 struct App {
-    user_repository: FirestoreUserRepository,
-    user_request_repository: FirestoreUserRequestRepository,
-    user_store: FirestoreUserStore,
-    worker_repository: FirestoreWorkerRepository,
+    user_repository: AnyUserRepository,
+    user_request_repository: AnyUserRequestRepository,
+    user_store: AnyUserStore,
+    worker_repository: AnyWorkerRepository,
 }
 
-impl Default for App {
-    fn default() -> Self {
-        let config = Config::load_from_env();
-        let user_repository = FirestoreUserRepository::new(config.clone());
-        let user_request_repository = FirestoreUserRequestRepository::new(config.clone());
-        let user_store = FirestoreUserStore::new(config.clone());
-        let worker_repository = FirestoreWorkerRepository::new(config);
+impl App {
+    fn new(database: &Database) -> Self {
         Self {
-            user_repository,
-            user_request_repository,
-            user_store,
-            worker_repository,
+            user_repository: database.user_repository(),
+            user_request_repository: database.user_request_repository(),
+            user_store: database.user_store(),
+            worker_repository: database.worker_repository(),
         }
     }
 }
 
 impl HasUserRepository for App {
-    type UserRepository = FirestoreUserRepository;
+    type UserRepository = AnyUserRepository;
 
     fn user_repository(&self) -> &Self::UserRepository {
         &self.user_repository
@@ -59,7 +75,7 @@ impl HasUserRepository for App {
 }
 
 impl HasUserRequestRepository for App {
-    type UserRequestRepository = FirestoreUserRequestRepository;
+    type UserRequestRepository = AnyUserRequestRepository;
 
     fn user_request_repository(&self) -> &Self::UserRequestRepository {
         &self.user_request_repository
@@ -67,7 +83,7 @@ impl HasUserRequestRepository for App {
 }
 
 impl HasUserStore for App {
-    type UserStore = FirestoreUserStore;
+    type UserStore = AnyUserStore;
 
     fn user_store(&self) -> &Self::UserStore {
         &self.user_store
@@ -75,7 +91,7 @@ impl HasUserStore for App {
 }
 
 impl HasWorkerRepository for App {
-    type WorkerRepository = FirestoreWorkerRepository;
+    type WorkerRepository = AnyWorkerRepository;
 
     fn worker_repository(&self) -> &Self::WorkerRepository {
         &self.worker_repository
@@ -92,8 +108,9 @@ async fn main() -> anyhow::Result<()> {
     tracing_subscriber::fmt::init();
     use Subcommand::*;
 
-    let app = App::default();
     let args = <Args as clap::Parser>::parse();
+    let database = Database::connect(Backend::load_from_env()?).await?;
+    let app = App::new(&database);
     match args.subcommand {
         CreateUserRequest => {
             create_user_request::handler(&app, create_user_request::Command).await?
@@ -101,6 +118,21 @@ async fn main() -> anyhow::Result<()> {
         SendUserRequest => send_user_request::handler(&app, send_user_request::Command).await?,
         UpdateQueryUser => update_query_user::handler(&app, update_query_user::Command).await?,
         UpdateUser => update_user::handler(&app, update_user::Command).await?,
+        Daemon {
+            interval,
+            max_attempts,
+            initial_backoff,
+            max_backoff,
+            port,
+        } => {
+            let policy = RetryPolicy {
+                max_attempts,
+                initial_backoff: Duration::from_secs(initial_backoff),
+                max_backoff: Duration::from_secs(max_backoff),
+            };
+            let addr = SocketAddr::from(([0, 0, 0, 0], port));
+            daemon::run(app, Duration::from_secs(interval), policy, addr).await?
+        }
     }
 
     Ok(())
@@ -0,0 +1,155 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use tracing::instrument;
+
+use crate::{
+    tweet::Tweet,
+    tweet_index::TweetIndex,
+    tweet_stats::{TweetStats, TweetStatsProjection},
+    tweet_store::{Result, TweetQuery, TweetStore},
+};
+
+type TweetId = String;
+
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryTweetStore {
+    tweets: Arc<Mutex<HashMap<TweetId, Tweet>>>,
+    index: Arc<Mutex<TweetIndex>>,
+    stats: Arc<Mutex<TweetStatsProjection>>,
+}
+
+#[async_trait]
+impl TweetStore for InMemoryTweetStore {
+    #[instrument]
+    async fn find_by_tweet_id(&self, tweet_id: &str) -> Result<Option<Tweet>> {
+        let tweets = self.tweets.lock().unwrap();
+        Ok(tweets.get(tweet_id).cloned())
+    }
+
+    #[instrument]
+    async fn search(&self, query: &TweetQuery) -> Result<Vec<Tweet>> {
+        let tweets = self.tweets.lock().unwrap();
+        let index = self.index.lock().unwrap();
+        let mut found = match index.candidates(query.text.as_str()) {
+            None => tweets.values().collect::<Vec<&Tweet>>(),
+            Some(tweet_ids) => tweet_ids
+                .iter()
+                .filter_map(|tweet_id| tweets.get(tweet_id))
+                .collect::<Vec<&Tweet>>(),
+        }
+        .into_iter()
+        .filter(|tweet| query.matches(tweet))
+        .cloned()
+        .collect::<Vec<Tweet>>();
+        found.sort_by(|a, b| {
+            b.at()
+                .cmp(&a.at())
+                .then_with(|| b.tweet_id.cmp(&a.tweet_id))
+        });
+        if let Some(limit) = query.limit {
+            found.truncate(limit);
+        }
+        Ok(found)
+    }
+
+    #[instrument]
+    async fn stats(&self, user_id: &str, top: usize) -> Result<Option<TweetStats>> {
+        let stats = self.stats.lock().unwrap();
+        Ok(stats.stats(user_id, top))
+    }
+
+    #[instrument]
+    async fn store(&self, tweet: Tweet) -> Result<()> {
+        let mut tweets = self.tweets.lock().unwrap();
+        let mut index = self.index.lock().unwrap();
+        let mut stats = self.stats.lock().unwrap();
+        if let Some(before) = tweets.remove(&tweet.tweet_id) {
+            index.remove(before.tweet_id.as_str(), before.text.as_str());
+            stats.remove(&before);
+        }
+        index.insert(tweet.tweet_id.as_str(), tweet.text.as_str());
+        stats.insert(&tweet);
+        tweets.insert(tweet.tweet_id.clone(), tweet);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tweet(tweet_id: &str, at: &str, retweet: bool, text: &str) -> Tweet {
+        Tweet {
+            tweet_id: tweet_id.to_owned(),
+            author_id: if retweet { "2" } else { "1" }.to_owned(),
+            at: at.to_owned(),
+            retweet,
+            text: text.to_owned(),
+            hashtags: vec![],
+            user_id: "1".to_owned(),
+            mentions: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let tweet_store = InMemoryTweetStore::default();
+        let tweet1 = tweet("1", "2022-01-01T00:00:00Z", false, "東京都に住む");
+        let tweet2 = tweet("2", "2022-01-02T00:00:00Z", true, "京都に行く");
+        let tweet3 = tweet("3", "2022-01-03T00:00:00Z", false, "Hello, world!");
+        for tweet in [tweet1.clone(), tweet2.clone(), tweet3.clone()] {
+            tweet_store.store(tweet).await?;
+        }
+        assert_eq!(
+            tweet_store.find_by_tweet_id("1").await?,
+            Some(tweet1.clone())
+        );
+        assert_eq!(tweet_store.find_by_tweet_id("4").await?, None);
+
+        let search = |text: &str| TweetQuery {
+            text: text.to_owned(),
+            ..Default::default()
+        };
+        assert_eq!(
+            tweet_store.search(&search("")).await?,
+            vec![tweet3.clone(), tweet2.clone(), tweet1.clone()]
+        );
+        assert_eq!(
+            tweet_store.search(&search("京都")).await?,
+            vec![tweet2.clone(), tweet1.clone()]
+        );
+        assert_eq!(
+            tweet_store.search(&search("東京")).await?,
+            vec![tweet1.clone()]
+        );
+        assert_eq!(
+            tweet_store.search(&search("WORLD")).await?,
+            vec![tweet3.clone()]
+        );
+        assert_eq!(
+            tweet_store
+                .search(&TweetQuery {
+                    retweet: Some(false),
+                    limit: Some(1),
+                    ..search("")
+                })
+                .await?,
+            vec![tweet3]
+        );
+
+        // re-index
+        let updated = tweet("1", "2022-01-01T00:00:00Z", false, "大阪府に住む");
+        tweet_store.store(updated.clone()).await?;
+        assert_eq!(tweet_store.search(&search("東京")).await?, vec![]);
+        assert_eq!(tweet_store.search(&search("大阪")).await?, vec![updated]);
+
+        let stats = tweet_store.stats("1", 10).await?.unwrap();
+        assert_eq!((stats.tweets, stats.retweets), (3, 1));
+        assert_eq!(tweet_store.stats("2", 10).await?, None);
+        Ok(())
+    }
+}
@@ -1,4 +1,9 @@
+pub mod in_memory_tweet_store;
 pub mod in_memory_user_store;
+pub mod tweet;
+mod tweet_index;
+pub mod tweet_stats;
+pub mod tweet_store;
 pub mod update_query_user;
 pub mod user;
 pub mod user_store;
@@ -0,0 +1,57 @@
+use time::{format_description::well_known::Iso8601, OffsetDateTime};
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Tweet {
+    pub tweet_id: String,
+    pub author_id: String,
+    // ISO 8601 (e.g. `2022-01-02T03:04:05.000000000Z`)
+    pub at: String,
+    pub retweet: bool,
+    pub text: String,
+    pub hashtags: Vec<String>,
+    // the owner of the timeline (the retweeter of a retweet). empty in the old files
+    #[serde(default)]
+    pub user_id: String,
+    // the mentioned user ids
+    #[serde(default)]
+    pub mentions: Vec<String>,
+}
+
+impl Tweet {
+    pub(crate) fn at(&self) -> Option<OffsetDateTime> {
+        OffsetDateTime::parse(self.at.as_str(), &Iso8601::DEFAULT).ok()
+    }
+
+    pub(crate) fn timeline_user_id(&self) -> &str {
+        if self.user_id.is_empty() {
+            self.author_id.as_str()
+        } else {
+            self.user_id.as_str()
+        }
+    }
+}
+
+impl From<domain::MyTweet> for Tweet {
+    fn from(my_tweet: domain::MyTweet) -> Self {
+        Self {
+            tweet_id: my_tweet.id_str,
+            author_id: my_tweet.author_id,
+            at: my_tweet.at,
+            retweet: my_tweet.retweet,
+            text: my_tweet.text,
+            hashtags: my_tweet
+                .entities
+                .hashtags
+                .into_iter()
+                .map(|hashtag| hashtag.tag)
+                .collect(),
+            user_id: my_tweet.user_id,
+            mentions: my_tweet
+                .entities
+                .mentions
+                .into_iter()
+                .map(|mention| mention.id_str)
+                .collect(),
+        }
+    }
+}
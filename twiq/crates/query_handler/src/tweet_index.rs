@@ -0,0 +1,178 @@
+use std::collections::{BTreeSet, HashMap};
+
+type TweetId = String;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Script {
+    // hiragana, katakana and kanji. there are no spaces between words.
+    Cjk,
+    Word,
+}
+
+// fullwidth alphanumerics to halfwidth, lowercase
+pub(crate) fn normalize(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '\u{3000}' => ' ',
+            '\u{ff01}'..='\u{ff5e}' => char::from_u32(c as u32 - 0xfee0).unwrap_or(c),
+            _ => c,
+        })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn script(c: char) -> Option<Script> {
+    match c {
+        '\u{3040}'..='\u{309f}'
+        | '\u{30a0}'..='\u{30ff}'
+        | '\u{31f0}'..='\u{31ff}'
+        | '\u{3400}'..='\u{4dbf}'
+        | '\u{4e00}'..='\u{9fff}'
+        | '\u{f900}'..='\u{faff}'
+        | '\u{3005}' => Some(Script::Cjk),
+        c if c.is_alphanumeric() || c == '_' => Some(Script::Word),
+        _ => None,
+    }
+}
+
+// splits the normalized text into runs of the same script
+fn segments(text: &str) -> Vec<(Script, String)> {
+    let mut segments: Vec<(Script, String)> = vec![];
+    let mut current = None;
+    for c in normalize(text).chars() {
+        let s = script(c);
+        match (s, segments.last_mut()) {
+            (Some(_), Some((_, segment))) if s == current => segment.push(c),
+            (Some(s), _) => segments.push((s, c.to_string())),
+            (None, _) => {}
+        }
+        current = s;
+    }
+    segments
+}
+
+// words and CJK uni-grams / bi-grams
+pub(crate) fn tokenize(text: &str) -> BTreeSet<String> {
+    let mut tokens = BTreeSet::new();
+    for (script, segment) in segments(text) {
+        match script {
+            Script::Cjk => {
+                let chars = segment.chars().collect::<Vec<char>>();
+                for c in chars.iter() {
+                    tokens.insert(c.to_string());
+                }
+                for w in chars.windows(2) {
+                    tokens.insert(w.iter().collect::<String>());
+                }
+            }
+            Script::Word => {
+                tokens.insert(segment);
+            }
+        }
+    }
+    tokens
+}
+
+// n-grams do not keep the order of characters. a CJK segment in the query must appear in the text.
+pub(crate) fn matches(text: &str, query: &str) -> bool {
+    let text_tokens = tokenize(text);
+    let normalized_text = normalize(text);
+    segments(query)
+        .into_iter()
+        .all(|(script, segment)| match script {
+            Script::Cjk => normalized_text.contains(segment.as_str()),
+            Script::Word => text_tokens.contains(segment.as_str()),
+        })
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TweetIndex {
+    postings: HashMap<String, BTreeSet<TweetId>>,
+}
+
+impl TweetIndex {
+    pub(crate) fn insert(&mut self, tweet_id: &str, text: &str) {
+        for token in tokenize(text) {
+            self.postings
+                .entry(token)
+                .or_default()
+                .insert(tweet_id.to_owned());
+        }
+    }
+
+    pub(crate) fn remove(&mut self, tweet_id: &str, text: &str) {
+        for token in tokenize(text) {
+            if let Some(tweet_ids) = self.postings.get_mut(&token) {
+                tweet_ids.remove(tweet_id);
+                if tweet_ids.is_empty() {
+                    self.postings.remove(&token);
+                }
+            }
+        }
+    }
+
+    // returns None if the query has no tokens (matches all tweets)
+    pub(crate) fn candidates(&self, query: &str) -> Option<BTreeSet<TweetId>> {
+        let mut candidates: Option<BTreeSet<TweetId>> = None;
+        for token in tokenize(query) {
+            let tweet_ids = self.postings.get(&token).cloned().unwrap_or_default();
+            candidates = Some(match candidates {
+                None => tweet_ids,
+                Some(candidates) => candidates.intersection(&tweet_ids).cloned().collect(),
+            });
+        }
+        candidates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenize_test() {
+        assert_eq!(
+            tokenize("Rustで検索する #ｒｕｓｔ"),
+            ["rust", "で", "で検", "検", "検索", "索", "索す", "す", "する", "る"]
+                .into_iter()
+                .map(|s| s.to_owned())
+                .collect::<BTreeSet<String>>()
+        );
+        assert!(tokenize("  ").is_empty());
+    }
+
+    #[test]
+    fn matches_test() {
+        let text = "全文検索を実装した。Rust is fun!";
+        assert!(matches(text, "検索"));
+        assert!(matches(text, "全文検索 rust"));
+        assert!(matches(text, "ＲＵＳＴ"));
+        assert!(matches(text, ""));
+        assert!(!matches(text, "rus"));
+        assert!(!matches(text, "索検"));
+        assert!(!matches(text, "検索 go"));
+    }
+
+    #[test]
+    fn index_test() {
+        let mut index = TweetIndex::default();
+        index.insert("1", "東京都に住む");
+        index.insert("2", "京都に行く");
+        assert_eq!(index.candidates(""), None);
+        assert_eq!(
+            index.candidates("京都"),
+            Some(BTreeSet::from(["1".to_owned(), "2".to_owned()]))
+        );
+        assert_eq!(
+            index.candidates("東京"),
+            Some(BTreeSet::from(["1".to_owned()]))
+        );
+        assert_eq!(index.candidates("大阪"), Some(BTreeSet::new()));
+        index.remove("1", "東京都に住む");
+        assert_eq!(index.candidates("東京"), Some(BTreeSet::new()));
+        assert_eq!(
+            index.candidates("京都"),
+            Some(BTreeSet::from(["2".to_owned()]))
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use std::collections::{BTreeMap, HashMap};
+
+use time::UtcOffset;
+
+use crate::{tweet::Tweet, tweet_index};
+
+type UserId = String;
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct TweetStats {
+    pub tweets: usize,
+    pub retweets: usize,
+    // retweets / tweets
+    pub retweet_ratio: f64,
+    // `YYYY-MM-DD` (UTC) -> count
+    pub per_day: BTreeMap<String, usize>,
+    // `YYYY-MM` (UTC) -> count
+    pub per_month: BTreeMap<String, usize>,
+    // most used first
+    pub top_hashtags: Vec<Count>,
+    // most mentioned first. retweets are not included
+    pub top_mentions: Vec<Count>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Count {
+    pub name: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Default)]
+struct Counts {
+    tweets: usize,
+    retweets: usize,
+    days: HashMap<String, usize>,
+    hashtags: HashMap<String, usize>,
+    mentions: HashMap<String, usize>,
+}
+
+// the counts per user, updated on each stored tweet
+#[derive(Debug, Default)]
+pub(crate) struct TweetStatsProjection {
+    users: HashMap<UserId, Counts>,
+}
+
+fn day(tweet: &Tweet) -> Option<String> {
+    tweet.at().map(|at| {
+        let date = at.to_offset(UtcOffset::UTC).date();
+        format!(
+            "{:04}-{:02}-{:02}",
+            date.year(),
+            u8::from(date.month()),
+            date.day()
+        )
+    })
+}
+
+fn update(map: &mut HashMap<String, usize>, key: String, inserted: bool) {
+    let count = map.entry(key).or_default();
+    if inserted {
+        *count += 1;
+    } else {
+        *count = count.saturating_sub(1);
+    }
+}
+
+fn top(map: &HashMap<String, usize>, n: usize) -> Vec<Count> {
+    let mut counts = map
+        .iter()
+        .filter(|(_, count)| **count > 0)
+        .map(|(name, count)| Count {
+            name: name.clone(),
+            count: *count,
+        })
+        .collect::<Vec<Count>>();
+    counts.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+    counts.truncate(n);
+    counts
+}
+
+impl TweetStatsProjection {
+    pub(crate) fn insert(&mut self, tweet: &Tweet) {
+        self.update(tweet, true);
+    }
+
+    pub(crate) fn remove(&mut self, tweet: &Tweet) {
+        self.update(tweet, false);
+    }
+
+    fn update(&mut self, tweet: &Tweet, inserted: bool) {
+        let counts = self
+            .users
+            .entry(tweet.timeline_user_id().to_owned())
+            .or_default();
+        if inserted {
+            counts.tweets += 1;
+            counts.retweets += usize::from(tweet.retweet);
+        } else {
+            counts.tweets = counts.tweets.saturating_sub(1);
+            counts.retweets = counts.retweets.saturating_sub(usize::from(tweet.retweet));
+        }
+        if let Some(day) = day(tweet) {
+            update(&mut counts.days, day, inserted);
+        }
+        for hashtag in tweet.hashtags.iter() {
+            update(
+                &mut counts.hashtags,
+                tweet_index::normalize(hashtag),
+                inserted,
+            );
+        }
+        if !tweet.retweet {
+            for mention in tweet.mentions.iter() {
+                update(&mut counts.mentions, mention.clone(), inserted);
+            }
+        }
+    }
+
+    pub(crate) fn stats(&self, user_id: &str, n: usize) -> Option<TweetStats> {
+        let counts = self.users.get(user_id).filter(|counts| counts.tweets > 0)?;
+        let per_day = counts
+            .days
+            .iter()
+            .filter(|(_, count)| **count > 0)
+            .map(|(day, count)| (day.clone(), *count))
+            .collect::<BTreeMap<String, usize>>();
+        let mut per_month = BTreeMap::new();
+        for (day, count) in per_day.iter() {
+            *per_month.entry(day[0..7].to_owned()).or_default() += *count;
+        }
+        Some(TweetStats {
+            tweets: counts.tweets,
+            retweets: counts.retweets,
+            retweet_ratio: counts.retweets as f64 / counts.tweets as f64,
+            per_day,
+            per_month,
+            top_hashtags: top(&counts.hashtags, n),
+            top_mentions: top(&counts.mentions, n),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tweet(
+        tweet_id: &str,
+        at: &str,
+        retweet: bool,
+        hashtags: &[&str],
+        mentions: &[&str],
+    ) -> Tweet {
+        Tweet {
+            tweet_id: tweet_id.to_owned(),
+            author_id: if retweet { "2" } else { "1" }.to_owned(),
+            at: at.to_owned(),
+            retweet,
+            text: "".to_owned(),
+            hashtags: hashtags.iter().map(|s| s.to_string()).collect(),
+            user_id: "1".to_owned(),
+            mentions: mentions.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test() {
+        let mut projection = TweetStatsProjection::default();
+        assert_eq!(projection.stats("1", 10), None);
+
+        let tweet1 = tweet("1", "2022-01-01T10:00:00Z", false, &["Rust"], &["3"]);
+        let tweet2 = tweet("2", "2022-01-01T11:00:00Z", true, &["rust"], &["2"]);
+        let tweet3 = tweet(
+            "3",
+            "2022-02-03T08:00:00+09:00",
+            false,
+            &["go"],
+            &["3", "4"],
+        );
+        for tweet in [&tweet1, &tweet2, &tweet3] {
+            projection.insert(tweet);
+        }
+        let count = |name: &str, count: usize| Count {
+            name: name.to_owned(),
+            count,
+        };
+        let stats = projection.stats("1", 10);
+        assert_eq!(
+            stats,
+            Some(TweetStats {
+                tweets: 3,
+                retweets: 1,
+                retweet_ratio: 1.0 / 3.0,
+                per_day: BTreeMap::from([
+                    ("2022-01-01".to_owned(), 2),
+                    ("2022-02-02".to_owned(), 1)
+                ]),
+                per_month: BTreeMap::from([("2022-01".to_owned(), 2), ("2022-02".to_owned(), 1)]),
+                top_hashtags: vec![count("rust", 2), count("go", 1)],
+                top_mentions: vec![count("3", 2), count("4", 1)],
+            })
+        );
+        assert_eq!(
+            projection.stats("1", 1).map(|stats| stats.top_hashtags),
+            Some(vec![count("rust", 2)])
+        );
+        assert_eq!(projection.stats("2", 10), None);
+
+        projection.remove(&tweet3);
+        let stats = projection.stats("1", 10).unwrap();
+        assert_eq!(stats.tweets, 2);
+        assert_eq!(stats.per_month, BTreeMap::from([("2022-01".to_owned(), 2)]));
+        assert_eq!(stats.top_hashtags, vec![count("rust", 2)]);
+        assert_eq!(stats.top_mentions, vec![count("3", 1)]);
+    }
+}
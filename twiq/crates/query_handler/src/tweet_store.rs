@@ -0,0 +1,153 @@
+use async_trait::async_trait;
+use time::OffsetDateTime;
+
+use crate::{tweet::Tweet, tweet_index, tweet_stats::TweetStats};
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum Error {
+    #[error("unknown {0}")]
+    Unknown(String),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TweetQuery {
+    pub text: String,
+    // inclusive
+    pub since: Option<OffsetDateTime>,
+    // exclusive
+    pub until: Option<OffsetDateTime>,
+    // without `#`
+    pub hashtag: Option<String>,
+    pub retweet: Option<bool>,
+    pub author_id: Option<String>,
+    // the owner of the timeline
+    pub user_id: Option<String>,
+    pub limit: Option<usize>,
+}
+
+impl TweetQuery {
+    pub fn matches(&self, tweet: &Tweet) -> bool {
+        if self.since.is_some() || self.until.is_some() {
+            let at = match tweet.at() {
+                Some(at) => at,
+                None => return false,
+            };
+            if self.since.iter().any(|since| at < *since)
+                || self.until.iter().any(|until| *until <= at)
+            {
+                return false;
+            }
+        }
+        if let Some(hashtag) = self.hashtag.as_ref() {
+            let hashtag = tweet_index::normalize(hashtag.trim_start_matches('#'));
+            if !tweet
+                .hashtags
+                .iter()
+                .any(|h| tweet_index::normalize(h) == hashtag)
+            {
+                return false;
+            }
+        }
+        if self.retweet.iter().any(|retweet| *retweet != tweet.retweet)
+            || self
+                .author_id
+                .iter()
+                .any(|author_id| *author_id != tweet.author_id)
+            || self
+                .user_id
+                .iter()
+                .any(|user_id| user_id != tweet.timeline_user_id())
+        {
+            return false;
+        }
+        tweet_index::matches(tweet.text.as_str(), self.text.as_str())
+    }
+}
+
+#[async_trait]
+pub trait TweetStore {
+    async fn find_by_tweet_id(&self, tweet_id: &str) -> Result<Option<Tweet>>;
+    // newest first
+    async fn search(&self, query: &TweetQuery) -> Result<Vec<Tweet>>;
+    // the timeline of the user. `top` is the number of hashtags and mentions
+    async fn stats(&self, user_id: &str, top: usize) -> Result<Option<TweetStats>>;
+    async fn store(&self, tweet: Tweet) -> Result<()>;
+}
+
+pub trait HasTweetStore {
+    type TweetStore: TweetStore + Send + Sync;
+
+    fn tweet_store(&self) -> &Self::TweetStore;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_test() -> anyhow::Result<()> {
+        let tweet = Tweet {
+            tweet_id: "1".to_owned(),
+            author_id: "125962981".to_owned(),
+            at: "2022-01-02T03:04:05.000000000Z".to_owned(),
+            retweet: false,
+            text: "全文検索 #Rust".to_owned(),
+            hashtags: vec!["Rust".to_owned()],
+            user_id: "125962981".to_owned(),
+            mentions: vec![],
+        };
+        let at = |s: &str| {
+            OffsetDateTime::parse(s, &time::format_description::well_known::Iso8601::DEFAULT)
+        };
+        assert!(TweetQuery::default().matches(&tweet));
+        let query = TweetQuery {
+            text: "検索".to_owned(),
+            since: Some(at("2022-01-02T00:00:00Z")?),
+            until: Some(at("2022-01-03T00:00:00Z")?),
+            hashtag: Some("#rust".to_owned()),
+            retweet: Some(false),
+            author_id: Some("125962981".to_owned()),
+            user_id: Some("125962981".to_owned()),
+            limit: None,
+        };
+        assert!(query.matches(&tweet));
+        assert!(!TweetQuery {
+            since: Some(at("2022-01-02T03:04:06Z")?),
+            ..query.clone()
+        }
+        .matches(&tweet));
+        assert!(!TweetQuery {
+            until: Some(at("2022-01-02T03:04:05Z")?),
+            ..query.clone()
+        }
+        .matches(&tweet));
+        assert!(!TweetQuery {
+            hashtag: Some("go".to_owned()),
+            ..query.clone()
+        }
+        .matches(&tweet));
+        assert!(!TweetQuery {
+            retweet: Some(true),
+            ..query.clone()
+        }
+        .matches(&tweet));
+        assert!(!TweetQuery {
+            author_id: Some("1".to_owned()),
+            ..query.clone()
+        }
+        .matches(&tweet));
+        assert!(!TweetQuery {
+            user_id: Some("1".to_owned()),
+            ..query.clone()
+        }
+        .matches(&tweet));
+        assert!(!TweetQuery {
+            text: "検査".to_owned(),
+            ..query
+        }
+        .matches(&tweet));
+        Ok(())
+    }
+}
@@ -36,7 +36,7 @@ pub trait Has: Context + Sized {
     }
 }
 
-async fn handle<C: Context>(
+pub async fn handle<C: Context>(
     context: &C,
     event: domain::Event,
 ) -> Result<(), Box<dyn std::error::Error>> {
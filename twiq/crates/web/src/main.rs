@@ -1,6 +1,6 @@
 mod router;
 
-use std::{env, sync::Arc};
+use std::{env, fs, sync::Arc};
 
 use axum::{Extension, Server};
 use command_handler::{
@@ -9,42 +9,65 @@ use command_handler::{
     user_request_repository::HasUserRequestRepository,
 };
 use db::{
-    config::Config, firestore_user_repository::FirestoreUserRepository,
-    firestore_user_request_repository::FirestoreUserRequestRepository,
-    firestore_user_store::FirestoreUserStore,
-    firestore_worker_repository::FirestoreWorkerRepository,
+    config::Backend,
+    database::{
+        AnyUserRepository, AnyUserRequestRepository, AnyUserStore, AnyWorkerRepository, Database,
+    },
+};
+use query_handler::{
+    in_memory_tweet_store::InMemoryTweetStore,
+    tweet_store::{HasTweetStore, TweetStore},
+    update_query_user,
+    user_store::HasUserStore,
 };
-use query_handler::{update_query_user, user_store::HasUserStore};
 use tower::ServiceBuilder;
 use tower_http::trace::{DefaultMakeSpan, DefaultOnRequest, DefaultOnResponse, TraceLayer};
 use tracing::{info, Level};
 use worker_helper::worker_repository::HasWorkerRepository;
 
 struct App {
-    user_repository: FirestoreUserRepository,
-    user_request_repository: FirestoreUserRequestRepository,
-    user_store: FirestoreUserStore,
-    worker_repository: FirestoreWorkerRepository,
+    tweet_store: InMemoryTweetStore,
+    user_repository: AnyUserRepository,
+    user_request_repository: AnyUserRequestRepository,
+    user_store: AnyUserStore,
+    worker_repository: AnyWorkerRepository,
 }
 
-impl Default for App {
-    fn default() -> Self {
-        let config = Config::load_from_env();
-        let user_repository = FirestoreUserRepository::new(config.clone());
-        let user_request_repository = FirestoreUserRequestRepository::new(config.clone());
-        let user_store = FirestoreUserStore::new(config.clone());
-        let worker_repository = FirestoreWorkerRepository::new(config);
+impl App {
+    fn new(database: &Database) -> Self {
         Self {
-            user_repository,
-            user_request_repository,
-            user_store,
-            worker_repository,
+            tweet_store: InMemoryTweetStore::default(),
+            user_repository: database.user_repository(),
+            user_request_repository: database.user_request_repository(),
+            user_store: database.user_store(),
+            worker_repository: database.worker_repository(),
+        }
+    }
+}
+
+impl App {
+    // JSON Lines of `query_handler::tweet::Tweet` (the output of import_twitter_data)
+    async fn load_tweets(&self, path: &str) -> anyhow::Result<()> {
+        for line in fs::read_to_string(path)?.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            self.tweet_store.store(serde_json::from_str(line)?).await?;
         }
+        Ok(())
+    }
+}
+
+impl HasTweetStore for App {
+    type TweetStore = InMemoryTweetStore;
+
+    fn tweet_store(&self) -> &Self::TweetStore {
+        &self.tweet_store
     }
 }
 
 impl HasUserRepository for App {
-    type UserRepository = FirestoreUserRepository;
+    type UserRepository = AnyUserRepository;
 
     fn user_repository(&self) -> &Self::UserRepository {
         &self.user_repository
@@ -52,7 +75,7 @@ impl HasUserRepository for App {
 }
 
 impl HasUserRequestRepository for App {
-    type UserRequestRepository = FirestoreUserRequestRepository;
+    type UserRequestRepository = AnyUserRequestRepository;
 
     fn user_request_repository(&self) -> &Self::UserRequestRepository {
         &self.user_request_repository
@@ -60,7 +83,7 @@ impl HasUserRequestRepository for App {
 }
 
 impl HasWorkerRepository for App {
-    type WorkerRepository = FirestoreWorkerRepository;
+    type WorkerRepository = AnyWorkerRepository;
 
     fn worker_repository(&self) -> &Self::WorkerRepository {
         &self.worker_repository
@@ -68,7 +91,7 @@ impl HasWorkerRepository for App {
 }
 
 impl HasUserStore for App {
-    type UserStore = FirestoreUserStore;
+    type UserStore = AnyUserStore;
 
     fn user_store(&self) -> &Self::UserStore {
         &self.user_store
@@ -84,7 +107,11 @@ impl update_user::Has for App {}
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     tracing_subscriber::fmt::init();
-    let app = App::default();
+    let database = Database::connect(Backend::load_from_env()?).await?;
+    let app = App::new(&database);
+    if let Ok(path) = env::var("TWEETS_FILE") {
+        app.load_tweets(path.as_str()).await?;
+    }
     let app = Arc::new(app);
     let app = router::router::<App>().layer(
         ServiceBuilder::new()
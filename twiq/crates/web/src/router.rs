@@ -1,10 +1,13 @@
 mod healthz;
+mod tweets_search;
 mod users_show;
+mod users_stats;
+mod users_tweets;
 mod worker;
 
 use axum::Router;
 use command_handler::command::{create_user_request, request_user, send_user_request, update_user};
-use query_handler::{update_query_user, user_store::HasUserStore};
+use query_handler::{tweet_store::HasTweetStore, update_query_user, user_store::HasUserStore};
 
 pub(crate) fn router<T>() -> Router
 where
@@ -13,6 +16,7 @@ where
         + send_user_request::Has
         + update_query_user::Has
         + update_user::Has
+        + HasTweetStore
         + HasUserStore
         + Send
         + Sync
@@ -20,7 +24,10 @@ where
 {
     Router::new()
         .merge(healthz::router())
+        .merge(tweets_search::router::<T>())
         .merge(users_show::router::<T>())
+        .merge(users_stats::router::<T>())
+        .merge(users_tweets::router::<T>())
     // .merge(worker::router::<T>())
 }
 
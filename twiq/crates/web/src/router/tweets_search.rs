@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use axum::{extract::Query, http::StatusCode, routing, Extension, Json, Router};
+use query_handler::{
+    tweet::Tweet,
+    tweet_store::{HasTweetStore, TweetQuery, TweetStore},
+};
+use time::{
+    format_description::{self, well_known::Iso8601},
+    Date, Duration, OffsetDateTime,
+};
+
+pub(crate) fn router<T>() -> Router
+where
+    T: HasTweetStore + Send + Sync + 'static,
+{
+    Router::new().route("/tweets/search", routing::get(tweets_search::<T>))
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Params {
+    q: Option<String>,
+    since: Option<String>,
+    until: Option<String>,
+    hashtag: Option<String>,
+    retweet: Option<bool>,
+    author_id: Option<String>,
+    limit: Option<usize>,
+}
+
+// `YYYY-MM-DD` (UTC) or ISO 8601 date and time
+fn parse_date_time(s: &str) -> Option<(OffsetDateTime, bool)> {
+    let date_format = format_description::parse("[year]-[month]-[day]").ok()?;
+    match Date::parse(s, &date_format) {
+        Ok(date) => Some((date.midnight().assume_utc(), true)),
+        Err(_) => OffsetDateTime::parse(s, &Iso8601::DEFAULT)
+            .ok()
+            .map(|at| (at, false)),
+    }
+}
+
+// `since` (inclusive) and `until` (exclusive). a date `until` includes the whole day
+pub(super) fn parse_range(
+    since: Option<String>,
+    until: Option<String>,
+) -> Result<(Option<OffsetDateTime>, Option<OffsetDateTime>), (StatusCode, String)> {
+    let bad_request = |name: &str| (StatusCode::BAD_REQUEST, format!("invalid {}", name));
+    let since = since
+        .map(|s| parse_date_time(s.as_str()).ok_or_else(|| bad_request("since")))
+        .transpose()?
+        .map(|(at, _)| at);
+    let until = until
+        .map(|s| parse_date_time(s.as_str()).ok_or_else(|| bad_request("until")))
+        .transpose()?
+        .map(|(at, is_date)| if is_date { at + Duration::DAY } else { at });
+    Ok((since, until))
+}
+
+async fn tweets_search<T>(
+    Extension(application): Extension<Arc<T>>,
+    Query(params): Query<Params>,
+) -> Result<Json<Vec<Tweet>>, (StatusCode, String)>
+where
+    T: HasTweetStore + Send + Sync,
+{
+    let (since, until) = parse_range(params.since, params.until)?;
+    let query = TweetQuery {
+        text: params.q.unwrap_or_default(),
+        since,
+        until,
+        hashtag: params.hashtag,
+        retweet: params.retweet,
+        author_id: params.author_id,
+        user_id: None,
+        limit: params.limit,
+    };
+    let tweets = application
+        .tweet_store()
+        .search(&query)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    Ok(Json(tweets))
+}
+
+#[cfg(test)]
+mod tests {
+    use query_handler::in_memory_tweet_store::InMemoryTweetStore;
+
+    use crate::router::tests::test_get_request;
+
+    use super::*;
+
+    struct MockApp {
+        tweet_store: InMemoryTweetStore,
+    }
+
+    impl HasTweetStore for MockApp {
+        type TweetStore = InMemoryTweetStore;
+
+        fn tweet_store(&self) -> &Self::TweetStore {
+            &self.tweet_store
+        }
+    }
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let application = MockApp {
+            tweet_store: InMemoryTweetStore::default(),
+        };
+        for (tweet_id, at, retweet, text, hashtags) in [
+            (
+                "1",
+                "2022-01-01T10:00:00Z",
+                false,
+                "全文検索 #rust",
+                vec!["rust"],
+            ),
+            (
+                "2",
+                "2022-01-02T10:00:00Z",
+                true,
+                "@bouzuya: 検索する",
+                vec![],
+            ),
+            ("3", "2022-01-03T10:00:00Z", false, "Hello", vec![]),
+        ] {
+            application
+                .tweet_store
+                .store(Tweet {
+                    tweet_id: tweet_id.to_owned(),
+                    author_id: if retweet { "2" } else { "1" }.to_owned(),
+                    at: at.to_owned(),
+                    retweet,
+                    text: text.to_owned(),
+                    hashtags: hashtags.into_iter().map(|h| h.to_owned()).collect(),
+                    user_id: "1".to_owned(),
+                    mentions: vec![],
+                })
+                .await?;
+        }
+        let router = router::<MockApp>().layer(Extension(Arc::new(application)));
+        let search = |uri: &'static str| {
+            let router = router.clone();
+            async move {
+                let (status, body) = test_get_request(router, uri).await?;
+                anyhow::ensure!(status == StatusCode::OK, "{} {}", status, body);
+                let tweets = serde_json::from_str::<Vec<Tweet>>(body.as_str())?;
+                anyhow::Ok(
+                    tweets
+                        .into_iter()
+                        .map(|tweet| tweet.tweet_id)
+                        .collect::<Vec<String>>(),
+                )
+            }
+        };
+        assert_eq!(search("/tweets/search").await?, vec!["3", "2", "1"]);
+        assert_eq!(
+            search("/tweets/search?q=%E6%A4%9C%E7%B4%A2").await?,
+            vec!["2", "1"]
+        );
+        assert_eq!(
+            search("/tweets/search?q=%E6%A4%9C%E7%B4%A2&retweet=false").await?,
+            vec!["1"]
+        );
+        assert_eq!(search("/tweets/search?hashtag=Rust").await?, vec!["1"]);
+        assert_eq!(search("/tweets/search?author_id=2").await?, vec!["2"]);
+        assert_eq!(
+            search("/tweets/search?since=2022-01-02&until=2022-01-02").await?,
+            vec!["2"]
+        );
+        assert_eq!(
+            search("/tweets/search?until=2022-01-02T10:00:00Z").await?,
+            vec!["1"]
+        );
+        assert_eq!(search("/tweets/search?limit=1").await?, vec!["3"]);
+
+        let (status, _) = test_get_request(router, "/tweets/search?since=yesterday").await?;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        Ok(())
+    }
+}
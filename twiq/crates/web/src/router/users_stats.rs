@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    routing, Extension, Json, Router,
+};
+use query_handler::{
+    tweet_stats::TweetStats,
+    tweet_store::{HasTweetStore, TweetStore},
+};
+
+const DEFAULT_TOP: usize = 10;
+
+pub(crate) fn router<T>() -> Router
+where
+    T: HasTweetStore + Send + Sync + 'static,
+{
+    Router::new().route("/users/:id/stats", routing::get(users_stats::<T>))
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Params {
+    // the number of hashtags and mentions
+    top: Option<usize>,
+}
+
+async fn users_stats<T>(
+    Extension(application): Extension<Arc<T>>,
+    Path(id): Path<String>,
+    Query(params): Query<Params>,
+) -> Result<Json<TweetStats>, (StatusCode, String)>
+where
+    T: HasTweetStore + Send + Sync,
+{
+    let stats = application
+        .tweet_store()
+        .stats(id.as_str(), params.top.unwrap_or(DEFAULT_TOP))
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    match stats {
+        None => Err((StatusCode::NOT_FOUND, id)),
+        Some(stats) => Ok(Json(stats)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use query_handler::{in_memory_tweet_store::InMemoryTweetStore, tweet::Tweet};
+
+    use crate::router::tests::test_get_request;
+
+    use super::*;
+
+    struct MockApp {
+        tweet_store: InMemoryTweetStore,
+    }
+
+    impl HasTweetStore for MockApp {
+        type TweetStore = InMemoryTweetStore;
+
+        fn tweet_store(&self) -> &Self::TweetStore {
+            &self.tweet_store
+        }
+    }
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let application = MockApp {
+            tweet_store: InMemoryTweetStore::default(),
+        };
+        for (tweet_id, at, retweet, hashtags) in [
+            ("1", "2022-01-01T10:00:00Z", false, vec!["rust", "go"]),
+            ("2", "2022-01-02T10:00:00Z", true, vec!["rust"]),
+        ] {
+            application
+                .tweet_store
+                .store(Tweet {
+                    tweet_id: tweet_id.to_owned(),
+                    author_id: if retweet { "2" } else { "1" }.to_owned(),
+                    at: at.to_owned(),
+                    retweet,
+                    text: "".to_owned(),
+                    hashtags: hashtags.into_iter().map(|h| h.to_owned()).collect(),
+                    user_id: "1".to_owned(),
+                    mentions: vec![],
+                })
+                .await?;
+        }
+        let router = router::<MockApp>().layer(Extension(Arc::new(application)));
+
+        let (status, body) = test_get_request(router.clone(), "/users/1/stats?top=1").await?;
+        assert_eq!(status, StatusCode::OK);
+        let stats = serde_json::from_str::<TweetStats>(body.as_str())?;
+        assert_eq!((stats.tweets, stats.retweets), (2, 1));
+        assert_eq!(stats.retweet_ratio, 0.5);
+        assert_eq!(stats.per_month.get("2022-01"), Some(&2));
+        assert_eq!(
+            stats
+                .top_hashtags
+                .into_iter()
+                .map(|count| (count.name, count.count))
+                .collect::<Vec<(String, usize)>>(),
+            vec![("rust".to_owned(), 2)]
+        );
+
+        let (status, _) = test_get_request(router, "/users/2/stats").await?;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    routing, Extension, Json, Router,
+};
+use query_handler::{
+    tweet::Tweet,
+    tweet_store::{HasTweetStore, TweetQuery, TweetStore},
+};
+
+use super::tweets_search::parse_range;
+
+const DEFAULT_PER_PAGE: usize = 20;
+const MAX_PER_PAGE: usize = 200;
+
+pub(crate) fn router<T>() -> Router
+where
+    T: HasTweetStore + Send + Sync + 'static,
+{
+    Router::new().route("/users/:id/tweets", routing::get(users_tweets::<T>))
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Params {
+    since: Option<String>,
+    until: Option<String>,
+    // 1-origin
+    page: Option<usize>,
+    per_page: Option<usize>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+struct TweetsPage {
+    // newest first
+    tweets: Vec<Tweet>,
+    page: usize,
+    per_page: usize,
+    total: usize,
+}
+
+async fn users_tweets<T>(
+    Extension(application): Extension<Arc<T>>,
+    Path(id): Path<String>,
+    Query(params): Query<Params>,
+) -> Result<Json<TweetsPage>, (StatusCode, String)>
+where
+    T: HasTweetStore + Send + Sync,
+{
+    let bad_request = |name: &str| (StatusCode::BAD_REQUEST, format!("invalid {}", name));
+    let (since, until) = parse_range(params.since, params.until)?;
+    let page = params.page.unwrap_or(1);
+    if page == 0 {
+        return Err(bad_request("page"));
+    }
+    let per_page = params.per_page.unwrap_or(DEFAULT_PER_PAGE);
+    if !(1..=MAX_PER_PAGE).contains(&per_page) {
+        return Err(bad_request("per_page"));
+    }
+    let query = TweetQuery {
+        since,
+        until,
+        user_id: Some(id),
+        ..Default::default()
+    };
+    let tweets = application
+        .tweet_store()
+        .search(&query)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let total = tweets.len();
+    let tweets = tweets
+        .into_iter()
+        .skip((page - 1).saturating_mul(per_page))
+        .take(per_page)
+        .collect::<Vec<Tweet>>();
+    Ok(Json(TweetsPage {
+        tweets,
+        page,
+        per_page,
+        total,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use query_handler::in_memory_tweet_store::InMemoryTweetStore;
+
+    use crate::router::tests::test_get_request;
+
+    use super::*;
+
+    struct MockApp {
+        tweet_store: InMemoryTweetStore,
+    }
+
+    impl HasTweetStore for MockApp {
+        type TweetStore = InMemoryTweetStore;
+
+        fn tweet_store(&self) -> &Self::TweetStore {
+            &self.tweet_store
+        }
+    }
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let application = MockApp {
+            tweet_store: InMemoryTweetStore::default(),
+        };
+        for (tweet_id, at, user_id) in [
+            ("1", "2022-01-01T10:00:00Z", "1"),
+            ("2", "2022-01-02T10:00:00Z", "1"),
+            ("3", "2022-01-03T10:00:00Z", "1"),
+            ("4", "2022-01-03T11:00:00Z", "2"),
+        ] {
+            application
+                .tweet_store
+                .store(Tweet {
+                    tweet_id: tweet_id.to_owned(),
+                    author_id: user_id.to_owned(),
+                    at: at.to_owned(),
+                    retweet: false,
+                    text: "".to_owned(),
+                    hashtags: vec![],
+                    user_id: user_id.to_owned(),
+                    mentions: vec![],
+                })
+                .await?;
+        }
+        let router = router::<MockApp>().layer(Extension(Arc::new(application)));
+        let get = |uri: &'static str| {
+            let router = router.clone();
+            async move {
+                let (status, body) = test_get_request(router, uri).await?;
+                anyhow::ensure!(status == StatusCode::OK, "{} {}", status, body);
+                let page = serde_json::from_str::<TweetsPage>(body.as_str())?;
+                anyhow::Ok(
+                    page.tweets
+                        .into_iter()
+                        .map(|tweet| tweet.tweet_id)
+                        .collect::<Vec<String>>(),
+                )
+            }
+        };
+        assert_eq!(get("/users/1/tweets").await?, vec!["3", "2", "1"]);
+        assert_eq!(get("/users/1/tweets?per_page=2").await?, vec!["3", "2"]);
+        assert_eq!(get("/users/1/tweets?per_page=2&page=2").await?, vec!["1"]);
+        assert!(get("/users/1/tweets?per_page=2&page=3").await?.is_empty());
+        assert_eq!(
+            get("/users/1/tweets?until=2022-01-02").await?,
+            vec!["2", "1"]
+        );
+        assert_eq!(get("/users/2/tweets").await?, vec!["4"]);
+
+        let (_, body) = test_get_request(router.clone(), "/users/1/tweets?per_page=1").await?;
+        let page = serde_json::from_str::<TweetsPage>(body.as_str())?;
+        assert_eq!((page.page, page.per_page, page.total), (1, 1, 3));
+
+        for uri in [
+            "/users/1/tweets?page=0",
+            "/users/1/tweets?per_page=0",
+            "/users/1/tweets?per_page=201",
+            "/users/1/tweets?since=yesterday",
+        ] {
+            let (status, _) = test_get_request(router.clone(), uri).await?;
+            assert_eq!(status, StatusCode::BAD_REQUEST);
+        }
+        Ok(())
+    }
+}
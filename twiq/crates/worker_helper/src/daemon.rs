@@ -0,0 +1,279 @@
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use tracing::{info, instrument, warn};
+
+use crate::{
+    worker_helper::{Error, Result, WorkerDeps},
+    worker_repository::{DeadLetter, WorkerName, WorkerRepository},
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(300),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// initial_backoff, initial_backoff * 2, initial_backoff * 4, ... (<= max_backoff)
+    pub fn backoff(&self, attempts: u32) -> Duration {
+        let factor = 2_u32.saturating_pow(attempts.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Serialize)]
+pub struct WorkerStatus {
+    /// the number of events not yet handled
+    pub lag: usize,
+    pub processed: u64,
+    pub failed: u64,
+    pub dead_lettered: u64,
+    /// the number of failed attempts of the next event
+    pub attempts: u32,
+    pub last_event_id: Option<String>,
+    pub last_error: Option<String>,
+    #[serde(skip)]
+    retry_at: Option<Instant>,
+}
+
+/// Handles the events after the last event of the worker.
+///
+/// Unlike `worker`, a failed event does not fail the call. It is retried by the later calls
+/// after the backoff, and is stored as a dead letter and skipped after `max_attempts` attempts.
+#[instrument(skip(context, handle, policy, status))]
+pub async fn poll<'a, C, F, Fut>(
+    context: &'a C,
+    worker_name: WorkerName,
+    handle: F,
+    policy: &RetryPolicy,
+    status: &mut WorkerStatus,
+) -> Result<()>
+where
+    C: WorkerDeps,
+    F: Fn(&'a C, domain::Event) -> Fut,
+    Fut: Future<Output = Result<(), Box<dyn std::error::Error + 'static>>>,
+{
+    if let Some(retry_at) = status.retry_at {
+        if Instant::now() < retry_at {
+            return Ok(());
+        }
+    }
+
+    let worker_repository = context.worker_repository();
+    let mut last_event_id = worker_repository.find_last_event_id(worker_name).await?;
+    let event_ids = worker_repository.find_event_ids(last_event_id).await?;
+    for event_id in event_ids {
+        if Some(event_id) == last_event_id {
+            continue;
+        }
+
+        let event = worker_repository
+            .find_event(event_id)
+            .await?
+            .ok_or_else(|| Error::Unknown("event not found".to_owned()))?;
+        let result = match domain::Event::try_from(event) {
+            Ok(event) => handle(context, event).await.map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        status.retry_at = None;
+        match result {
+            Ok(()) => {
+                status.processed += 1;
+                status.attempts = 0;
+            }
+            Err(e) => {
+                status.failed += 1;
+                status.attempts += 1;
+                status.last_error = Some(e.clone());
+                if status.attempts < policy.max_attempts {
+                    let backoff = policy.backoff(status.attempts);
+                    warn!(
+                        "{} failed (attempt {}/{}), retry after {:?} : {}",
+                        event_id, status.attempts, policy.max_attempts, backoff, e
+                    );
+                    status.retry_at = Some(Instant::now() + backoff);
+                    break;
+                }
+
+                warn!("{} moved to dead letters : {}", event_id, e);
+                worker_repository
+                    .store_dead_letter(DeadLetter {
+                        worker_name,
+                        event_id,
+                        attempts: status.attempts,
+                        error: e,
+                    })
+                    .await?;
+                status.dead_lettered += 1;
+                status.attempts = 0;
+            }
+        }
+
+        worker_repository
+            .store_last_event_id(worker_name, last_event_id, event_id)
+            .await?;
+        last_event_id = Some(event_id);
+    }
+
+    status.last_event_id = last_event_id.map(|event_id| event_id.to_string());
+    status.lag = worker_repository
+        .find_event_ids(last_event_id)
+        .await?
+        .into_iter()
+        .filter(|event_id| Some(*event_id) != last_event_id)
+        .count();
+    info!("{} {:?}", worker_name, status);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use domain::aggregate::user::{TwitterUserId, TwitterUserName, User};
+    use event_store_core::{
+        event_store::EventStore, in_memory_event_store::InMemoryEventStore, EventStream,
+    };
+
+    use crate::{
+        in_memory_worker_repository::InMemoryWorkerRepository,
+        worker_repository::HasWorkerRepository,
+    };
+
+    use super::*;
+
+    struct App {
+        worker_repository: InMemoryWorkerRepository,
+    }
+
+    impl HasWorkerRepository for App {
+        type WorkerRepository = InMemoryWorkerRepository;
+
+        fn worker_repository(&self) -> &Self::WorkerRepository {
+            &self.worker_repository
+        }
+    }
+
+    async fn handle(
+        _: &App,
+        event: domain::Event,
+    ) -> Result<(), Box<dyn std::error::Error + 'static>> {
+        match event {
+            domain::Event::UserUpdated(_) => Err("poison".into()),
+            _ => Ok(()),
+        }
+    }
+
+    #[test]
+    fn backoff_test() {
+        let policy = RetryPolicy {
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(5),
+        };
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(3), Duration::from_secs(4));
+        assert_eq!(policy.backoff(4), Duration::from_secs(5));
+        assert_eq!(policy.backoff(100), Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn poll_test() -> anyhow::Result<()> {
+        let event_store = InMemoryEventStore::default();
+        let app = App {
+            worker_repository: InMemoryWorkerRepository::new(event_store.clone()),
+        };
+        let worker_name = WorkerName::UpdateQueryUser;
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+        };
+        let mut status = WorkerStatus::default();
+
+        // UserCreated, UserUpdated (poison)
+        let user = User::create(TwitterUserId::from_str("125962981")?)?;
+        let created = EventStream::from(user.clone());
+        event_store.store(None, created.clone()).await?;
+        let updated = EventStream::from(user.update(TwitterUserName::from_str("bouzuya")?)?);
+        event_store
+            .store(Some(created.seq()), updated.clone())
+            .await?;
+        let event_ids = updated
+            .events()
+            .into_iter()
+            .map(|event| event.id())
+            .collect::<Vec<_>>();
+
+        poll(&app, worker_name, handle, &policy, &mut status).await?;
+        assert_eq!(status.processed, 1);
+        assert_eq!(status.failed, 1);
+        assert_eq!(status.attempts, 1);
+        assert_eq!(status.lag, 1);
+        assert_eq!(status.last_event_id, Some(event_ids[0].to_string()));
+
+        poll(&app, worker_name, handle, &policy, &mut status).await?;
+        assert_eq!(status.failed, 2);
+        assert_eq!(status.attempts, 2);
+        assert!(app
+            .worker_repository
+            .find_dead_letters(worker_name)
+            .await
+            .is_empty());
+
+        poll(&app, worker_name, handle, &policy, &mut status).await?;
+        assert_eq!(status.processed, 1);
+        assert_eq!(status.failed, 3);
+        assert_eq!(status.dead_lettered, 1);
+        assert_eq!(status.attempts, 0);
+        assert_eq!(status.lag, 0);
+        assert_eq!(status.last_event_id, Some(event_ids[1].to_string()));
+        assert_eq!(
+            app.worker_repository.find_dead_letters(worker_name).await,
+            vec![DeadLetter {
+                worker_name,
+                event_id: event_ids[1],
+                attempts: 3,
+                error: "poison".to_owned(),
+            }]
+        );
+        assert_eq!(
+            app.worker_repository
+                .find_last_event_id(worker_name)
+                .await?,
+            Some(event_ids[1])
+        );
+
+        // waits for the backoff
+        let worker_name = WorkerName::UpdateUser;
+        let policy = RetryPolicy {
+            initial_backoff: Duration::from_secs(60),
+            max_backoff: Duration::from_secs(60),
+            ..policy
+        };
+        let mut status = WorkerStatus::default();
+        poll(&app, worker_name, handle, &policy, &mut status).await?;
+        assert_eq!(status.failed, 1);
+        poll(&app, worker_name, handle, &policy, &mut status).await?;
+        assert_eq!(status.failed, 1);
+        assert_eq!(status.attempts, 1);
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, sync::Arc};
 
-use crate::worker_repository::{Error, Result, WorkerName, WorkerRepository};
+use crate::worker_repository::{DeadLetter, Error, Result, WorkerName, WorkerRepository};
 use async_trait::async_trait;
 use event_store_core::{
     event_store::EventStore, in_memory_event_store::InMemoryEventStore, Event, EventId,
@@ -12,6 +12,7 @@ use tracing::{debug, instrument};
 pub struct InMemoryWorkerRepository {
     event_store: InMemoryEventStore,
     data: Arc<Mutex<HashMap<WorkerName, EventId>>>,
+    dead_letters: Arc<Mutex<Vec<DeadLetter>>>,
 }
 
 impl InMemoryWorkerRepository {
@@ -19,8 +20,18 @@ impl InMemoryWorkerRepository {
         Self {
             event_store: empty_event_store,
             data: Default::default(),
+            dead_letters: Default::default(),
         }
     }
+
+    pub async fn find_dead_letters(&self, worker_name: WorkerName) -> Vec<DeadLetter> {
+        let dead_letters = self.dead_letters.lock().await;
+        dead_letters
+            .iter()
+            .filter(|dead_letter| dead_letter.worker_name == worker_name)
+            .cloned()
+            .collect()
+    }
 }
 
 #[async_trait]
@@ -73,6 +84,15 @@ impl WorkerRepository for InMemoryWorkerRepository {
             }
         }
     }
+
+    async fn store_dead_letter(&self, dead_letter: DeadLetter) -> Result<()> {
+        let mut dead_letters = self.dead_letters.lock().await;
+        dead_letters.retain(|d| {
+            (d.worker_name, d.event_id) != (dead_letter.worker_name, dead_letter.event_id)
+        });
+        dead_letters.push(dead_letter);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -114,6 +134,20 @@ mod tests {
             Some(event_id2)
         );
         assert!(repository.find_last_event_id(worker_name2).await?.is_none());
+
+        let dead_letter = DeadLetter {
+            worker_name: worker_name1,
+            event_id: event_id1,
+            attempts: 3,
+            error: "error".to_owned(),
+        };
+        repository.store_dead_letter(dead_letter.clone()).await?;
+        repository.store_dead_letter(dead_letter.clone()).await?;
+        assert_eq!(
+            repository.find_dead_letters(worker_name1).await,
+            vec![dead_letter]
+        );
+        assert!(repository.find_dead_letters(worker_name2).await.is_empty());
         Ok(())
     }
 }
@@ -1,3 +1,4 @@
+pub mod daemon;
 pub mod in_memory_worker_repository;
 pub mod worker_helper;
 pub mod worker_repository;
@@ -34,6 +34,15 @@ impl Display for WorkerName {
     }
 }
 
+/// An event that the worker gave up handling.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeadLetter {
+    pub worker_name: WorkerName,
+    pub event_id: EventId,
+    pub attempts: u32,
+    pub error: String,
+}
+
 #[async_trait]
 pub trait WorkerRepository {
     async fn find_last_event_id(&self, worker_name: WorkerName) -> Result<Option<EventId>>;
@@ -43,6 +52,7 @@ pub trait WorkerRepository {
         before: Option<EventId>,
         after: EventId,
     ) -> Result<()>;
+    async fn store_dead_letter(&self, dead_letter: DeadLetter) -> Result<()>;
     //
     async fn find_event_ids(&self, event_id: Option<EventId>) -> Result<Vec<EventId>>;
     async fn find_event(&self, event_id: EventId) -> Result<Option<Event>>;